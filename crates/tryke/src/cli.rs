@@ -1,6 +1,10 @@
+use std::ffi::OsString;
+use std::ops::Range;
 use std::path::PathBuf;
+use std::time::Duration;
 
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::parser::ValueSource;
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
 use clap_verbosity_flag::{Verbosity as LogVerbosity, WarnLevel};
 
 /// How tests are distributed across workers.
@@ -58,10 +62,58 @@ pub struct Cli {
     /// `<project-root>/.tryke/cache`.
     #[arg(long = "cache-dir", global = true)]
     pub cache_dir: Option<PathBuf>,
+
+    /// Maximum threads used to read and parse files during discovery.
+    ///
+    /// Defaults to the number of CPUs, capped at 16. Lower it if discovery
+    /// exhausts file descriptors on very large repositories.
+    #[arg(long = "discovery-jobs", global = true, value_name = "N")]
+    pub discovery_jobs: Option<usize>,
+}
+
+/// Parses a `KEY=VALUE` pair, splitting on the first `=` so values may
+/// contain more.
+fn parse_property(raw: &str) -> Result<(String, String), String> {
+    match raw.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_owned(), value.to_owned())),
+        _ => Err(format!("expected KEY=VALUE, got `{raw}`")),
+    }
+}
+
+/// Parses a positive number of seconds, e.g. `5` or `0.5`.
+fn parse_timeout(raw: &str) -> Result<Duration, String> {
+    match raw.parse::<f64>().map(Duration::try_from_secs_f64) {
+        Ok(Ok(timeout)) if !timeout.is_zero() => Ok(timeout),
+        _ => Err(format!(
+            "expected a positive number of seconds, got `{raw}`"
+        )),
+    }
+}
+
+/// When reporter output is colored.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum Color {
+    /// Color when the reporter's stream is a terminal and `NO_COLOR` is unset
+    #[default]
+    Auto,
+    /// Always color, even when piped or with `NO_COLOR` set
+    Always,
+    /// Never color
+    Never,
+}
+
+impl From<Color> for tryke_reporter::ColorChoice {
+    fn from(c: Color) -> Self {
+        match c {
+            Color::Auto => Self::Auto,
+            Color::Always => Self::Always,
+            Color::Never => Self::Never,
+        }
+    }
 }
 
 /// Reporter format used to render test results.
-#[derive(Clone, Debug, ValueEnum)]
+#[derive(Clone, Debug, PartialEq, Eq, ValueEnum)]
 pub enum ReporterFormat {
     /// Human-readable per-test output with assertion diagnostics
     Text,
@@ -79,6 +131,17 @@ pub enum ReporterFormat {
     Sugar,
 }
 
+/// Alternative `--collect-only` output selected with `--format`.
+#[derive(Clone, Debug, ValueEnum)]
+pub enum CollectFormat {
+    /// One pytest-style node id per line
+    Nodeids,
+}
+
+// `Test` carries every `tryke test` flag. The enum is parsed once per
+// process, so its size doesn't matter and boxing would only obscure the
+// flags' destructuring in `main`.
+#[expect(clippy::large_enum_variant)]
 #[derive(Debug, Subcommand)]
 pub enum Commands {
     /// Collect and run tests.
@@ -123,6 +186,16 @@ pub enum Commands {
         #[arg(short = 'i', long = "include")]
         include: Vec<String>,
 
+        /// Only collect tests from files whose name matches this glob.
+        ///
+        /// Overrides the `[tool.tryke] python_files` list in
+        /// `pyproject.toml`, e.g. `--python-files 'test_*.py'
+        /// --python-files '*_test.py'`. Other files are still parsed so
+        /// `--changed` and watch mode track them as dependencies. May be
+        /// repeated.
+        #[arg(long = "python-files", value_name = "GLOB")]
+        python_files: Vec<String>,
+
         /// Collect tests without running them.
         ///
         /// Prints the discovered test list and exits. Useful for verifying
@@ -130,6 +203,92 @@ pub enum Commands {
         #[arg(long, conflicts_with = "watch")]
         collect_only: bool,
 
+        /// Print collected tests as a directory tree.
+        ///
+        /// With `--collect-only --reporter text`, tests are grouped under
+        /// the directories, files, and `describe` groups they belong to,
+        /// with a test count on each node, instead of a flat per-file list.
+        #[arg(long, requires = "collect_only")]
+        tree: bool,
+
+        /// Draw the `--tree` with ASCII characters instead of box-drawing
+        /// glyphs.
+        #[arg(long, requires = "tree")]
+        ascii: bool,
+
+        /// List each collected test's expected assertions.
+        ///
+        /// With `--collect-only --reporter text`, every test is followed by
+        /// the `expect(...)` checklist discovery extracted from its body, to
+        /// review what a suite checks without running it.
+        #[arg(long, requires = "collect_only", conflicts_with = "tree")]
+        show_assertions: bool,
+
+        /// Print collected tests in another format instead of the report.
+        ///
+        /// `nodeids` prints one pytest-style node id per line, like
+        /// `tests/test_math.py::test_add`, for tools that take pytest node
+        /// ids. Requires `--collect-only`.
+        #[arg(long, value_enum, requires = "collect_only", conflicts_with_all = ["tree", "show_assertions"])]
+        format: Option<CollectFormat>,
+
+        /// Collect tests from the `.py` members of a zip archive or wheel.
+        ///
+        /// Reads the archive in place, without extracting it. Module paths
+        /// come from each member's path inside the archive, the way
+        /// `zipimport` resolves them. Requires `--collect-only`; archived
+        /// tests can't be run yet. May be repeated.
+        #[arg(
+            long,
+            value_name = "PATH",
+            requires = "collect_only",
+            conflicts_with_all = [
+                "paths",
+                "changed",
+                "changed_first",
+                "debug_extraction",
+                "discovery_stats"
+            ]
+        )]
+        archive: Vec<PathBuf>,
+
+        /// Fail on configuration problems and assertion-count violations.
+        ///
+        /// Turns the warnings for unknown or mistyped `[tool.tryke]` keys
+        /// (see `tryke config check`) and for tests outside `min_assertions`
+        /// / `max_assertions` into an error, so the run exits non-zero
+        /// before any test executes. Combine with `--collect-only` for a
+        /// lint-only check.
+        #[arg(long, conflicts_with = "watch")]
+        strict: bool,
+
+        /// Print assertion extraction coverage for each discovered test.
+        ///
+        /// Reports to stderr how many calls in each test body were
+        /// recognized as assertions, with the lines of `expect(...)` calls
+        /// discovery couldn't parse. Explains why an assertion is
+        /// missing from a test's checklist.
+        #[arg(long, conflicts_with = "watch")]
+        debug_extraction: bool,
+
+        /// Print what discovery cost after collection.
+        ///
+        /// Reports to stderr how many Python files the walk found, how many
+        /// missed the discovery cache and were parsed, the bytes read for
+        /// them, and the time spent parsing. Useful for diagnosing slow
+        /// collection in large repositories.
+        #[arg(long, conflicts_with = "watch")]
+        discovery_stats: bool,
+
+        /// Print everything discovery knows about one test and exit.
+        ///
+        /// Takes a test id as shown by `--collect-only` (e.g.
+        /// `tests/test_math.py::test_add`) and prints its module, file and
+        /// line, decorator kind, display name, tags, and expected
+        /// assertions without running anything.
+        #[arg(long, value_name = "TEST_ID", conflicts_with_all = ["watch", "collect_only"])]
+        explain: Option<String>,
+
         /// Filter tests by name expression.
         ///
         /// Supports substring matching with boolean operators (`and`, `or`,
@@ -144,22 +303,238 @@ pub enum Commands {
         /// Filter tests by tag expression.
         ///
         /// Matches against the `tags=[...]` argument on the `@test`
-        /// decorator. Same boolean syntax as `-k`.
+        /// decorator. Same boolean syntax as `-k`. `--tag` is an alias.
         ///
-        /// Examples: `-m "slow"`, `-m "fast and not network"`.
-        #[arg(short = 'm', long = "markers")]
+        /// Examples: `-m "slow"`, `-m "fast and not network"`,
+        /// `--tag "(db or cache) and integration"`.
+        #[arg(short = 'm', long = "markers", visible_alias = "tag")]
         markers: Option<String>,
 
+        /// Exclude tests carrying this tag.
+        ///
+        /// Applied after `-m` and the other filters, so `--tag-not slow`
+        /// runs everything except tests tagged `slow`. The name must match
+        /// a tag exactly. May be repeated.
+        #[arg(long = "tag-not", value_name = "NAME")]
+        tag_not: Vec<String>,
+
         /// Reporter format for test output.
         #[arg(long = "reporter", default_value = "text")]
         reporter: ReporterFormat,
 
+        /// When to color the text and dot reporters' output.
+        ///
+        /// `auto` is decided for each reporter's own output stream: it
+        /// colors only when that stream is a terminal and the `NO_COLOR`
+        /// environment variable is unset or empty, so output piped to a
+        /// file is plain text. `always` and `never` override both.
+        #[arg(long, value_name = "WHEN", default_value = "auto")]
+        color: Color,
+
+        /// Stream JSON reporter events to a socket or named pipe.
+        ///
+        /// With `--reporter json`, NDJSON events are written to the Unix
+        /// domain socket or FIFO at this path (a named pipe on Windows)
+        /// instead of stdout, so an IDE can consume them live. Falls back
+        /// to stdout with a warning if the path can't be opened.
+        #[arg(long = "event-socket", value_name = "PATH")]
+        event_socket: Option<PathBuf>,
+
+        /// Emit an event for each assertion as it's evaluated.
+        ///
+        /// With `--reporter json`, every `expect(...)` produces an
+        /// `assertion` event while its test is still running, so an editor
+        /// can mark lines as they pass or fail. The final `test_complete`
+        /// event is unchanged.
+        #[arg(long)]
+        stream_assertions: bool,
+
+        /// Write assertions in a compact shape.
+        ///
+        /// With `--reporter json`, each assertion carries only its
+        /// `expression`, `line`, `expected`, and `received`, dropping the
+        /// source span and file to keep large reports small.
+        #[arg(long)]
+        json_minimal: bool,
+
+        /// End the run with one event holding every result.
+        ///
+        /// With `--reporter json`, a final `run_report` event follows
+        /// `run_complete` with the full `results` list and the `summary`,
+        /// so a consumer can read the last line instead of collecting
+        /// `test_complete` events.
+        #[arg(long)]
+        json_final_report: bool,
+
+        /// Report tests that are still running every this many seconds.
+        ///
+        /// With `--reporter json`, every interval emits a `heartbeat` event
+        /// for each test that is still running, with its `id` and
+        /// `elapsed_ms`, so a UI can show "still running test X (45s)".
+        /// Fractions like `0.5` are allowed.
+        #[arg(long, value_name = "SECONDS", value_parser = parse_timeout)]
+        heartbeat: Option<Duration>,
+
+        /// Show a duration bar next to each passing test.
+        ///
+        /// With `--reporter text`, results are held until the run finishes
+        /// and each passing test gets a bar scaled to the slowest test, for
+        /// quick visual profiling.
+        #[arg(long)]
+        timings: bool,
+
+        /// Leave test and run durations out of the text report.
+        ///
+        /// With `--reporter text`, drops the `[12ms]` suffix after each test
+        /// and the summary's `Duration` line, so output can be diffed
+        /// between runs.
+        #[arg(long, conflicts_with = "timings")]
+        no_duration: bool,
+
+        /// Close each file's results with its pass/fail counts.
+        ///
+        /// With `--reporter text`, a footer like `tests/math.py: 5 passed,
+        /// 1 failed` follows the last test of each file, to spot which
+        /// files have problems in a long run.
+        #[arg(long)]
+        group_summary: bool,
+
+        /// Show the distribution of test durations after the run.
+        ///
+        /// With `--reporter text`, a footer lists the mean and the p50,
+        /// p95, and p99 test durations, to spot slowdowns that a total
+        /// run time hides.
+        #[arg(long)]
+        timing_stats: bool,
+
+        /// List the `N` slowest tests after the run.
+        ///
+        /// With `--reporter text`, the tests with the longest durations are
+        /// listed slowest first above the summary, for performance triage.
+        /// `0` leaves the list out.
+        #[arg(long, value_name = "N", default_value_t = 0)]
+        durations: usize,
+
+        /// Render full diagnostics for only the first `N` failures.
+        ///
+        /// With `--reporter text`, later failures print just their name and
+        /// the run closes with `… and M more failures`, so a run where
+        /// everything fails doesn't flood the terminal. The summary still
+        /// counts every failure.
+        #[arg(long, value_name = "N")]
+        max_failures_shown: Option<usize>,
+
+        /// Recap skipped tests grouped by skip reason after the run.
+        ///
+        /// With `--reporter text`, a list like `requires GPU — 12 tests`
+        /// follows the results, most common reason first, to audit why
+        /// tests in a large suite are skipped.
+        #[arg(long)]
+        show_skipped_summary: bool,
+
+        /// Print a dot for each passing test.
+        ///
+        /// With `--reporter text`, passing tests collapse to a `.` on a
+        /// shared line while failures, errors, and skips keep their full
+        /// text output, so large suites stay readable without hiding
+        /// failure context.
+        #[arg(long)]
+        quiet_pass_dot: bool,
+
+        /// Leave out the `N/M assertions failed` line under failures.
+        ///
+        /// With `--reporter text`, a failed test's diagnostics end at its
+        /// last failed assertion. Useful when most tests make a single
+        /// assertion and the count adds nothing.
+        #[arg(long)]
+        no_assertion_footer: bool,
+
+        /// Name who last edited each failed test.
+        ///
+        /// With `--reporter text`, a failure recap follows the results, and
+        /// each failed test in it shows the author of the latest commit to
+        /// touch its lines, per `git blame`. Tests outside a git repository
+        /// are listed without an author.
+        #[arg(long)]
+        blame: bool,
+
+        /// Show captured output for passing tests too.
+        ///
+        /// With `--reporter text`, a failing test's stdout and stderr are
+        /// always printed beneath it. This prints them under passing tests
+        /// as well, for tracing what a run did.
+        #[arg(long)]
+        show_capture: bool,
+
+        /// Show file paths relative to this directory.
+        ///
+        /// Paths in the report are normally relative to the project root,
+        /// which reads oddly when running from a subdirectory. With this
+        /// flag they're re-rooted to `DIR`, or to the current directory
+        /// when no value is given. Test ids keep their root-relative form.
+        /// Not supported with `--reporter json` or `--reporter junit`.
+        #[arg(long, value_name = "DIR", num_args = 0..=1, default_missing_value = ".")]
+        relative_to: Option<PathBuf>,
+
+        /// Add a run-level property to the JUnit report.
+        ///
+        /// With `--reporter junit`, each `KEY=VALUE` becomes a `<property>`
+        /// in the suite's `<properties>` block. Commit, branch, and job
+        /// variables from common CI services are included automatically;
+        /// a flag with the same key replaces them. May be repeated.
+        #[arg(long = "junit-property", value_name = "KEY=VALUE", value_parser = parse_property)]
+        junit_property: Vec<(String, String)>,
+
+        /// POST the run summary to a webhook when the run finishes.
+        ///
+        /// Sends the summary counts and the ids of failing tests to this
+        /// URL as JSON, for chat notifications or dashboards. Delivery is
+        /// best effort: an unreachable endpoint is logged as a warning and
+        /// doesn't change the exit code.
+        #[arg(long, value_name = "URL", conflicts_with = "watch")]
+        webhook: Option<String>,
+
+        /// Write each test's result to its own JSON file in this directory.
+        ///
+        /// Works alongside any reporter. Each finished test's full result
+        /// is written to `DIR/<id>.json`, for CI systems that collect one
+        /// artifact per test. Ids are made filesystem-safe (`/` and `::`
+        /// become `_` and `__`), long ids are shortened with a hash, and
+        /// ids that still collide get a numeric suffix. `.json` files left
+        /// in DIR by an earlier run are removed first.
+        #[arg(long, value_name = "DIR")]
+        results_dir: Option<PathBuf>,
+
         /// Project root used for discovery and execution.
         ///
         /// Defaults to the current working directory. Discovery, the import
         /// graph, and `pyproject.toml` resolution are all anchored here.
+        /// Repeat to run several projects, each with its own
+        /// `pyproject.toml`, as one report; test ids are then prefixed with
+        /// the root they came from. `--watch` follows a single project and
+        /// rejects more than one `--root`.
         #[arg(long)]
-        root: Option<PathBuf>,
+        root: Vec<PathBuf>,
+
+        /// Use this directory as the project root exactly as given.
+        ///
+        /// `--root` and the current directory are only starting points:
+        /// tryke walks up from them to the nearest `pyproject.toml`,
+        /// `setup.py`, `setup.cfg`, or `.git`. `--rootdir` skips that walk,
+        /// so module names and test paths are relative to it, which helps
+        /// in monorepos whose tests sit far from the nearest marker.
+        #[arg(long, value_name = "PATH", conflicts_with = "root")]
+        rootdir: Option<PathBuf>,
+
+        /// Run every package of a workspace as one report.
+        ///
+        /// Finds each directory below the current one that has its own
+        /// `pyproject.toml` and runs it as though passed with `--root`, so
+        /// test ids are prefixed with the package's path. A package nested
+        /// inside another is left to the outer one. Can't be combined with
+        /// `--root`, `--rootdir`, or `--watch`.
+        #[arg(long, conflicts_with_all = ["root", "rootdir", "watch"])]
+        workspace: bool,
 
         /// Run only tests affected by uncommitted changes.
         ///
@@ -178,6 +553,15 @@ pub enum Commands {
         #[arg(long, conflicts_with_all = ["changed", "watch"])]
         changed_first: bool,
 
+        /// Run only tests whose assertions changed since the last run.
+        ///
+        /// Compares each test's `expect(...)` calls with what the discovery
+        /// cache recorded for its file, so it needs no git. Edits elsewhere
+        /// in a test body don't select it, and tests new to the cache
+        /// always run.
+        #[arg(long, conflicts_with_all = ["changed", "changed_first", "watch"])]
+        only_changed_assertions: bool,
+
         /// Base branch for `--changed` / `--changed-first` diff.
         ///
         /// Compares against `git merge-base <base> HEAD` instead of the
@@ -186,7 +570,20 @@ pub enum Commands {
         #[arg(long)]
         base_branch: Option<String>,
 
+        /// Run only benchmark tests, timing repeated calls of each.
+        ///
+        /// Selects tests declared with `@test(benchmark=True)`. Each one that
+        /// passes is called again for a few warmup and 20 timed runs, and
+        /// the reporter shows their min, mean, and standard deviation.
+        /// Without this flag, benchmark tests run once like any other test.
+        #[arg(long, conflicts_with = "watch")]
+        benchmark: bool,
+
         /// Stop after the first failing test.
+        ///
+        /// Tests already running on other workers finish and are reported,
+        /// no new ones start, and the summary ends with `stopped after first
+        /// failure`.
         #[arg(short = 'x', long = "fail-fast")]
         fail_fast: bool,
 
@@ -196,12 +593,58 @@ pub enum Commands {
         #[arg(long)]
         maxfail: Option<usize>,
 
+        /// Fail any test that runs longer than this many seconds.
+        ///
+        /// A test over the limit is reported as failed with `timed out
+        /// after <limit>`, and the worker running it is killed and replaced
+        /// so the rest of the run continues. Fractions like `0.5` are
+        /// allowed. Disabled by default.
+        #[arg(long, value_name = "SECONDS", value_parser = parse_timeout, conflicts_with = "watch")]
+        timeout: Option<Duration>,
+
+        /// Flag any test that runs longer than this many milliseconds.
+        ///
+        /// Slow tests get a `slow` marker in the text reporter, `"slow":
+        /// true` in JSON results, and a count in the summary. They still
+        /// pass unless `--fail-slow` is given.
+        #[arg(long, value_name = "MS", conflicts_with = "watch")]
+        slow_threshold: Option<u64>,
+
+        /// Exit with a failure when any test is slower than
+        /// `--slow-threshold`.
+        #[arg(long, requires = "slow_threshold")]
+        fail_slow: bool,
+
+        /// Exit successfully even when tests fail.
+        ///
+        /// A run with failing or erroring tests normally exits with status
+        /// 1 so CI marks it red. With this flag it exits 0 once the report
+        /// is written, for jobs that only want the report. An interrupted
+        /// run still exits with 130.
+        #[arg(long)]
+        no_fail_on_error: bool,
+
+        /// Run the selected tests in a random order.
+        ///
+        /// Catches tests that only pass because of the tests that ran
+        /// before them. The seed is printed with the results; pass it back
+        /// with `--seed` to reproduce the order. Without `--seed`, the
+        /// `TRYKE_SEED` environment variable supplies it, otherwise a new
+        /// one is picked for each run.
+        #[arg(long, conflicts_with_all = ["changed_first", "watch"])]
+        shuffle: bool,
+
+        /// Seed for `--shuffle`, reproducing the order of an earlier run.
+        #[arg(long, value_name = "SEED", requires = "shuffle")]
+        seed: Option<u64>,
+
         /// Number of worker processes.
         ///
         /// Defaults to `min(test_count, cpu_count)`. Set to `1` to run
         /// tests in a single worker (useful when debugging concurrency
-        /// issues).
-        #[arg(short = 'j', long = "workers")]
+        /// issues). Results are still reported file by file in discovery
+        /// order. `--jobs` is an alias.
+        #[arg(short = 'j', long = "workers", visible_alias = "jobs")]
         workers: Option<usize>,
 
         /// How tests are distributed across workers.
@@ -296,6 +739,17 @@ pub enum Commands {
         workers: Option<usize>,
     },
 
+    /// Watch the project and rerun affected tests on each change.
+    ///
+    /// Shorthand for `tryke test --watch` that accepts every `tryke test`
+    /// option, e.g. `tryke watch --reporter dot -k parser`. Press `q` to
+    /// quit, `enter` to run all tests, or `c` to clear results.
+    Watch {
+        /// Options forwarded to `tryke test --watch`.
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true, hide = true)]
+        args: Vec<OsString>,
+    },
+
     /// Remove tryke's persistent discovery cache.
     ///
     /// Deletes the default `<project-root>/.tryke/cache` directory. When
@@ -307,6 +761,12 @@ pub enum Commands {
         root: Option<PathBuf>,
     },
 
+    /// Inspect tryke's `pyproject.toml` configuration.
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+
     /// Print the import dependency graph for the project.
     ///
     /// Renders the static import graph that drives discovery, change
@@ -350,6 +810,32 @@ pub enum Commands {
         #[arg(long, conflicts_with_all = ["connected_only", "changed", "base_branch"])]
         fixtures: bool,
     },
+
+    /// Describe the JSON-RPC protocol tryke speaks with its Python workers.
+    ///
+    /// For developing the worker harness on its own. Each test is sent to a
+    /// worker as a `run_test` request on its stdin, and the worker answers
+    /// with one response line on its stdout.
+    WorkerProtocol {
+        /// Print sample `run_test` requests and the responses tryke expects.
+        #[arg(long, required = true)]
+        emit_sample: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// Check `[tool.tryke]` for unknown keys and mistyped values.
+    ///
+    /// Loading skips unknown keys, and a single value of the wrong type
+    /// makes tryke ignore the whole table, both without a word. This
+    /// lists every such problem in the nearest `pyproject.toml` with a
+    /// `[tool.tryke]` table and exits non-zero if there are any.
+    Check {
+        /// Project root used to find `pyproject.toml`.
+        #[arg(long)]
+        root: Option<PathBuf>,
+    },
 }
 
 impl Commands {
@@ -359,16 +845,58 @@ impl Commands {
             paths: Vec::new(),
             exclude: Vec::new(),
             include: Vec::new(),
+            python_files: Vec::new(),
             collect_only: false,
+            tree: false,
+            ascii: false,
+            show_assertions: false,
+            format: None,
+            archive: Vec::new(),
+            strict: false,
+            debug_extraction: false,
+            discovery_stats: false,
+            explain: None,
             filter: None,
             markers: None,
+            tag_not: Vec::new(),
             reporter: ReporterFormat::Text,
-            root: None,
+            color: Color::Auto,
+            event_socket: None,
+            stream_assertions: false,
+            json_minimal: false,
+            json_final_report: false,
+            heartbeat: None,
+            timings: false,
+            no_duration: false,
+            group_summary: false,
+            timing_stats: false,
+            durations: 0,
+            max_failures_shown: None,
+            show_skipped_summary: false,
+            quiet_pass_dot: false,
+            no_assertion_footer: false,
+            blame: false,
+            show_capture: false,
+            relative_to: None,
+            junit_property: Vec::new(),
+            webhook: None,
+            results_dir: None,
+            root: Vec::new(),
+            rootdir: None,
+            workspace: false,
             changed: false,
             changed_first: false,
+            only_changed_assertions: false,
             base_branch: None,
+            benchmark: false,
             fail_fast: false,
             maxfail: None,
+            timeout: None,
+            slow_threshold: None,
+            fail_slow: false,
+            no_fail_on_error: false,
+            shuffle: false,
+            seed: None,
             workers: None,
             dist: Dist::Test,
             watch: true,
@@ -379,6 +907,138 @@ impl Commands {
     }
 }
 
+impl Cli {
+    /// Parses `args` over the project's `[tool.tryke] addopts`.
+    ///
+    /// The addopts are a layer under `tryke test`'s own options: each one
+    /// applies unless the command line gives the same option, which then
+    /// replaces it. Bare `tryke` takes them the way `tryke test --watch`
+    /// would. Other subcommands ignore them.
+    ///
+    /// # Errors
+    ///
+    /// Returns clap's error when the merged command line doesn't parse.
+    pub fn try_parse_with_addopts(
+        args: &[OsString],
+        addopts: &[String],
+    ) -> Result<Self, clap::Error> {
+        let mut command = Self::command();
+        command.build();
+        let matches = command.clone().try_get_matches_from(args)?;
+        let addopts: Vec<OsString> = addopts.iter().map(OsString::from).collect();
+        let (at, layer): (usize, Vec<OsString>) =
+            match (matches.subcommand(), subcommand_index(&command, args)) {
+                (Some(("test", given)), Some(index)) => {
+                    let Some(test) = command.find_subcommand("test") else {
+                        return Self::from_arg_matches(&matches);
+                    };
+                    let layer = arg_runs(test, &addopts)
+                        .into_iter()
+                        .filter(|(id, _)| {
+                            id.is_none_or(|id| {
+                                given.value_source(id) != Some(ValueSource::CommandLine)
+                            })
+                        })
+                        .flat_map(|(_, run)| addopts[run].iter().cloned())
+                        .collect();
+                    (index + 1, layer)
+                }
+                (None, _) => {
+                    let watch = ["test", "--watch"].map(OsString::from);
+                    (args.len(), watch.into_iter().chain(addopts).collect())
+                }
+                _ => return Self::from_arg_matches(&matches),
+            };
+        let mut args = args.to_vec();
+        args.splice(at..at, layer);
+        Self::try_parse_from(args)
+    }
+
+    /// The index in `args` of the subcommand's name, skipping the program
+    /// name and any global options (and their values) before it.
+    #[must_use]
+    pub fn subcommand_index(args: &[OsString]) -> Option<usize> {
+        let mut command = Self::command();
+        command.build();
+        subcommand_index(&command, args)
+    }
+}
+
+fn subcommand_index(command: &clap::Command, args: &[OsString]) -> Option<usize> {
+    let mut index = 1;
+    while index < args.len() {
+        let (id, end) = next_arg(command, args, index);
+        if id.is_none() && !args[index].to_string_lossy().starts_with('-') {
+            return Some(index);
+        }
+        index = end;
+    }
+    None
+}
+
+/// Splits `tokens` into one run per argument of `command`: an option with
+/// its values, or a positional. Each run carries the id of the argument
+/// it sets, `None` for tokens `command` doesn't know.
+fn arg_runs<'a>(
+    command: &'a clap::Command,
+    tokens: &[OsString],
+) -> Vec<(Option<&'a str>, Range<usize>)> {
+    let mut runs = Vec::new();
+    let mut index = 0;
+    while index < tokens.len() {
+        let (id, end) = next_arg(command, tokens, index);
+        runs.push((id, index..end));
+        index = end;
+    }
+    runs
+}
+
+/// The argument of `command` that `tokens[index]` starts, and the index
+/// just past its values.
+fn next_arg<'a>(
+    command: &'a clap::Command,
+    tokens: &[OsString],
+    index: usize,
+) -> (Option<&'a str>, usize) {
+    let token = tokens[index].to_string_lossy();
+    let (arg, attached) = if let Some(long) = token.strip_prefix("--") {
+        let (name, value) = long
+            .split_once('=')
+            .map_or((long, false), |(name, _)| (name, true));
+        let arg = command.get_arguments().find(|arg| {
+            arg.get_long() == Some(name)
+                || arg
+                    .get_all_aliases()
+                    .is_some_and(|aliases| aliases.contains(&name))
+        });
+        (arg, value)
+    } else if let Some(short) = token.strip_prefix('-')
+        && let Some(first) = short.chars().next()
+    {
+        let arg = command
+            .get_arguments()
+            .find(|arg| arg.get_short() == Some(first));
+        (arg, short.len() > first.len_utf8())
+    } else {
+        let arg = command.get_positionals().next();
+        return (arg.map(|arg| arg.get_id().as_str()), index + 1);
+    };
+    let Some(arg) = arg else {
+        return (None, index + 1);
+    };
+    let mut end = index + 1;
+    if !attached && arg.get_action().takes_values() {
+        let values = arg.get_num_args().unwrap_or_default();
+        while end - index <= values.max_values()
+            && let Some(next) = tokens.get(end)
+            && (end - index <= values.min_values() || !next.to_string_lossy().starts_with('-'))
+        {
+            end += 1;
+        }
+    }
+    (Some(arg.get_id().as_str()), end)
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
@@ -410,4 +1070,389 @@ mod tests {
         let cli = Cli::parse_from(["tryke", "test"]);
         assert_eq!(cli.cache_dir, None);
     }
+
+    #[test]
+    fn parses_repeated_junit_properties() {
+        let cli = Cli::parse_from([
+            "tryke",
+            "test",
+            "--reporter",
+            "junit",
+            "--junit-property",
+            "branch=main",
+            "--junit-property",
+            "query=a=b",
+        ]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test { junit_property, .. })
+                if junit_property == [
+                    ("branch".to_owned(), "main".to_owned()),
+                    ("query".to_owned(), "a=b".to_owned()),
+                ]
+        ));
+        assert!(Cli::try_parse_from(["tryke", "test", "--junit-property", "novalue"]).is_err());
+    }
+
+    #[test]
+    fn tag_is_an_alias_for_markers() {
+        let cli = Cli::parse_from(["tryke", "test", "--tag", "slow and not network"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test { markers: Some(markers), .. })
+                if markers == "slow and not network"
+        ));
+    }
+
+    #[test]
+    fn jobs_is_an_alias_for_workers() {
+        let cli = Cli::parse_from(["tryke", "test", "--jobs", "3"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                workers: Some(3),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn no_duration_conflicts_with_timings() {
+        let cli = Cli::parse_from(["tryke", "test", "--no-duration"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                no_duration: true,
+                ..
+            })
+        ));
+        assert!(Cli::try_parse_from(["tryke", "test", "--no-duration", "--timings"]).is_err());
+    }
+
+    #[test]
+    fn heartbeat_parses_seconds() {
+        let cli = Cli::parse_from(["tryke", "test", "--reporter", "json", "--heartbeat", "15"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                heartbeat: Some(interval),
+                ..
+            }) if interval == Duration::from_secs(15)
+        ));
+        assert!(Cli::try_parse_from(["tryke", "test", "--heartbeat", "0"]).is_err());
+    }
+
+    #[test]
+    fn stream_assertions_is_off_by_default() {
+        let cli = Cli::parse_from(["tryke", "test", "--reporter", "json"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                stream_assertions: false,
+                ..
+            })
+        ));
+        let cli = Cli::parse_from(["tryke", "test", "--reporter", "json", "--stream-assertions"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                stream_assertions: true,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn quiet_pass_dot_is_off_by_default() {
+        let cli = Cli::parse_from(["tryke", "test"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                quiet_pass_dot: false,
+                ..
+            })
+        ));
+        let cli = Cli::parse_from(["tryke", "test", "--quiet-pass-dot"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                quiet_pass_dot: true,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn assertion_footer_is_on_by_default() {
+        let cli = Cli::parse_from(["tryke", "test"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                no_assertion_footer: false,
+                ..
+            })
+        ));
+        let cli = Cli::parse_from(["tryke", "test", "--no-assertion-footer"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                no_assertion_footer: true,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn json_final_report_is_off_by_default() {
+        let cli = Cli::parse_from(["tryke", "test"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                json_final_report: false,
+                ..
+            })
+        ));
+        let cli = Cli::parse_from(["tryke", "test", "--json-final-report"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                json_final_report: true,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn relative_to_defaults_to_current_directory() {
+        let cli = Cli::parse_from(["tryke", "test", "--relative-to"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test { relative_to: Some(dir), .. }) if dir == Path::new(".")
+        ));
+        let cli = Cli::parse_from(["tryke", "test", "--relative-to", "tests"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test { relative_to: Some(dir), .. }) if dir == Path::new("tests")
+        ));
+    }
+
+    #[test]
+    fn webhook_conflicts_with_watch() {
+        let cli = Cli::parse_from(["tryke", "test", "--webhook", "https://example.com/hook"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test { webhook: Some(url), .. }) if url == "https://example.com/hook"
+        ));
+        assert!(
+            Cli::try_parse_from([
+                "tryke",
+                "test",
+                "--watch",
+                "--webhook",
+                "https://example.com/hook"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn max_failures_shown_parsed() {
+        let cli = Cli::parse_from(["tryke", "test", "--max-failures-shown", "2"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                max_failures_shown: Some(2),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn results_dir_parses() {
+        let cli = Cli::parse_from(["tryke", "test", "--results-dir", "out/results"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test { results_dir: Some(dir), .. }) if dir == Path::new("out/results")
+        ));
+    }
+
+    #[test]
+    fn durations_defaults_to_off() {
+        let cli = Cli::parse_from(["tryke", "test"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test { durations: 0, .. })
+        ));
+        let cli = Cli::parse_from(["tryke", "test", "--durations", "5"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test { durations: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn color_defaults_to_auto() {
+        let cli = Cli::parse_from(["tryke", "test"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                color: Color::Auto,
+                ..
+            })
+        ));
+        let cli = Cli::parse_from(["tryke", "test", "--color", "never"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                color: Color::Never,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn tree_requires_collect_only() {
+        let cli = Cli::parse_from(["tryke", "test", "--collect-only", "--tree", "--ascii"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                tree: true,
+                ascii: true,
+                ..
+            })
+        ));
+        assert!(Cli::try_parse_from(["tryke", "test", "--tree"]).is_err());
+        assert!(Cli::try_parse_from(["tryke", "test", "--collect-only", "--ascii"]).is_err());
+    }
+
+    #[test]
+    fn parses_config_check() {
+        let cli = Cli::parse_from(["tryke", "config", "check", "--root", "svc"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Config {
+                command: ConfigCommand::Check { root: Some(root) }
+            }) if root == Path::new("svc")
+        ));
+    }
+
+    #[test]
+    fn archive_requires_collect_only() {
+        let cli = Cli::parse_from([
+            "tryke",
+            "test",
+            "--collect-only",
+            "--archive",
+            "dist/suite.zip",
+        ]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test { archive, .. }) if archive == [PathBuf::from("dist/suite.zip")]
+        ));
+        assert!(Cli::try_parse_from(["tryke", "test", "--archive", "dist/suite.zip"]).is_err());
+    }
+
+    #[test]
+    fn parses_global_discovery_jobs() {
+        let cli = Cli::parse_from(["tryke", "test", "--discovery-jobs", "4"]);
+        assert_eq!(cli.discovery_jobs, Some(4));
+    }
+
+    fn parse_with_addopts(args: &[&str], addopts: &[&str]) -> Result<Cli, clap::Error> {
+        let args: Vec<OsString> = args.iter().map(OsString::from).collect();
+        let addopts: Vec<String> = addopts.iter().map(|&opt| opt.to_owned()).collect();
+        Cli::try_parse_with_addopts(&args, &addopts)
+    }
+
+    #[test]
+    fn command_line_options_replace_addopts() {
+        let cli = parse_with_addopts(
+            &["tryke", "-v", "test", "--maxfail", "3", "--exclude", "b"],
+            &["--maxfail", "1", "--no-duration", "--exclude", "a"],
+        )
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                maxfail: Some(3),
+                no_duration: true,
+                exclude,
+                ..
+            }) if exclude == ["b"]
+        ));
+    }
+
+    #[test]
+    fn flag_in_addopts_and_on_command_line_parses() {
+        let cli = parse_with_addopts(
+            &["tryke", "test", "--no-duration", "--maxfail=2"],
+            &["--no-duration", "--maxfail=1"],
+        )
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                no_duration: true,
+                maxfail: Some(2),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn addopts_follow_the_subcommand_not_a_value_named_test() {
+        let cli = parse_with_addopts(
+            &["tryke", "--cache-dir", "test", "test", "-k", "test"],
+            &["--maxfail", "1"],
+        )
+        .unwrap();
+        assert_eq!(cli.cache_dir.as_deref(), Some(Path::new("test")));
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                maxfail: Some(1),
+                filter: Some(filter),
+                ..
+            }) if filter == "test"
+        ));
+    }
+
+    #[test]
+    fn bare_tryke_takes_addopts_as_watch_mode() {
+        let cli = parse_with_addopts(&["tryke", "--no-progress"], &["--reporter", "dot"]).unwrap();
+        assert!(cli.no_progress);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Test {
+                watch: true,
+                reporter: ReporterFormat::Dot,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn other_subcommands_ignore_addopts() {
+        let cli = parse_with_addopts(&["tryke", "graph"], &["--maxfail", "1"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Graph { .. })));
+    }
+
+    #[test]
+    fn subcommand_index_skips_global_option_values() {
+        let args = [
+            "tryke",
+            "--cache-dir",
+            "watch",
+            "-v",
+            "watch",
+            "-k",
+            "watch",
+        ]
+        .map(OsString::from);
+        assert_eq!(Cli::subcommand_index(&args), Some(4));
+        assert_eq!(
+            Cli::subcommand_index(&["tryke", "-v"].map(OsString::from)),
+            None
+        );
+    }
 }
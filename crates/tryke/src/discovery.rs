@@ -1,10 +1,15 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
 
 use log::{debug, warn};
 use tryke_config::TrykeConfig;
-use tryke_discovery::Discoverer;
+use tryke_discovery::{Discoverer, DiscoveryStats};
 use tryke_types::filter::PathSpec;
-use tryke_types::{DiscoveryWarning, DiscoveryWarningKind, HookItem};
+use tryke_types::{
+    DiscoveryError, DiscoveryWarning, DiscoveryWarningKind, DuplicateTest, HookItem,
+    SelectionSummary, duplicate_tests,
+};
 
 use crate::git::resolve_changed_files;
 
@@ -17,6 +22,11 @@ pub struct DiscoverySelection {
     pub changed_prefix_len: Option<usize>,
     /// Files where dynamic imports were detected; these will always re-run with --changed.
     pub warnings: Vec<DiscoveryWarning>,
+    /// How many of `tests` were served from the discovery cache. `None`
+    /// when `tests` is a `--changed` subset the count wouldn't line up with.
+    pub cached_tests: Option<usize>,
+    /// What the discovery walk cost, for `--discovery-stats`.
+    pub stats: DiscoveryStats,
 }
 
 fn dynamic_import_warnings(discoverer: &Discoverer) -> Vec<DiscoveryWarning> {
@@ -56,10 +66,135 @@ fn testing_guard_else_warnings(discoverer: &Discoverer) -> Vec<DiscoveryWarning>
         .collect()
 }
 
-fn all_discovery_warnings(discoverer: &Discoverer) -> Vec<DiscoveryWarning> {
-    let mut warnings = dynamic_import_warnings(discoverer);
+/// One warning per test file that failed to parse, so a broken file reads
+/// as broken rather than as having no tests.
+fn syntax_error_warnings(discoverer: &Discoverer) -> Vec<DiscoveryWarning> {
+    discoverer
+        .syntax_errors()
+        .iter()
+        .map(|error| DiscoveryWarning {
+            file_path: error.file_path.clone(),
+            kind: DiscoveryWarningKind::SyntaxError,
+            message: format!(
+                "{}:{} — failed to parse ({}); no tests were collected from it",
+                error.file_path.display(),
+                error.line_number.unwrap_or_default(),
+                error.message
+            ),
+        })
+        .collect()
+}
+
+fn cached_test_count(discoverer: &Discoverer) -> usize {
+    discoverer
+        .file_discoveries()
+        .iter()
+        .filter(|file| file.from_cache)
+        .map(|file| file.tests.len())
+        .sum()
+}
+
+fn all_discovery_warnings(config: &TrykeConfig, discoverer: &Discoverer) -> Vec<DiscoveryWarning> {
+    let tests = discoverer.tests();
+    let mut warnings = syntax_error_warnings(discoverer);
+    warnings.extend(dynamic_import_warnings(discoverer));
     warnings.extend(testing_guard_else_warnings(discoverer));
+    warnings.extend(shadowed_test_warnings(&tests));
+    let discovery = &config.discovery;
+    if discovery.min_assertions.is_some() || discovery.max_assertions.is_some() {
+        warnings.extend(assertion_count_warnings(discovery, &tests));
+    }
+    warnings
+}
+
+/// One warning per test whose extracted assertion count is outside
+/// `[tool.tryke] min_assertions` / `max_assertions`. Pending assertions
+/// never run, so they don't count; todo tests and doctests have no
+/// `expect` calls to count and are skipped.
+#[must_use]
+pub fn assertion_count_warnings(
+    discovery: &tryke_config::DiscoveryConfig,
+    tests: &[tryke_types::TestItem],
+) -> Vec<DiscoveryWarning> {
+    let mut warnings = Vec::new();
+    for test in tests {
+        if test.todo.is_some() || test.doctest_object.is_some() {
+            continue;
+        }
+        let count = test
+            .expected_assertions
+            .iter()
+            .filter(|assertion| !assertion.pending)
+            .count();
+        let limit = match (discovery.min_assertions, discovery.max_assertions) {
+            (Some(min), _) if count < min => format!("fewer than min_assertions = {min}"),
+            (_, Some(max)) if count > max => format!("more than max_assertions = {max}"),
+            _ => continue,
+        };
+        let file_path = test.file_path.clone().unwrap_or_default();
+        let message = format!(
+            "{}:{} {} — {count} assertion(s), {limit}",
+            file_path.display(),
+            test.line_number.unwrap_or_default(),
+            test.display_label(),
+        );
+        warnings.push(DiscoveryWarning {
+            file_path,
+            kind: DiscoveryWarningKind::AssertionCount,
+            message,
+        });
+    }
+    warnings
+}
+
+/// One warning per test function that reuses the name of an earlier one
+/// in the same file, naming both lines. At runtime the later definition
+/// replaces the earlier, which then never runs. The cases of one
+/// `@test.cases` function share its line and count once.
+#[must_use]
+pub fn shadowed_test_warnings(tests: &[tryke_types::TestItem]) -> Vec<DiscoveryWarning> {
+    let line = |line: Option<u32>| line.map_or_else(|| "?".to_owned(), |line| line.to_string());
+    let mut first_lines: HashMap<(&Path, &str), Option<u32>> = HashMap::new();
+    let mut reported = BTreeSet::new();
+    let mut warnings = Vec::new();
+    for test in tests {
+        let Some(file_path) = test.file_path.as_deref() else {
+            continue;
+        };
+        if test.doctest_object.is_some() {
+            continue;
+        }
+        let first = *first_lines
+            .entry((file_path, test.name.as_str()))
+            .or_insert(test.line_number);
+        if first == test.line_number
+            || !reported.insert((file_path, test.name.as_str(), test.line_number))
+        {
+            continue;
+        }
+        warnings.push(DiscoveryWarning {
+            file_path: file_path.to_path_buf(),
+            kind: DiscoveryWarningKind::ShadowedTest,
+            message: format!(
+                "{}:{} {} shadows the test of the same name on line {}",
+                file_path.display(),
+                line(test.line_number),
+                test.name,
+                line(first),
+            ),
+        });
+    }
+    warnings
+}
+
+/// How many of `warnings` are assertion-count violations, which fail the
+/// run under `--strict`.
+#[must_use]
+pub fn assertion_count_violations(warnings: &[DiscoveryWarning]) -> usize {
     warnings
+        .iter()
+        .filter(|warning| warning.kind == DiscoveryWarningKind::AssertionCount)
+        .count()
 }
 
 /// Discover tests, optionally restricting to changed files.
@@ -69,16 +204,9 @@ pub fn discover_tests(
     base_branch: Option<&str>,
 ) -> DiscoverySelection {
     let root = config.root();
-    let src_roots = config.src_roots();
-    let cache_dir = config.cache_dir();
-    let mut discoverer = Discoverer::new(
-        root,
-        src_roots,
-        &config.discovery.exclude,
-        cache_dir.as_deref(),
-    );
+    let mut discoverer = Discoverer::from_config(config);
     discoverer.rediscover();
-    let warnings = all_discovery_warnings(&discoverer);
+    let warnings = all_discovery_warnings(config, &discoverer);
     let hooks = discoverer.hooks();
 
     if changed {
@@ -91,6 +219,8 @@ pub fn discover_tests(
                     changed_files: Some(changed_files.len()),
                     changed_prefix_len: None,
                     warnings,
+                    cached_tests: None,
+                    stats: discoverer.stats(),
                 }
             }
             Some(_) => {
@@ -101,6 +231,8 @@ pub fn discover_tests(
                     changed_files: Some(0),
                     changed_prefix_len: None,
                     warnings,
+                    cached_tests: None,
+                    stats: discoverer.stats(),
                 }
             }
             None => {
@@ -111,6 +243,8 @@ pub fn discover_tests(
                     changed_files: None,
                     changed_prefix_len: None,
                     warnings,
+                    cached_tests: Some(cached_test_count(&discoverer)),
+                    stats: discoverer.stats(),
                 }
             }
         }
@@ -121,10 +255,30 @@ pub fn discover_tests(
             changed_files: None,
             changed_prefix_len: None,
             warnings,
+            cached_tests: Some(cached_test_count(&discoverer)),
+            stats: discoverer.stats(),
         }
     }
 }
 
+/// Discover tests, keeping only those whose assertions changed since the
+/// discovery cache last saw their file (`--only-changed-assertions`).
+pub fn discover_tests_with_changed_assertions(config: &TrykeConfig) -> DiscoverySelection {
+    let mut discoverer = Discoverer::from_config(config);
+    discoverer.rediscover();
+    let warnings = all_discovery_warnings(config, &discoverer);
+    let hooks = discoverer.hooks();
+    DiscoverySelection {
+        tests: discoverer.tests_with_changed_assertions(),
+        hooks,
+        changed_files: None,
+        changed_prefix_len: None,
+        warnings,
+        cached_tests: None,
+        stats: discoverer.stats(),
+    }
+}
+
 /// Discover tests restricted to the given path specs. Skips the full
 /// project walk and the import-graph build, since path-restricted runs
 /// don't drive change-based selection. Falls back to `discover_tests`
@@ -144,16 +298,9 @@ pub fn discover_tests_for_paths(
         }
     };
 
-    let src_roots = config.src_roots();
-    let cache_dir = config.cache_dir();
-    let mut discoverer = Discoverer::new(
-        root,
-        src_roots,
-        &config.discovery.exclude,
-        cache_dir.as_deref(),
-    );
+    let mut discoverer = Discoverer::from_config(config);
     let tests = discoverer.rediscover_restricted(&walk_roots);
-    let warnings = all_discovery_warnings(&discoverer);
+    let warnings = all_discovery_warnings(config, &discoverer);
     let hooks = discoverer.hooks();
     DiscoverySelection {
         tests,
@@ -161,7 +308,125 @@ pub fn discover_tests_for_paths(
         changed_files: None,
         changed_prefix_len: None,
         warnings,
+        cached_tests: Some(cached_test_count(&discoverer)),
+        stats: discoverer.stats(),
+    }
+}
+
+/// One [`DiscoveryError`] per path argument that exists neither under
+/// `root` nor under the current directory and matched none of `tests`,
+/// so a mistyped path is reported instead of quietly selecting nothing.
+#[must_use]
+pub fn missing_path_errors(
+    root: &Path,
+    path_specs: &[PathSpec],
+    tests: &[tryke_types::TestItem],
+) -> Vec<DiscoveryError> {
+    path_specs
+        .iter()
+        .filter_map(|spec| {
+            let (PathSpec::File(path) | PathSpec::FileLine(path, _)) = spec;
+            let exists = root.join(path).exists() || path.exists();
+            (!exists && !tests.iter().any(|test| spec.matches(test))).then(|| DiscoveryError {
+                file_path: path.clone(),
+                message: "no such file or directory".to_owned(),
+                line_number: None,
+            })
+        })
+        .collect()
+}
+
+/// One [`DiscoveryError`] per test that repeats an earlier test's
+/// [`TestItem::id`] or, in the same file, its display name: e.g. two
+/// `def test_adds`, two functions both decorated `@test("adds")`, or the
+/// two cases of `@test.each([1, 1])`. Results for the two can't be told
+/// apart; the error names both lines. A redefined function is also
+/// reported by [`shadowed_test_warnings`].
+///
+/// [`TestItem::id`]: tryke_types::TestItem::id
+#[must_use]
+pub fn duplicate_id_errors(tests: &[tryke_types::TestItem]) -> Vec<DiscoveryError> {
+    let line = |line: Option<u32>| line.map_or_else(|| "?".to_owned(), |line| line.to_string());
+    duplicate_tests(tests)
+        .into_iter()
+        .map(|DuplicateTest { test, first, .. }| {
+            let id = test.id();
+            let what = if id == first.id() {
+                format!("test id `{id}`")
+            } else {
+                format!("test name `{}`", test.display_label())
+            };
+            DiscoveryError {
+                file_path: test
+                    .file_path
+                    .clone()
+                    .unwrap_or_else(|| PathBuf::from(&test.module_path)),
+                message: format!(
+                    "duplicate {what} on lines {} and {}",
+                    line(first.line_number),
+                    line(test.line_number)
+                ),
+                line_number: test.line_number,
+            }
+        })
+        .collect()
+}
+
+/// Apply `--benchmark` to a filtered selection.
+///
+/// With `benchmark`, only tests declared with `benchmark=True` are kept
+/// and `selection` is narrowed to match. Without it, those tests stay
+/// selected but lose the flag, so they run once like any other test.
+pub fn select_benchmarks(
+    tests: &mut Vec<tryke_types::TestItem>,
+    selection: &mut SelectionSummary,
+    benchmark: bool,
+) {
+    if benchmark {
+        tests.retain(|test| test.benchmark);
+        selection.selected = tests.len();
+        selection.via = Some(match selection.via.take() {
+            Some(via) => format!("{via} --benchmark"),
+            None => "--benchmark".to_owned(),
+        });
+    } else {
+        for test in tests {
+            test.benchmark = false;
+        }
+    }
+}
+
+/// Apply `--shuffle` to a filtered selection.
+///
+/// With a `seed`, `tests` are put in a pseudo-random order that depends
+/// only on the seed and the discovery order, so passing the same seed
+/// back reproduces a failing order. The seed is recorded on `selection`
+/// for the reporters. Without one, discovery order is left untouched.
+pub fn shuffle_tests(
+    tests: &mut [tryke_types::TestItem],
+    selection: &mut SelectionSummary,
+    seed: Option<u64>,
+) {
+    let Some(seed) = seed else {
+        return;
+    };
+    // SplitMix64: small, seedable, and stable across platforms and
+    // releases, which a reproducible order depends on.
+    let mut state = seed;
+    let mut next = || {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    };
+    // Fisher-Yates, swapping each position with one at or before it.
+    for i in (1..tests.len()).rev() {
+        let bound = u64::try_from(i + 1).unwrap_or(u64::MAX);
+        let j = usize::try_from(next() % bound).unwrap_or(i);
+        tests.swap(i, j);
     }
+    selection.seed = Some(seed);
 }
 
 /// Translate `PathSpec`s into a deduplicated list of filesystem walk
@@ -218,16 +483,9 @@ pub fn discover_tests_changed_first(
     base_branch: Option<&str>,
 ) -> DiscoverySelection {
     let root = config.root();
-    let src_roots = config.src_roots();
-    let cache_dir = config.cache_dir();
-    let mut discoverer = Discoverer::new(
-        root,
-        src_roots,
-        &config.discovery.exclude,
-        cache_dir.as_deref(),
-    );
+    let mut discoverer = Discoverer::from_config(config);
     discoverer.rediscover();
-    let warnings = all_discovery_warnings(&discoverer);
+    let warnings = all_discovery_warnings(config, &discoverer);
     let hooks = discoverer.hooks();
     let changed_files = resolve_changed_files(root, base_branch);
     let all_tests = discoverer.tests();
@@ -248,6 +506,8 @@ pub fn discover_tests_changed_first(
                 changed_files: Some(cf.len()),
                 changed_prefix_len: Some(changed_prefix_len),
                 warnings,
+                cached_tests: Some(cached_test_count(&discoverer)),
+                stats: discoverer.stats(),
             }
         }
         Some(_) => {
@@ -258,6 +518,8 @@ pub fn discover_tests_changed_first(
                 changed_files: None,
                 changed_prefix_len: None,
                 warnings,
+                cached_tests: Some(cached_test_count(&discoverer)),
+                stats: discoverer.stats(),
             }
         }
         None => {
@@ -268,9 +530,149 @@ pub fn discover_tests_changed_first(
                 changed_files: None,
                 changed_prefix_len: None,
                 warnings,
+                cached_tests: Some(cached_test_count(&discoverer)),
+                stats: discoverer.stats(),
+            }
+        }
+    }
+}
+
+/// Render `--debug-extraction` output: one line per selected test
+/// function with how many of its calls assertion extraction
+/// recognized, plus the lines of `expect(...)` calls it couldn't parse.
+#[must_use]
+pub fn extraction_report(config: &TrykeConfig, tests: &[tryke_types::TestItem]) -> String {
+    let mut selected: BTreeSet<(&Path, u32)> = BTreeSet::new();
+    for test in tests {
+        if let (Some(file), Some(line)) = (test.file_path.as_deref(), test.line_number) {
+            selected.insert((file, line));
+        }
+    }
+    let files: BTreeSet<&Path> = selected.iter().map(|(file, _)| *file).collect();
+    let mut out = String::new();
+    for file in files {
+        let Ok(source) = std::fs::read_to_string(config.root().join(file)) else {
+            continue;
+        };
+        for coverage in tryke_discovery::extraction_coverage_from_source(
+            &source,
+            &config.discovery.expect_names,
+            &config.discovery.decorator,
+        ) {
+            let Some(line) = coverage.line_number else {
+                continue;
+            };
+            if !selected.contains(&(file, line)) {
+                continue;
+            }
+            let _ = write!(
+                out,
+                "{}:{line} {}: {} recognized, {} unrecognized",
+                file.display(),
+                coverage.name,
+                coverage.recognized,
+                coverage.unrecognized,
+            );
+            if !coverage.unmatched_expect_lines.is_empty() {
+                let lines: Vec<String> = coverage
+                    .unmatched_expect_lines
+                    .iter()
+                    .map(u32::to_string)
+                    .collect();
+                let _ = write!(out, " (unmatched expect at line {})", lines.join(", "));
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Render `--discovery-stats` output: how many files the walk found,
+/// how many missed the cache and were read and parsed, and how long
+/// parsing took.
+#[must_use]
+pub fn discovery_stats_report(stats: &DiscoveryStats) -> String {
+    format!(
+        "discovery: {} files walked, {} parsed, {} bytes read, parse time {:.2?}\n",
+        stats.files_walked, stats.files_parsed, stats.bytes_read, stats.parse_time,
+    )
+}
+
+/// Render `--explain` output: every field discovery recorded for `test`,
+/// one per line, followed by its expected assertions.
+#[must_use]
+pub fn explain_test(test: &tryke_types::TestItem) -> String {
+    let mut out = format!("{}\n", test.id());
+    let mut field = |label: &str, value: &str| {
+        let _ = writeln!(out, "  {:<13} {value}", format!("{label}:"));
+    };
+    field("name", &test.name);
+    if let Some(display_name) = &test.display_name {
+        field("display name", display_name);
+    }
+    field("module", &test.module_path);
+    if let Some(file) = &test.file_path {
+        match test.line_number {
+            Some(line) => field("file", &format!("{}:{line}", file.display())),
+            None => field("file", &file.display().to_string()),
+        }
+    }
+    let kind = if test.doctest_object.is_some() {
+        "doctest".to_owned()
+    } else if let Some(label) = &test.case_label {
+        format!("@test.cases ({label})")
+    } else {
+        "@test".to_owned()
+    };
+    field("kind", &kind);
+    if !test.groups.is_empty() {
+        field("groups", &test.groups.join(" > "));
+    }
+    if !test.tags.is_empty() {
+        field("tags", &test.tags.join(", "));
+    }
+    if let Some(reason) = &test.skip {
+        match &test.skip_category {
+            Some(category) => field("skip", &format!("{reason} [{category}]")),
+            None => field("skip", reason),
+        }
+    }
+    if let Some(reason) = &test.xfail {
+        field("xfail", reason);
+    }
+    if let Some(description) = &test.todo {
+        field("todo", description);
+    }
+    for (key, value) in &test.decorator_kwargs {
+        field("kwarg", &format!("{key}={value}"));
+    }
+    if !test.parameters.is_empty() {
+        field("parameters", &test.parameters.join(", "));
+    }
+    if test.expected_assertions.is_empty() {
+        field("assertions", "none");
+    } else {
+        field("assertions", &test.expected_assertions.len().to_string());
+        for assertion in &test.expected_assertions {
+            let not = if assertion.negated { ".not_" } else { "" };
+            let _ = write!(
+                out,
+                "    line {}: expect({}){not}.{}({})",
+                assertion.line,
+                assertion.subject,
+                assertion.matcher,
+                assertion.args.join(", "),
+            );
+            if let Some(label) = &assertion.label {
+                let _ = write!(out, " [{label}]");
+            }
+            if assertion.pending {
+                out.push_str(" (pending)");
             }
+            out.push('\n');
         }
     }
+    out
 }
 
 #[cfg(test)]
@@ -424,6 +826,28 @@ mod tests {
         assert_eq!(names.len(), 3, "all 3 tests should be present: {names:?}");
     }
 
+    #[test]
+    fn discover_tests_warns_about_files_that_fail_to_parse() {
+        let dir = make_project(&[
+            ("test_ok.py", "@test\ndef test_one():\n    pass\n"),
+            ("test_broken.py", "@test\ndef test_two():\n    x = )\n"),
+        ]);
+        let config = TrykeConfig::discover(dir.path());
+        let discovered = discover_tests(&config, false, None);
+        assert_eq!(discovered.tests.len(), 1);
+        let messages: Vec<&str> = discovered
+            .warnings
+            .iter()
+            .filter(|w| w.kind == DiscoveryWarningKind::SyntaxError)
+            .map(|w| w.message.as_str())
+            .collect();
+        assert_eq!(messages.len(), 1, "{messages:?}");
+        assert!(
+            messages[0].contains("test_broken.py:3 — failed to parse"),
+            "{messages:?}"
+        );
+    }
+
     #[test]
     fn discover_tests_includes_dynamic_import_warnings() {
         let dir = tempfile::tempdir().expect("tempdir");
@@ -490,6 +914,165 @@ mod tests {
         assert_eq!(names, vec!["test_a"], "got: {names:?}");
     }
 
+    #[test]
+    fn missing_path_is_reported_as_discovery_error() {
+        let dir = make_project(&[(
+            "tests/test_a.py",
+            "from tryke import test\n@test\ndef test_a(): pass\n",
+        )]);
+        let specs = vec![
+            pathspec_file("tests/test_a.py"),
+            pathspec_file("tests/nope.py"),
+        ];
+        let config = TrykeConfig::discover(dir.path());
+        let discovered = discover_tests_for_paths(&config, &specs);
+        let errors = missing_path_errors(config.root(), &specs, &discovered.tests);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].file_path, PathBuf::from("tests/nope.py"));
+    }
+
+    #[test]
+    fn redefined_test_warns_and_errors_with_both_lines() {
+        let dir = make_project(&[(
+            "tests/test_a.py",
+            "from tryke import test\n@test\ndef test_x(): pass\n@test\ndef test_x(): pass\n",
+        )]);
+        let config = TrykeConfig::discover(dir.path());
+        let discovered = discover_tests(&config, false, None);
+        let shadowed: Vec<_> = discovered
+            .warnings
+            .iter()
+            .filter(|w| w.kind == DiscoveryWarningKind::ShadowedTest)
+            .collect();
+        assert_eq!(shadowed.len(), 1, "{:?}", discovered.warnings);
+        assert!(
+            shadowed[0]
+                .message
+                .ends_with(":4 test_x shadows the test of the same name on line 2"),
+            "{}",
+            shadowed[0].message
+        );
+        let errors = duplicate_id_errors(&discovered.tests);
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert_eq!(errors[0].line_number, Some(4));
+        assert!(
+            errors[0].message.contains("on lines 2 and 4"),
+            "{}",
+            errors[0].message
+        );
+    }
+
+    #[test]
+    fn repeated_case_labels_are_duplicate_ids() {
+        let dir = make_project(&[(
+            "tests/test_a.py",
+            "from tryke import test\n@test.each([1, 1])\ndef test_x(n): pass\n",
+        )]);
+        let config = TrykeConfig::discover(dir.path());
+        let discovered = discover_tests(&config, false, None);
+        assert!(
+            discovered
+                .warnings
+                .iter()
+                .all(|w| w.kind != DiscoveryWarningKind::ShadowedTest)
+        );
+        let errors = duplicate_id_errors(&discovered.tests);
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert_eq!(errors[0].line_number, Some(2));
+        assert!(
+            errors[0].message.ends_with("on lines 2 and 2"),
+            "{}",
+            errors[0].message
+        );
+    }
+
+    #[test]
+    fn repeated_display_names_are_duplicates() {
+        let dir = make_project(&[(
+            "tests/test_a.py",
+            "import tryke\n@tryke.test(\"adds\")\ndef test_one(): pass\n@tryke.test(\"adds\")\ndef test_two(): pass\n",
+        )]);
+        let config = TrykeConfig::discover(dir.path());
+        let discovered = discover_tests(&config, false, None);
+        let errors = duplicate_id_errors(&discovered.tests);
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert_eq!(
+            errors[0].message,
+            "duplicate test name `adds` on lines 2 and 4"
+        );
+    }
+
+    #[test]
+    fn select_benchmarks_keeps_only_benchmark_tests() {
+        let tests = vec![
+            tryke_types::TestItem {
+                name: "test_fast".into(),
+                benchmark: true,
+                ..Default::default()
+            },
+            tryke_types::TestItem {
+                name: "test_plain".into(),
+                ..Default::default()
+            },
+        ];
+        let selection = SelectionSummary {
+            collected: 2,
+            selected: 2,
+            via: None,
+            cached: None,
+            seed: None,
+        };
+
+        let (mut kept, mut narrowed) = (tests.clone(), selection.clone());
+        select_benchmarks(&mut kept, &mut narrowed, true);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].name, "test_fast");
+        assert_eq!(narrowed.selected, 1);
+        assert_eq!(narrowed.via.as_deref(), Some("--benchmark"));
+
+        let (mut all, mut unchanged) = (tests, selection);
+        select_benchmarks(&mut all, &mut unchanged, false);
+        assert_eq!(all.len(), 2);
+        assert!(all.iter().all(|test| !test.benchmark));
+        assert_eq!(unchanged.selected, 2);
+    }
+
+    #[test]
+    fn shuffle_tests_is_reproducible_from_the_seed() {
+        let tests: Vec<_> = (0..20)
+            .map(|i| tryke_types::TestItem {
+                name: format!("test_{i}"),
+                ..Default::default()
+            })
+            .collect();
+        let names = |tests: &[tryke_types::TestItem]| -> Vec<String> {
+            tests.iter().map(|test| test.name.clone()).collect()
+        };
+        let shuffled = |seed| {
+            let mut shuffled = tests.clone();
+            let mut selection = SelectionSummary {
+                collected: tests.len(),
+                selected: tests.len(),
+                via: None,
+                cached: None,
+                seed: None,
+            };
+            shuffle_tests(&mut shuffled, &mut selection, seed);
+            (names(&shuffled), selection.seed)
+        };
+
+        let (first, seed) = shuffled(Some(7));
+        assert_eq!(seed, Some(7));
+        assert_eq!(shuffled(Some(7)).0, first);
+        assert_ne!(first, names(&tests));
+        assert_ne!(shuffled(Some(8)).0, first);
+        let mut sorted = first.clone();
+        sorted.sort_by_key(|name| name[5..].parse::<usize>().unwrap());
+        assert_eq!(sorted, names(&tests));
+
+        assert_eq!(shuffled(None), (names(&tests), None));
+    }
+
     #[test]
     fn for_paths_directory_walks_only_subtree() {
         let dir = make_project(&[
@@ -601,6 +1184,35 @@ mod tests {
         assert_eq!(names, vec!["test_a"], "got: {names:?}");
     }
 
+    #[test]
+    fn explicit_rootdir_anchors_modules_and_paths() {
+        let dir = make_project(&[
+            (
+                "services/api/tests/test_routes.py",
+                "from tryke import test\n@test\ndef test_routes(): pass\n",
+            ),
+            (
+                "test_top.py",
+                "from tryke import test\n@test\ndef test_top(): pass\n",
+            ),
+        ]);
+        let config = TrykeConfig::load(
+            dir.path(),
+            tryke_config::ConfigOverrides {
+                rootdir: Some(dir.path().join("services/api")),
+                ..tryke_config::ConfigOverrides::default()
+            },
+        );
+        let discovered = discover_tests(&config, false, None);
+        assert_eq!(discovered.tests.len(), 1, "{:?}", discovered.tests);
+        let test = &discovered.tests[0];
+        assert_eq!(test.module_path, "tests.test_routes");
+        assert_eq!(
+            test.file_path.as_deref(),
+            Some(Path::new("tests/test_routes.py"))
+        );
+    }
+
     #[test]
     fn for_paths_outside_root_falls_back_to_full_walk() {
         let dir = make_project(&[(
@@ -621,4 +1233,82 @@ mod tests {
             "fallback should still find in-project tests: {names:?}"
         );
     }
+
+    #[test]
+    fn extraction_report_lists_unmatched_expect_lines() {
+        let dir = make_project(&[(
+            "test_math.py",
+            "from tryke import test, expect\n@test\ndef test_add():\n    \
+             expect(1).to_equal(1)\n    expect(1).not_.not_.to_equal(1)\n",
+        )]);
+        let config = TrykeConfig::discover(dir.path());
+        let discovered = discover_tests(&config, false, None);
+        assert_eq!(
+            extraction_report(&config, &discovered.tests),
+            "test_math.py:2 test_add: 1 recognized, 1 unrecognized (unmatched expect at line 5)\n"
+        );
+    }
+
+    #[test]
+    fn assertion_count_limits_flag_under_and_over_counts() {
+        let dir = make_project(&[
+            (
+                "pyproject.toml",
+                "[tool.tryke]\nmin_assertions = 1\nmax_assertions = 2\n",
+            ),
+            (
+                "test_counts.py",
+                "from tryke import test, expect\n\
+                 @test\ndef test_none():\n    pass\n\
+                 @test\ndef test_one():\n    expect(1).to_equal(1)\n\
+                 @test\ndef test_three():\n    expect(1).to_equal(1)\n    \
+                 expect(2).to_equal(2)\n    expect(3).to_equal(3)\n\
+                 @test\ndef test_pending_only():\n    expect(1).pending().to_equal(2)\n\
+                 @test.todo\ndef test_later():\n    pass\n",
+            ),
+        ]);
+        let config = TrykeConfig::discover(dir.path());
+        let discovered = discover_tests(&config, false, None);
+        let messages: Vec<&str> = discovered
+            .warnings
+            .iter()
+            .filter(|w| w.kind == DiscoveryWarningKind::AssertionCount)
+            .map(|w| w.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "test_counts.py:2 test_none — 0 assertion(s), fewer than min_assertions = 1",
+                "test_counts.py:8 test_three — 3 assertion(s), more than max_assertions = 2",
+                "test_counts.py:13 test_pending_only — 0 assertion(s), fewer than min_assertions = 1",
+            ]
+        );
+        assert_eq!(assertion_count_violations(&discovered.warnings), 3);
+    }
+
+    #[test]
+    fn explain_lists_matchers_and_lines() {
+        let dir = make_project(&[(
+            "test_math.py",
+            "from tryke import test, expect\n@test(tags=[\"fast\"])\ndef test_add():\n    \
+             expect(1 + 1).to_equal(2)\n    expect(None).not_.to_be_truthy()\n",
+        )]);
+        let config = TrykeConfig::discover(dir.path());
+        let discovered = discover_tests(&config, false, None);
+        let output = explain_test(&discovered.tests[0]);
+        assert!(output.starts_with("test_math.py::test_add\n"), "{output}");
+        assert!(
+            output.contains("  file:         test_math.py:2\n"),
+            "{output}"
+        );
+        assert!(output.contains("  tags:         fast\n"), "{output}");
+        assert!(
+            output.contains("    line 4: expect(1 + 1).to_equal(2)\n"),
+            "{output}"
+        );
+        assert!(
+            output.contains("    line 5: expect(None).not_.to_be_truthy()\n"),
+            "{output}"
+        );
+    }
 }
@@ -1,17 +1,95 @@
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use log::LevelFilter;
-use tokio_stream::StreamExt;
+use tokio::sync::Notify;
+use tokio_stream::{Stream, StreamExt};
 use tryke_config::TrykeConfig;
 use tryke_reporter::Reporter;
-use tryke_runner::{DistMode, WorkerPool, partition_with_hooks};
-use tryke_types::{ChangedSelectionSummary, HookItem, RunSummary, TestOutcome};
+use tryke_runner::{DistMode, PoolEvent, SubmitOptions, WorkerPool, partition_with_hooks};
+use tryke_types::{
+    ChangedSelectionSummary, DiscoveryWarning, HookItem, RunSummary, TestItem, TestOutcome,
+    TimingStats,
+};
+
+/// Woken by the Ctrl-C handler from [`install_interrupt_handler`].
+static INTERRUPT: Notify = Notify::const_new();
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Install a Ctrl-C handler that stops the current run at the next
+/// result and lets the reporter close it with `on_run_interrupted` — so
+/// every reporter prints its partial summary and a `--reporter json`
+/// stream still ends with a terminating event. The run then shuts its
+/// worker pool down as usual.
+///
+/// Idempotent: `ctrlc::set_handler` errors if called twice, which we
+/// ignore.
+pub fn install_interrupt_handler() {
+    let _ = ctrlc::set_handler(|| {
+        INTERRUPTED.store(true, Ordering::SeqCst);
+        INTERRUPT.notify_one();
+    });
+}
+
+/// Whether a Ctrl-C was received since [`install_interrupt_handler`].
+pub fn was_interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+/// Forget a Ctrl-C that ended an earlier cycle, so the next one in the
+/// same process (a watch re-run) starts clean.
+pub fn reset_interrupt() {
+    clear_interrupt(&INTERRUPTED, &INTERRUPT);
+}
+
+/// Clear `flag` and consume any wake-up `notify_one` stored on
+/// `interrupt` while no cycle was waiting — left in place, that stored
+/// permit would cut the next cycle short the moment it starts.
+fn clear_interrupt(flag: &AtomicBool, interrupt: &Notify) {
+    flag.store(false, Ordering::SeqCst);
+    let notified = std::pin::pin!(interrupt.notified());
+    notified.enable();
+}
+
+/// Where a run reads the time from. [`SystemClock`] is the real one;
+/// tests substitute a fixed clock so the summary's `Start at` and
+/// `Duration` lines come out the same on every run.
+pub trait Clock {
+    /// A monotonic reading, for measuring how long the run took.
+    fn now(&self) -> Instant;
+    /// The local time of day, shown as the summary's `Start at`.
+    fn time_of_day(&self) -> String;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn time_of_day(&self) -> String {
+        chrono::Local::now().format("%H:%M:%S").to_string()
+    }
+}
 
 pub fn worker_pool_size() -> usize {
     std::thread::available_parallelism().map_or(4, std::num::NonZero::get)
 }
 
+/// Per-test limits of a run, from `tryke test --timeout` and
+/// `--slow-threshold`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TestLimits {
+    /// How long a test may run before its worker is killed and the test
+    /// fails. `None` waits forever.
+    pub timeout: Option<Duration>,
+    /// Duration past which a test is flagged slow. `None` flags nothing.
+    pub slow_threshold: Option<Duration>,
+}
+
 #[expect(clippy::too_many_arguments)]
 pub async fn run_tests(
     reporter: &mut dyn Reporter,
@@ -22,6 +100,7 @@ pub async fn run_tests(
     maxfail: Option<usize>,
     workers: Option<usize>,
     dist: DistMode,
+    limits: TestLimits,
     discovery_duration: Option<Duration>,
     changed_selection: Option<ChangedSelectionSummary>,
 ) -> Result<RunSummary> {
@@ -35,6 +114,7 @@ pub async fn run_tests(
         &pool,
         maxfail,
         dist,
+        limits,
         discovery_duration,
         changed_selection,
     )
@@ -59,6 +139,33 @@ fn flush_buffer(
     }
 }
 
+/// How one reporting cycle is bounded and timed.
+struct CycleOptions<'a> {
+    /// Stop scheduling after this many failures.
+    maxfail: Option<usize>,
+    /// Results that took longer are flagged slow.
+    slow_threshold: Option<Duration>,
+    /// Folded into the summary's total duration.
+    discovery_duration: Option<Duration>,
+    changed_selection: Option<ChangedSelectionSummary>,
+    /// Woken to stop the cycle early.
+    interrupt: &'a Notify,
+    clock: &'a dyn Clock,
+}
+
+impl Default for CycleOptions<'static> {
+    fn default() -> Self {
+        Self {
+            maxfail: None,
+            slow_threshold: None,
+            discovery_duration: None,
+            changed_selection: None,
+            interrupt: &INTERRUPT,
+            clock: &SystemClock,
+        }
+    }
+}
+
 #[expect(clippy::too_many_arguments)]
 pub async fn report_cycle(
     reporter: &mut dyn Reporter,
@@ -67,31 +174,165 @@ pub async fn report_cycle(
     pool: &WorkerPool,
     maxfail: Option<usize>,
     dist: DistMode,
+    limits: TestLimits,
     discovery_duration: Option<Duration>,
     changed_selection: Option<ChangedSelectionSummary>,
 ) -> Result<RunSummary> {
+    let submit = SubmitOptions {
+        stream_assertions: reporter.streams_assertions(),
+        timeout: limits.timeout,
+    };
+    report_cycle_with(
+        reporter,
+        tests,
+        CycleOptions {
+            maxfail,
+            slow_threshold: limits.slow_threshold,
+            discovery_duration,
+            changed_selection,
+            ..CycleOptions::default()
+        },
+        |run_tests| {
+            let partition = partition_with_hooks(run_tests, hooks, dist);
+            (
+                partition.warnings,
+                pool.submit_events(partition.units, submit),
+            )
+        },
+    )
+    .await
+}
+
+/// One project root's share of a run that spans several `--root`s.
+pub struct RootRun {
+    pub config: TrykeConfig,
+    pub tests: Vec<TestItem>,
+    pub hooks: Vec<HookItem>,
+}
+
+/// Run tests from several project roots as a single report. Workers
+/// import test modules relative to their root, so each root gets its own
+/// pool; tests are routed by [`TestItem::root`] and the per-root result
+/// streams are merged.
+#[expect(clippy::too_many_arguments)]
+pub async fn run_tests_across_roots(
+    reporter: &mut dyn Reporter,
+    roots: Vec<RootRun>,
+    log_level: LevelFilter,
+    maxfail: Option<usize>,
+    workers: Option<usize>,
+    dist: DistMode,
+    limits: TestLimits,
+    discovery_duration: Option<Duration>,
+) -> Result<RunSummary> {
+    let mut pools = Vec::with_capacity(roots.len());
+    let mut hooks = Vec::with_capacity(roots.len());
+    let mut tests = Vec::new();
+    for run in roots {
+        let pool_size = workers.unwrap_or_else(|| run.tests.len().min(worker_pool_size()));
+        let python = run.config.python();
+        let pool =
+            WorkerPool::spawn(pool_size, &python, run.config.root(), None, log_level, true).await;
+        pools.push((run.config.root().to_path_buf(), pool));
+        hooks.push(run.hooks);
+        tests.extend(run.tests);
+    }
+    let submit = SubmitOptions {
+        stream_assertions: reporter.streams_assertions(),
+        timeout: limits.timeout,
+    };
+    let summary = report_cycle_with(
+        reporter,
+        tests,
+        CycleOptions {
+            maxfail,
+            slow_threshold: limits.slow_threshold,
+            discovery_duration,
+            ..CycleOptions::default()
+        },
+        |run_tests| {
+            let mut per_root: Vec<Vec<TestItem>> = pools.iter().map(|_| Vec::new()).collect();
+            for test in run_tests {
+                let idx = pools
+                    .iter()
+                    .position(|(root, _)| test.root.as_ref() == Some(root))
+                    .unwrap_or_default();
+                if let Some(bucket) = per_root.get_mut(idx) {
+                    bucket.push(test);
+                }
+            }
+            let mut warnings = Vec::new();
+            let mut merged: Pin<Box<dyn Stream<Item = PoolEvent>>> =
+                Box::pin(tokio_stream::empty());
+            for (((_, pool), hooks), tests) in pools.iter().zip(&hooks).zip(per_root) {
+                let partition = partition_with_hooks(tests, hooks, dist);
+                warnings.extend(partition.warnings);
+                merged = Box::pin(merged.merge(pool.submit_events(partition.units, submit)));
+            }
+            (warnings, merged)
+        },
+    )
+    .await?;
+    for (_, pool) in pools {
+        pool.shutdown();
+    }
+    Ok(summary)
+}
+
+/// Drive one reporting cycle: short-circuit skip/todo tests, hand the
+/// rest to `submit` for scheduling, and report results in discovery
+/// order per file as they stream back. A wake-up on `options.interrupt`
+/// stops the cycle early and closes it with `on_run_interrupted`. The
+/// run's start time and duration are read from `options.clock`.
+async fn report_cycle_with<S>(
+    reporter: &mut dyn Reporter,
+    tests: Vec<tryke_types::TestItem>,
+    options: CycleOptions<'_>,
+    submit: impl FnOnce(Vec<TestItem>) -> (Vec<DiscoveryWarning>, S),
+) -> Result<RunSummary>
+where
+    S: Stream<Item = PoolEvent> + Unpin,
+{
     use std::collections::{HashMap, HashSet};
     use std::path::PathBuf;
 
+    let CycleOptions {
+        maxfail,
+        slow_threshold,
+        discovery_duration,
+        changed_selection,
+        interrupt,
+        clock,
+    } = options;
+
     let file_count = tests
         .iter()
         .filter_map(|t| t.file_path.as_ref())
         .collect::<HashSet<_>>()
         .len();
 
-    let start_time = chrono::Local::now().format("%H:%M:%S").to_string();
+    let start_time = clock.time_of_day();
 
     // build discovery-order index and per-file expected counts
     // before partitioning so shortcircuit tests are included
     let discovery_order: HashMap<String, usize> =
         tests.iter().enumerate().map(|(i, t)| (t.id(), i)).collect();
 
+    // Files in discovery order. A file is reported once all its results
+    // are in and every earlier file has been, so the order doesn't depend
+    // on which worker finishes first.
+    let mut file_order: Vec<Option<PathBuf>> = Vec::new();
     let mut expected_per_file: HashMap<Option<PathBuf>, usize> = HashMap::new();
     for t in &tests {
-        *expected_per_file.entry(t.file_path.clone()).or_default() += 1;
+        let expected = expected_per_file.entry(t.file_path.clone()).or_default();
+        if *expected == 0 {
+            file_order.push(t.file_path.clone());
+        }
+        *expected += 1;
     }
+    let mut next_file = 0usize;
 
-    let start = Instant::now();
+    let start = clock.now();
     reporter.on_run_start(&tests);
 
     let mut passed = 0usize;
@@ -100,6 +341,9 @@ pub async fn report_cycle(
     let mut errors = 0usize;
     let mut xfailed = 0usize;
     let mut todo = 0usize;
+    let mut slow = 0usize;
+    // Every executed test's duration, for the summary's timing stats.
+    let mut durations: Vec<Duration> = Vec::with_capacity(tests.len());
 
     type FileBuffer = Vec<(usize, tryke_types::TestResult)>;
     let mut buffers: HashMap<Option<PathBuf>, FileBuffer> = HashMap::new();
@@ -121,13 +365,7 @@ pub async fn report_cycle(
                 reason: t.skip.clone(),
             }
         };
-        let result = tryke_types::TestResult {
-            test: t,
-            outcome,
-            duration: std::time::Duration::ZERO,
-            stdout: String::new(),
-            stderr: String::new(),
-        };
+        let result = tryke_types::TestResult::new(t, outcome, Duration::ZERO);
         let idx = discovery_order
             .get(&result.test.id())
             .copied()
@@ -137,12 +375,58 @@ pub async fn report_cycle(
     }
 
     let mut hit_maxfail = false;
-    let partition = partition_with_hooks(run_tests, hooks, dist);
-    for warning in &partition.warnings {
+    let mut interrupted = false;
+    // Tests a worker has started but not finished, keyed by id, for
+    // heartbeats and for letting a stopped run finish what's in flight.
+    let mut running: HashMap<String, (TestItem, Instant)> = HashMap::new();
+    let mut heartbeat = reporter.heartbeat_interval().map(|period| {
+        let mut ticker = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        ticker
+    });
+    let (warnings, mut stream) = submit(run_tests);
+    for warning in &warnings {
         reporter.on_discovery_warning(warning);
     }
-    let mut stream = pool.submit(partition.units);
-    while let Some(result) = stream.next().await {
+    loop {
+        // Results that are already available win over an interrupt so the
+        // partial summary counts everything that actually finished.
+        let next = tokio::select! {
+            biased;
+            result = stream.next() => result,
+            () = interrupt.notified() => {
+                interrupted = true;
+                None
+            }
+            _ = async {
+                match heartbeat.as_mut() {
+                    Some(ticker) => ticker.tick().await,
+                    None => std::future::pending().await,
+                }
+            } => {
+                send_heartbeats(reporter, &running, clock.now());
+                continue;
+            }
+        };
+        let mut result = match next {
+            Some(PoolEvent::Result(result)) => result,
+            // Tests a worker picks up after the run was stopped aren't
+            // waited for.
+            Some(PoolEvent::Started(_)) if hit_maxfail => continue,
+            Some(PoolEvent::Started(test)) => {
+                running.insert(test.id(), (test, clock.now()));
+                continue;
+            }
+            Some(PoolEvent::Assertion(event)) => {
+                reporter.on_assertion(&event);
+                continue;
+            }
+            None => break,
+        };
+        let was_running = running.remove(&result.test.id()).is_some();
+        if hit_maxfail && !was_running {
+            continue;
+        }
         match &result.outcome {
             TestOutcome::Passed => passed += 1,
             TestOutcome::Failed { .. } | TestOutcome::XPassed => failed += 1,
@@ -151,31 +435,41 @@ pub async fn report_cycle(
             TestOutcome::XFailed { .. } => xfailed += 1,
             TestOutcome::Todo { .. } => todo += 1,
         }
+        durations.push(result.duration);
+        result.slow = result.exceeds_slow_threshold(slow_threshold);
+        slow += usize::from(result.slow);
 
         let idx = discovery_order
             .get(&result.test.id())
             .copied()
             .unwrap_or(usize::MAX);
         let file = result.test.file_path.clone();
-        buffers.entry(file.clone()).or_default().push((idx, result));
+        buffers.entry(file).or_default().push((idx, result));
 
-        // flush if this file's buffer is complete
-        if let Some(&expected) = expected_per_file.get(&file)
-            && buffers.get(&file).is_some_and(|b| b.len() >= expected)
+        // flush complete files, stopping at the first one still running
+        while let Some(file) = file_order.get(next_file)
+            && let Some(&expected) = expected_per_file.get(file)
+            && buffers.get(file).is_some_and(|b| b.len() >= expected)
         {
-            flush_buffer(&file, &mut buffers, reporter);
+            flush_buffer(file, &mut buffers, reporter);
+            next_file += 1;
         }
 
         if let Some(max) = maxfail
             && failed >= max
         {
             hit_maxfail = true;
+        }
+        // Once stopped, tests already running still finish and are
+        // reported; the cycle ends when the last of them does.
+        if hit_maxfail && running.is_empty() {
             break;
         }
     }
 
-    // flush any remaining buffered files (partial files from maxfail, or edge cases)
-    if hit_maxfail || !buffers.is_empty() {
+    // flush any remaining buffered files (partial files from maxfail or an
+    // interrupt, or edge cases)
+    if hit_maxfail || interrupted || !buffers.is_empty() {
         let mut remaining: Vec<(usize, Option<PathBuf>)> = buffers
             .iter()
             .map(|(file, buf)| {
@@ -189,6 +483,7 @@ pub async fn report_cycle(
         }
     }
 
+    let elapsed = clock.now().saturating_duration_since(start);
     let summary = RunSummary {
         passed,
         failed,
@@ -196,17 +491,38 @@ pub async fn report_cycle(
         errors,
         xfailed,
         todo,
-        duration: discovery_duration.unwrap_or_default() + start.elapsed(),
+        duration: discovery_duration.unwrap_or_default() + elapsed,
         discovery_duration,
-        test_duration: Some(start.elapsed()),
+        test_duration: Some(elapsed),
         file_count,
         start_time: Some(start_time),
         changed_selection,
+        timing_stats: TimingStats::from_durations(&durations),
+        slow,
+        stopped_after: maxfail.filter(|_| hit_maxfail),
     };
-    reporter.on_run_complete(&summary);
+    if interrupted {
+        reporter.on_run_interrupted(&summary);
+    } else {
+        reporter.on_run_complete(&summary);
+    }
     Ok(summary)
 }
 
+/// Report every test in `running`, longest-running first, with how long
+/// it has been running as of `now`.
+fn send_heartbeats(
+    reporter: &mut dyn Reporter,
+    running: &std::collections::HashMap<String, (TestItem, Instant)>,
+    now: Instant,
+) {
+    let mut tests: Vec<_> = running.values().collect();
+    tests.sort_by_key(|(test, started)| (*started, test.id()));
+    for (test, started) in tests {
+        reporter.on_heartbeat(test, now.saturating_duration_since(*started));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
@@ -217,7 +533,7 @@ mod tests {
         DotReporter, JSONReporter, JUnitReporter, NextReporter, SugarReporter, TextReporter,
     };
     use tryke_testing::python_bin as test_python_bin;
-    use tryke_types::TestOutcome;
+    use tryke_types::{TestOutcome, TestResult};
 
     use super::*;
     use crate::discovery::discover_tests;
@@ -244,12 +560,338 @@ mod tests {
             pool,
             None,
             DistMode::Test,
+            TestLimits::default(),
             None,
             None,
         )
         .await
     }
 
+    /// Remembers the order tests were reported in and which terminal
+    /// callback a cycle ended with.
+    #[derive(Default)]
+    struct EndRecorder {
+        reported: Vec<String>,
+        completed: Option<RunSummary>,
+        interrupted: Option<RunSummary>,
+    }
+
+    impl Reporter for EndRecorder {
+        fn on_run_start(&mut self, _tests: &[tryke_types::TestItem]) {}
+
+        fn on_test_complete(&mut self, result: &TestResult) {
+            self.reported.push(result.test.name.clone());
+        }
+
+        fn on_run_complete(&mut self, summary: &RunSummary) {
+            self.completed = Some(summary.clone());
+        }
+
+        fn on_run_interrupted(&mut self, summary: &RunSummary) {
+            self.interrupted = Some(summary.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn interrupt_closes_cycle_with_partial_summary() {
+        let tests: Vec<TestItem> = ["test_done", "test_hangs"]
+            .into_iter()
+            .map(|name| TestItem {
+                name: name.into(),
+                module_path: "tests.m".into(),
+                ..Default::default()
+            })
+            .collect();
+        let finished = TestResult::new(
+            tests[0].clone(),
+            TestOutcome::Passed,
+            Duration::from_millis(1),
+        );
+        // Simulates the Ctrl-C handler firing while the second test hangs.
+        let interrupt = Notify::new();
+        interrupt.notify_one();
+
+        let mut reporter = EndRecorder::default();
+        let summary = report_cycle_with(
+            &mut reporter,
+            tests,
+            CycleOptions {
+                interrupt: &interrupt,
+                ..CycleOptions::default()
+            },
+            |_| {
+                let stream = tokio_stream::iter(vec![PoolEvent::Result(finished)])
+                    .chain(tokio_stream::pending());
+                (Vec::new(), stream)
+            },
+        )
+        .await
+        .expect("cycle");
+
+        assert_eq!(summary.passed, 1);
+        assert!(reporter.completed.is_none());
+        assert_eq!(reporter.interrupted.map(|s| s.passed), Some(1));
+    }
+
+    #[tokio::test]
+    async fn cleared_interrupt_does_not_cut_the_next_cycle_short() {
+        let test = TestItem {
+            name: "test_done".into(),
+            module_path: "tests.m".into(),
+            ..Default::default()
+        };
+        let finished = TestResult::new(test.clone(), TestOutcome::Passed, Duration::from_millis(1));
+        // A Ctrl-C that landed after the previous cycle finished.
+        let flag = AtomicBool::new(true);
+        let interrupt = Notify::new();
+        interrupt.notify_one();
+        clear_interrupt(&flag, &interrupt);
+        assert!(!flag.load(Ordering::SeqCst));
+
+        let mut reporter = EndRecorder::default();
+        report_cycle_with(
+            &mut reporter,
+            vec![test],
+            CycleOptions {
+                interrupt: &interrupt,
+                ..CycleOptions::default()
+            },
+            |_| {
+                let stream = tokio_stream::iter(vec![PoolEvent::Result(finished)]);
+                (Vec::new(), stream)
+            },
+        )
+        .await
+        .expect("cycle");
+
+        assert!(reporter.interrupted.is_none());
+        assert_eq!(reporter.completed.map(|s| s.passed), Some(1));
+    }
+
+    /// Reads `start` first, then `start + elapsed` for every later reading,
+    /// so a run always appears to take exactly `elapsed`.
+    struct FixedClock {
+        start: Instant,
+        elapsed: Duration,
+        started: std::cell::Cell<bool>,
+    }
+
+    impl Clock for FixedClock {
+        fn now(&self) -> Instant {
+            if self.started.replace(true) {
+                self.start + self.elapsed
+            } else {
+                self.start
+            }
+        }
+
+        fn time_of_day(&self) -> String {
+            "09:30:00".into()
+        }
+    }
+
+    #[tokio::test]
+    async fn fixed_clock_makes_summary_timing_deterministic() {
+        let tests: Vec<TestItem> = ["test_a", "test_b"]
+            .into_iter()
+            .map(|name| TestItem {
+                name: name.into(),
+                module_path: "tests.m".into(),
+                ..Default::default()
+            })
+            .collect();
+        let results: Vec<PoolEvent> = tests
+            .iter()
+            .map(|test| {
+                PoolEvent::Result(TestResult::new(
+                    test.clone(),
+                    TestOutcome::Passed,
+                    Duration::from_millis(5),
+                ))
+            })
+            .collect();
+        let clock = FixedClock {
+            start: Instant::now(),
+            elapsed: Duration::from_millis(250),
+            started: std::cell::Cell::new(false),
+        };
+
+        let mut reporter = TextReporter::with_writer(Vec::new()).with_color(false);
+        let summary = report_cycle_with(
+            &mut reporter,
+            tests,
+            CycleOptions {
+                discovery_duration: Some(Duration::from_millis(40)),
+                clock: &clock,
+                ..CycleOptions::default()
+            },
+            |_| (Vec::new(), tokio_stream::iter(results)),
+        )
+        .await
+        .expect("cycle");
+
+        assert_eq!(summary.test_duration, Some(Duration::from_millis(250)));
+        let out = String::from_utf8(reporter.into_writer()).expect("utf-8 output");
+        assert!(out.contains("   Start at  09:30:00\n"), "{out}");
+        assert!(
+            out.contains("   Duration  290.00ms (discover 40.00ms, tests 250.00ms)\n"),
+            "{out}"
+        );
+    }
+
+    #[tokio::test]
+    async fn fail_fast_finishes_running_tests_and_drops_later_ones() {
+        let tests: Vec<TestItem> = ["test_fails", "test_running", "test_late"]
+            .into_iter()
+            .map(|name| TestItem {
+                name: name.into(),
+                module_path: "tests.m".into(),
+                ..Default::default()
+            })
+            .collect();
+        let result = |test: &TestItem, outcome: TestOutcome| {
+            PoolEvent::Result(TestResult::new(
+                test.clone(),
+                outcome,
+                Duration::from_millis(1),
+            ))
+        };
+        let failed = TestOutcome::Failed {
+            message: "boom".into(),
+            traceback: None,
+            assertions: vec![],
+            executed_lines: vec![],
+        };
+        // `test_running` was in flight when `test_fails` failed, and a
+        // worker picked up `test_late` after that. The stream never ends,
+        // so the cycle must stop on its own once `test_running` is in.
+        let events = vec![
+            PoolEvent::Started(tests[0].clone()),
+            PoolEvent::Started(tests[1].clone()),
+            result(&tests[0], failed),
+            PoolEvent::Started(tests[2].clone()),
+            result(&tests[1], TestOutcome::Passed),
+            result(&tests[2], TestOutcome::Passed),
+        ];
+
+        let mut reporter = EndRecorder::default();
+        let summary = report_cycle_with(
+            &mut reporter,
+            tests,
+            CycleOptions {
+                maxfail: Some(1),
+                ..CycleOptions::default()
+            },
+            |_| {
+                let stream = tokio_stream::iter(events).chain(tokio_stream::pending());
+                (Vec::new(), stream)
+            },
+        )
+        .await
+        .expect("cycle");
+
+        assert_eq!(reporter.reported, ["test_fails", "test_running"]);
+        assert_eq!((summary.failed, summary.passed), (1, 1));
+        assert_eq!(summary.stopped_after, Some(1));
+        assert!(summary.aborted());
+        assert!(reporter.completed.is_some());
+    }
+
+    #[tokio::test]
+    async fn parallel_results_are_reported_by_file_in_discovery_order() {
+        let item = |name: &str, file: &str| TestItem {
+            name: name.into(),
+            module_path: "tests.m".into(),
+            file_path: Some(file.into()),
+            ..Default::default()
+        };
+        let tests = vec![
+            item("test_a1", "tests/a.py"),
+            item("test_a2", "tests/a.py"),
+            item("test_b1", "tests/b.py"),
+        ];
+        // Workers finish out of order, each test taking 100ms of a run
+        // that only took 120ms of wall-clock time.
+        let results: Vec<PoolEvent> = [2, 1, 0]
+            .into_iter()
+            .map(|i: usize| {
+                PoolEvent::Result(TestResult::new(
+                    tests[i].clone(),
+                    TestOutcome::Passed,
+                    Duration::from_millis(100),
+                ))
+            })
+            .collect();
+        let clock = FixedClock {
+            start: Instant::now(),
+            elapsed: Duration::from_millis(120),
+            started: std::cell::Cell::new(false),
+        };
+
+        let mut reporter = EndRecorder::default();
+        let summary = report_cycle_with(
+            &mut reporter,
+            tests,
+            CycleOptions {
+                clock: &clock,
+                ..CycleOptions::default()
+            },
+            |_| (Vec::new(), tokio_stream::iter(results)),
+        )
+        .await
+        .expect("cycle");
+
+        assert_eq!(reporter.reported, ["test_a1", "test_a2", "test_b1"]);
+        assert_eq!(summary.passed, 3);
+        assert_eq!(summary.duration, Duration::from_millis(120));
+    }
+
+    #[test]
+    fn heartbeats_name_each_running_test_longest_first() {
+        let item = |name: &str| TestItem {
+            name: name.into(),
+            module_path: "tests.m".into(),
+            file_path: Some("tests/m.py".into()),
+            ..Default::default()
+        };
+        let now = Instant::now() + Duration::from_secs(60);
+        let mut running = std::collections::HashMap::new();
+        for (name, age) in [("test_new", 5), ("test_old", 45)] {
+            let test = item(name);
+            running.insert(test.id(), (test, now - Duration::from_secs(age)));
+        }
+
+        let mut reporter = JSONReporter::with_writer(Vec::new());
+        send_heartbeats(&mut reporter, &running, now);
+
+        let out = String::from_utf8(reporter.into_writer()).expect("utf-8 output");
+        let events: Vec<serde_json::Value> = out
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("json line"))
+            .collect();
+        let beats: Vec<_> = events
+            .iter()
+            .map(|e| {
+                (
+                    e["event"].as_str(),
+                    e["id"].as_str(),
+                    e["elapsed_ms"].as_u64(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            beats,
+            vec![
+                (
+                    Some("heartbeat"),
+                    Some("tests/m.py::test_old"),
+                    Some(45_000)
+                ),
+                (Some("heartbeat"), Some("tests/m.py::test_new"), Some(5_000)),
+            ]
+        );
+    }
+
     /// Smoke-test a reporter against the full `run_tests` pipeline using an
     /// empty project. Exercises pool init/teardown and the reporter's
     /// run_start/run_summary callbacks without doing real work. Snapshot
@@ -268,6 +910,7 @@ mod tests {
             None,
             None,
             DistMode::Test,
+            TestLimits::default(),
             None,
             None,
         )
@@ -384,6 +1027,7 @@ mod tests {
                 None,
                 None,
                 DistMode::Test,
+                TestLimits::default(),
                 None,
                 None
             )
@@ -491,6 +1135,7 @@ def test_failing():
             &pool,
             None,
             DistMode::Test,
+            TestLimits::default(),
             None,
             None,
         )
@@ -534,6 +1179,7 @@ def test_failing():
             &pool,
             None,
             DistMode::Test,
+            TestLimits::default(),
             None,
             None,
         )
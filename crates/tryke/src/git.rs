@@ -1,8 +1,11 @@
 use std::{
     collections::BTreeSet,
+    ops::RangeInclusive,
     path::{Path, PathBuf},
 };
 
+use tryke_types::TestItem;
+
 fn git_paths(root: &Path, args: &[&str]) -> Option<Vec<PathBuf>> {
     let output = std::process::Command::new("git")
         .args(args)
@@ -63,6 +66,73 @@ pub fn resolve_changed_files(root: &Path, base_branch: Option<&str>) -> Option<V
     }
 }
 
+/// The author who last edited `test`'s source lines, for `--blame`.
+/// `base` is the directory `test.file_path` is relative to. Returns
+/// `None` when the file isn't in a git repository or git is unavailable.
+#[must_use]
+pub fn blame_test(base: &Path, test: &TestItem) -> Option<String> {
+    let file = base.join(test.file_path.as_ref()?);
+    let source = std::fs::read_to_string(&file).ok()?;
+    blame_last_author(&file, test_lines(&source, test.line_number?))
+}
+
+/// Lines of the test that starts at line `start`: its decorators, its
+/// `def`, and every following line indented deeper than the `def`, up to
+/// the last non-blank one.
+fn test_lines(source: &str, start: u32) -> RangeInclusive<u32> {
+    let mut end = start;
+    let mut def_indent = None;
+    for (line, number) in source.lines().zip(1u32..).skip_while(|(_, n)| *n < start) {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        match def_indent {
+            None => {
+                end = number;
+                if trimmed.starts_with("def ") || trimmed.starts_with("async def ") {
+                    def_indent = Some(indent);
+                }
+            }
+            Some(_) if trimmed.is_empty() => {}
+            Some(def) if indent <= def => break,
+            Some(_) => end = number,
+        }
+    }
+    start..=end
+}
+
+/// Author of the most recent commit among `lines` of `file`, per
+/// `git blame`. Returns `None` if git is unavailable or the blame fails.
+fn blame_last_author(file: &Path, lines: RangeInclusive<u32>) -> Option<String> {
+    let range = format!("{},{}", lines.start(), lines.end());
+    let dir = file
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let output = std::process::Command::new("git")
+        .args(["blame", "--line-porcelain", "-L", &range, "--"])
+        .arg(file)
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8(output.stdout).ok()?;
+    let mut author = None;
+    let mut latest: Option<(i64, &str)> = None;
+    for line in text.lines() {
+        if let Some(name) = line.strip_prefix("author ") {
+            author = Some(name);
+        } else if let Some(time) = line.strip_prefix("author-time ")
+            && let (Some(name), Ok(time)) = (author, time.parse::<i64>())
+            && latest.is_none_or(|(newest, _)| time > newest)
+        {
+            latest = Some((time, name));
+        }
+    }
+    latest.map(|(_, name)| name.to_owned())
+}
+
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use std::path::Path;
@@ -330,4 +400,83 @@ mod tests {
             "feature changes should appear: {changed:?}"
         );
     }
+
+    #[test]
+    fn test_lines_spans_decorators_and_body() {
+        let source = "import x\n\n@test\ndef test_a():\n    a = 1\n\n    expect(a)\n\n\n@test\ndef test_b(): pass\n";
+        assert_eq!(test_lines(source, 3), 3..=7);
+        assert_eq!(test_lines(source, 10), 10..=11);
+    }
+
+    #[test]
+    fn blame_attributes_failing_test_in_recap() {
+        use tryke_reporter::{Reporter, TextReporter};
+        use tryke_types::{RunSummary, TestOutcome, TestResult};
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let source = "from tryke import test, expect\n\n@test\ndef test_sum():\n    expect(1 + 1).to_equal(2)\n";
+        seed_git_repo(dir.path(), &[("test_blame.py", source)]);
+        std::fs::write(
+            dir.path().join("test_blame.py"),
+            source.replace("to_equal(2)", "to_equal(3)"),
+        )
+        .expect("edit test");
+        git_run(
+            dir.path(),
+            &[
+                "commit",
+                "-am",
+                "break test",
+                "--author",
+                "Ada Lovelace <ada@example.com>",
+                "--date",
+                "2030-01-01T00:00:00",
+            ],
+        );
+
+        let test = TestItem {
+            name: "test_sum".into(),
+            module_path: "test_blame".into(),
+            file_path: Some(PathBuf::from("test_blame.py")),
+            line_number: Some(3),
+            ..Default::default()
+        };
+        let result = TestResult::new(
+            test,
+            TestOutcome::Failed {
+                message: "expected 3".into(),
+                traceback: None,
+                assertions: vec![],
+                executed_lines: vec![],
+            },
+            std::time::Duration::from_millis(1),
+        );
+
+        let root = dir.path().to_path_buf();
+        let mut reporter = TextReporter::with_writer(Vec::new()).with_blame(Some(Box::new(
+            move |test: &TestItem| blame_test(&root, test),
+        )));
+        reporter.on_run_start(&[]);
+        reporter.on_test_complete(&result);
+        reporter.on_run_complete(&RunSummary::from_results(&[result]));
+        let out = String::from_utf8(reporter.into_writer()).expect("utf-8 output");
+        let recap = out.find("failures:").expect("recap header");
+        assert!(
+            out[recap..].contains("last edited by Ada Lovelace"),
+            "{out}"
+        );
+    }
+
+    #[test]
+    fn blame_test_outside_git_is_none() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        std::fs::write(dir.path().join("test_a.py"), "@test\ndef test_a(): pass\n").expect("write");
+        let test = TestItem {
+            name: "test_a".into(),
+            file_path: Some(PathBuf::from("test_a.py")),
+            line_number: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(blame_test(dir.path(), &test), None);
+    }
 }
@@ -14,14 +14,7 @@ pub fn run_graph(
     base_branch: Option<&str>,
 ) -> Result<()> {
     let root_path = config.root();
-    let src_roots = config.src_roots();
-    let cache_dir = config.cache_dir();
-    let mut discoverer = Discoverer::new(
-        root_path,
-        src_roots,
-        &config.discovery.exclude,
-        cache_dir.as_deref(),
-    );
+    let mut discoverer = Discoverer::from_config(config);
     discoverer.rediscover();
 
     let changed_files = if changed {
@@ -107,15 +100,7 @@ pub fn run_graph(
 /// discovered module) are printed with a `?` suffix so users can spot
 /// typos or missing fixtures without reading through test output.
 pub fn run_fixture_graph(config: &TrykeConfig) -> Result<()> {
-    let root_path = config.root();
-    let src_roots = config.src_roots();
-    let cache_dir = config.cache_dir();
-    let mut discoverer = Discoverer::new(
-        root_path,
-        src_roots,
-        &config.discovery.exclude,
-        cache_dir.as_deref(),
-    );
+    let mut discoverer = Discoverer::from_config(config);
     discoverer.rediscover();
 
     let hooks = discoverer.hooks();
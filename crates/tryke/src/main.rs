@@ -1,57 +1,254 @@
-use std::{env, path::Path, time::Instant};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::{
+    env,
+    ffi::OsString,
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
 
 use anyhow::Result;
 use clap::Parser;
-use log::debug;
-use tryke::cli::{Cli, Commands, ReporterFormat};
-use tryke::discovery::{discover_tests, discover_tests_changed_first, discover_tests_for_paths};
-use tryke::execution::{run_tests, worker_pool_size};
+use log::{debug, warn};
+use tryke::cli::{Cli, CollectFormat, Commands, ConfigCommand, ReporterFormat};
+use tryke::discovery::{
+    assertion_count_violations, discover_tests, discover_tests_changed_first,
+    discover_tests_for_paths, discover_tests_with_changed_assertions, discovery_stats_report,
+    duplicate_id_errors, explain_test, extraction_report, missing_path_errors, select_benchmarks,
+    shuffle_tests,
+};
+use tryke::execution::{
+    RootRun, TestLimits, install_interrupt_handler, run_tests, run_tests_across_roots,
+    was_interrupted, worker_pool_size,
+};
+use tryke::git::blame_test;
 use tryke::graph::{run_fixture_graph, run_graph};
 use tryke::watch::run_watch;
 use tryke_config::{ConfigOverrides, TrykeConfig};
-use tryke_discovery::Discoverer;
+use tryke_discovery::{Discoverer, DiscoveryStats, discover_archive};
+use tryke_reporter::summary::TreeGlyphs;
+use tryke_reporter::text::BlameFn;
 use tryke_reporter::{
-    DotReporter, JSONReporter, JUnitReporter, LlmReporter, NextReporter, ProgressReporter,
-    Reporter, SugarReporter, TextReporter, Verbosity,
+    ColorChoice, DisplayPaths, DotReporter, JSONReporter, JUnitReporter, LlmReporter, NextReporter,
+    ProgressReporter, Reporter, ResultsDirReporter, SugarReporter, TextReporter, Verbosity,
+    WebhookReporter,
 };
 use tryke_runner::WorkerPool;
-use tryke_types::ChangedSelectionSummary;
 use tryke_types::filter::TestFilter;
+use tryke_types::{ChangedSelectionSummary, RunSummary, TestItem};
 
-fn build_reporter(
-    format: &ReporterFormat,
+/// How to build the run's reporter, gathered once from the command line
+/// and the project config. The flags mirror independent CLI switches.
+#[expect(clippy::struct_excessive_bools)]
+struct ReporterOptions<'a> {
+    format: &'a ReporterFormat,
     verbosity: Verbosity,
+    color: ColorChoice,
     no_progress: bool,
-) -> Box<dyn Reporter> {
+    watch: bool,
+    event_socket: Option<&'a Path>,
+    stream_assertions: bool,
+    json_minimal: bool,
+    json_final_report: bool,
+    heartbeat: Option<Duration>,
+    timings: bool,
+    no_duration: bool,
+    group_summary: bool,
+    timing_stats: bool,
+    durations: usize,
+    max_failures_shown: Option<usize>,
+    show_skipped_summary: bool,
+    quiet_pass_dot: bool,
+    no_assertion_footer: bool,
+    junit_properties: &'a [(String, String)],
+    collect_tree: Option<TreeGlyphs>,
+    collect_assertions: bool,
+    show_capture: bool,
+    failure_template: Option<String>,
+    blame: Option<BlameFn>,
+    run_id: String,
+}
+
+fn build_reporter(options: ReporterOptions<'_>) -> Box<dyn Reporter> {
+    let format = options.format;
     // Next and Sugar reporters render their own progress UI, so we don't
     // overlay the terminal's native OSC 9;4 progress bar on top of them.
-    let use_progress = !no_progress
+    let use_progress = !options.no_progress
         && tryke_reporter::progress::supports_progress()
         && matches!(format, ReporterFormat::Text | ReporterFormat::Dot);
 
-    if use_progress {
+    if use_progress && options.watch {
         // ProgressReporter emits OSC 9;4 "set progress" on every test
-        // completion. On Ctrl+C, `on_run_complete` (which emits the
-        // clear sequence) never runs, so the terminal's progress bar
-        // would freeze. Install a signal handler that clears it first.
+        // completion. Ctrl+C ends watch mode without `on_run_complete`
+        // (which emits the clear sequence), so the terminal's progress
+        // bar would freeze. Install a signal handler that clears it
+        // first. Single runs instead close with `on_run_interrupted`.
         tryke_reporter::progress::install_cleanup_handler();
     }
 
+    let color = options.color;
     match format {
-        ReporterFormat::Text if use_progress => Box::new(ProgressReporter::new(
-            TextReporter::with_verbosity(verbosity),
+        ReporterFormat::Text if use_progress => {
+            Box::new(ProgressReporter::new(text_reporter(options)))
+        }
+        ReporterFormat::Text => Box::new(text_reporter(options)),
+        ReporterFormat::Dot if use_progress => Box::new(ProgressReporter::new(
+            DotReporter::new().with_color_choice(color),
         )),
-        ReporterFormat::Text => Box::new(TextReporter::with_verbosity(verbosity)),
-        ReporterFormat::Dot if use_progress => Box::new(ProgressReporter::new(DotReporter::new())),
-        ReporterFormat::Dot => Box::new(DotReporter::new()),
+        ReporterFormat::Dot => Box::new(DotReporter::new().with_color_choice(color)),
         ReporterFormat::Next => Box::new(NextReporter::new()),
         ReporterFormat::Sugar => Box::new(SugarReporter::new()),
-        ReporterFormat::Json => Box::new(JSONReporter::new()),
-        ReporterFormat::Junit => Box::new(JUnitReporter::new()),
+        ReporterFormat::Json => match options
+            .event_socket
+            .map(tryke_reporter::event_sink::connect)
+        {
+            Some(Ok(writer)) => Box::new(json_reporter(JSONReporter::with_writer(writer), options)),
+            Some(Err(err)) => {
+                warn!("--event-socket: could not open sink ({err}); writing events to stdout");
+                Box::new(json_reporter(JSONReporter::new(), options))
+            }
+            None => Box::new(json_reporter(JSONReporter::new(), options)),
+        },
+        ReporterFormat::Junit => {
+            let mut properties = tryke_reporter::junit::ci_properties();
+            properties.push(("tryke.run_id".to_owned(), options.run_id));
+            properties
+                .retain(|(name, _)| !options.junit_properties.iter().any(|(key, _)| key == name));
+            properties.extend_from_slice(options.junit_properties);
+            Box::new(JUnitReporter::new().with_properties(properties))
+        }
         ReporterFormat::Llm => Box::new(LlmReporter::new()),
     }
 }
 
+/// `reporter` with the JSON stream options applied.
+fn json_reporter<W: io::Write>(
+    reporter: JSONReporter<W>,
+    options: ReporterOptions<'_>,
+) -> JSONReporter<W> {
+    reporter
+        .with_assertions(options.stream_assertions)
+        .with_minimal_assertions(options.json_minimal)
+        .with_final_report(options.json_final_report)
+        .with_heartbeat(options.heartbeat)
+        .with_run_id(Some(options.run_id))
+}
+
+/// The text reporter `options` describe, before any progress wrapper.
+fn text_reporter(options: ReporterOptions<'_>) -> TextReporter {
+    TextReporter::with_verbosity(options.verbosity)
+        .with_timings(options.timings)
+        .with_durations(!options.no_duration)
+        .with_group_summary(options.group_summary)
+        .with_timing_stats(options.timing_stats)
+        .with_slowest(options.durations)
+        .with_max_failures_shown(options.max_failures_shown)
+        .with_skipped_summary(options.show_skipped_summary)
+        .with_pass_dots(options.quiet_pass_dot)
+        .with_assertion_footer(!options.no_assertion_footer)
+        .with_collect_tree(options.collect_tree)
+        .with_collect_assertions(options.collect_assertions)
+        .with_show_capture(options.show_capture)
+        .with_failure_template(options.failure_template)
+        .with_blame(options.blame)
+        .with_color_choice(options.color)
+}
+
+/// Identifies one `tryke` invocation across the artifacts it writes: the
+/// start time plus the process id, so two runs started in the same
+/// millisecond still differ.
+fn new_run_id() -> String {
+    format!(
+        "{}-{}",
+        chrono::Local::now().format("%Y%m%dT%H%M%S%.3f"),
+        std::process::id()
+    )
+}
+
+/// The seed for `--shuffle`: `--seed`, then `TRYKE_SEED`, then a fresh
+/// random one.
+fn shuffle_seed(seed: Option<u64>, env_seed: Option<&str>) -> u64 {
+    if let Some(seed) = seed {
+        return seed;
+    }
+    if let Some(raw) = env_seed {
+        match raw.trim().parse() {
+            Ok(seed) => return seed,
+            Err(_) => warn!(
+                "TRYKE_SEED: expected an unsigned integer, got `{raw}`; picking a random seed"
+            ),
+        }
+    }
+    RandomState::new().build_hasher().finish()
+}
+
+/// Logs every problem `validate_config` finds in the `[tool.tryke]` table
+/// governing `root`. Under `--strict` they abort the run.
+fn check_config(root: &Path, strict: bool) -> Result<()> {
+    let warnings = tryke_config::validate_config(root);
+    for warning in &warnings {
+        warn!("{warning}");
+    }
+    if strict && !warnings.is_empty() {
+        return Err(anyhow::anyhow!(
+            "--strict: {} problem(s) in [tool.tryke]; run `tryke config check` for details",
+            warnings.len()
+        ));
+    }
+    Ok(())
+}
+
+/// With `--relative-to DIR`, have `rep` print paths relative to `DIR`
+/// rather than to `root`, the directory test `file_path`s are recorded
+/// against.
+fn set_relative_to(rep: &mut dyn Reporter, root: &Path, dir: Option<&Path>) -> Result<()> {
+    let Some(dir) = dir else {
+        return Ok(());
+    };
+    let base = dir
+        .canonicalize()
+        .map_err(|e| anyhow::anyhow!("--relative-to {}: {e}", dir.display()))?;
+    rep.set_display_paths(&DisplayPaths::relative_to(root, base));
+    Ok(())
+}
+
+/// Finish a `--collect-only` run: the reporter's listing, or one line per
+/// test in the `--format` asked for.
+fn report_collected(rep: &mut dyn Reporter, tests: &[TestItem], format: Option<&CollectFormat>) {
+    match format {
+        Some(CollectFormat::Nodeids) => {
+            for test in tests {
+                println!("{}", test.nodeid());
+            }
+        }
+        None => rep.on_collect_complete(tests),
+    }
+}
+
+/// Under `--strict`, any test outside the `[tool.tryke]` assertion-count
+/// limits aborts the run. The warnings themselves were already reported.
+fn check_strict(strict: bool, violations: usize) -> Result<()> {
+    if strict && violations > 0 {
+        return Err(anyhow::anyhow!(
+            "--strict: {violations} test(s) outside [tool.tryke] min_assertions/max_assertions"
+        ));
+    }
+    Ok(())
+}
+
+/// Whether a finished run should exit non-zero: any failure or error,
+/// or under `--fail-slow` any test past `--slow-threshold`.
+fn run_failed(summary: &RunSummary, fail_slow: bool) -> bool {
+    summary.failed > 0 || summary.errors > 0 || (fail_slow && summary.slow > 0)
+}
+
+/// The process exit status for a finished run: `1` when [`run_failed`],
+/// unless `--no-fail-on-error` asked for the report alone.
+fn exit_code(summary: &RunSummary, fail_slow: bool, no_fail_on_error: bool) -> i32 {
+    i32::from(!no_fail_on_error && run_failed(summary, fail_slow))
+}
+
 struct EffectiveCommand {
     command: Commands,
     bare_watch: bool,
@@ -67,34 +264,87 @@ impl EffectiveCommand {
     }
 }
 
-fn effective_command(command: Option<Commands>) -> EffectiveCommand {
-    let bare_watch = command.is_none();
+fn effective_command(command: Option<Commands>, bare_watch: bool) -> EffectiveCommand {
     EffectiveCommand {
         command: command.unwrap_or_else(Commands::default_watch),
         bare_watch,
     }
 }
 
-fn load_config(
-    root: &Path,
-    python: Option<&str>,
-    cache_dir: Option<&Path>,
-    exclude: &[String],
-    include: &[String],
-) -> TrykeConfig {
-    TrykeConfig::load(
-        root,
-        ConfigOverrides {
-            python: python.map(str::to_owned),
-            cache_dir: cache_dir.map(Path::to_path_buf),
-            exclude: exclude.to_vec(),
-            include: include.to_vec(),
-        },
-    )
+/// The single project `--watch` follows. Watch mode drives one
+/// project's file watcher and discovery cache, so several `--root`
+/// values are rejected rather than quietly watching the first.
+fn watch_root(roots: &[PathBuf]) -> Result<Option<&Path>> {
+    match roots {
+        [] => Ok(None),
+        [root] => Ok(Some(root.as_path())),
+        _ => Err(anyhow::anyhow!(
+            "--watch follows a single project; pass at most one --root"
+        )),
+    }
+}
+
+/// The packages `--workspace` runs, relative to `cwd` so each test id is
+/// prefixed with its package's path.
+fn workspace_labels(cwd: &Path) -> Result<Vec<PathBuf>> {
+    let labels: Vec<PathBuf> = tryke_discovery::find_workspace_roots(cwd)
+        .into_iter()
+        .map(|root| {
+            root.strip_prefix(cwd)
+                .map(Path::to_path_buf)
+                .unwrap_or(root)
+        })
+        .collect();
+    if labels.is_empty() {
+        return Err(anyhow::anyhow!(
+            "--workspace: no pyproject.toml found below {}",
+            cwd.display()
+        ));
+    }
+    Ok(labels)
+}
+
+/// Rewrites the `watch` subcommand to `test --watch`, keeping every
+/// option around it in place.
+fn watch_as_test(mut args: Vec<OsString>) -> Vec<OsString> {
+    if let Some(at) = Cli::subcommand_index(&args) {
+        args.splice(at..=at, ["test", "--watch"].map(OsString::from));
+    }
+    args
+}
+
+/// Parses the command line, re-parsing `tryke watch` as `tryke test
+/// --watch` and layering the project's `[tool.tryke] addopts` under
+/// `tryke test` and bare `tryke`. Also returns whether no subcommand was
+/// given.
+fn parse_cli() -> (Cli, bool) {
+    let mut args: Vec<OsString> = env::args_os().collect();
+    let mut cli = Cli::parse_from(&args);
+    if matches!(cli.command, Some(Commands::Watch { .. })) {
+        args = watch_as_test(args);
+        cli = Cli::parse_from(&args);
+    }
+    let bare = cli.command.is_none();
+    let start = match &cli.command {
+        Some(Commands::Test { root, rootdir, .. }) => {
+            rootdir.clone().or_else(|| root.first().cloned())
+        }
+        Some(_) => return (cli, bare),
+        None => None,
+    };
+    let Some(start) = start.or_else(|| env::current_dir().ok()) else {
+        return (cli, bare);
+    };
+    let config = TrykeConfig::discover(&start);
+    if config.addopts().is_empty() {
+        return (cli, bare);
+    }
+    let cli = Cli::try_parse_with_addopts(&args, config.addopts()).unwrap_or_else(|err| err.exit());
+    (cli, bare)
 }
 
 fn main() -> Result<()> {
-    let cli = Cli::parse();
+    let (cli, bare) = parse_cli();
     let cli_filter = cli.verbose.log_level_filter();
     let tryke_log = env::var("TRYKE_LOG").ok();
     // Rust-side default for env_logger when `RUST_LOG` is unset. `RUST_LOG`
@@ -110,23 +360,66 @@ fn main() -> Result<()> {
     let verbosity = Verbosity::from_level_filter(rust_default);
     let runtime = tokio::runtime::Runtime::new()?;
     let cache_dir = cli.cache_dir.clone();
-    let effective = effective_command(cli.command);
+    let discovery_jobs = cli.discovery_jobs;
+    let effective = effective_command(cli.command, bare);
     let command = effective.as_command();
     let bare_watch = effective.is_bare_watch();
     match command {
         Commands::Test {
             paths,
             exclude,
+            python_files,
             collect_only,
+            tree,
+            ascii,
+            show_assertions,
+            format,
+            archive,
+            strict,
+            debug_extraction,
+            discovery_stats,
+            explain,
             filter,
             markers,
+            tag_not,
             reporter,
+            color,
+            event_socket,
+            stream_assertions,
+            json_minimal,
+            json_final_report,
+            heartbeat,
+            timings,
+            no_duration,
+            group_summary,
+            timing_stats,
+            durations,
+            max_failures_shown,
+            show_skipped_summary,
+            quiet_pass_dot,
+            no_assertion_footer,
+            blame,
+            show_capture,
+            relative_to,
+            junit_property,
+            webhook,
+            results_dir,
             root,
+            rootdir,
+            workspace,
             changed,
             changed_first,
+            only_changed_assertions,
             base_branch,
+            benchmark,
             fail_fast,
             maxfail,
+            timeout,
+            slow_threshold,
+            fail_slow,
+            no_fail_on_error,
+            shuffle,
+            seed,
             workers,
             dist,
             include,
@@ -140,25 +433,173 @@ fn main() -> Result<()> {
                     "--base-branch requires --changed or --changed-first"
                 ));
             }
+            // Flags only one reporter understands, grouped by that reporter.
+            let reporter_flags = [
+                (
+                    ReporterFormat::Json,
+                    "json",
+                    &[
+                        ("--event-socket", event_socket.is_some()),
+                        ("--stream-assertions", *stream_assertions),
+                        ("--json-minimal", *json_minimal),
+                        ("--json-final-report", *json_final_report),
+                        ("--heartbeat", heartbeat.is_some()),
+                    ][..],
+                ),
+                (
+                    ReporterFormat::Text,
+                    "text",
+                    &[
+                        ("--timings", *timings),
+                        ("--no-duration", *no_duration),
+                        ("--group-summary", *group_summary),
+                        ("--timing-stats", *timing_stats),
+                        ("--durations", *durations > 0),
+                        ("--max-failures-shown", max_failures_shown.is_some()),
+                        ("--show-skipped-summary", *show_skipped_summary),
+                        ("--quiet-pass-dot", *quiet_pass_dot),
+                        ("--no-assertion-footer", *no_assertion_footer),
+                        ("--blame", *blame),
+                        ("--show-capture", *show_capture),
+                        ("--tree", *tree),
+                        ("--show-assertions", *show_assertions),
+                    ][..],
+                ),
+                (
+                    ReporterFormat::Junit,
+                    "junit",
+                    &[("--junit-property", !junit_property.is_empty())][..],
+                ),
+            ];
+            for (required, name, flags) in reporter_flags {
+                if *reporter != required
+                    && let Some((flag, _)) = flags.iter().find(|(_, set)| *set)
+                {
+                    return Err(anyhow::anyhow!("{flag} requires --reporter {name}"));
+                }
+            }
+            if relative_to.is_some()
+                && matches!(reporter, ReporterFormat::Json | ReporterFormat::Junit)
+            {
+                return Err(anyhow::anyhow!(
+                    "--relative-to can't be combined with --reporter json or junit"
+                ));
+            }
+            // `--workspace` stands in for one `--root` per package.
+            let workspace_roots;
+            let root = if *workspace {
+                workspace_roots = workspace_labels(&env::current_dir()?)?;
+                &workspace_roots
+            } else {
+                root
+            };
+            let several_roots = *workspace || root.len() > 1;
+            if several_roots
+                && (*changed
+                    || *changed_first
+                    || *only_changed_assertions
+                    || *debug_extraction
+                    || explain.is_some()
+                    || !archive.is_empty()
+                    || !paths.is_empty())
+            {
+                return Err(anyhow::anyhow!(
+                    "multiple --root values and --workspace can't be combined with --changed, \
+                     --changed-first, --only-changed-assertions, --debug-extraction, \
+                     --explain, --archive, or paths"
+                ));
+            }
+            if let Some(dir) = rootdir
+                && !dir.is_dir()
+            {
+                return Err(anyhow::anyhow!(
+                    "--rootdir {}: not a directory",
+                    dir.display()
+                ));
+            }
             let resolved_maxfail = if *fail_fast { Some(1) } else { *maxfail };
-            let mut rep = build_reporter(reporter, verbosity, cli.no_progress);
+            let order_seed = shuffle.then(|| {
+                let env_seed = env::var("TRYKE_SEED").ok().filter(|raw| !raw.is_empty());
+                shuffle_seed(*seed, env_seed.as_deref())
+            });
+            let collect_tree = match (*tree, *ascii) {
+                (false, _) => None,
+                (true, false) => Some(TreeGlyphs::Unicode),
+                (true, true) => Some(TreeGlyphs::Ascii),
+            };
+            // The first root's configuration, loaded once: the reporter
+            // takes `failure_template` from it, and a single-root run or
+            // watch uses it as is. Several roots each load their own.
+            let cwd = env::current_dir()?;
+            let config = TrykeConfig::load(
+                root.first().unwrap_or(&cwd),
+                ConfigOverrides {
+                    rootdir: rootdir.clone(),
+                    python: python.clone(),
+                    cache_dir: cache_dir.clone(),
+                    exclude: exclude.clone(),
+                    include: include.clone(),
+                    python_files: python_files.clone(),
+                    discovery_jobs,
+                },
+            );
+            let failure_template = config.failure_template().map(str::to_owned);
+            // Test paths are relative to the project root, or to the
+            // current directory once several roots qualify them.
+            let blame = blame.then(|| {
+                let base = if several_roots {
+                    cwd.clone()
+                } else {
+                    config.root().to_path_buf()
+                };
+                Box::new(move |test: &TestItem| blame_test(&base, test)) as BlameFn
+            });
+            let mut rep = build_reporter(ReporterOptions {
+                format: reporter,
+                verbosity,
+                color: ColorChoice::from(*color),
+                no_progress: cli.no_progress,
+                watch: *watch,
+                event_socket: event_socket.as_deref(),
+                stream_assertions: *stream_assertions,
+                json_minimal: *json_minimal,
+                json_final_report: *json_final_report,
+                heartbeat: *heartbeat,
+                timings: *timings,
+                no_duration: *no_duration,
+                group_summary: *group_summary,
+                timing_stats: *timing_stats,
+                durations: *durations,
+                max_failures_shown: *max_failures_shown,
+                show_skipped_summary: *show_skipped_summary,
+                quiet_pass_dot: *quiet_pass_dot,
+                no_assertion_footer: *no_assertion_footer,
+                junit_properties: junit_property,
+                collect_tree,
+                collect_assertions: *show_assertions,
+                show_capture: *show_capture,
+                failure_template,
+                blame,
+                run_id: new_run_id(),
+            });
+            if let Some(url) = webhook {
+                rep = Box::new(WebhookReporter::new(rep, url.clone()));
+            }
+            if let Some(dir) = results_dir {
+                rep = Box::new(ResultsDirReporter::new(rep, dir.clone()));
+            }
             if *watch {
+                watch_root(root)?;
                 rep.set_subcommand_label(if bare_watch {
                     "tryke"
                 } else {
                     "tryke test --watch"
                 });
                 rep.set_watch_hint(Some("Waiting for file changes...".into()));
-                let cwd = env::current_dir()?;
-                let config = load_config(
-                    root.as_deref().unwrap_or(&cwd),
-                    python.as_deref(),
-                    cache_dir.as_deref(),
-                    exclude,
-                    include,
-                );
+                set_relative_to(&mut *rep, config.root(), relative_to.as_deref())?;
                 let test_filter = TestFilter::from_args(&[], filter.as_deref(), markers.as_deref())
-                    .map_err(|e| anyhow::anyhow!(e))?;
+                    .map_err(|e| anyhow::anyhow!(e))?
+                    .with_excluded_tags(tag_not);
                 return runtime.block_on(run_watch(
                     &mut *rep,
                     &config,
@@ -171,29 +612,179 @@ fn main() -> Result<()> {
                     *now,
                 ));
             }
-            let cwd = env::current_dir()?;
-            let config = load_config(
-                root.as_deref().unwrap_or(&cwd),
-                python.as_deref(),
-                cache_dir.as_deref(),
-                exclude,
-                include,
-            );
+            let limits = TestLimits {
+                timeout: *timeout,
+                slow_threshold: slow_threshold.map(Duration::from_millis),
+            };
+            if several_roots {
+                // Each test's path is prefixed with its `--root` as the user
+                // spelled it, so the paths are relative to the current
+                // directory rather than to any one root.
+                let cwd = env::current_dir()?;
+                let cwd = cwd.canonicalize().unwrap_or(cwd);
+                set_relative_to(&mut *rep, &cwd, relative_to.as_deref())?;
+                let test_filter = TestFilter::from_args(&[], filter.as_deref(), markers.as_deref())
+                    .map_err(|e| anyhow::anyhow!(e))?
+                    .with_excluded_tags(tag_not);
+                let discovery_start = Instant::now();
+                let mut runs = Vec::with_capacity(root.len());
+                let mut discovered_tests = Vec::new();
+                let mut cached_tests = 0;
+                let mut stats = DiscoveryStats::default();
+                let mut violations = 0;
+                for label in root {
+                    let config = TrykeConfig::load(
+                        label,
+                        ConfigOverrides {
+                            python: python.clone(),
+                            cache_dir: cache_dir.clone(),
+                            exclude: exclude.clone(),
+                            include: include.clone(),
+                            python_files: python_files.clone(),
+                            discovery_jobs,
+                            ..ConfigOverrides::default()
+                        },
+                    );
+                    check_config(config.root(), *strict)?;
+                    let mut discovered = discover_tests(&config, false, None);
+                    for warning in &discovered.warnings {
+                        rep.on_discovery_warning(warning);
+                    }
+                    violations += assertion_count_violations(&discovered.warnings);
+                    for test in &mut discovered.tests {
+                        test.qualify_root(label, config.root());
+                    }
+                    discovered_tests.append(&mut discovered.tests);
+                    cached_tests += discovered.cached_tests.unwrap_or_default();
+                    stats += discovered.stats;
+                    runs.push(RootRun {
+                        config,
+                        tests: Vec::new(),
+                        hooks: discovered.hooks,
+                    });
+                }
+                check_strict(*strict, violations)?;
+                let (mut tests, mut selection) = test_filter.select(discovered_tests);
+                select_benchmarks(&mut tests, &mut selection, *benchmark);
+                shuffle_tests(&mut tests, &mut selection, order_seed);
+                // The same checks as a single root, one root at a time.
+                for run in &runs {
+                    let root = run.config.root();
+                    let root_tests: Vec<TestItem> = tests
+                        .iter()
+                        .filter(|test| test.root.as_deref() == Some(root))
+                        .cloned()
+                        .collect();
+                    for error in missing_path_errors(root, &test_filter.path_specs, &root_tests) {
+                        rep.on_discovery_error(&error);
+                    }
+                    for error in duplicate_id_errors(&root_tests) {
+                        rep.on_discovery_error(&error);
+                    }
+                }
+                selection.cached = Some(cached_tests);
+                rep.set_selection(&selection);
+                let discovery_duration = discovery_start.elapsed();
+                if *discovery_stats {
+                    eprint!("{}", discovery_stats_report(&stats));
+                }
+                if *collect_only {
+                    report_collected(&mut *rep, &tests, format.as_ref());
+                    return Ok(());
+                }
+                for test in tests {
+                    if let Some(run) = runs
+                        .iter_mut()
+                        .find(|run| test.root.as_deref() == Some(run.config.root()))
+                    {
+                        run.tests.push(test);
+                    }
+                }
+                install_interrupt_handler();
+                let summary = runtime.block_on(run_tests_across_roots(
+                    &mut *rep,
+                    runs,
+                    worker_log,
+                    resolved_maxfail,
+                    *workers,
+                    (*dist).into(),
+                    limits,
+                    Some(discovery_duration),
+                ))?;
+                // 128 + SIGINT, as for an unhandled Ctrl-C, once the
+                // interrupted run has been reported and its pool shut down.
+                let code = if was_interrupted() {
+                    130
+                } else {
+                    exit_code(&summary, *fail_slow, *no_fail_on_error)
+                };
+                if code != 0 {
+                    std::process::exit(code);
+                }
+                return Ok(());
+            }
+            check_config(config.root(), *strict)?;
+            set_relative_to(&mut *rep, config.root(), relative_to.as_deref())?;
             let test_filter = TestFilter::from_args(paths, filter.as_deref(), markers.as_deref())
-                .map_err(|e| anyhow::anyhow!(e))?;
+                .map_err(|e| anyhow::anyhow!(e))?
+                .with_excluded_tags(tag_not);
+            if !archive.is_empty() {
+                let mut archived = Vec::new();
+                for path in archive {
+                    let tests = discover_archive(
+                        path,
+                        &config.discovery.expect_names,
+                        &config.discovery.decorator,
+                    )
+                    .map_err(|e| anyhow::anyhow!("--archive {}: {e}", path.display()))?;
+                    archived.extend(tests);
+                }
+                let (tests, selection) = test_filter.select(archived);
+                rep.set_selection(&selection);
+                report_collected(&mut *rep, &tests, format.as_ref());
+                return Ok(());
+            }
             let discovery_start = Instant::now();
-            let discovered = if !paths.is_empty() && !*changed && !*changed_first {
+            let discovered = if *only_changed_assertions {
+                discover_tests_with_changed_assertions(&config)
+            } else if !paths.is_empty() && !*changed && !*changed_first {
                 discover_tests_for_paths(&config, &test_filter.path_specs)
             } else if *changed_first {
                 discover_tests_changed_first(&config, base_branch.as_deref())
             } else {
                 discover_tests(&config, *changed, base_branch.as_deref())
             };
+            if let Some(dir) = rootdir
+                && paths.is_empty()
+                && discovered.stats.files_walked == 0
+            {
+                return Err(anyhow::anyhow!(
+                    "--rootdir {}: no Python files found",
+                    dir.display()
+                ));
+            }
             for warning in &discovered.warnings {
                 rep.on_discovery_warning(warning);
             }
-            let tests = test_filter.apply(discovered.tests);
+            check_strict(*strict, assertion_count_violations(&discovered.warnings))?;
+            let (mut tests, mut selection) = test_filter.select(discovered.tests);
+            select_benchmarks(&mut tests, &mut selection, *benchmark);
+            shuffle_tests(&mut tests, &mut selection, order_seed);
+            for error in missing_path_errors(config.root(), &test_filter.path_specs, &tests) {
+                rep.on_discovery_error(&error);
+            }
+            for error in duplicate_id_errors(&tests) {
+                rep.on_discovery_error(&error);
+            }
+            selection.cached = discovered.cached_tests;
+            rep.set_selection(&selection);
             let discovery_duration = discovery_start.elapsed();
+            if *debug_extraction {
+                eprint!("{}", extraction_report(&config, &tests));
+            }
+            if *discovery_stats {
+                eprint!("{}", discovery_stats_report(&discovered.stats));
+            }
             let changed_selection =
                 discovered
                     .changed_files
@@ -202,10 +793,21 @@ fn main() -> Result<()> {
                         affected_tests: tests.len(),
                     });
 
-            if *collect_only {
-                rep.on_collect_complete(&tests);
+            if let Some(id) = explain {
+                let test = tests
+                    .iter()
+                    .find(|test| &test.id() == id)
+                    .ok_or_else(|| anyhow::anyhow!("no test with id `{id}`"))?;
+                print!("{}", explain_test(test));
+                Ok(())
+            } else if *collect_only {
+                report_collected(&mut *rep, &tests, format.as_ref());
                 Ok(())
             } else {
+                // Ctrl-C stops the run and lets the reporter close it with
+                // the partial summary. Installed only now so Ctrl-C during
+                // discovery still exits at once.
+                install_interrupt_handler();
                 let summary = runtime.block_on(run_tests(
                     &mut *rep,
                     &config,
@@ -215,11 +817,19 @@ fn main() -> Result<()> {
                     resolved_maxfail,
                     *workers,
                     (*dist).into(),
+                    limits,
                     Some(discovery_duration),
                     changed_selection,
                 ))?;
-                if summary.failed > 0 || summary.errors > 0 {
-                    std::process::exit(1);
+                // 128 + SIGINT, as for an unhandled Ctrl-C, once the
+                // interrupted run has been reported and its pool shut down.
+                let code = if was_interrupted() {
+                    130
+                } else {
+                    exit_code(&summary, *fail_slow, *no_fail_on_error)
+                };
+                if code != 0 {
+                    std::process::exit(code);
                 }
                 Ok(())
             }
@@ -232,36 +842,30 @@ fn main() -> Result<()> {
             workers,
         } => {
             let cwd = env::current_dir()?;
-            let config = load_config(
+            let config = TrykeConfig::load(
                 root.as_deref().unwrap_or(&cwd),
-                python.as_deref(),
-                cache_dir.as_deref(),
-                exclude,
-                include,
+                ConfigOverrides {
+                    python: python.clone(),
+                    cache_dir: cache_dir.clone(),
+                    exclude: exclude.clone(),
+                    include: include.clone(),
+                    discovery_jobs,
+                    ..ConfigOverrides::default()
+                },
             );
-            let root_path = config.root().to_path_buf();
-            let excludes = config.discovery.exclude.clone();
-            let src_roots = config.src_roots();
-            let resolved_python = config.python();
-            let resolved_cache_dir = config.cache_dir();
 
             runtime.block_on(async move {
                 let worker_pool = WorkerPool::spawn(
                     workers.unwrap_or_else(worker_pool_size),
-                    &resolved_python,
-                    &root_path,
+                    &config.python(),
+                    config.root(),
                     None,
                     worker_log,
                     false,
                 )
                 .await;
 
-                let discoverer = Discoverer::new(
-                    &root_path,
-                    src_roots,
-                    &excludes,
-                    resolved_cache_dir.as_deref(),
-                );
+                let discoverer = Discoverer::from_config(&config);
 
                 tryke_server::Server::new(worker_pool, discoverer)
                     .serve()
@@ -270,12 +874,13 @@ fn main() -> Result<()> {
         }
         Commands::Clean { root } => {
             let cwd = env::current_dir()?;
-            let config = load_config(
+            let config = TrykeConfig::load(
                 root.as_deref().unwrap_or(&cwd),
-                None,
-                cache_dir.as_deref(),
-                &[],
-                &[],
+                ConfigOverrides {
+                    cache_dir: cache_dir.clone(),
+                    discovery_jobs,
+                    ..ConfigOverrides::default()
+                },
             );
             let report = tryke_discovery::clean_project_cache(&config)?;
             if report.removed_entries == 0 {
@@ -291,6 +896,28 @@ fn main() -> Result<()> {
             }
             Ok(())
         }
+        Commands::WorkerProtocol { emit_sample: _ } => {
+            let sample = tryke_runner::protocol::sample_exchanges()?;
+            println!("{}", serde_json::to_string_pretty(&sample)?);
+            Ok(())
+        }
+        Commands::Config {
+            command: ConfigCommand::Check { root },
+        } => {
+            let cwd = env::current_dir()?;
+            let warnings = tryke_config::validate_config(root.as_deref().unwrap_or(&cwd));
+            if warnings.is_empty() {
+                println!("No problems found in [tool.tryke]");
+                return Ok(());
+            }
+            for warning in &warnings {
+                println!("{warning}");
+            }
+            Err(anyhow::anyhow!(
+                "{} problem(s) in [tool.tryke]",
+                warnings.len()
+            ))
+        }
         Commands::Graph {
             root,
             exclude,
@@ -304,12 +931,15 @@ fn main() -> Result<()> {
                 return Err(anyhow::anyhow!("--base-branch requires --changed"));
             }
             let cwd = env::current_dir()?;
-            let config = load_config(
+            let config = TrykeConfig::load(
                 root.as_deref().unwrap_or(&cwd),
-                None,
-                cache_dir.as_deref(),
-                exclude,
-                include,
+                ConfigOverrides {
+                    cache_dir: cache_dir.clone(),
+                    exclude: exclude.clone(),
+                    include: include.clone(),
+                    discovery_jobs,
+                    ..ConfigOverrides::default()
+                },
             );
             if *fixtures {
                 run_fixture_graph(&config)
@@ -317,12 +947,16 @@ fn main() -> Result<()> {
                 run_graph(&config, *connected_only, *changed, base_branch.as_deref())
             }
         }
+        // `parse_cli` re-parses `tryke watch` as `tryke test --watch`.
+        Commands::Watch { .. } => Err(anyhow::anyhow!(
+            "`tryke watch` was not rewritten to `tryke test --watch`"
+        )),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::path::PathBuf;
+    use std::time::Duration;
 
     use clap_verbosity_flag::log::LevelFilter;
     use tryke_reporter::{JSONReporter, TextReporter};
@@ -354,7 +988,7 @@ mod tests {
     fn bare_command_defaults_to_watch_mode() {
         let cli = Cli::try_parse_from(["tryke"]).unwrap();
         assert!(cli.command.is_none());
-        let effective = effective_command(cli.command);
+        let effective = effective_command(cli.command, true);
         assert!(effective.is_bare_watch());
         assert!(matches!(
             effective.as_command(),
@@ -368,12 +1002,67 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn watch_subcommand_forwards_test_options() {
+        let args = [
+            "tryke",
+            "--no-progress",
+            "watch",
+            "--reporter",
+            "dot",
+            "-k",
+            "parser",
+        ]
+        .map(OsString::from);
+        let cli = Cli::try_parse_from(&args).unwrap();
+        assert!(matches!(command(&cli), Commands::Watch { .. }));
+        let args = watch_as_test(args.to_vec());
+        assert_eq!(
+            args,
+            [
+                "tryke",
+                "--no-progress",
+                "test",
+                "--watch",
+                "--reporter",
+                "dot",
+                "-k",
+                "parser"
+            ]
+            .map(OsString::from)
+        );
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert!(cli.no_progress);
+        assert!(matches!(
+            command(&cli),
+            Commands::Test {
+                watch: true,
+                reporter: ReporterFormat::Dot,
+                filter: Some(filter),
+                ..
+            } if filter == "parser"
+        ));
+    }
+
     #[test]
     fn test_verbose_flag_sets_debug_level() {
         let cli = Cli::try_parse_from(["tryke", "-vv", "test"]).unwrap();
         assert_eq!(cli.verbose.log_level_filter(), LevelFilter::Debug);
     }
 
+    #[test]
+    fn test_explain_flag_parsed() {
+        let cli =
+            Cli::try_parse_from(["tryke", "test", "--explain", "test_math.py::test_add"]).unwrap();
+        assert!(matches!(
+            command(&cli),
+            Commands::Test { explain: Some(id), .. } if id == "test_math.py::test_add"
+        ));
+        assert!(
+            Cli::try_parse_from(["tryke", "test", "--explain", "x", "--collect-only"]).is_err()
+        );
+    }
+
     #[test]
     fn test_collect_only_flag_parsed() {
         let cli = Cli::try_parse_from(["tryke", "test", "--collect-only"]).unwrap();
@@ -386,6 +1075,30 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_tag_not_flag_repeats() {
+        let cli = Cli::try_parse_from(["tryke", "test", "--tag-not", "slow", "--tag-not", "flaky"])
+            .unwrap();
+        assert!(matches!(
+            command(&cli),
+            Commands::Test { tag_not, .. } if tag_not == &["slow", "flaky"]
+        ));
+    }
+
+    #[test]
+    fn test_collect_format_parsed() {
+        let cli = Cli::try_parse_from(["tryke", "test", "--collect-only", "--format", "nodeids"])
+            .unwrap();
+        assert!(matches!(
+            command(&cli),
+            Commands::Test {
+                format: Some(CollectFormat::Nodeids),
+                ..
+            }
+        ));
+        assert!(Cli::try_parse_from(["tryke", "test", "--format", "nodeids"]).is_err());
+    }
+
     #[test]
     fn test_collect_only_text() {
         let mut reporter = TextReporter::with_writer(Vec::new());
@@ -414,12 +1127,41 @@ mod tests {
     #[test]
     fn test_root_flag_parsed() {
         let cli = Cli::try_parse_from(["tryke", "test", "--root", "/tmp"]).unwrap();
+        assert!(matches!(
+            command(&cli),
+            Commands::Test { root, .. } if root == &[PathBuf::from("/tmp")]
+        ));
+    }
+
+    #[test]
+    fn test_root_flag_repeats() {
+        let cli =
+            Cli::try_parse_from(["tryke", "test", "--root", "svc-a", "--root", "svc-b"]).unwrap();
+        assert!(matches!(
+            command(&cli),
+            Commands::Test { root, .. }
+                if root == &[PathBuf::from("svc-a"), PathBuf::from("svc-b")]
+        ));
+    }
+
+    #[test]
+    fn test_event_socket_flag_parsed() {
+        let cli = Cli::try_parse_from([
+            "tryke",
+            "test",
+            "--reporter",
+            "json",
+            "--event-socket",
+            "/tmp/tryke.sock",
+        ])
+        .unwrap();
         assert!(matches!(
             command(&cli),
             Commands::Test {
-                root: Some(p),
+                reporter: ReporterFormat::Json,
+                event_socket: Some(p),
                 ..
-            } if p == &PathBuf::from("/tmp")
+            } if p == &PathBuf::from("/tmp/tryke.sock")
         ));
     }
 
@@ -698,6 +1440,129 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_benchmark_flag_parsed() {
+        let cli = Cli::try_parse_from(["tryke", "test", "--benchmark"]).unwrap();
+        assert!(matches!(
+            command(&cli),
+            Commands::Test {
+                benchmark: true,
+                ..
+            }
+        ));
+        assert!(Cli::try_parse_from(["tryke", "test", "--benchmark", "--watch"]).is_err());
+    }
+
+    #[test]
+    fn test_shuffle_and_seed_flags_parsed() {
+        let cli = Cli::try_parse_from(["tryke", "test", "--shuffle", "--seed", "42"]).unwrap();
+        assert!(matches!(
+            command(&cli),
+            Commands::Test {
+                shuffle: true,
+                seed: Some(42),
+                ..
+            }
+        ));
+        assert!(Cli::try_parse_from(["tryke", "test", "--seed", "42"]).is_err());
+        assert!(Cli::try_parse_from(["tryke", "test", "--shuffle", "--watch"]).is_err());
+    }
+
+    #[test]
+    fn shuffle_seed_prefers_flag_then_environment() {
+        assert_eq!(shuffle_seed(Some(1), Some("2")), 1);
+        assert_eq!(shuffle_seed(None, Some(" 2 ")), 2);
+        // Anything else gets a fresh seed; two in a row colliding is
+        // vanishingly unlikely.
+        assert_ne!(shuffle_seed(None, Some("two")), shuffle_seed(None, None));
+    }
+
+    #[test]
+    fn test_show_capture_flag_parsed() {
+        let cli = Cli::try_parse_from(["tryke", "test", "--show-capture"]).unwrap();
+        assert!(matches!(
+            command(&cli),
+            Commands::Test {
+                show_capture: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_timeout_flag_parsed() {
+        let cli = Cli::try_parse_from(["tryke", "test", "--timeout", "0.5"]).unwrap();
+        assert!(matches!(
+            command(&cli),
+            Commands::Test {
+                timeout: Some(limit),
+                ..
+            } if *limit == Duration::from_millis(500)
+        ));
+        let cli = Cli::try_parse_from(["tryke", "test"]).unwrap();
+        assert!(matches!(
+            command(&cli),
+            Commands::Test { timeout: None, .. }
+        ));
+        for bad in ["0", "-1", "soon"] {
+            assert!(Cli::try_parse_from(["tryke", "test", "--timeout", bad]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_slow_threshold_flags_parsed() {
+        let cli = Cli::try_parse_from(["tryke", "test", "--slow-threshold", "250", "--fail-slow"])
+            .unwrap();
+        assert!(matches!(
+            command(&cli),
+            Commands::Test {
+                slow_threshold: Some(250),
+                fail_slow: true,
+                ..
+            }
+        ));
+        assert!(Cli::try_parse_from(["tryke", "test", "--fail-slow"]).is_err());
+    }
+
+    #[test]
+    fn fail_slow_fails_runs_with_slow_tests() {
+        let mut summary = RunSummary::from_results(&[]);
+        summary.passed = 3;
+        summary.slow = 1;
+        assert!(!run_failed(&summary, false));
+        assert!(run_failed(&summary, true));
+        summary.slow = 0;
+        assert!(!run_failed(&summary, true));
+        summary.failed = 1;
+        assert!(run_failed(&summary, false));
+    }
+
+    #[test]
+    fn exit_code_reflects_run_outcome() {
+        let mut all_pass = RunSummary::from_results(&[]);
+        all_pass.passed = 3;
+        assert_eq!(exit_code(&all_pass, false, false), 0);
+
+        let mut some_fail = RunSummary::from_results(&[]);
+        some_fail.passed = 2;
+        some_fail.failed = 1;
+        assert_eq!(exit_code(&some_fail, false, false), 1);
+        assert_eq!(exit_code(&some_fail, false, true), 0);
+
+        let mut all_skip = RunSummary::from_results(&[]);
+        all_skip.skipped = 3;
+        assert_eq!(exit_code(&all_skip, false, false), 0);
+
+        let cli = Cli::try_parse_from(["tryke", "test", "--no-fail-on-error"]).unwrap();
+        assert!(matches!(
+            command(&cli),
+            Commands::Test {
+                no_fail_on_error: true,
+                ..
+            }
+        ));
+    }
+
     #[test]
     fn test_exclude_flag_parsed() {
         let cli = Cli::try_parse_from(["tryke", "test", "-e", "generated/suites"]).unwrap();
@@ -707,6 +1572,78 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_python_files_flag_repeats() {
+        let cli = Cli::try_parse_from([
+            "tryke",
+            "test",
+            "--python-files",
+            "test_*.py",
+            "--python-files",
+            "conftest.py",
+        ])
+        .unwrap();
+        assert!(matches!(
+            command(&cli),
+            Commands::Test { python_files, .. } if python_files == &["test_*.py", "conftest.py"]
+        ));
+    }
+
+    #[test]
+    fn watch_rejects_multiple_roots() {
+        assert_eq!(watch_root(&[]).unwrap(), None);
+        let one = [PathBuf::from("api")];
+        assert_eq!(watch_root(&one).unwrap(), Some(Path::new("api")));
+        let two = [PathBuf::from("api"), PathBuf::from("web")];
+        let err = watch_root(&two).unwrap_err().to_string();
+        assert!(err.contains("--watch"), "{err}");
+    }
+
+    #[test]
+    fn workspace_flag_parsed() {
+        let cli = Cli::try_parse_from(["tryke", "test", "--workspace"]).unwrap();
+        assert!(matches!(
+            command(&cli),
+            Commands::Test {
+                workspace: true,
+                ..
+            }
+        ));
+        for other in [["--root", "svc"], ["--rootdir", "svc"]] {
+            let args = ["tryke", "test", "--workspace", other[0], other[1]];
+            assert!(Cli::try_parse_from(args).is_err(), "{other:?}");
+        }
+        assert!(Cli::try_parse_from(["tryke", "test", "--workspace", "--watch"]).is_err());
+    }
+
+    #[test]
+    fn workspace_labels_are_relative_package_paths() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        for name in ["api", "web"] {
+            let package = dir.path().join("packages").join(name);
+            std::fs::create_dir_all(&package).expect("mkdir package");
+            std::fs::write(package.join("pyproject.toml"), "").expect("write pyproject.toml");
+        }
+        let labels = workspace_labels(dir.path()).unwrap();
+        assert_eq!(
+            labels,
+            vec![PathBuf::from("packages/api"), PathBuf::from("packages/web")]
+        );
+        let empty = tempfile::tempdir().expect("tempdir");
+        let err = workspace_labels(empty.path()).unwrap_err().to_string();
+        assert!(err.contains("--workspace"), "{err}");
+    }
+
+    #[test]
+    fn test_rootdir_conflicts_with_root() {
+        let cli = Cli::try_parse_from(["tryke", "test", "--rootdir", "services/api"]).unwrap();
+        assert!(matches!(
+            command(&cli),
+            Commands::Test { rootdir: Some(dir), .. } if dir == Path::new("services/api")
+        ));
+        assert!(Cli::try_parse_from(["tryke", "test", "--rootdir", "a", "--root", "b"]).is_err());
+    }
+
     #[test]
     fn test_include_flag_parsed() {
         let cli = Cli::try_parse_from(["tryke", "test", "--include", "generated/suites"]).unwrap();
@@ -912,6 +1849,16 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn worker_protocol_requires_emit_sample() {
+        let cli = Cli::try_parse_from(["tryke", "worker-protocol", "--emit-sample"]).unwrap();
+        assert!(matches!(
+            command(&cli),
+            Commands::WorkerProtocol { emit_sample: true }
+        ));
+        assert!(Cli::try_parse_from(["tryke", "worker-protocol"]).is_err());
+    }
+
     #[test]
     fn graph_subcommand_parsed() {
         let cli = Cli::try_parse_from(["tryke", "graph"]).unwrap();
@@ -976,6 +1923,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn only_changed_assertions_conflicts_with_changed() {
+        let result =
+            Cli::try_parse_from(["tryke", "test", "--only-changed-assertions", "--changed"]);
+        assert!(
+            result.is_err(),
+            "--only-changed-assertions and --changed should conflict"
+        );
+    }
+
     #[test]
     fn test_changed_first_with_base_branch_parsed() {
         let cli =
@@ -10,7 +10,7 @@ use tryke_runner::{DistMode, WorkerPool};
 use tryke_types::{DiscoveryWarning, DiscoveryWarningKind, HookItem, filter::TestFilter};
 use tryke_watcher::{FileChangeBatch, FileWatcher};
 
-use crate::execution::{report_cycle, worker_pool_size};
+use crate::execution::{TestLimits, report_cycle, reset_interrupt, worker_pool_size};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum WatchKeyAction {
@@ -98,6 +98,7 @@ fn clear_watch_results(reporter: &mut dyn Reporter) {
 /// Run a single watch cycle. Test failures are non-fatal here: in watch
 /// mode the whole point is to iterate on failing tests, so we discard
 /// the run summary and any setup error and let the watcher keep running.
+/// A Ctrl-C left over from an earlier cycle is forgotten first.
 async fn run_watch_cycle(
     reporter: &mut dyn Reporter,
     tests: Vec<tryke_types::TestItem>,
@@ -108,6 +109,7 @@ async fn run_watch_cycle(
     discovery_duration: Option<Duration>,
 ) {
     pool.restart_workers().await;
+    reset_interrupt();
     if let Err(e) = report_cycle(
         reporter,
         tests,
@@ -115,6 +117,8 @@ async fn run_watch_cycle(
         pool,
         maxfail,
         dist,
+        // `--timeout` and `--slow-threshold` can't be combined with `--watch`.
+        TestLimits::default(),
         discovery_duration,
         None,
     )
@@ -181,10 +185,7 @@ pub async fn run_watch(
     run_now: bool,
 ) -> Result<()> {
     let root = config.root();
-    let src_roots = config.src_roots();
-    let cache_dir = config.cache_dir();
-    let excludes = &config.discovery.exclude;
-    let mut discoverer = Discoverer::new(root, src_roots, excludes, cache_dir.as_deref());
+    let mut discoverer = Discoverer::from_config(config);
 
     let pool_size = workers.unwrap_or_else(worker_pool_size);
     let python = config.python();
@@ -201,7 +202,7 @@ pub async fn run_watch(
     )
     .await;
 
-    let mut watcher = FileWatcher::spawn(root, excludes)?;
+    let mut watcher = FileWatcher::spawn(root, &config.discovery.exclude)?;
     let mut commands = spawn_key_listener();
 
     loop {
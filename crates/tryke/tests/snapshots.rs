@@ -42,9 +42,9 @@ fn strip_ansi(s: &str) -> String {
 #[test]
 fn snapshot_failed_with_assertion() {
     let mut r = TextReporter::with_writer(Vec::new());
-    r.on_test_complete(&TestResult {
-        test: make_test("test_credit_card_all_zeros", "tests/test_validation.py"),
-        outcome: TestOutcome::Failed {
+    r.on_test_complete(&TestResult::new(
+        make_test("test_credit_card_all_zeros", "tests/test_validation.py"),
+        TestOutcome::Failed {
             message: "expected falsy, received True".into(),
             traceback: None,
             assertions: vec![Assertion {
@@ -59,10 +59,8 @@ fn snapshot_failed_with_assertion() {
             }],
             executed_lines: vec![],
         },
-        duration: Duration::from_millis(75),
-        stdout: String::new(),
-        stderr: String::new(),
-    });
+        Duration::from_millis(75),
+    ));
     let out = String::from_utf8(r.into_writer()).expect("valid utf-8");
     insta::assert_snapshot!("snapshot_failed_with_assertion", strip_ansi(&out));
 }
@@ -70,9 +68,9 @@ fn snapshot_failed_with_assertion() {
 #[test]
 fn snapshot_failed_with_traceback() {
     let mut r = TextReporter::with_writer(Vec::new());
-    r.on_test_complete(&TestResult {
-        test: make_test("test_credit_card_all_zeros", "tests/test_validation.py"),
-        outcome: TestOutcome::Failed {
+    r.on_test_complete(&TestResult::new(
+        make_test("test_credit_card_all_zeros", "tests/test_validation.py"),
+        TestOutcome::Failed {
             message: "expected True to be falsy".into(),
             traceback: Some(
                 "Traceback (most recent call last):\n  \
@@ -84,10 +82,8 @@ fn snapshot_failed_with_traceback() {
             assertions: vec![],
             executed_lines: vec![],
         },
-        duration: Duration::from_millis(75),
-        stdout: String::new(),
-        stderr: String::new(),
-    });
+        Duration::from_millis(75),
+    ));
     let out = String::from_utf8(r.into_writer()).expect("valid utf-8");
     insta::assert_snapshot!("snapshot_failed_with_traceback", strip_ansi(&out));
 }
@@ -96,19 +92,19 @@ fn snapshot_failed_with_traceback() {
 fn snapshot_grouped_test_output() {
     let mut r = TextReporter::with_writer(Vec::new());
     let file = "tests/test_math.py";
-    let make = |name: &str, groups: &[&str]| TestResult {
-        test: TestItem {
-            name: name.into(),
-            module_path: "tests.test_math".into(),
-            file_path: Some(PathBuf::from(file)),
-            line_number: Some(1),
-            groups: groups.iter().map(|&s| s.into()).collect(),
-            ..Default::default()
-        },
-        outcome: TestOutcome::Passed,
-        duration: Duration::from_millis(1),
-        stdout: String::new(),
-        stderr: String::new(),
+    let make = |name: &str, groups: &[&str]| {
+        TestResult::new(
+            TestItem {
+                name: name.into(),
+                module_path: "tests.test_math".into(),
+                file_path: Some(PathBuf::from(file)),
+                line_number: Some(1),
+                groups: groups.iter().map(|&s| s.into()).collect(),
+                ..Default::default()
+            },
+            TestOutcome::Passed,
+            Duration::from_millis(1),
+        )
     };
     r.on_run_start(&[]);
     r.on_test_complete(&make("adds_two_numbers", &["Math", "addition"]));
@@ -124,18 +120,8 @@ fn snapshot_grouped_test_output() {
 
 fn no_summary() -> RunSummary {
     RunSummary {
-        passed: 0,
-        failed: 0,
-        skipped: 0,
-        errors: 0,
-        xfailed: 0,
-        todo: 0,
         duration: Duration::from_millis(10),
-        discovery_duration: None,
-        test_duration: None,
-        file_count: 0,
-        start_time: None,
-        changed_selection: None,
+        ..Default::default()
     }
 }
 
@@ -154,32 +140,26 @@ fn snapshot_next_two_pass_one_fail_two_files() {
         make_test("test_gamma", "tests/test_two.py"),
     ];
     r.on_run_start(&tests);
-    r.on_test_complete(&TestResult {
-        test: tests[0].clone(),
-        outcome: TestOutcome::Passed,
-        duration: Duration::from_millis(9),
-        stdout: String::new(),
-        stderr: String::new(),
-    });
-    r.on_test_complete(&TestResult {
-        test: tests[1].clone(),
-        outcome: TestOutcome::Failed {
+    r.on_test_complete(&TestResult::new(
+        tests[0].clone(),
+        TestOutcome::Passed,
+        Duration::from_millis(9),
+    ));
+    r.on_test_complete(&TestResult::new(
+        tests[1].clone(),
+        TestOutcome::Failed {
             message: "expected 1, got 2".into(),
             traceback: None,
             assertions: vec![],
             executed_lines: vec![],
         },
-        duration: Duration::from_millis(123),
-        stdout: String::new(),
-        stderr: String::new(),
-    });
-    r.on_test_complete(&TestResult {
-        test: tests[2].clone(),
-        outcome: TestOutcome::Passed,
-        duration: Duration::from_millis(4),
-        stdout: String::new(),
-        stderr: String::new(),
-    });
+        Duration::from_millis(123),
+    ));
+    r.on_test_complete(&TestResult::new(
+        tests[2].clone(),
+        TestOutcome::Passed,
+        Duration::from_millis(4),
+    ));
     r.on_run_complete(&RunSummary {
         passed: 2,
         failed: 1,
@@ -207,32 +187,26 @@ fn snapshot_sugar_two_files_mixed() {
         make_test("c", "tests/b.py"),
     ];
     r.on_run_start(&tests);
-    r.on_test_complete(&TestResult {
-        test: tests[0].clone(),
-        outcome: TestOutcome::Passed,
-        duration: Duration::from_millis(1),
-        stdout: String::new(),
-        stderr: String::new(),
-    });
-    r.on_test_complete(&TestResult {
-        test: tests[1].clone(),
-        outcome: TestOutcome::Failed {
+    r.on_test_complete(&TestResult::new(
+        tests[0].clone(),
+        TestOutcome::Passed,
+        Duration::from_millis(1),
+    ));
+    r.on_test_complete(&TestResult::new(
+        tests[1].clone(),
+        TestOutcome::Failed {
             message: "boom".into(),
             traceback: None,
             assertions: vec![],
             executed_lines: vec![],
         },
-        duration: Duration::from_millis(1),
-        stdout: String::new(),
-        stderr: String::new(),
-    });
-    r.on_test_complete(&TestResult {
-        test: tests[2].clone(),
-        outcome: TestOutcome::Passed,
-        duration: Duration::from_millis(1),
-        stdout: String::new(),
-        stderr: String::new(),
-    });
+        Duration::from_millis(1),
+    ));
+    r.on_test_complete(&TestResult::new(
+        tests[2].clone(),
+        TestOutcome::Passed,
+        Duration::from_millis(1),
+    ));
     r.on_run_complete(&RunSummary {
         passed: 2,
         failed: 1,
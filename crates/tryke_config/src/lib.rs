@@ -1,10 +1,27 @@
 use std::{
-    env, fs,
+    env, fmt, fs,
     path::{Component, Path, PathBuf},
 };
 
 use serde::Deserialize;
 
+/// File-name globs used when `[tool.tryke] strict_files = true` is set
+/// without an explicit `python_files` list.
+pub const DEFAULT_PYTHON_FILES: [&str; 3] = ["test_*.py", "*_test.py", "tests.py"];
+
+/// Assertion entrypoints recognized when `[tool.tryke] expect_names` is
+/// unset.
+pub const DEFAULT_EXPECT_NAMES: [&str; 1] = ["expect"];
+
+/// Test decorator name recognized when `[tool.tryke] decorator` is unset.
+pub const DEFAULT_DECORATOR: &str = "test";
+
+/// Upper bound on discovery threads when `--discovery-jobs` is unset.
+/// Each thread holds at most one source file open while it stats and
+/// reads, so capping threads keeps very large trees clear of low
+/// file-descriptor limits on many-core machines.
+pub const MAX_DEFAULT_DISCOVERY_JOBS: usize = 16;
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DiscoveryConfig {
     pub exclude: Vec<String>,
@@ -13,6 +30,35 @@ pub struct DiscoveryConfig {
     /// each root in order, matching how `sys.path` layers multiple
     /// package roots. Defaults to `["."]` — the project root.
     pub src: Vec<String>,
+    /// File-name globs a file must match for its tests to be collected.
+    /// `None` collects tests from every `.py` file. Non-matching files are
+    /// still walked so the import graph stays complete.
+    pub python_files: Option<Vec<String>>,
+    /// `[tool.tryke] testpaths`: directories, relative to the project
+    /// root, that tests are collected from. Empty collects from the
+    /// whole project. Files outside them are still walked so the import
+    /// graph stays complete.
+    pub testpaths: Vec<String>,
+    /// Function names treated as assertion subjects when extracting
+    /// expected assertions, so `check(x).to_equal(1)` is picked up for
+    /// projects that re-export `expect` under another name.
+    pub expect_names: Vec<String>,
+    /// `[tool.tryke] decorator`: the name a test decorator goes by, so
+    /// `@case` and `@tryke.case` mark tests instead of `@test` for
+    /// projects that export the decorator under another name.
+    pub decorator: String,
+    /// `--discovery-jobs`: threads used to read and parse files. `None`
+    /// picks [`DiscoveryConfig::jobs`]'s capped default.
+    pub jobs: Option<usize>,
+    /// `[tool.tryke] min_assertions`: tests with fewer extracted
+    /// assertions are reported after discovery.
+    pub min_assertions: Option<usize>,
+    /// `[tool.tryke] max_assertions`: tests with more extracted
+    /// assertions are reported after discovery.
+    pub max_assertions: Option<usize>,
+    /// `[tool.tryke] exclude_disabled`: leave `@test(enabled=False)`
+    /// tests out of collection instead of reporting them as skipped.
+    pub exclude_disabled: bool,
 }
 
 impl Default for DiscoveryConfig {
@@ -20,11 +66,33 @@ impl Default for DiscoveryConfig {
         Self {
             exclude: Vec::new(),
             src: vec![".".into()],
+            python_files: None,
+            testpaths: Vec::new(),
+            expect_names: DEFAULT_EXPECT_NAMES.map(String::from).to_vec(),
+            decorator: DEFAULT_DECORATOR.to_owned(),
+            jobs: None,
+            min_assertions: None,
+            max_assertions: None,
+            exclude_disabled: false,
         }
     }
 }
 
 impl DiscoveryConfig {
+    /// Number of discovery threads: the explicit `--discovery-jobs`
+    /// value, otherwise the available parallelism capped at
+    /// [`MAX_DEFAULT_DISCOVERY_JOBS`]. Always at least 1.
+    #[must_use]
+    pub fn jobs(&self) -> usize {
+        self.jobs
+            .unwrap_or_else(|| {
+                std::thread::available_parallelism()
+                    .map_or(1, std::num::NonZero::get)
+                    .min(MAX_DEFAULT_DISCOVERY_JOBS)
+            })
+            .max(1)
+    }
+
     /// Resolves configured source roots relative to `root`.
     #[must_use]
     pub fn src_roots(&self, root: &Path) -> Vec<PathBuf> {
@@ -44,10 +112,17 @@ impl DiscoveryConfig {
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct ConfigOverrides {
+    /// `--rootdir`: used as the project root as given, instead of the
+    /// nearest ancestor holding a root marker.
+    pub rootdir: Option<PathBuf>,
     pub python: Option<String>,
     pub cache_dir: Option<PathBuf>,
     pub exclude: Vec<String>,
     pub include: Vec<String>,
+    /// `--python-files`: replaces the `[tool.tryke] python_files` globs
+    /// when non-empty.
+    pub python_files: Vec<String>,
+    pub discovery_jobs: Option<usize>,
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -95,6 +170,8 @@ pub struct TrykeConfig {
     project_root: PathBuf,
     python: Option<ConfigValue<String>>,
     cache_dir: Option<ConfigValue<PathBuf>>,
+    failure_template: Option<String>,
+    addopts: Vec<String>,
     environment: EnvironmentConfig,
 }
 
@@ -106,7 +183,13 @@ impl TrykeConfig {
 
     #[must_use]
     pub fn load(start: &Path, overrides: ConfigOverrides) -> Self {
-        let project_root = resolve_project_root(start);
+        let project_root = overrides.rootdir.as_deref().map_or_else(
+            || resolve_project_root(start),
+            |rootdir| {
+                let rootdir = absolute(rootdir);
+                rootdir.canonicalize().unwrap_or(rootdir)
+            },
+        );
         let config_root = find_config_root(&project_root);
 
         let file = config_root
@@ -144,14 +227,38 @@ impl TrykeConfig {
                     .map(|value| ConfigValue::new(value, value_root))
             });
 
+        let python_files = if overrides.python_files.is_empty() {
+            file.python_files.or_else(|| {
+                file.strict_files
+                    .unwrap_or(false)
+                    .then(|| DEFAULT_PYTHON_FILES.map(String::from).to_vec())
+            })
+        } else {
+            Some(overrides.python_files)
+        };
+
         Self {
             discovery: DiscoveryConfig {
                 exclude,
                 src: file.src.unwrap_or_else(|| vec![".".into()]),
+                python_files,
+                testpaths: file.testpaths.unwrap_or_default(),
+                expect_names: file
+                    .expect_names
+                    .unwrap_or_else(|| DEFAULT_EXPECT_NAMES.map(String::from).to_vec()),
+                decorator: file
+                    .decorator
+                    .unwrap_or_else(|| DEFAULT_DECORATOR.to_owned()),
+                jobs: overrides.discovery_jobs,
+                min_assertions: file.min_assertions,
+                max_assertions: file.max_assertions,
+                exclude_disabled: file.exclude_disabled.unwrap_or(false),
             },
             project_root,
             python,
             cache_dir,
+            failure_template: file.failure_template,
+            addopts: file.addopts.unwrap_or_default(),
             environment: EnvironmentConfig::from_env(),
         }
     }
@@ -193,6 +300,20 @@ impl TrykeConfig {
             .map(|value| anchor_path(&value.value, &value.relative_to))
     }
 
+    /// `[tool.tryke] failure_template`: the line the text reporter
+    /// prints for each failed assertion in place of its diagnostic.
+    #[must_use]
+    pub fn failure_template(&self) -> Option<&str> {
+        self.failure_template.as_deref()
+    }
+
+    /// `[tool.tryke] addopts`: arguments applied to every `tryke test`
+    /// invocation unless the command line gives the same option.
+    #[must_use]
+    pub fn addopts(&self) -> &[String] {
+        &self.addopts
+    }
+
     #[must_use]
     pub fn src_roots(&self) -> Vec<PathBuf> {
         self.discovery.src_roots(&self.project_root)
@@ -215,12 +336,27 @@ fn absolute(path: &Path) -> PathBuf {
     env::current_dir().map_or_else(|_| path.to_path_buf(), |cwd| cwd.join(path))
 }
 
+/// Files or directories that mark a project root, in priority order.
+pub const DEFAULT_ROOT_MARKERS: &[&str] = &["pyproject.toml", "setup.py", "setup.cfg", ".git"];
+
+/// The nearest ancestor of `start` (or `start` itself) holding one of
+/// [`DEFAULT_ROOT_MARKERS`].
 #[must_use]
 pub fn find_project_root(start: &Path) -> Option<PathBuf> {
-    start
-        .ancestors()
-        .find(|dir| dir.join("pyproject.toml").exists())
-        .map(Path::to_path_buf)
+    find_project_root_with(start, DEFAULT_ROOT_MARKERS)
+}
+
+/// Like [`find_project_root`] with a custom marker list. Markers are tried
+/// in order and the first one found in any ancestor wins, so a
+/// `pyproject.toml` further up beats a `.git` directory closer to `start`.
+#[must_use]
+pub fn find_project_root_with(start: &Path, markers: &[&str]) -> Option<PathBuf> {
+    markers.iter().find_map(|marker| {
+        start
+            .ancestors()
+            .find(|dir| dir.join(marker).exists())
+            .map(Path::to_path_buf)
+    })
 }
 
 #[must_use]
@@ -348,6 +484,209 @@ fn parse_toml(contents: &str) -> Option<RawTrykeConfig> {
     toml::from_str::<PyprojectToml>(contents).ok()?.tool?.tryke
 }
 
+/// A problem in a `[tool.tryke]` table that loading would otherwise
+/// ignore without a word.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigWarning {
+    /// The `pyproject.toml` the problem was found in.
+    pub path: PathBuf,
+    /// The offending key, or `None` when the file or table as a whole is
+    /// unusable.
+    pub key: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.key {
+            Some(key) => write!(
+                f,
+                "{}: [tool.tryke] {key}: {}",
+                self.path.display(),
+                self.message
+            ),
+            None => write!(f, "{}: {}", self.path.display(), self.message),
+        }
+    }
+}
+
+/// The TOML type each `[tool.tryke]` key must have, from its field type
+/// in `RawTrykeConfig` via [`TomlKind`].
+#[derive(Clone, Copy)]
+enum ValueKind {
+    String,
+    Bool,
+    Count,
+    StringList,
+}
+
+impl ValueKind {
+    fn matches(self, value: &toml::Value) -> bool {
+        match self {
+            Self::String => value.is_str(),
+            Self::Bool => value.is_bool(),
+            Self::Count => value.as_integer().is_some_and(|n| n >= 0),
+            Self::StringList => value
+                .as_array()
+                .is_some_and(|items| items.iter().all(toml::Value::is_str)),
+        }
+    }
+
+    fn expected(self) -> &'static str {
+        match self {
+            Self::String => "a string",
+            Self::Bool => "a boolean",
+            Self::Count => "a non-negative integer",
+            Self::StringList => "a list of strings",
+        }
+    }
+}
+
+/// A `RawTrykeConfig` field type, and the TOML type it reads from.
+trait TomlKind {
+    const KIND: ValueKind;
+}
+
+impl TomlKind for String {
+    const KIND: ValueKind = ValueKind::String;
+}
+
+impl TomlKind for PathBuf {
+    const KIND: ValueKind = ValueKind::String;
+}
+
+impl TomlKind for bool {
+    const KIND: ValueKind = ValueKind::Bool;
+}
+
+impl TomlKind for usize {
+    const KIND: ValueKind = ValueKind::Count;
+}
+
+impl TomlKind for Vec<String> {
+    const KIND: ValueKind = ValueKind::StringList;
+}
+
+/// Declares `RawTrykeConfig` and `CONFIG_KEYS` from one list of keys, so
+/// validation knows exactly the keys loading reads.
+macro_rules! raw_tryke_config {
+    ($($key:ident: $ty:ty),* $(,)?) => {
+        #[derive(Debug, Default, Deserialize)]
+        struct RawTrykeConfig {
+            $($key: Option<$ty>,)*
+        }
+
+        /// Every `[tool.tryke]` key with the TOML type it must have.
+        const CONFIG_KEYS: &[(&str, ValueKind)] =
+            &[$((stringify!($key), <$ty as TomlKind>::KIND)),*];
+    };
+}
+
+raw_tryke_config! {
+    exclude: Vec<String>,
+    src: Vec<String>,
+    python: String,
+    cache_dir: PathBuf,
+    python_files: Vec<String>,
+    strict_files: bool,
+    expect_names: Vec<String>,
+    decorator: String,
+    min_assertions: usize,
+    max_assertions: usize,
+    exclude_disabled: bool,
+    failure_template: String,
+    testpaths: Vec<String>,
+    addopts: Vec<String>,
+}
+
+/// Checks the nearest `[tool.tryke]` table above `start` for unknown keys
+/// and values of the wrong type.
+///
+/// Loading never fails on a bad table: an unknown key is skipped, and a
+/// single mistyped value makes the whole table fall back to defaults.
+/// This reports both, so a typo doesn't silently change what runs. A
+/// `pyproject.toml` that isn't valid TOML is reported too. Returns no
+/// warnings when there's no `[tool.tryke]` table at all.
+#[must_use]
+pub fn validate_config(start: &Path) -> Vec<ConfigWarning> {
+    let project_root = resolve_project_root(start);
+    for dir in project_root.ancestors() {
+        let path = dir.join("pyproject.toml");
+        let Ok(contents) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let document = match toml::from_str::<toml::Table>(&contents) {
+            Ok(document) => document,
+            Err(err) => {
+                return vec![ConfigWarning {
+                    path,
+                    key: None,
+                    message: format!("not valid TOML: {}", err.message()),
+                }];
+            }
+        };
+        if let Some(table) = document.get("tool").and_then(|tool| tool.get("tryke")) {
+            return validate_tryke_table(&path, table);
+        }
+    }
+    Vec::new()
+}
+
+fn validate_tryke_table(path: &Path, table: &toml::Value) -> Vec<ConfigWarning> {
+    let Some(table) = table.as_table() else {
+        return vec![ConfigWarning {
+            path: path.to_path_buf(),
+            key: None,
+            message: format!("[tool.tryke] must be a table, found {}", table.type_str()),
+        }];
+    };
+    let mut warnings = Vec::new();
+    for (key, value) in table {
+        let warning = |message| ConfigWarning {
+            path: path.to_path_buf(),
+            key: Some(key.clone()),
+            message,
+        };
+        match CONFIG_KEYS.iter().find(|(name, _)| name == key) {
+            Some((_, kind)) if !kind.matches(value) => warnings.push(warning(format!(
+                "expected {}, found {}; the whole table is ignored until this is fixed",
+                kind.expected(),
+                value.type_str()
+            ))),
+            Some(_) => {}
+            None => warnings.push(warning(match closest_key(key) {
+                Some(known) => format!("unknown key (did you mean `{known}`?)"),
+                None => "unknown key".to_owned(),
+            })),
+        }
+    }
+    warnings
+}
+
+/// The known key within two single-character edits of `key`, if any.
+fn closest_key(key: &str) -> Option<&'static str> {
+    CONFIG_KEYS
+        .iter()
+        .map(|(name, _)| (*name, edit_distance(key, name)))
+        .filter(|(_, distance)| *distance <= 2)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(name, _)| name)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitute.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
 #[derive(Debug, Default, Deserialize)]
 struct PyprojectToml {
     tool: Option<PyprojectTool>,
@@ -358,14 +697,6 @@ struct PyprojectTool {
     tryke: Option<RawTrykeConfig>,
 }
 
-#[derive(Debug, Default, Deserialize)]
-struct RawTrykeConfig {
-    exclude: Option<Vec<String>>,
-    src: Option<Vec<String>>,
-    python: Option<String>,
-    cache_dir: Option<PathBuf>,
-}
-
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -416,6 +747,8 @@ mod tests {
         let config = DiscoveryConfig {
             exclude: Vec::new(),
             src: vec![".".into(), "python".into()],
+            python_files: None,
+            ..DiscoveryConfig::default()
         };
 
         assert_eq!(
@@ -433,6 +766,8 @@ mod tests {
         let config = DiscoveryConfig {
             exclude: Vec::new(),
             src: Vec::new(),
+            python_files: None,
+            ..DiscoveryConfig::default()
         };
 
         assert_eq!(
@@ -441,6 +776,238 @@ mod tests {
         );
     }
 
+    #[test]
+    fn python_files_default_to_unrestricted() {
+        let dir = tempdir();
+        fs::write(dir.path().join("pyproject.toml"), "[tool.tryke]\n").expect("write pyproject");
+        let config = load_without_environment(dir.path(), ConfigOverrides::default());
+        assert_eq!(config.discovery.python_files, None);
+    }
+
+    #[test]
+    fn strict_files_uses_default_python_files() {
+        let dir = tempdir();
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\nstrict_files = true\n",
+        )
+        .expect("write pyproject");
+        let config = load_without_environment(dir.path(), ConfigOverrides::default());
+        assert_eq!(
+            config.discovery.python_files,
+            Some(vec![
+                "test_*.py".into(),
+                "*_test.py".into(),
+                "tests.py".into()
+            ])
+        );
+    }
+
+    #[test]
+    fn explicit_python_files_win() {
+        let dir = tempdir();
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\npython_files = [\"check_*.py\"]\n",
+        )
+        .expect("write pyproject");
+        let config = load_without_environment(dir.path(), ConfigOverrides::default());
+        assert_eq!(
+            config.discovery.python_files,
+            Some(vec!["check_*.py".into()])
+        );
+    }
+
+    #[test]
+    fn python_files_override_replaces_config() {
+        let dir = tempdir();
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\npython_files = [\"check_*.py\"]\n",
+        )
+        .expect("write pyproject");
+        let config = load_without_environment(
+            dir.path(),
+            ConfigOverrides {
+                python_files: vec!["test_*.py".into(), "*_test.py".into()],
+                ..ConfigOverrides::default()
+            },
+        );
+        assert_eq!(
+            config.discovery.python_files,
+            Some(vec!["test_*.py".into(), "*_test.py".into()])
+        );
+    }
+
+    #[test]
+    fn expect_names_default_and_override() {
+        let dir = tempdir();
+        fs::write(dir.path().join("pyproject.toml"), "[tool.tryke]\n").expect("write pyproject");
+        let config = load_without_environment(dir.path(), ConfigOverrides::default());
+        assert_eq!(config.discovery.expect_names, vec!["expect"]);
+
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\nexpect_names = [\"expect\", \"check\"]\n",
+        )
+        .expect("write pyproject");
+        let config = load_without_environment(dir.path(), ConfigOverrides::default());
+        assert_eq!(config.discovery.expect_names, vec!["expect", "check"]);
+    }
+
+    #[test]
+    fn decorator_default_and_override() {
+        let dir = tempdir();
+        fs::write(dir.path().join("pyproject.toml"), "[tool.tryke]\n").expect("write pyproject");
+        let config = load_without_environment(dir.path(), ConfigOverrides::default());
+        assert_eq!(config.discovery.decorator, "test");
+
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\ndecorator = \"case\"\n",
+        )
+        .expect("write pyproject");
+        let config = load_without_environment(dir.path(), ConfigOverrides::default());
+        assert_eq!(config.discovery.decorator, "case");
+    }
+
+    #[test]
+    fn parses_assertion_count_limits() {
+        let dir = tempdir();
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\nmin_assertions = 1\nmax_assertions = 20\n",
+        )
+        .expect("write pyproject");
+        let config = load_without_environment(dir.path(), ConfigOverrides::default());
+        assert_eq!(config.discovery.min_assertions, Some(1));
+        assert_eq!(config.discovery.max_assertions, Some(20));
+    }
+
+    #[test]
+    fn parses_exclude_disabled() {
+        let dir = tempdir();
+        let config = load_without_environment(dir.path(), ConfigOverrides::default());
+        assert!(!config.discovery.exclude_disabled);
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\nexclude_disabled = true\n",
+        )
+        .expect("write pyproject");
+        let config = load_without_environment(dir.path(), ConfigOverrides::default());
+        assert!(config.discovery.exclude_disabled);
+    }
+
+    #[test]
+    fn parses_failure_template() {
+        let dir = tempdir();
+        let config = load_without_environment(dir.path(), ConfigOverrides::default());
+        assert_eq!(config.failure_template(), None);
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\nfailure_template = \"{id}: expected {expected}, got {received}\"\n",
+        )
+        .expect("write pyproject");
+        let config = load_without_environment(dir.path(), ConfigOverrides::default());
+        assert_eq!(
+            config.failure_template(),
+            Some("{id}: expected {expected}, got {received}")
+        );
+    }
+
+    #[test]
+    fn parses_testpaths_and_addopts() {
+        let dir = tempdir();
+        let config = load_without_environment(dir.path(), ConfigOverrides::default());
+        assert!(config.discovery.testpaths.is_empty());
+        assert!(config.addopts().is_empty());
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\ntestpaths = [\"tests\"]\naddopts = [\"--maxfail\", \"1\"]\n",
+        )
+        .expect("write pyproject");
+        let config = load_without_environment(dir.path(), ConfigOverrides::default());
+        assert_eq!(config.discovery.testpaths, vec!["tests"]);
+        assert_eq!(config.addopts(), ["--maxfail", "1"]);
+    }
+
+    #[test]
+    fn malformed_testpaths_fall_back_with_warning() {
+        let dir = tempdir();
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\ntestpaths = \"tests\"\n",
+        )
+        .expect("write pyproject");
+        let config = load_without_environment(dir.path(), ConfigOverrides::default());
+        assert!(config.discovery.testpaths.is_empty());
+        let keys: Vec<_> = validate_config(dir.path())
+            .into_iter()
+            .filter_map(|warning| warning.key)
+            .collect();
+        assert_eq!(keys, vec!["testpaths"]);
+    }
+
+    #[test]
+    fn validate_config_names_misspelled_key() {
+        let dir = tempdir();
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\nexlcude = [\"build\"]\nsrc = [\".\"]\n",
+        )
+        .expect("write pyproject");
+        let warnings = validate_config(dir.path());
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].key.as_deref(), Some("exlcude"));
+        assert!(
+            warnings[0].message.contains("did you mean `exclude`"),
+            "{}",
+            warnings[0].message
+        );
+    }
+
+    #[test]
+    fn validate_config_flags_wrong_types() {
+        let dir = tempdir();
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\nsrc = \"python\"\nmin_assertions = -1\nstrict_files = true\n",
+        )
+        .expect("write pyproject");
+        let keys: Vec<_> = validate_config(dir.path())
+            .into_iter()
+            .filter_map(|warning| warning.key)
+            .collect();
+        assert_eq!(keys, vec!["min_assertions", "src"]);
+    }
+
+    #[test]
+    fn validate_config_accepts_valid_table() {
+        let dir = tempdir();
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\nexclude = [\"build\"]\npython = \".venv\"\nmax_assertions = 3\n",
+        )
+        .expect("write pyproject");
+        assert_eq!(validate_config(dir.path()), vec![]);
+    }
+
+    #[test]
+    fn discovery_jobs_override_and_default_cap() {
+        let dir = tempdir();
+        let config = load_without_environment(
+            dir.path(),
+            ConfigOverrides {
+                discovery_jobs: Some(2),
+                ..ConfigOverrides::default()
+            },
+        );
+        assert_eq!(config.discovery.jobs(), 2);
+
+        let config = load_without_environment(dir.path(), ConfigOverrides::default());
+        assert!((1..=MAX_DEFAULT_DISCOVERY_JOBS).contains(&config.discovery.jobs()));
+    }
+
     #[test]
     fn parses_python_path() {
         let config = parse_toml("[tool.tryke]\npython = \"/usr/bin/python3.13\"\n").expect("some");
@@ -473,6 +1040,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn recognizes_each_root_marker() {
+        for marker in DEFAULT_ROOT_MARKERS {
+            let dir = tempdir();
+            let child = dir.path().join("pkg");
+            fs::create_dir(&child).expect("create child");
+            if *marker == ".git" {
+                fs::create_dir(dir.path().join(marker)).expect("create .git");
+            } else {
+                fs::write(dir.path().join(marker), "").expect("write marker");
+            }
+
+            assert_eq!(
+                find_project_root(&child),
+                Some(dir.path().to_path_buf()),
+                "{marker}"
+            );
+        }
+    }
+
+    #[test]
+    fn pyproject_wins_over_closer_markers() {
+        let dir = tempdir();
+        let sub = dir.path().join("sub");
+        let child = sub.join("pkg");
+        fs::create_dir_all(&child).expect("create child");
+        fs::write(dir.path().join("pyproject.toml"), "").expect("write pyproject");
+        fs::create_dir(sub.join(".git")).expect("create .git");
+        fs::write(sub.join("setup.py"), "").expect("write setup.py");
+
+        assert_eq!(find_project_root(&child), Some(dir.path().to_path_buf()));
+        assert_eq!(
+            find_project_root_with(&child, &["setup.py", "pyproject.toml"]),
+            Some(sub)
+        );
+    }
+
+    #[test]
+    fn rootdir_override_pins_the_project_root() {
+        let dir = tempdir();
+        let sub = dir.path().join("services/api");
+        fs::create_dir_all(&sub).expect("create sub");
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\nexclude = [\"build\"]\n",
+        )
+        .expect("write pyproject");
+
+        let config = load_without_environment(
+            dir.path(),
+            ConfigOverrides {
+                rootdir: Some(sub.clone()),
+                ..ConfigOverrides::default()
+            },
+        );
+        assert_eq!(config.root(), sub.canonicalize().expect("canonical sub"));
+        assert_eq!(config.discovery.exclude, vec!["build"]);
+    }
+
     #[test]
     fn project_root_falls_back_to_start() {
         let dir = tempdir();
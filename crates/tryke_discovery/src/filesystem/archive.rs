@@ -0,0 +1,121 @@
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+use log::trace;
+use tryke_types::TestItem;
+
+/// Discover tests in the `.py` members of a zip archive (a `.zip` built
+/// for `zipimport`, or a wheel) without extracting it.
+///
+/// The archive itself acts as the project root and only source root,
+/// the way `zipimport` treats an archive on `sys.path`: a member at
+/// `pkg/tests/test_math.py` becomes module `pkg.tests.test_math`, and
+/// its tests carry `<archive>/pkg/tests/test_math.py` as their file
+/// path, matching the `__file__` Python reports for it. Members that
+/// aren't valid UTF-8 are skipped.
+///
+/// # Errors
+/// Returns an error if the archive can't be opened or isn't a readable
+/// zip file.
+pub fn discover_archive(
+    archive: &Path,
+    expect_names: &[String],
+    decorator: &str,
+) -> io::Result<Vec<TestItem>> {
+    let mut zip = zip::ZipArchive::new(File::open(archive)?)?;
+    let src_roots = [archive.to_path_buf()];
+    let mut tests = Vec::new();
+    for index in 0..zip.len() {
+        let mut member = zip.by_index(index)?;
+        // `enclosed_name` rejects absolute and `..` member names, so a
+        // member can't resolve to a module outside the archive.
+        let Some(name) = member.enclosed_name() else {
+            continue;
+        };
+        if member.is_dir() || name.extension().is_none_or(|ext| ext != "py") {
+            continue;
+        }
+        let mut source = String::new();
+        if member.read_to_string(&mut source).is_err() {
+            trace!("skipping non-UTF-8 member {}", name.display());
+            continue;
+        }
+        let file = archive.join(name);
+        let parsed = super::parse_tests_from_named_source(
+            archive,
+            &src_roots,
+            expect_names,
+            decorator,
+            &file,
+            &source,
+        );
+        for err in &parsed.errors {
+            log::error!("tryke discovery: {err}");
+        }
+        tests.extend(parsed.tests);
+    }
+    tests.sort_by(|a, b| {
+        a.file_path
+            .cmp(&b.file_path)
+            .then(a.line_number.cmp(&b.line_number))
+    });
+    Ok(tests)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use zip::write::SimpleFileOptions;
+
+    use super::*;
+
+    fn write_zip(path: &Path, members: &[(&str, &str)]) {
+        let mut writer = zip::ZipWriter::new(File::create(path).expect("create zip"));
+        for (name, source) in members {
+            writer
+                .start_file(*name, SimpleFileOptions::default())
+                .expect("start member");
+            writer.write_all(source.as_bytes()).expect("write member");
+        }
+        writer.finish().expect("finish zip");
+    }
+
+    #[test]
+    fn discovers_tests_from_zip_members() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let archive = dir.path().join("suite.zip");
+        write_zip(
+            &archive,
+            &[
+                ("pkg/__init__.py", ""),
+                (
+                    "pkg/tests/test_math.py",
+                    "from tryke import test, expect\n\n@test\ndef adds():\n    expect(1 + 1).to_equal(2)\n",
+                ),
+                ("README.txt", "@test\ndef not_python():\n    pass\n"),
+            ],
+        );
+
+        let tests = discover_archive(&archive, &["expect".to_owned()], "test").expect("discover");
+
+        assert_eq!(tests.len(), 1);
+        let test = &tests[0];
+        assert_eq!(test.name, "adds");
+        assert_eq!(test.module_path, "pkg.tests.test_math");
+        assert_eq!(
+            test.file_path.as_deref(),
+            Some(archive.join("pkg/tests/test_math.py").as_path())
+        );
+        assert_eq!(test.expected_assertions.len(), 1);
+    }
+
+    #[test]
+    fn rejects_non_zip_archive() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let archive = dir.path().join("not_a.zip");
+        std::fs::write(&archive, "plain text").expect("write");
+        assert!(discover_archive(&archive, &["expect".to_owned()], "test").is_err());
+    }
+}
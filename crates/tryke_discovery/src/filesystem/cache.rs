@@ -30,7 +30,37 @@ use super::db::DiscoveredFile;
 /// v3: absolute-import resolution now walks configured `src` roots,
 /// so cached `import_candidates` from v2 (always keyed to project
 /// root) would miss resolutions under secondary roots like `python/`.
-const CACHE_VERSION: u32 = 3;
+/// v4: `expect(..., group=...)` is recorded as the assertion's `group`,
+/// so cached assertions would render outside their group.
+/// v5: `@test(...)` keyword arguments are recorded as `decorator_kwargs`.
+/// v6: subscripted decorators (`@decorators["test"]`) are recognised,
+/// so cached entries would be missing those tests.
+/// v7: `@test.skip(category=...)` is recorded as `skip_category`.
+/// v8: `.pending()` and `# pending` mark an assertion `pending`, so
+/// cached assertions would still count as live expectations.
+/// v9: `@test(enabled=False)` is recorded as `disabled`.
+/// v10: test function parameter names and arity are recorded.
+/// v11: `expect` chains inside `await` expressions are extracted.
+/// v12: a file that fails to parse records its `syntax_error`, so cached
+/// entries would pass it off as a file with no tests.
+/// v13: `@tryke.skip` and `@tryke.xfail` stacked beside `@test` now apply,
+/// so cached entries would still show those tests as plain tests.
+/// v14: `@test(benchmark=True)` is recorded as `benchmark`.
+/// v15: decorated methods inside classes are now collected, so cached
+/// entries for files with test classes would be missing those tests.
+/// v16: `@test.each([...])` rows expand into one test per row, so cached
+/// entries would hold a single unexpanded test.
+/// v17: f-string test names are now rendered, so cached entries would
+/// still show the function name.
+/// v18: `expect` chains inside `and`/`or`, conditional expressions, and
+/// comparisons are now extracted, so cached entries would undercount.
+/// v19: a package's `__init__.py` is named after its directory in
+/// `module_path`, so cached entries would keep the `.__init__` suffix.
+/// v20: each file's top-level imports are recorded in `imports`.
+/// v21: `expect` chains inside comprehensions and lambdas are extracted.
+/// v22: `expect` chains on the right of assignments are extracted.
+/// v23: `expect` chains inside `match` statements are extracted.
+const CACHE_VERSION: u32 = 23;
 
 /// Name of the cache file within its directory. The stem is also reused
 /// (with a `.tmp` extension) for the atomic write in `save`.
@@ -73,11 +103,24 @@ struct CacheEntry {
 struct CacheFile {
     version: u32,
     entries: HashMap<PathBuf, CacheEntry>,
+    /// `[tool.tryke] expect_names` the entries were extracted with.
+    /// Missing from older files, which then fail the comparison in
+    /// `DiskCache::use_names` and start empty.
+    #[serde(default)]
+    expect_names: Vec<String>,
+    /// `[tool.tryke] decorator` the entries were collected with; empty in
+    /// older files, like `expect_names`.
+    #[serde(default)]
+    decorator: String,
 }
 
 #[derive(Debug, Default)]
 pub struct DiskCache {
     entries: HashMap<PathBuf, CacheEntry>,
+    /// Assertion entrypoints baked into the cached `expected_assertions`.
+    expect_names: Vec<String>,
+    /// Test decorator name the cached tests were collected under.
+    decorator: String,
     /// The path we loaded from / will save to. `None` disables I/O
     /// (used by tests that don't want a filesystem footprint).
     path: Option<PathBuf>,
@@ -194,11 +237,11 @@ impl DiskCache {
     }
 
     fn load_with_gitignore(path: PathBuf, gitignore: Option<GitignoreConfig>) -> Self {
-        let entries = match Self::try_load(&path) {
-            Ok(entries) => entries,
+        let (entries, expect_names, decorator) = match Self::try_load(&path) {
+            Ok(loaded) => loaded,
             Err(err) => {
                 trace!("discovery cache load failed ({err}): starting empty");
-                HashMap::new()
+                (HashMap::new(), Vec::new(), String::new())
             }
         };
         debug!(
@@ -208,12 +251,14 @@ impl DiskCache {
         );
         Self {
             entries,
+            expect_names,
+            decorator,
             path: Some(path),
             gitignore,
         }
     }
 
-    fn try_load(path: &Path) -> io::Result<HashMap<PathBuf, CacheEntry>> {
+    fn try_load(path: &Path) -> io::Result<(HashMap<PathBuf, CacheEntry>, Vec<String>, String)> {
         let bytes = fs::read(path)?;
         let file: CacheFile = rmp_serde::from_slice(&bytes)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
@@ -222,9 +267,22 @@ impl DiskCache {
                 "discovery cache version mismatch ({} vs {}): discarding",
                 file.version, CACHE_VERSION
             );
-            return Ok(HashMap::new());
+            return Ok((HashMap::new(), Vec::new(), String::new()));
+        }
+        Ok((file.entries, file.expect_names, file.decorator))
+    }
+
+    /// Declare the assertion entrypoints and test decorator name discovery
+    /// will run with. Cached results produced under different names are
+    /// discarded, since the file key alone can't tell that their tests or
+    /// `expected_assertions` are stale.
+    pub fn use_names(&mut self, expect_names: &[String], decorator: &str) {
+        if self.expect_names != expect_names || self.decorator != decorator {
+            debug!("discovery cache expect_names or decorator changed: discarding");
+            self.entries.clear();
+            self.expect_names = expect_names.to_vec();
+            self.decorator = decorator.to_owned();
         }
-        Ok(file.entries)
     }
 
     /// Lookup a cached result for `path`. Returns the stored
@@ -237,6 +295,13 @@ impl DiskCache {
             .map(|e| &e.data)
     }
 
+    /// The stored result for `path` whatever its `FileKey`: what the
+    /// file held the last time it was discovered, even if it has
+    /// changed since.
+    pub fn previous(&self, path: &Path) -> Option<&DiscoveredFile> {
+        self.entries.get(path).map(|e| &e.data)
+    }
+
     pub fn insert(&mut self, path: PathBuf, key: FileKey, data: DiscoveredFile) {
         self.entries.insert(path, CacheEntry { key, data });
     }
@@ -273,6 +338,8 @@ impl DiskCache {
         let file = CacheFile {
             version: CACHE_VERSION,
             entries: self.entries.clone(),
+            expect_names: self.expect_names.clone(),
+            decorator: self.decorator.clone(),
         };
         // `to_vec_named` encodes structs as maps (field names + values)
         // so `#[serde(skip_serializing_if = ...)]` + `#[serde(default)]`
@@ -2,7 +2,7 @@ use std::path::PathBuf;
 
 use log::trace;
 use ruff_python_ast::{ModModule, Stmt};
-use ruff_python_parser::parse_module;
+use ruff_python_parser::{ParseError, parse_module};
 
 pub use tryke_types::DiscoveredFile;
 
@@ -18,6 +18,10 @@ pub struct SourceFile {
     #[returns(ref)]
     pub src_roots: Vec<PathBuf>,
     #[returns(ref)]
+    pub expect_names: Vec<String>,
+    #[returns(ref)]
+    pub decorator: String,
+    #[returns(ref)]
     pub path: PathBuf,
 }
 
@@ -26,20 +30,24 @@ pub struct SourceFile {
 /// Equality intentionally ignores raw source text. If the parser produces the
 /// same AST body for a new source string, Salsa keeps the old value and
 /// backdates dependents, so discovery is not re-run for trivia-only edits.
+/// A parse error is compared too, so moving a syntax error re-reports it.
 #[derive(Debug, Clone)]
 pub(crate) struct ParsedAst {
     source: String,
     syntax: Option<ModModule>,
+    error: Option<ParseError>,
 }
 
 impl ParsedAst {
     pub(crate) fn parse(source: &str) -> Self {
-        let syntax = parse_module(source)
-            .ok()
-            .map(ruff_python_parser::Parsed::into_syntax);
+        let (syntax, error) = match parse_module(source) {
+            Ok(parsed) => (Some(parsed.into_syntax()), None),
+            Err(err) => (None, Some(err)),
+        };
         Self {
             source: source.to_owned(),
             syntax,
+            error,
         }
     }
 
@@ -51,6 +59,10 @@ impl ParsedAst {
         self.syntax.as_ref()
     }
 
+    pub(crate) fn error(&self) -> Option<&ParseError> {
+        self.error.as_ref()
+    }
+
     fn body(&self) -> Option<&[Stmt]> {
         self.syntax.as_ref().map(|module| module.body.as_slice())
     }
@@ -58,7 +70,7 @@ impl ParsedAst {
 
 impl PartialEq for ParsedAst {
     fn eq(&self, other: &Self) -> bool {
-        self.body() == other.body()
+        self.body() == other.body() && self.error == other.error
     }
 }
 
@@ -106,6 +118,8 @@ pub fn discover_file(db: &dyn Db, file: SourceFile) -> DiscoveredFile {
     super::discover_file_from_ast(
         file.root(db),
         file.src_roots(db),
+        file.expect_names(db),
+        file.decorator(db),
         file.path(db),
         parse_file(db, file),
     )
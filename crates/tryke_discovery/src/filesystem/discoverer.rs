@@ -1,14 +1,17 @@
 use std::{
     collections::{HashMap, HashSet},
+    hash::{DefaultHasher, Hash, Hasher},
     path::{Path, PathBuf},
+    time::{Duration, Instant},
 };
 
 use log::{debug, trace, warn};
 use rayon::prelude::*;
 use salsa::Setter;
-use tryke_types::{HookItem, TestItem};
+use tryke_types::{DiscoveryError, FileDiscovery, HookItem, TestItem};
 
 use super::{
+    CollectFilter,
     cache::{DiskCache, FileKey},
     db::{Database, DiscoveredFile, SourceFile, discover_file},
     import_graph::{GraphEntry, ImportGraph},
@@ -25,6 +28,18 @@ pub struct Discoverer {
     src_roots: Vec<PathBuf>,
     import_graph: ImportGraph,
     excludes: Vec<String>,
+    /// `[tool.tryke] python_files`, `testpaths`, and `exclude_disabled`.
+    /// Every `.py` file is still parsed so imports resolve and the graph
+    /// stays complete; this only picks which tests are collected.
+    filter: CollectFilter,
+    /// `[tool.tryke] expect_names`: callees recognized as assertion
+    /// subjects when extracting each test's expected assertions.
+    expect_names: Vec<String>,
+    /// `[tool.tryke] decorator`: the name `@test` goes by in this project.
+    decorator: String,
+    /// Dedicated pool for the parallel read and parse phases, sized by
+    /// `--discovery-jobs`. `None` uses rayon's global pool.
+    pool: Option<rayon::ThreadPool>,
     /// Set of all project-local python files known to the discoverer.
     /// Populated by the most recent `rediscover` and updated by
     /// `rediscover_changed` so import candidates can be resolved via
@@ -43,6 +58,37 @@ pub struct Discoverer {
     /// stat of each enumerated file. Used to decide which entries to
     /// persist back into `cache` after parsing.
     cache_keys_hit: HashMap<PathBuf, FileKey>,
+    /// Files whose entry in `results` came straight from `cache` rather
+    /// than a parse. Surfaced through [`Discoverer::file_discoveries`].
+    cached_files: HashSet<PathBuf>,
+    /// Cost of the most recent `rediscover` / `rediscover_restricted`.
+    stats: DiscoveryStats,
+    /// Ids of tests whose assertions differ from the discovery cache's
+    /// previous entry for their file, as of the most recent `rediscover`.
+    changed_assertions: HashSet<String>,
+}
+
+/// What the most recent discovery pass cost, for `--discovery-stats`.
+/// Cache hits are walked but neither read nor parsed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiscoveryStats {
+    /// Python files enumerated by the walk.
+    pub files_walked: usize,
+    /// Files that missed the discovery cache and were parsed.
+    pub files_parsed: usize,
+    /// Source bytes read for the parsed files.
+    pub bytes_read: u64,
+    /// Wall-clock time of the parallel parse phase.
+    pub parse_time: Duration,
+}
+
+impl std::ops::AddAssign for DiscoveryStats {
+    fn add_assign(&mut self, other: Self) {
+        self.files_walked += other.files_walked;
+        self.files_parsed += other.files_parsed;
+        self.bytes_read += other.bytes_read;
+        self.parse_time += other.parse_time;
+    }
 }
 
 #[derive(Debug, Default)]
@@ -99,10 +145,103 @@ impl Discoverer {
             src_roots,
             import_graph: ImportGraph::default(),
             excludes: excludes.to_vec(),
+            filter: CollectFilter::default(),
+            expect_names: tryke_config::DiscoveryConfig::default().expect_names,
+            decorator: tryke_config::DEFAULT_DECORATOR.to_owned(),
+            pool: None,
             project_files: HashSet::new(),
             results: HashMap::new(),
             cache,
             cache_keys_hit: HashMap::new(),
+            cached_files: HashSet::new(),
+            stats: DiscoveryStats::default(),
+            changed_assertions: HashSet::new(),
+        }
+    }
+
+    /// Creates a discoverer for `config`'s project, with every
+    /// `[tool.tryke]` discovery setting applied.
+    #[must_use]
+    pub fn from_config(config: &tryke_config::TrykeConfig) -> Self {
+        let discovery = &config.discovery;
+        Self::new(
+            config.root(),
+            config.src_roots(),
+            &discovery.exclude,
+            config.cache_dir().as_deref(),
+        )
+        .with_python_files(discovery.python_files.as_deref())
+        .with_testpaths(&discovery.testpaths)
+        .with_expect_names(&discovery.expect_names)
+        .with_decorator(&discovery.decorator)
+        .with_exclude_disabled(discovery.exclude_disabled)
+        .with_jobs(discovery.jobs())
+    }
+
+    /// Restricts test collection to files matching the given
+    /// `[tool.tryke] python_files` globs. `None` keeps every `.py` file.
+    #[must_use]
+    pub fn with_python_files(mut self, patterns: Option<&[String]>) -> Self {
+        self.filter.python_files =
+            patterns.map(|patterns| super::build_python_files_matcher(&self.root, patterns));
+        self
+    }
+
+    /// Restricts test collection to files under the given
+    /// `[tool.tryke] testpaths`, relative to the project root. An empty
+    /// list keeps the whole project.
+    #[must_use]
+    pub fn with_testpaths(mut self, testpaths: &[String]) -> Self {
+        self.filter.testpaths = testpaths.iter().map(|path| self.root.join(path)).collect();
+        self
+    }
+
+    /// Recognizes `names` as assertion entrypoints in place of the
+    /// default `expect`. Must be set before the first `rediscover`.
+    #[must_use]
+    pub fn with_expect_names(mut self, names: &[String]) -> Self {
+        self.expect_names = names.to_vec();
+        self
+    }
+
+    /// Recognizes `@<name>` / `@tryke.<name>` as the test decorator in
+    /// place of the default `test`. Must be set before the first
+    /// `rediscover`.
+    #[must_use]
+    pub fn with_decorator(mut self, name: &str) -> Self {
+        name.clone_into(&mut self.decorator);
+        self
+    }
+
+    /// Leaves `@test(enabled=False)` tests out of every result instead of
+    /// collecting them as skipped.
+    #[must_use]
+    pub fn with_exclude_disabled(mut self, exclude: bool) -> Self {
+        self.filter.exclude_disabled = exclude;
+        self
+    }
+
+    /// Bounds the threads that read and parse files to `jobs`, so huge
+    /// trees can't hold more files open at once than the machine allows.
+    /// Falls back to rayon's global pool if the pool can't be built.
+    #[must_use]
+    pub fn with_jobs(mut self, jobs: usize) -> Self {
+        match rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs.max(1))
+            .thread_name(|i| format!("tryke-discovery-{i}"))
+            .build()
+        {
+            Ok(pool) => self.pool = Some(pool),
+            Err(err) => warn!("discovery: couldn't build a {jobs}-thread pool ({err})"),
+        }
+        self
+    }
+
+    /// Run `op` on the discovery pool when one was configured.
+    fn install<R: Send>(&self, op: impl FnOnce() -> R + Send) -> R {
+        match &self.pool {
+            Some(pool) => pool.install(op),
+            None => op(),
         }
     }
 
@@ -117,6 +256,7 @@ impl Discoverer {
     }
 
     pub fn rediscover(&mut self) -> Vec<TestItem> {
+        self.cache.use_names(&self.expect_names, &self.decorator);
         let mut paths = super::collect_python_files(&self.root, &self.excludes);
         paths.sort();
         debug!(
@@ -133,10 +273,12 @@ impl Discoverer {
         // it again. The closure captures `&self.cache` only — the
         // rest of `self` holds a salsa `Database` that isn't `Sync`.
         let cache_ref = &self.cache;
-        let keyed: Vec<FileWork> = paths
-            .par_iter()
-            .map(|path| Self::prepare_work(cache_ref, path))
-            .collect();
+        let keyed: Vec<FileWork> = self.install(|| {
+            paths
+                .par_iter()
+                .map(|path| Self::prepare_work(cache_ref, path))
+                .collect()
+        });
 
         // Phase 2: drop cache entries for paths we no longer enumerate
         // (file deleted or newly excluded).
@@ -147,6 +289,7 @@ impl Discoverer {
         // single-threaded. The expensive work has already happened.
         let mut misses: Vec<PathBuf> = Vec::new();
         let mut hit_count = 0usize;
+        let mut bytes_read = 0u64;
         let removed: Vec<PathBuf> = self
             .results
             .keys()
@@ -156,6 +299,7 @@ impl Discoverer {
         for path in removed {
             self.import_graph.remove(&path);
             self.inputs.remove(&path);
+            self.cached_files.remove(&path);
             self.results.remove(&path);
         }
         for work in keyed {
@@ -164,6 +308,7 @@ impl Discoverer {
                     // Hot path: file unchanged since last run. Use the
                     // cached result directly; no parse, no salsa.
                     self.results.insert(path.clone(), data);
+                    self.cached_files.insert(path.clone());
                     self.cache_keys_hit
                         .entry(path)
                         .and_modify(|k| *k = key)
@@ -171,12 +316,15 @@ impl Discoverer {
                     hit_count += 1;
                 }
                 FileWork::Miss { path, source, key } => {
+                    bytes_read += source.len() as u64;
+                    self.cached_files.remove(&path);
                     self.upsert_source(&path, source);
                     self.cache_keys_hit.insert(path.clone(), key);
                     misses.push(path);
                 }
                 FileWork::StatError { path } => {
                     warn!("rediscover: stat failed for {}, skipping", path.display());
+                    self.cached_files.remove(&path);
                 }
             }
         }
@@ -193,8 +341,17 @@ impl Discoverer {
             .iter()
             .filter_map(|p| self.inputs.get(p).map(|f| (p.clone(), *f)))
             .collect();
+        let parse_start = Instant::now();
         let miss_results: Vec<(PathBuf, DiscoveredFile)> = self.parse_in_parallel(&miss_snapshots);
+        self.stats = DiscoveryStats {
+            files_walked: paths.len(),
+            files_parsed: miss_results.len(),
+            bytes_read,
+            parse_time: parse_start.elapsed(),
+        };
+        self.changed_assertions.clear();
         for (path, data) in &miss_results {
+            self.record_assertion_changes(path, data);
             self.results.insert(path.clone(), data.clone());
             if let Some(&key) = self.cache_keys_hit.get(path) {
                 self.cache.insert(path.clone(), key, data.clone());
@@ -221,13 +378,19 @@ impl Discoverer {
             .collect();
         let mut tests: Vec<TestItem> = Vec::new();
         for (path, imports, dynamic, file_tests) in resolved {
+            if self.filter.is_test_file(&path) {
+                tests.extend(
+                    file_tests
+                        .into_iter()
+                        .filter(|test| self.filter.collects(test)),
+                );
+            }
             self.import_graph.update(path.clone(), imports);
             if dynamic {
                 self.import_graph.mark_always_dirty(path);
             } else {
                 self.import_graph.clear_always_dirty(&path);
             }
-            tests.extend(file_tests);
         }
         self.project_files = path_set;
 
@@ -259,7 +422,9 @@ impl Discoverer {
         // `always_dirty` lookups for files outside `walk_roots`.
         self.results.clear();
         self.inputs.clear();
+        self.cached_files.clear();
         self.import_graph = ImportGraph::default();
+        self.cache.use_names(&self.expect_names, &self.decorator);
 
         let paths = super::collect_python_files_restricted(&self.root, walk_roots, &self.excludes);
         debug!(
@@ -271,10 +436,12 @@ impl Discoverer {
 
         // Phase 1: parallel stat + cache lookup (same as `rediscover`).
         let cache_ref = &self.cache;
-        let keyed: Vec<FileWork> = paths
-            .par_iter()
-            .map(|path| Self::prepare_work(cache_ref, path))
-            .collect();
+        let keyed: Vec<FileWork> = self.install(|| {
+            paths
+                .par_iter()
+                .map(|path| Self::prepare_work(cache_ref, path))
+                .collect()
+        });
 
         // Phase 2 (`self.cache.retain`) intentionally skipped: we walked
         // a deliberate subset, so we must not evict entries for files
@@ -284,10 +451,12 @@ impl Discoverer {
         // Phase 3: serial salsa ingest.
         let mut misses: Vec<PathBuf> = Vec::new();
         let mut hit_count = 0usize;
+        let mut bytes_read = 0u64;
         for work in keyed {
             match work {
                 FileWork::Hit { path, data, key } => {
                     self.results.insert(path.clone(), data);
+                    self.cached_files.insert(path.clone());
                     self.cache_keys_hit
                         .entry(path)
                         .and_modify(|k| *k = key)
@@ -295,6 +464,8 @@ impl Discoverer {
                     hit_count += 1;
                 }
                 FileWork::Miss { path, source, key } => {
+                    bytes_read += source.len() as u64;
+                    self.cached_files.remove(&path);
                     self.upsert_source(&path, source);
                     self.cache_keys_hit.insert(path.clone(), key);
                     misses.push(path);
@@ -319,7 +490,14 @@ impl Discoverer {
             .iter()
             .filter_map(|p| self.inputs.get(p).map(|f| (p.clone(), *f)))
             .collect();
+        let parse_start = Instant::now();
         let miss_results: Vec<(PathBuf, DiscoveredFile)> = self.parse_in_parallel(&miss_snapshots);
+        self.stats = DiscoveryStats {
+            files_walked: paths.len(),
+            files_parsed: miss_results.len(),
+            bytes_read,
+            parse_time: parse_start.elapsed(),
+        };
         for (path, data) in &miss_results {
             self.results.insert(path.clone(), data.clone());
             if let Some(&key) = self.cache_keys_hit.get(path) {
@@ -354,8 +532,11 @@ impl Discoverer {
         // deterministic across runs.
         let tests: Vec<TestItem> = paths
             .iter()
+            .filter(|p| self.filter.is_test_file(p))
             .filter_map(|p| self.results.get(p))
-            .flat_map(|r| r.parsed.tests.clone())
+            .flat_map(|r| r.parsed.tests.iter())
+            .filter(|test| self.filter.collects(test))
+            .cloned()
             .collect();
         debug!(
             "rediscover_restricted: discovered {} tests total",
@@ -390,7 +571,7 @@ impl Discoverer {
                 key,
             }
         } else {
-            let source = std::fs::read_to_string(path).unwrap_or_default();
+            let source = super::read_source_or_empty(path);
             FileWork::Miss {
                 path: path.to_path_buf(),
                 source,
@@ -408,13 +589,38 @@ impl Discoverer {
         snapshots: &[(PathBuf, SourceFile)],
     ) -> Vec<(PathBuf, DiscoveredFile)> {
         let handle = self.db.storage_handle();
-        snapshots
-            .par_iter()
-            .map_init(
-                || Database::from_handle(handle.clone()),
-                |db, (path, file)| (path.clone(), discover_file(db, *file)),
-            )
-            .collect()
+        self.install(|| {
+            snapshots
+                .par_iter()
+                .map_init(
+                    || Database::from_handle(handle.clone()),
+                    |db, (path, file)| (path.clone(), discover_file(db, *file)),
+                )
+                .collect()
+        })
+    }
+
+    /// Note every test in a freshly parsed `path` whose assertions differ
+    /// from the previous cache entry, before that entry is overwritten.
+    /// Tests the cache has never seen count as changed.
+    fn record_assertion_changes(&mut self, path: &Path, data: &DiscoveredFile) {
+        let previous: HashMap<String, u64> = self
+            .cache
+            .previous(path)
+            .map(|old| {
+                old.parsed
+                    .tests
+                    .iter()
+                    .map(|test| (test.id(), assertion_fingerprint(test)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        for test in &data.parsed.tests {
+            let id = test.id();
+            if previous.get(&id) != Some(&assertion_fingerprint(test)) {
+                self.changed_assertions.insert(id);
+            }
+        }
     }
 
     /// Upsert the salsa input for `path` with the given text: either create
@@ -432,6 +638,8 @@ impl Discoverer {
                 text,
                 self.root.clone(),
                 self.src_roots.clone(),
+                self.expect_names.clone(),
+                self.decorator.clone(),
                 path.to_path_buf(),
             );
             self.inputs.insert(path.to_path_buf(), file);
@@ -440,11 +648,57 @@ impl Discoverer {
 
     pub fn tests(&self) -> Vec<TestItem> {
         self.results
-            .values()
-            .flat_map(|r| r.parsed.tests.clone())
+            .iter()
+            .filter(|(path, _)| self.filter.is_test_file(path))
+            .flat_map(|(_, r)| r.parsed.tests.iter())
+            .filter(|test| self.filter.collects(test))
+            .cloned()
             .collect()
     }
 
+    /// Per-file view of [`Discoverer::tests`], sorted by path, noting
+    /// whether each file's result was served from the discovery cache.
+    pub fn file_discoveries(&self) -> Vec<FileDiscovery> {
+        let mut files: Vec<FileDiscovery> = self
+            .results
+            .iter()
+            .filter(|(path, _)| self.filter.is_test_file(path))
+            .map(|(path, r)| FileDiscovery {
+                file_path: path.clone(),
+                tests: r
+                    .parsed
+                    .tests
+                    .iter()
+                    .filter(|test| self.filter.collects(test))
+                    .cloned()
+                    .collect(),
+                from_cache: self.cached_files.contains(path),
+                imports: r.parsed.imports.clone(),
+            })
+            .collect();
+        files.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+        files
+    }
+
+    /// Test files that failed to parse, sorted by path. Their tests are
+    /// missing from [`Discoverer::tests`] rather than absent.
+    pub fn syntax_errors(&self) -> Vec<DiscoveryError> {
+        let mut errors: Vec<DiscoveryError> = self
+            .results
+            .iter()
+            .filter(|(path, _)| self.filter.is_test_file(path))
+            .filter_map(|(_, r)| r.parsed.syntax_error.clone())
+            .collect();
+        errors.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+        errors
+    }
+
+    /// What the most recent discovery pass walked, read, and parsed.
+    #[must_use]
+    pub fn stats(&self) -> DiscoveryStats {
+        self.stats
+    }
+
     /// Returns all hooks discovered across all known files.
     pub fn hooks(&self) -> Vec<HookItem> {
         self.results
@@ -512,6 +766,7 @@ impl Discoverer {
     }
 
     pub fn rediscover_changed(&mut self, changed: &[PathBuf]) -> Vec<TestItem> {
+        self.cache.use_names(&self.expect_names, &self.decorator);
         let changed = Self::canonicalize_paths(changed);
         debug!(
             "rediscover_changed: processing {} changed paths",
@@ -521,8 +776,9 @@ impl Discoverer {
         for path in &changed {
             if path.extension().is_some_and(|ext| ext == "py") {
                 if path.exists() {
-                    let text = std::fs::read_to_string(path).unwrap_or_default();
+                    let text = super::read_source_or_empty(path);
                     self.upsert_source(path, text);
+                    self.cached_files.remove(path);
                     self.project_files.insert(path.clone());
                     touched.push(path.clone());
                 } else {
@@ -533,6 +789,7 @@ impl Discoverer {
                     self.import_graph.remove(path);
                     self.inputs.remove(path);
                     self.project_files.remove(path);
+                    self.cached_files.remove(path);
                     self.results.remove(path);
                     self.cache.remove(path);
                 }
@@ -562,11 +819,7 @@ impl Discoverer {
         if let Err(err) = self.cache.save() {
             warn!("rediscover_changed: failed to save discovery cache: {err}");
         }
-        let tests: Vec<TestItem> = self
-            .results
-            .values()
-            .flat_map(|r| r.parsed.tests.clone())
-            .collect();
+        let tests = self.tests();
         debug!("rediscover_changed: {} tests after update", tests.len());
         tests
     }
@@ -638,6 +891,20 @@ impl Discoverer {
         tests
     }
 
+    /// Returns only tests whose assertions changed since the discovery
+    /// cache last saw their file, per the most recent `rediscover`. Edits
+    /// elsewhere in a test body, or that only move an assertion to
+    /// another line, don't select it.
+    pub fn tests_with_changed_assertions(&self) -> Vec<TestItem> {
+        let tests: Vec<TestItem> = self
+            .tests()
+            .into_iter()
+            .filter(|test| self.changed_assertions.contains(&test.id()))
+            .collect();
+        debug!("tests_with_changed_assertions: {} tests", tests.len());
+        tests
+    }
+
     /// Returns all files that contain dynamic imports (`importlib.import_module()` or
     /// `__import__()`). These files are marked always-dirty: they are included in every
     /// `--changed` run and may produce stale module state in watch/server mode.
@@ -690,6 +957,22 @@ impl Discoverer {
     }
 }
 
+/// Hash of the source of `test`'s assertions, ignoring where they sit in
+/// the file.
+fn assertion_fingerprint(test: &TestItem) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for assertion in &test.expected_assertions {
+        assertion.subject.hash(&mut hasher);
+        assertion.matcher.hash(&mut hasher);
+        assertion.negated.hash(&mut hasher);
+        assertion.args.hash(&mut hasher);
+        assertion.label.hash(&mut hasher);
+        assertion.expression.hash(&mut hasher);
+        assertion.pending.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -717,6 +1000,213 @@ mod tests {
         Discoverer::new(config.root(), config.src_roots(), excludes, cache_dir)
     }
 
+    #[test]
+    fn low_job_count_discovers_large_tree() {
+        let files: Vec<(String, String)> = (0..2_000)
+            .map(|i| {
+                (
+                    format!("pkg{}/test_{i}.py", i % 40),
+                    format!("@test\ndef test_{i}():\n    pass\n"),
+                )
+            })
+            .collect();
+        let refs: Vec<(&str, &str)> = files
+            .iter()
+            .map(|(path, source)| (path.as_str(), source.as_str()))
+            .collect();
+        let dir = make_project(&refs);
+        let mut discoverer = make_discoverer(dir.path(), &[], None).with_jobs(2);
+        assert_eq!(discoverer.rediscover().len(), 2_000);
+    }
+
+    #[test]
+    fn python_files_restricts_test_collection() {
+        let source = "@test\ndef test_hello():\n    pass\n";
+        let dir = make_project(&[
+            ("pkg/foo_test.py", source),
+            ("pkg/helpers.py", source),
+            ("test_top.py", source),
+        ]);
+        let patterns: Vec<String> = tryke_config::DEFAULT_PYTHON_FILES
+            .map(String::from)
+            .to_vec();
+        let mut discoverer =
+            make_discoverer(dir.path(), &[], None).with_python_files(Some(&patterns));
+        let mut files: Vec<_> = discoverer
+            .rediscover()
+            .into_iter()
+            .filter_map(|t| t.file_path)
+            .collect();
+        files.sort();
+        assert_eq!(
+            files,
+            vec![
+                PathBuf::from("pkg/foo_test.py"),
+                PathBuf::from("test_top.py")
+            ]
+        );
+        assert_eq!(discoverer.tests().len(), 2);
+    }
+
+    #[test]
+    fn python_files_can_include_or_exclude_conftest() {
+        let source = "@test\ndef test_hello():\n    pass\n";
+        let dir = make_project(&[("tests/conftest.py", source), ("tests/test_api.py", source)]);
+        let collected = |patterns: Option<&[&str]>| {
+            let patterns: Option<Vec<String>> =
+                patterns.map(|patterns| patterns.iter().map(|p| (*p).to_owned()).collect());
+            let mut files: Vec<_> = make_discoverer(dir.path(), &[], None)
+                .with_python_files(patterns.as_deref())
+                .rediscover()
+                .into_iter()
+                .filter_map(|t| t.file_path)
+                .collect();
+            files.sort();
+            files
+        };
+        let conftest = PathBuf::from("tests/conftest.py");
+        let test_api = PathBuf::from("tests/test_api.py");
+
+        assert_eq!(collected(None), vec![conftest.clone(), test_api.clone()]);
+        assert_eq!(
+            collected(Some(&["test_*.py", "*_test.py"])),
+            vec![test_api.clone()]
+        );
+        assert_eq!(
+            collected(Some(&["test_*.py", "conftest.py"])),
+            vec![conftest, test_api]
+        );
+    }
+
+    #[test]
+    fn tool_tryke_testpaths_and_python_files_narrow_collection() {
+        let source = "@test\ndef test_hello():\n    pass\n";
+        let dir = make_project(&[
+            ("tests/test_api.py", source),
+            ("tests/helpers.py", source),
+            ("scripts/test_release.py", source),
+            ("test_top.py", source),
+        ]);
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\ntestpaths = [\"tests\"]\npython_files = [\"test_*.py\"]\n",
+        )
+        .expect("write pyproject.toml");
+        let config = tryke_config::TrykeConfig::discover(dir.path());
+        let mut discoverer = make_discoverer(dir.path(), &[], None)
+            .with_python_files(config.discovery.python_files.as_deref())
+            .with_testpaths(&config.discovery.testpaths);
+        let files: Vec<_> = discoverer
+            .rediscover()
+            .into_iter()
+            .filter_map(|t| t.file_path)
+            .collect();
+        assert_eq!(files, vec![PathBuf::from("tests/test_api.py")]);
+    }
+
+    #[test]
+    fn exclude_disabled_drops_disabled_tests() {
+        let dir = make_project(&[(
+            "test_toggle.py",
+            "@test(enabled=False)\ndef test_off(): pass\n\n@test\ndef test_on(): pass\n",
+        )]);
+        let mut discoverer = make_discoverer(dir.path(), &[], None);
+        assert_eq!(discoverer.rediscover().len(), 2);
+
+        let mut discoverer = make_discoverer(dir.path(), &[], None).with_exclude_disabled(true);
+        let names: Vec<String> = discoverer
+            .rediscover()
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(names, vec!["test_on"]);
+        assert_eq!(discoverer.tests().len(), 1);
+    }
+
+    #[test]
+    fn from_config_applies_tool_tryke_settings() {
+        let dir = make_project(&[
+            (
+                "tests/test_api.py",
+                "@test(enabled=False)\ndef test_off(): pass\n\n@test\ndef test_on(): pass\n",
+            ),
+            (
+                "scripts/test_release.py",
+                "@test\ndef test_release(): pass\n",
+            ),
+        ]);
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\ntestpaths = [\"tests\"]\nexclude_disabled = true\n",
+        )
+        .expect("write pyproject.toml");
+        let config = tryke_config::TrykeConfig::discover(dir.path());
+        let mut discoverer = Discoverer::from_config(&config);
+        let names: Vec<String> = discoverer
+            .rediscover()
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(names, vec!["test_on"]);
+    }
+
+    #[test]
+    fn configured_decorator_replaces_test() {
+        let dir = make_project(&[(
+            "test_cases.py",
+            "import tryke\nfrom tryke import test\n\n@case\ndef test_bare(): pass\n\n@tryke.case(\"named\")\ndef test_qualified(): pass\n\n@test\ndef test_default(): pass\n",
+        )]);
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\ndecorator = \"case\"\n",
+        )
+        .expect("write pyproject.toml");
+        let config = tryke_config::TrykeConfig::discover(dir.path());
+        let mut discoverer =
+            make_discoverer(dir.path(), &[], None).with_decorator(&config.discovery.decorator);
+        let mut names: Vec<String> = discoverer
+            .rediscover()
+            .into_iter()
+            .map(|test| test.name)
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["test_bare", "test_qualified"]);
+
+        let mut default = make_discoverer(dir.path(), &[], None);
+        let names: Vec<String> = default
+            .rediscover()
+            .into_iter()
+            .map(|test| test.name)
+            .collect();
+        assert_eq!(names, vec!["test_default"]);
+    }
+
+    #[test]
+    fn expect_names_recognizes_custom_assertion_entrypoints() {
+        let dir = make_project(&[(
+            "test_check.py",
+            "@test\ndef test_custom():\n    x = 1\n    check(x).to_equal(1)\n",
+        )]);
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\nexpect_names = [\"expect\", \"check\"]\n",
+        )
+        .expect("write pyproject.toml");
+        let config = tryke_config::TrykeConfig::discover(dir.path());
+        let mut discoverer = make_discoverer(dir.path(), &[], None)
+            .with_expect_names(&config.discovery.expect_names);
+        let tests = discoverer.rediscover();
+        assert_eq!(tests.len(), 1);
+        let assertions = &tests[0].expected_assertions;
+        assert_eq!(assertions.len(), 1);
+        assert_eq!(assertions[0].subject, "x");
+        assert_eq!(assertions[0].matcher, "to_equal");
+        assert_eq!(assertions[0].args, vec!["1"]);
+
+        let mut default = make_discoverer(dir.path(), &[], None);
+        assert!(default.rediscover()[0].expected_assertions.is_empty());
+    }
+
     #[test]
     fn tests_returns_same_results_as_prior_rediscover() {
         let source = "@test\ndef test_hello():\n    pass\n";
@@ -857,6 +1347,152 @@ mod tests {
         );
     }
 
+    #[test]
+    fn file_discoveries_report_cache_hits() {
+        let dir = make_project(&[("test_a.py", "@test\ndef test_one():\n    pass\n")]);
+        let cache_dir = dir.path().join("cache");
+
+        let mut first = make_discoverer(dir.path(), &[], Some(&cache_dir));
+        first.rediscover();
+        let files = first.file_discoveries();
+        assert_eq!(files.len(), 1);
+        assert!(!files[0].from_cache, "first run parses the file");
+
+        let mut second = make_discoverer(dir.path(), &[], Some(&cache_dir));
+        second.rediscover();
+        let files = second.file_discoveries();
+        assert!(files[0].from_cache, "second run reads the cached result");
+        assert_eq!(files[0].tests.len(), 1);
+
+        fs::write(
+            dir.path().join("test_a.py"),
+            "@test\ndef test_one():\n    pass\n\n@test\ndef test_two():\n    pass\n",
+        )
+        .expect("rewrite test file");
+        second.rediscover();
+        let files = second.file_discoveries();
+        assert!(!files[0].from_cache, "a changed file is re-parsed");
+        assert_eq!(files[0].tests.len(), 2);
+    }
+
+    #[test]
+    fn syntax_errors_name_the_line_and_follow_edits() {
+        let dir = make_project(&[
+            ("test_ok.py", "@test\ndef test_one():\n    pass\n"),
+            ("test_broken.py", "@test\ndef test_two():\n    x = )\n"),
+        ]);
+        let mut discoverer = make_discoverer(dir.path(), &[], None);
+        discoverer.rediscover();
+        assert_eq!(discoverer.tests().len(), 1);
+        let errors = discoverer.syntax_errors();
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert!(errors[0].file_path.ends_with("test_broken.py"));
+        assert_eq!(errors[0].line_number, Some(3));
+
+        fs::write(
+            dir.path().join("test_broken.py"),
+            "@test\ndef test_two():\n    pass\n\ndef helper(:\n    pass\n",
+        )
+        .expect("rewrite test file");
+        discoverer.rediscover();
+        let errors = discoverer.syntax_errors();
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert_eq!(errors[0].line_number, Some(5));
+    }
+
+    #[test]
+    fn stats_count_walked_and_parsed_files() {
+        let source = "@test\ndef test_one():\n    pass\n";
+        let dir = make_project(&[
+            ("test_a.py", source),
+            ("test_b.py", source),
+            ("pkg/helpers.py", "x = 1\n"),
+        ]);
+        let cache_dir = dir.path().join("cache");
+
+        let mut first = make_discoverer(dir.path(), &[], Some(&cache_dir));
+        first.rediscover();
+        let stats = first.stats();
+        assert_eq!(stats.files_walked, 3);
+        assert_eq!(stats.files_parsed, 3);
+        assert_eq!(
+            stats.bytes_read,
+            (2 * source.len() + "x = 1\n".len()) as u64
+        );
+
+        fs::write(dir.path().join("test_b.py"), format!("{source}\n")).expect("rewrite test_b");
+        let mut second = make_discoverer(dir.path(), &[], Some(&cache_dir));
+        second.rediscover();
+        let stats = second.stats();
+        assert_eq!(stats.files_walked, 3);
+        assert_eq!(
+            stats.files_parsed, 1,
+            "only the edited file misses the cache"
+        );
+        assert!(stats.files_parsed <= stats.files_walked);
+        assert_eq!(stats.bytes_read, (source.len() + 1) as u64);
+    }
+
+    #[test]
+    fn negating_an_assertion_changes_its_fingerprint() {
+        let dir = make_project(&[(
+            "test_a.py",
+            "@test\ndef test_one():\n    expect(x).to_equal(1)\n    expect(x).not_.to_equal(2)\n",
+        )]);
+        let tests = make_discoverer(dir.path(), &[], None).rediscover();
+        let mut flipped = tests[0].clone();
+        assert_eq!(
+            flipped
+                .expected_assertions
+                .iter()
+                .map(|a| a.negated)
+                .collect::<Vec<_>>(),
+            vec![false, true]
+        );
+        flipped.expected_assertions[1].negated = false;
+        assert_ne!(
+            assertion_fingerprint(&tests[0]),
+            assertion_fingerprint(&flipped)
+        );
+    }
+
+    #[test]
+    fn tests_with_changed_assertions_follow_the_discovery_cache() {
+        let source = "@test\ndef test_one():\n    expect(1).to_equal(1)\n\n@test\ndef test_two():\n    expect(2).to_equal(2)\n";
+        let dir = make_project(&[("test_a.py", source)]);
+        let cache_dir = dir.path().join("cache");
+
+        let mut first = make_discoverer(dir.path(), &[], Some(&cache_dir));
+        first.rediscover();
+        assert_eq!(
+            first.tests_with_changed_assertions().len(),
+            2,
+            "an empty cache selects every test"
+        );
+
+        let mut unchanged = make_discoverer(dir.path(), &[], Some(&cache_dir));
+        unchanged.rediscover();
+        assert!(unchanged.tests_with_changed_assertions().is_empty());
+
+        fs::write(
+            dir.path().join("test_a.py"),
+            source.replace("expect(2).to_equal(2)", "expect(2).to_equal(20)"),
+        )
+        .expect("rewrite test file");
+        let mut edited = make_discoverer(dir.path(), &[], Some(&cache_dir));
+        edited.rediscover();
+        let names: Vec<String> = edited
+            .tests_with_changed_assertions()
+            .into_iter()
+            .map(|test| test.name)
+            .collect();
+        assert_eq!(names, ["test_two"]);
+
+        let mut rerun = make_discoverer(dir.path(), &[], Some(&cache_dir));
+        rerun.rediscover();
+        assert!(rerun.tests_with_changed_assertions().is_empty());
+    }
+
     #[test]
     fn discoverer_saves_cache_under_custom_cache_dir() {
         let source = "@test\ndef test_hello():\n    pass\n";
@@ -1,19 +1,22 @@
 use std::path::{Path, PathBuf};
-use std::{env, fs};
+use std::time::Instant;
+use std::{env, fs, io};
 
 use ignore::WalkBuilder;
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use log::trace;
 use rayon::prelude::*;
-use tryke_types::{ParsedFile, TestItem};
+use tryke_types::{DiscoveryError, DiscoveryResult, FileDiscovery, ParsedFile, TestItem};
 
+mod archive;
 pub(crate) mod cache;
 pub(crate) mod db;
 mod discoverer;
 pub(crate) mod import_graph;
 
+pub use archive::discover_archive;
 pub use cache::{CleanCacheReport, clean_project_cache};
-pub use discoverer::{ChangeImpact, Discoverer};
+pub use discoverer::{ChangeImpact, Discoverer, DiscoveryStats};
 
 fn build_excludes(root: &Path, excludes: &[String]) -> Gitignore {
     let mut builder = GitignoreBuilder::new(root);
@@ -45,6 +48,66 @@ pub fn build_change_set_ignore(root: &Path, excludes: &[String]) -> Gitignore {
     builder.build().unwrap_or_else(|_| Gitignore::empty())
 }
 
+/// Build the `[tool.tryke] python_files` matcher. Patterns use gitignore
+/// glob syntax, so a slash-free pattern like `test_*.py` matches the file
+/// name at any depth. A "match" here means the file is a test file.
+pub(crate) fn build_python_files_matcher(root: &Path, patterns: &[String]) -> Gitignore {
+    build_excludes(root, patterns)
+}
+
+/// The `[tool.tryke]` settings that pick which parsed tests a walk
+/// collects: `testpaths`, `python_files`, and `exclude_disabled`.
+#[derive(Default)]
+pub(crate) struct CollectFilter {
+    /// `python_files` matcher. `None` keeps every `.py` file.
+    pub(crate) python_files: Option<Gitignore>,
+    /// `testpaths` joined onto the project root. Empty keeps the whole
+    /// project.
+    pub(crate) testpaths: Vec<PathBuf>,
+    /// Drop `@test(enabled=False)` tests rather than collecting them as
+    /// skipped.
+    pub(crate) exclude_disabled: bool,
+}
+
+impl CollectFilter {
+    /// The filter `config`'s `[tool.tryke]` table describes.
+    pub(crate) fn from_config(config: &tryke_config::TrykeConfig) -> Self {
+        let root = config.root();
+        let discovery = &config.discovery;
+        Self {
+            python_files: discovery
+                .python_files
+                .as_deref()
+                .map(|patterns| build_python_files_matcher(root, patterns)),
+            testpaths: discovery
+                .testpaths
+                .iter()
+                .map(|path| root.join(path))
+                .collect(),
+            exclude_disabled: discovery.exclude_disabled,
+        }
+    }
+
+    /// `true` if tests defined in `path` should be collected.
+    pub(crate) fn is_test_file(&self, path: &Path) -> bool {
+        let in_testpaths = self.testpaths.is_empty()
+            || self
+                .testpaths
+                .iter()
+                .any(|testpath| path.starts_with(testpath));
+        in_testpaths
+            && self
+                .python_files
+                .as_ref()
+                .is_none_or(|matcher| matcher.matched(path, false).is_ignore())
+    }
+
+    /// `true` unless `test` is disabled and disabled tests are excluded.
+    pub(crate) fn collects(&self, test: &TestItem) -> bool {
+        !(self.exclude_disabled && test.disabled)
+    }
+}
+
 pub(crate) fn collect_python_files(root: &Path, excludes: &[String]) -> Vec<PathBuf> {
     let exclude_matcher = build_excludes(root, excludes);
     WalkBuilder::new(root)
@@ -96,16 +159,24 @@ pub(crate) fn collect_python_files_restricted(
 pub(crate) fn discover_file_from_ast(
     root: &Path,
     src_roots: &[PathBuf],
+    expect_names: &[String],
+    decorator: &str,
     file: &Path,
     parsed: &db::ParsedAst,
 ) -> tryke_types::DiscoveredFile {
     let Some(module) = parsed.syntax() else {
         trace!("parse error in {}", file.display());
-        return tryke_types::DiscoveredFile::default();
+        return parsed
+            .error()
+            .map_or_else(tryke_types::DiscoveredFile::default, |err| {
+                crate::source::unparsable_file(file, parsed.source(), err)
+            });
     };
     let result = crate::source::discover_file_from_body(
         root,
         src_roots,
+        expect_names,
+        decorator,
         file,
         &module.body,
         parsed.source(),
@@ -116,11 +187,82 @@ pub(crate) fn discover_file_from_ast(
     result
 }
 
-fn parse_tests_from_file(root: &Path, src_roots: &[PathBuf], file: &Path) -> ParsedFile {
-    let Ok(source) = fs::read_to_string(file) else {
-        return ParsedFile::default();
+/// Read a Python source file for discovery, dropping a leading UTF-8
+/// byte order mark. Files that aren't valid UTF-8 fail with
+/// [`io::ErrorKind::InvalidData`] and a message naming the file.
+pub(crate) fn read_source(file: &Path) -> io::Result<String> {
+    let mut bytes = fs::read(file)?;
+    if bytes.starts_with(b"\xEF\xBB\xBF") {
+        bytes.drain(..3);
+    }
+    String::from_utf8(bytes).map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "{}: source is not valid UTF-8 ({})",
+                file.display(),
+                err.utf8_error()
+            ),
+        )
+    })
+}
+
+/// [`read_source`] for the discovery walk: an undecodable file is logged
+/// as a discovery error and read as empty, so the rest of the walk
+/// carries on. Other read failures stay silent, as for a file deleted
+/// mid-walk.
+pub(crate) fn read_source_or_empty(file: &Path) -> String {
+    match read_source(file) {
+        Ok(source) => source,
+        Err(err) => {
+            if err.kind() == io::ErrorKind::InvalidData {
+                log::error!("tryke discovery: {err}");
+            }
+            String::new()
+        }
+    }
+}
+
+fn parse_tests_from_file(
+    root: &Path,
+    src_roots: &[PathBuf],
+    expect_names: &[String],
+    decorator: &str,
+    file: &Path,
+) -> ParsedFile {
+    let source = match read_source(file) {
+        Ok(source) => source,
+        Err(err) if err.kind() == io::ErrorKind::InvalidData => {
+            return ParsedFile {
+                errors: vec![err.to_string()],
+                ..ParsedFile::default()
+            };
+        }
+        Err(_) => return ParsedFile::default(),
     };
-    crate::source::parse_tests_from_source(root, src_roots, file, &source)
+    parse_tests_from_named_source(root, src_roots, expect_names, decorator, file, &source)
+}
+
+/// [`parse_tests_from_file`] for source already in memory. `name` only
+/// has to sit under `root` for module paths to resolve; it need not
+/// exist on disk, which is how archive members are parsed.
+pub(crate) fn parse_tests_from_named_source(
+    root: &Path,
+    src_roots: &[PathBuf],
+    expect_names: &[String],
+    decorator: &str,
+    name: &Path,
+    source: &str,
+) -> ParsedFile {
+    crate::source::discover_file_from_source_with_names(
+        root,
+        src_roots,
+        expect_names,
+        decorator,
+        name,
+        source,
+    )
+    .parsed
 }
 
 #[must_use]
@@ -128,7 +270,74 @@ pub fn discover_from(start: &Path) -> Vec<TestItem> {
     let config = tryke_config::TrykeConfig::discover(start);
     let root = config.root();
     let src_roots = config.src_roots();
-    discover_from_with_options(root, &config.discovery.exclude, &src_roots)
+    collect_tests(
+        root,
+        &config.discovery.exclude,
+        &src_roots,
+        &config.discovery.expect_names,
+        &config.discovery.decorator,
+        &CollectFilter::from_config(&config),
+    )
+}
+
+/// Discover tests in a single file without walking the project. Meant
+/// for editor integrations that re-parse the open buffer's file on each
+/// change. `root` is resolved to the project root the same way
+/// [`discover_from`] resolves it, so module paths and source roots match
+/// a full walk.
+#[must_use]
+pub fn discover_file(root: &Path, file: &Path) -> ParsedFile {
+    let config = tryke_config::TrykeConfig::discover(root);
+    let file = file.canonicalize().unwrap_or_else(|_| file.to_path_buf());
+    parse_tests_from_file(
+        config.root(),
+        &config.src_roots(),
+        &config.discovery.expect_names,
+        &config.discovery.decorator,
+        &file,
+    )
+}
+
+/// Discover tests across several project roots, each resolved and
+/// configured independently. Tests are namespaced with
+/// [`TestItem::qualify_root`] using the root as passed in, so ids never
+/// collide between roots.
+#[must_use]
+pub fn discover_roots(roots: &[PathBuf]) -> Vec<TestItem> {
+    roots
+        .iter()
+        .flat_map(|label| {
+            let root = tryke_config::resolve_project_root(label);
+            let mut tests = discover_from(&root);
+            for test in &mut tests {
+                test.qualify_root(label, &root);
+            }
+            tests
+        })
+        .collect()
+}
+
+/// The project roots of a workspace: every directory below `start` with
+/// its own `pyproject.toml`, sorted. A package nested inside another is
+/// left to the outer one, whose discovery already walks it.
+#[must_use]
+pub fn find_workspace_roots(start: &Path) -> Vec<PathBuf> {
+    let mut roots: Vec<PathBuf> = WalkBuilder::new(start)
+        .build()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_some_and(|t| t.is_file()))
+        .filter(|e| e.file_name() == "pyproject.toml")
+        .filter_map(|e| e.path().parent().map(Path::to_path_buf))
+        .filter(|dir| dir != start)
+        .collect();
+    roots.sort();
+    let mut outermost: Vec<PathBuf> = Vec::new();
+    for root in roots {
+        if !outermost.iter().any(|outer| root.starts_with(outer)) {
+            outermost.push(root);
+        }
+    }
+    outermost
 }
 
 #[must_use]
@@ -136,7 +345,14 @@ pub fn discover_from_with_excludes(start: &Path, excludes: &[String]) -> Vec<Tes
     let config = tryke_config::TrykeConfig::discover(start);
     let root = config.root();
     let src_roots = config.src_roots();
-    discover_from_with_options(root, excludes, &src_roots)
+    collect_tests(
+        root,
+        excludes,
+        &src_roots,
+        &config.discovery.expect_names,
+        &config.discovery.decorator,
+        &CollectFilter::from_config(&config),
+    )
 }
 
 #[must_use]
@@ -144,20 +360,110 @@ pub fn discover_from_with_options(
     root: &Path,
     excludes: &[String],
     src_roots: &[PathBuf],
+) -> Vec<TestItem> {
+    let defaults = tryke_config::DiscoveryConfig::default();
+    collect_tests(
+        root,
+        excludes,
+        src_roots,
+        &defaults.expect_names,
+        &defaults.decorator,
+        &CollectFilter::default(),
+    )
+}
+
+/// [`discover_from`] restricted to `paths`: directories are walked for
+/// `.py` files and files are parsed on their own. Relative paths resolve
+/// against `start`. With no paths this is exactly [`discover_from`].
+///
+/// # Errors
+/// Returns a [`DiscoveryError`] for the first path that doesn't exist,
+/// rather than quietly collecting nothing from it.
+pub fn discover_paths_from(
+    start: &Path,
+    paths: &[PathBuf],
+) -> Result<Vec<TestItem>, DiscoveryError> {
+    if paths.is_empty() {
+        return Ok(discover_from(start));
+    }
+    let config = tryke_config::TrykeConfig::discover(start);
+    let mut walk_roots = Vec::with_capacity(paths.len());
+    for path in paths {
+        let Ok(resolved) = start.join(path).canonicalize() else {
+            return Err(DiscoveryError {
+                file_path: path.clone(),
+                message: "no such file or directory".to_owned(),
+                line_number: None,
+            });
+        };
+        walk_roots.push(resolved);
+    }
+    let files =
+        collect_python_files_restricted(config.root(), &walk_roots, &config.discovery.exclude);
+    let parsed = parse_files(
+        config.root(),
+        files,
+        &config.src_roots(),
+        &config.discovery.expect_names,
+        &config.discovery.decorator,
+        &CollectFilter::from_config(&config),
+    );
+    Ok(parsed
+        .into_iter()
+        .flat_map(|(_, parsed)| parsed.tests)
+        .collect())
+}
+
+/// [`discover_paths_from`] relative to the current directory.
+///
+/// # Errors
+/// Returns a [`DiscoveryError`] if the current directory can't be
+/// determined or a path doesn't exist.
+pub fn discover_paths(paths: &[PathBuf]) -> Result<Vec<TestItem>, DiscoveryError> {
+    let cwd = env::current_dir().map_err(|err| DiscoveryError {
+        file_path: PathBuf::from("."),
+        message: err.to_string(),
+        line_number: None,
+    })?;
+    discover_paths_from(&cwd, paths)
+}
+
+fn collect_tests(
+    root: &Path,
+    excludes: &[String],
+    src_roots: &[PathBuf],
+    expect_names: &[String],
+    decorator: &str,
+    filter: &CollectFilter,
 ) -> Vec<TestItem> {
     let mut files = collect_python_files(root, excludes);
     files.sort();
-    let parsed: Vec<ParsedFile> = files
-        .par_iter()
-        .map(|f| parse_tests_from_file(root, src_roots, f))
-        .collect();
-    let mut tests: Vec<TestItem> = parsed.into_iter().flat_map(|p| p.tests).collect();
-    tests.sort_by(|a, b| {
-        a.file_path
-            .cmp(&b.file_path)
-            .then(a.line_number.cmp(&b.line_number))
-    });
-    tests
+    parse_files(root, files, src_roots, expect_names, decorator, filter)
+        .into_iter()
+        .flat_map(|(_, parsed)| parsed.tests)
+        .collect()
+}
+
+/// Parse the test files among `files` in parallel. They come back in the
+/// order given, each with the tests `filter` collects in line order.
+fn parse_files(
+    root: &Path,
+    files: Vec<PathBuf>,
+    src_roots: &[PathBuf],
+    expect_names: &[String],
+    decorator: &str,
+    filter: &CollectFilter,
+) -> Vec<(PathBuf, ParsedFile)> {
+    files
+        .into_par_iter()
+        .filter(|file| filter.is_test_file(file))
+        .map(|file| {
+            let mut parsed = parse_tests_from_file(root, src_roots, expect_names, decorator, &file);
+            parsed.tests.retain(|test| filter.collects(test));
+            parsed.tests.sort_by_key(|test| test.line_number);
+            (file, parsed)
+        })
+        .collect()
 }
 
 /// # Errors
@@ -167,6 +473,52 @@ pub fn discover() -> std::io::Result<Vec<TestItem>> {
     Ok(discover_from(&cwd))
 }
 
+/// [`discover_from`] file by file: each file's tests, the files that
+/// failed to parse, and how long the whole walk took.
+#[must_use]
+pub fn discover_detailed_from(start: &Path) -> DiscoveryResult {
+    let started = Instant::now();
+    let config = tryke_config::TrykeConfig::discover(start);
+    let root = config.root();
+    let mut paths = collect_python_files(root, &config.discovery.exclude);
+    paths.sort();
+    let parsed = parse_files(
+        root,
+        paths,
+        &config.src_roots(),
+        &config.discovery.expect_names,
+        &config.discovery.decorator,
+        &CollectFilter::from_config(&config),
+    );
+    let mut errors = Vec::new();
+    let files = parsed
+        .into_iter()
+        .map(|(file_path, mut parsed)| {
+            errors.extend(parsed.syntax_error.take());
+            FileDiscovery {
+                file_path,
+                tests: parsed.tests,
+                from_cache: false,
+                imports: parsed.imports,
+            }
+        })
+        .collect();
+    DiscoveryResult {
+        files,
+        errors,
+        duration: started.elapsed(),
+    }
+}
+
+/// [`discover_detailed_from`] the current directory.
+///
+/// # Errors
+/// Returns an error if the current directory cannot be determined.
+pub fn discover_detailed() -> std::io::Result<DiscoveryResult> {
+    let cwd = env::current_dir()?;
+    Ok(discover_detailed_from(&cwd))
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -234,6 +586,250 @@ mod tests {
         assert_eq!(items[0].name, "test_hello");
     }
 
+    #[test]
+    fn discover_detailed_from_reports_files_and_parse_errors() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        fs::write(dir.path().join("pyproject.toml"), "").expect("write pyproject.toml");
+        fs::write(
+            dir.path().join("test_ok.py"),
+            "@test\ndef test_one():\n    pass\n\n@test\ndef test_two():\n    pass\n",
+        )
+        .expect("write test file");
+        fs::write(
+            dir.path().join("test_broken.py"),
+            "@test\ndef test_three():\n    x = )\n",
+        )
+        .expect("write test file");
+
+        let result = discover_detailed_from(dir.path());
+        let files: Vec<_> = result
+            .files
+            .iter()
+            .map(|f| {
+                let name = f.file_path.file_name().and_then(|n| n.to_str());
+                (name, f.tests.len())
+            })
+            .collect();
+        assert_eq!(
+            files,
+            vec![(Some("test_broken.py"), 0), (Some("test_ok.py"), 2)]
+        );
+        assert_eq!(result.errors.len(), 1, "{:?}", result.errors);
+        assert!(result.errors[0].file_path.ends_with("test_broken.py"));
+        assert_eq!(result.errors[0].line_number, Some(3));
+        assert!(!result.errors[0].message.is_empty());
+    }
+
+    #[test]
+    fn discover_detailed_from_applies_tool_tryke_collection_settings() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        fs::write(
+            dir.path().join("pyproject.toml"),
+            "[tool.tryke]\ntestpaths = [\"tests\"]\npython_files = [\"test_*.py\"]\nexclude_disabled = true\n",
+        )
+        .expect("write pyproject.toml");
+        fs::create_dir_all(dir.path().join("tests")).expect("create tests dir");
+        fs::create_dir_all(dir.path().join("scripts")).expect("create scripts dir");
+        fs::write(
+            dir.path().join("tests/test_api.py"),
+            "@test(enabled=False)\ndef test_off(): pass\n\n@test\ndef test_on(): pass\n",
+        )
+        .expect("write test file");
+        fs::write(
+            dir.path().join("tests/helpers.py"),
+            "@test\ndef test_helper(): pass\n",
+        )
+        .expect("write helper file");
+        fs::write(
+            dir.path().join("scripts/test_release.py"),
+            "@test\ndef test_release():\n    x = )\n",
+        )
+        .expect("write script file");
+
+        let result = discover_detailed_from(dir.path());
+        let files: Vec<(Option<&str>, Vec<&str>)> = result
+            .files
+            .iter()
+            .map(|f| {
+                let name = f.file_path.file_name().and_then(|n| n.to_str());
+                (name, f.tests.iter().map(|t| t.name.as_str()).collect())
+            })
+            .collect();
+        assert_eq!(files, vec![(Some("test_api.py"), vec!["test_on"])]);
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        let names: Vec<String> = discover_from(dir.path())
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(names, vec!["test_on"]);
+    }
+
+    #[test]
+    fn discover_file_matches_full_walk_for_that_file() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        fs::write(dir.path().join("pyproject.toml"), "").expect("write pyproject.toml");
+        let source = "@test\ndef test_one():\n    expect(1).to_equal(1)\n\n@test\ndef test_two():\n    pass\n";
+        fs::create_dir(dir.path().join("tests")).expect("mkdir");
+        fs::write(dir.path().join("tests/test_a.py"), source).expect("write test file");
+        fs::write(
+            dir.path().join("tests/test_b.py"),
+            "@test\ndef test_other():\n    pass\n",
+        )
+        .expect("write test file");
+
+        let single = discover_file(dir.path(), &dir.path().join("tests/test_a.py")).tests;
+        let walked: Vec<TestItem> = discover_from(dir.path())
+            .into_iter()
+            .filter(|t| t.file_path.as_deref() == Some(Path::new("tests/test_a.py")))
+            .collect();
+        assert_eq!(single.len(), 2);
+        assert_eq!(single, walked);
+    }
+
+    #[test]
+    fn discover_file_strips_byte_order_mark() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        fs::write(dir.path().join("pyproject.toml"), "").expect("write pyproject.toml");
+        let file = dir.path().join("test_bom.py");
+        fs::write(
+            &file,
+            "\u{feff}@test\ndef test_one():\n    expect(1).to_equal(1)\n",
+        )
+        .expect("write test file");
+
+        let parsed = discover_file(dir.path(), &file);
+        assert!(parsed.errors.is_empty(), "{:?}", parsed.errors);
+        assert_eq!(parsed.tests.len(), 1);
+        assert_eq!(parsed.tests[0].name, "test_one");
+        assert_eq!(parsed.tests[0].line_number, Some(1));
+    }
+
+    #[test]
+    fn discover_file_reports_non_utf8_source() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        fs::write(dir.path().join("pyproject.toml"), "").expect("write pyproject.toml");
+        let file = dir.path().join("test_latin1.py");
+        fs::write(&file, b"# caf\xe9\n@test\ndef test_one():\n    pass\n")
+            .expect("write test file");
+
+        let parsed = discover_file(dir.path(), &file);
+        assert!(parsed.tests.is_empty());
+        assert_eq!(parsed.errors.len(), 1);
+        assert!(
+            parsed.errors[0].contains("not valid UTF-8"),
+            "{:?}",
+            parsed.errors
+        );
+    }
+
+    #[test]
+    fn discover_paths_scopes_to_files_and_directories() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        fs::write(dir.path().join("pyproject.toml"), "").expect("write pyproject.toml");
+        for rel in [
+            "tests/unit/test_a.py",
+            "tests/unit/test_b.py",
+            "tests/test_c.py",
+        ] {
+            let path = dir.path().join(rel);
+            fs::create_dir_all(path.parent().expect("parent")).expect("mkdir");
+            let name = path
+                .file_stem()
+                .expect("stem")
+                .to_string_lossy()
+                .into_owned();
+            fs::write(&path, format!("@test\ndef {name}():\n    pass\n")).expect("write test file");
+        }
+        let names = |tests: Vec<TestItem>| -> Vec<String> {
+            tests.into_iter().map(|test| test.name).collect()
+        };
+
+        let unit = discover_paths_from(dir.path(), &[PathBuf::from("tests/unit")]).expect("dir");
+        assert_eq!(names(unit), ["test_a", "test_b"]);
+
+        let file =
+            discover_paths_from(dir.path(), &[PathBuf::from("tests/test_c.py")]).expect("file");
+        assert_eq!(names(file), ["test_c"]);
+
+        let all = discover_paths_from(dir.path(), &[]).expect("no paths");
+        assert_eq!(all, discover_from(dir.path()));
+    }
+
+    #[test]
+    fn discover_paths_reports_missing_path() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        fs::write(dir.path().join("pyproject.toml"), "").expect("write pyproject.toml");
+        let err = discover_paths_from(dir.path(), &[PathBuf::from("tests/missing.py")])
+            .expect_err("missing path");
+        assert_eq!(err.file_path, PathBuf::from("tests/missing.py"));
+    }
+
+    #[test]
+    fn workspace_roots_are_discovered_per_package() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        fs::write(dir.path().join("pyproject.toml"), "").expect("write pyproject.toml");
+        for name in ["packages/api", "packages/web", "packages/web/vendored"] {
+            let root = dir.path().join(name);
+            fs::create_dir_all(&root).expect("mkdir package");
+            fs::write(root.join("pyproject.toml"), "").expect("write pyproject.toml");
+        }
+        fs::write(
+            dir.path().join("packages/api/test_api.py"),
+            "@test\ndef test_health():\n    pass\n",
+        )
+        .expect("write test file");
+        fs::write(
+            dir.path().join("packages/web/test_web.py"),
+            "@test\ndef test_render():\n    pass\n",
+        )
+        .expect("write test file");
+
+        let roots = find_workspace_roots(dir.path());
+        assert_eq!(
+            roots,
+            vec![
+                dir.path().join("packages/api"),
+                dir.path().join("packages/web"),
+            ]
+        );
+
+        let items = discover_roots(&roots);
+        let names: Vec<&str> = items.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["test_health", "test_render"]);
+        assert!(items[0].id().starts_with(&roots[0].display().to_string()));
+        assert!(items[1].id().starts_with(&roots[1].display().to_string()));
+    }
+
+    #[test]
+    fn discover_roots_namespaces_ids_per_root() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut roots = Vec::new();
+        for name in ["svc_a", "svc_b"] {
+            let root = dir.path().join(name);
+            fs::create_dir(&root).expect("mkdir root");
+            fs::write(root.join("pyproject.toml"), "").expect("write pyproject.toml");
+            fs::write(
+                root.join("test_api.py"),
+                "@test\ndef test_health():\n    pass\n",
+            )
+            .expect("write test file");
+            roots.push(root);
+        }
+
+        let items = discover_roots(&roots);
+        let ids: Vec<String> = items.iter().map(TestItem::id).collect();
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0], ids[1]);
+        for (item, root) in items.iter().zip(&roots) {
+            assert!(item.id().starts_with(&root.display().to_string()));
+            assert_eq!(item.module_path, "test_api");
+            assert_eq!(
+                item.root.as_deref(),
+                Some(root.canonicalize().expect("canonical root").as_path())
+            );
+        }
+    }
+
     #[test]
     fn discover_from_returns_tests_in_line_order() {
         let dir = tempfile::tempdir().expect("tempdir");
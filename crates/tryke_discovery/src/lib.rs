@@ -4,13 +4,16 @@ mod source;
 mod filesystem;
 
 pub use source::{
-    discover_file_from_source, parse_tests_from_source, resolve_import_candidate_groups,
+    discover_file_from_source, extraction_coverage_from_source, parse_tests_from_source,
+    resolve_import_candidate_groups,
 };
 
 #[cfg(feature = "filesystem")]
 pub use filesystem::{
-    ChangeImpact, CleanCacheReport, Discoverer, build_change_set_ignore, clean_project_cache,
-    discover, discover_from, discover_from_with_excludes, discover_from_with_options,
+    ChangeImpact, CleanCacheReport, Discoverer, DiscoveryStats, build_change_set_ignore,
+    clean_project_cache, discover, discover_archive, discover_detailed, discover_detailed_from,
+    discover_file, discover_from, discover_from_with_excludes, discover_from_with_options,
+    discover_paths, discover_paths_from, discover_roots, find_workspace_roots,
 };
 
 #[cfg(feature = "filesystem")]
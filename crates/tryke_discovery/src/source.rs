@@ -1,10 +1,13 @@
 use std::path::{Path, PathBuf};
 
-use ruff_python_ast::{Expr, Stmt};
-use ruff_python_parser::parse_module;
+use ruff_python_ast::{Comprehension, Expr, Number, Stmt, UnaryOp};
+use ruff_python_parser::{ParseError, parse_module};
 use ruff_source_file::LineIndex;
 use ruff_text_size::{Ranged, TextRange, TextSize};
-use tryke_types::{ExpectedAssertion, FixturePer, HookItem, ParsedFile, TestItem};
+use tryke_types::{
+    DiscoveryError, ExpectedAssertion, ExtractionCoverage, FixturePer, HookItem, ParsedFile,
+    TestItem,
+};
 
 pub(crate) fn path_to_module(root: &Path, file: &Path) -> String {
     tryke_types::path_to_module(root, file).unwrap_or_default()
@@ -306,7 +309,7 @@ const MARKER_ATTRS: &[&str] = &["skip", "todo", "xfail", "skip_if"];
 /// Tryke symbols that can be imported directly with `from tryke import …`.
 /// Used to bound the `symbol_aliases` table so unrelated imports don't
 /// pollute it.
-const TRYKE_SYMBOLS: &[&str] = &["describe", "test", "fixture", "Depends"];
+const TRYKE_SYMBOLS: &[&str] = &["describe", "test", "fixture", "Depends", "skip", "xfail"];
 
 /// Per-file table of local names that refer to tryke module / symbols.
 ///
@@ -323,11 +326,17 @@ struct TrykeAliases {
     /// `{"describe": "describe"}` after `from tryke import describe`;
     /// `{"d": "describe"}` after `from tryke import describe as d`.
     symbol_aliases: std::collections::HashMap<String, &'static str>,
+    /// `[tool.tryke] decorator`: how the `test` symbol is spelled in this
+    /// project, e.g. `"case"` for `@case` / `@tryke.case`.
+    test_name: String,
 }
 
 impl TrykeAliases {
-    fn collect(body: &[Stmt]) -> Self {
-        let mut out = Self::default();
+    fn collect(body: &[Stmt], decorator: &str) -> Self {
+        let mut out = Self {
+            test_name: decorator.to_owned(),
+            ..Self::default()
+        };
         // Seed with the canonical module name so qualified `tryke.describe`,
         // `tryke.test`, etc. remain recognised even in files with no visible
         // `import tryke` — this mirrors the bare-symbol legacy fallback
@@ -355,7 +364,7 @@ impl TrykeAliases {
                 {
                     for alias in &s.names {
                         let name = alias.name.id.as_str();
-                        if let Some(canon) = TRYKE_SYMBOLS.iter().find(|s| **s == name) {
+                        if let Some(canon) = self.canonical(name) {
                             let local = alias.asname.as_ref().map_or(name, |n| n.id.as_str());
                             self.symbol_aliases.insert(local.to_owned(), canon);
                         }
@@ -370,6 +379,27 @@ impl TrykeAliases {
         }
     }
 
+    /// The tryke symbol spelled `name` in this project. The `test`
+    /// decorator goes by the configured name only.
+    fn canonical(&self, name: &str) -> Option<&'static str> {
+        if name == self.test_name {
+            return Some("test");
+        }
+        TRYKE_SYMBOLS
+            .iter()
+            .copied()
+            .find(|canon| *canon == name && *canon != "test")
+    }
+
+    /// How the tryke symbol `canon` is spelled in this project.
+    fn spelling<'a>(&'a self, canon: &'a str) -> &'a str {
+        if canon == "test" {
+            &self.test_name
+        } else {
+            canon
+        }
+    }
+
     /// Is `name` a local reference to the `tryke` module?
     fn is_module(&self, name: &str) -> bool {
         self.module_aliases.contains(name)
@@ -381,9 +411,13 @@ impl TrykeAliases {
     }
 }
 
-/// Returns `true` if `expr` is a `@test.cases(...)` call (bare or qualified).
-/// Must be a `Call` expression — the bare `test.cases` attribute form has no
-/// runtime meaning.
+/// Attributes of `test` that parametrise a function: `@test.cases(...)`
+/// with labelled cases and `@test.each([...])` with rows of positional args.
+const CASES_ATTRS: &[&str] = &["cases", "each"];
+
+/// Returns `true` if `expr` is a `@test.cases(...)` or `@test.each(...)`
+/// call (bare or qualified). Must be a `Call` expression — the bare
+/// `test.cases` attribute form has no runtime meaning.
 fn is_tryke_test_cases_decorator(expr: &Expr, body: &[Stmt], aliases: &TrykeAliases) -> bool {
     let Expr::Call(call) = expr else {
         return false;
@@ -391,7 +425,7 @@ fn is_tryke_test_cases_decorator(expr: &Expr, body: &[Stmt], aliases: &TrykeAlia
     let Expr::Attribute(attr) = &*call.func else {
         return false;
     };
-    if attr.attr.id.as_str() != "cases" {
+    if !CASES_ATTRS.contains(&attr.attr.id.as_str()) {
         return false;
     }
     is_test_or_call_wrapper(&attr.value, body, aliases)
@@ -399,10 +433,15 @@ fn is_tryke_test_cases_decorator(expr: &Expr, body: &[Stmt], aliases: &TrykeAlia
 
 /// Recognises bare `test` / `tryke.test` plus the marker attribute forms
 /// (`test.skip`, `test.xfail`, …) and their call wrappers.
+///
+/// Parenthesised decorators (`@(tryke.test)`) need no special case: the
+/// parser drops the parentheses, so they arrive as the inner expression.
 fn is_tryke_test_decorator(expr: &Expr, body: &[Stmt], aliases: &TrykeAliases) -> bool {
     match expr {
+        // PEP 614 walrus form: @(t := test) decorates with the value.
+        Expr::Named(n) => is_tryke_test_decorator(&n.value, body, aliases),
         // tryke.test (or any module alias of tryke)
-        Expr::Attribute(a) if a.attr.id.as_str() == "test" => {
+        Expr::Attribute(a) if a.attr.id.as_str() == aliases.spelling("test") => {
             matches!(&*a.value, Expr::Name(n) if aliases.is_module(n.id.as_str()))
         }
         // test.skip, test.todo, test.xfail, test.skip_if
@@ -413,18 +452,44 @@ fn is_tryke_test_decorator(expr: &Expr, body: &[Stmt], aliases: &TrykeAliases) -
         Expr::Name(n) => is_bare_tryke_symbol(n.id.as_str(), "test", body, aliases),
         // Call wrapper: @test(), @test.skip("reason"), @test("name"), etc.
         Expr::Call(c) => is_tryke_test_decorator(&c.func, body, aliases),
+        // PEP 614 subscript form: @decorators["test"]
+        Expr::Subscript(_) => is_subscripted_test(expr, aliases),
         _ => false,
     }
 }
 
-/// Returns true for `test` (Name) or `tryke.test` (Attribute).
+/// Peel PEP 614 walrus wrappers (`@(t := test(name="x"))`) down to the
+/// expression that is actually applied as the decorator. Subscripts
+/// (`@decorators["test"]`) already are that expression and pass through.
+fn decorator_target(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Named(n) => decorator_target(&n.value),
+        _ => expr,
+    }
+}
+
+/// `@decorators["test"]`: a lookup keyed by the project's `test`
+/// spelling. The container can't be resolved statically, so the string
+/// key alone identifies the decorator.
+fn is_subscripted_test(expr: &Expr, aliases: &TrykeAliases) -> bool {
+    if let Expr::Subscript(s) = expr
+        && let Expr::StringLiteral(key) = &*s.slice
+    {
+        return key.value.to_str() == aliases.spelling("test");
+    }
+    false
+}
+
+/// Returns true for `test` (Name), `tryke.test` (Attribute) or
+/// `decorators["test"]` (Subscript).
 fn is_bare_test_or_qualified(expr: &Expr, body: &[Stmt], aliases: &TrykeAliases) -> bool {
     match expr {
         Expr::Name(n) => is_bare_tryke_symbol(n.id.as_str(), "test", body, aliases),
         Expr::Attribute(a) => {
-            a.attr.id.as_str() == "test"
+            a.attr.id.as_str() == aliases.spelling("test")
                 && matches!(&*a.value, Expr::Name(n) if aliases.is_module(n.id.as_str()))
         }
+        Expr::Subscript(_) => is_subscripted_test(expr, aliases),
         _ => false,
     }
 }
@@ -443,14 +508,16 @@ fn is_test_or_call_wrapper(expr: &Expr, body: &[Stmt], aliases: &TrykeAliases) -
 /// Resolve a bare name to a canonical tryke symbol.
 ///
 /// Matches when either (a) the name is explicitly aliased to `canon` via
-/// `from tryke import <canon> [as <name>]`, or (b) the name is literally
-/// `canon` and not shadowed by a local definition — the legacy heuristic
-/// that keeps working for files with no visible import.
+/// `from tryke import <canon> [as <name>]`, or (b) the name is `canon` as
+/// spelled in this project (see `[tool.tryke] decorator`) and not shadowed
+/// by a local definition — the legacy heuristic that keeps working for
+/// files with no visible import, including those that bind tryke's names
+/// with `from tryke import *`.
 fn is_bare_tryke_symbol(name: &str, canon: &str, body: &[Stmt], aliases: &TrykeAliases) -> bool {
     if is_locally_defined(name, body) {
         return false;
     }
-    aliases.is_symbol(name, canon) || name == canon
+    aliases.is_symbol(name, canon) || name == aliases.spelling(canon)
 }
 
 /// Check whether a decorator is the tryke `@fixture` decorator. Returns the
@@ -696,6 +763,72 @@ fn extract_test_modifier(expr: &Expr) -> TestModifier {
     }
 }
 
+/// Extract `category=` from a `@test.skip(...)` call. Other modifiers and
+/// the bare `@test.skip` form have no category.
+fn extract_skip_category(expr: &Expr) -> Option<String> {
+    let Expr::Call(call) = expr else {
+        return None;
+    };
+    match extract_test_modifier(&call.func) {
+        TestModifier::Skip(_) => extract_string_kwarg(&call.arguments.keywords, "category"),
+        _ => None,
+    }
+}
+
+/// Skip and xfail markers applied by `@tryke.skip` / `@tryke.xfail`
+/// decorators stacked beside a test's `@test` decorator.
+#[derive(Debug, Default)]
+struct SiblingMarkers {
+    skip: Option<String>,
+    skip_category: Option<String>,
+    xfail: Option<String>,
+}
+
+/// Collect the sibling markers on `func`. The first `skip` and the first
+/// `xfail` win; anything else in the decorator list is ignored.
+fn extract_sibling_markers(
+    func: &ruff_python_ast::StmtFunctionDef,
+    body: &[Stmt],
+    aliases: &TrykeAliases,
+) -> SiblingMarkers {
+    let mut out = SiblingMarkers::default();
+    for dec in &func.decorator_list {
+        let expr = decorator_target(&dec.expression);
+        let call = match expr {
+            Expr::Call(c) => Some(c),
+            _ => None,
+        };
+        let reason = call.map(extract_first_string_arg).unwrap_or_default();
+        match sibling_marker_name(call.map_or(expr, |c| &*c.func), body, aliases) {
+            Some("skip") if out.skip.is_none() => {
+                out.skip = Some(reason);
+                out.skip_category =
+                    call.and_then(|c| extract_string_kwarg(&c.arguments.keywords, "category"));
+            }
+            Some("xfail") if out.xfail.is_none() => out.xfail = Some(reason),
+            _ => {}
+        }
+    }
+    out
+}
+
+/// `"skip"` / `"xfail"` for `tryke.skip` / `tryke.xfail` (through any
+/// module alias) or a name imported with `from tryke import skip`. Unlike
+/// `test`, a bare `skip` is only trusted when it was imported from tryke.
+fn sibling_marker_name(expr: &Expr, body: &[Stmt], aliases: &TrykeAliases) -> Option<&'static str> {
+    let name = match expr {
+        Expr::Attribute(a) => match &*a.value {
+            Expr::Name(n) if aliases.is_module(n.id.as_str()) => a.attr.id.as_str(),
+            _ => return None,
+        },
+        Expr::Name(n) if !is_locally_defined(n.id.as_str(), body) => {
+            aliases.symbol_aliases.get(n.id.as_str()).copied()?
+        }
+        _ => return None,
+    };
+    ["skip", "xfail"].into_iter().find(|marker| *marker == name)
+}
+
 /// Extract the first positional string arg or `reason=`/`description=` kwarg.
 fn extract_first_string_arg(call: &ruff_python_ast::ExprCall) -> String {
     for kw in &call.arguments.keywords {
@@ -716,6 +849,40 @@ fn extract_first_string_arg(call: &ruff_python_ast::ExprCall) -> String {
     String::new()
 }
 
+/// Names of `func`'s parameters in declaration order, without `*args`
+/// and `**kwargs`.
+fn parameter_names(func: &ruff_python_ast::StmtFunctionDef) -> Vec<String> {
+    func.parameters
+        .iter_non_variadic_params()
+        .map(|param| param.parameter.name.id.as_str().to_owned())
+        .collect()
+}
+
+/// `true` when a call-form decorator passes the literal `enabled=False`.
+/// Any other value, including a non-literal one, leaves the test enabled.
+fn extract_disabled(expr: &Expr) -> bool {
+    let Expr::Call(call) = expr else {
+        return false;
+    };
+    call.arguments.keywords.iter().any(|kw| {
+        kw.arg.as_ref().is_some_and(|k| k.id.as_str() == "enabled")
+            && matches!(&kw.value, Expr::BooleanLiteral(b) if !b.value)
+    })
+}
+
+/// `true` when a call-form decorator passes the literal `benchmark=True`.
+fn extract_benchmark(expr: &Expr) -> bool {
+    let Expr::Call(call) = expr else {
+        return false;
+    };
+    call.arguments.keywords.iter().any(|kw| {
+        kw.arg
+            .as_ref()
+            .is_some_and(|k| k.id.as_str() == "benchmark")
+            && matches!(&kw.value, Expr::BooleanLiteral(b) if b.value)
+    })
+}
+
 /// Extract `tags=[...]` kwarg from any call-form decorator.
 fn extract_decorator_tags(expr: &Expr) -> Vec<String> {
     let Expr::Call(call) = expr else {
@@ -755,7 +922,7 @@ fn is_test_case_call(expr: &Expr, body: &[Stmt], aliases: &TrykeAliases) -> bool
     match &*attr.value {
         Expr::Name(n) => is_bare_tryke_symbol(n.id.as_str(), "test", body, aliases),
         Expr::Attribute(a) => {
-            a.attr.id.as_str() == "test"
+            a.attr.id.as_str() == aliases.spelling("test")
                 && matches!(&*a.value, Expr::Name(n) if aliases.is_module(n.id.as_str()))
         }
         _ => false,
@@ -903,23 +1070,282 @@ fn extract_cases(
     Err("test.cases() requires at least one case".to_owned())
 }
 
-fn extract_decorator_name(expr: &Expr) -> Option<String> {
+/// Returns `true` if `expr` is a `@test.each(...)` call rather than
+/// `@test.cases(...)`. Assumes [`is_tryke_test_cases_decorator`] matched.
+fn is_each_decorator(expr: &Expr) -> bool {
+    matches!(expr, Expr::Call(call)
+        if matches!(&*call.func, Expr::Attribute(attr) if attr.attr.id.as_str() == "each"))
+}
+
+/// Extract case info from a `@test.each([...])` decorator.
+///
+/// Each element of the list literal is one row: a tuple supplies the
+/// function's positional arguments, any other literal a single argument.
+/// Labels are the rows' Python `repr`s joined with `", "` — the same
+/// labels the runtime stamps — so `(1, 2, 3)` collects as `fn[1, 2, 3]`.
+///
+/// Returns `Ok(None)` when the rows are not plain literals (e.g.
+/// `@test.each(build())`); the function is then collected as a single
+/// item and the worker runs every row in turn. Literals that can't be
+/// labelled here — ints past `u64`, complex numbers — are an error
+/// rather than a silent fallback.
+fn extract_each_cases(expr: &Expr) -> Result<Option<Vec<CaseInfo>>, String> {
+    let Expr::Call(call) = expr else {
+        return Ok(None);
+    };
+    let [rows] = &*call.arguments.args else {
+        return Err("test.each() takes exactly one list of rows".to_owned());
+    };
+    let elts = match rows {
+        Expr::List(list) => &list.elts,
+        Expr::Tuple(tuple) => &tuple.elts,
+        _ => return Ok(None),
+    };
+    if elts.is_empty() {
+        return Err("test.each() requires at least one row".to_owned());
+    }
+    let mut cases = Vec::with_capacity(elts.len());
+    for (i, row) in elts.iter().enumerate() {
+        if let Some(what) = unsupported_literal(row) {
+            return Err(format!("row {i} holds {what}, which discovery can't label"));
+        }
+        let label = match row {
+            Expr::Tuple(tuple) => tuple
+                .elts
+                .iter()
+                .map(python_repr)
+                .collect::<Option<Vec<_>>>()
+                .map(|parts| parts.join(", ")),
+            _ => python_repr(row),
+        };
+        let Some(label) = label else {
+            return Ok(None);
+        };
+        cases.push(CaseInfo {
+            label,
+            ..CaseInfo::default()
+        });
+    }
+    Ok(Some(cases))
+}
+
+/// The first literal in `expr` that [`python_repr`] can't render even
+/// though it is plain: an int larger than `u64` or a complex number.
+fn unsupported_literal(expr: &Expr) -> Option<&'static str> {
+    match expr {
+        Expr::NumberLiteral(n) => match &n.value {
+            Number::Int(i) if i.as_u64().is_none() => Some("an integer larger than 2**64 - 1"),
+            Number::Complex { .. } => Some("a complex number"),
+            Number::Int(_) | Number::Float(_) => None,
+        },
+        Expr::UnaryOp(u) if matches!(u.op, UnaryOp::USub) => match &*u.operand {
+            operand @ Expr::NumberLiteral(_) => unsupported_literal(operand),
+            _ => None,
+        },
+        Expr::Tuple(tuple) => tuple.elts.iter().find_map(unsupported_literal),
+        Expr::List(list) => list.elts.iter().find_map(unsupported_literal),
+        _ => None,
+    }
+}
+
+/// Render a literal the way Python's `repr()` would. Returns `None` for
+/// anything that is not a plain literal, since discovery can't evaluate it.
+fn python_repr(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::NumberLiteral(n) => match &n.value {
+            Number::Int(i) => i.as_u64().map(|v| v.to_string()),
+            Number::Float(f) => python_float_repr(*f),
+            Number::Complex { .. } => None,
+        },
+        Expr::UnaryOp(u) if matches!(u.op, UnaryOp::USub) => match &*u.operand {
+            Expr::NumberLiteral(n) => match &n.value {
+                Number::Int(i) => match i.as_u64()? {
+                    0 => Some("0".to_owned()),
+                    v => Some(format!("-{v}")),
+                },
+                Number::Float(f) => python_float_repr(-*f),
+                Number::Complex { .. } => None,
+            },
+            _ => None,
+        },
+        Expr::StringLiteral(s) => Some(python_str_repr(s.value.to_str())),
+        Expr::BooleanLiteral(b) => Some(if b.value { "True" } else { "False" }.to_owned()),
+        Expr::NoneLiteral(_) => Some("None".to_owned()),
+        Expr::Tuple(tuple) => {
+            let parts = tuple
+                .elts
+                .iter()
+                .map(python_repr)
+                .collect::<Option<Vec<_>>>()?;
+            Some(match parts.as_slice() {
+                [only] => format!("({only},)"),
+                _ => format!("({})", parts.join(", ")),
+            })
+        }
+        Expr::List(list) => {
+            let parts = list
+                .elts
+                .iter()
+                .map(python_repr)
+                .collect::<Option<Vec<_>>>()?;
+            Some(format!("[{}]", parts.join(", ")))
+        }
+        _ => None,
+    }
+}
+
+/// Python's `repr()` of a float: the shortest round-tripping digits,
+/// positional when the decimal point falls within `-4 < point <= 16` and
+/// scientific (`1e+16`, `1e-05`) otherwise.
+fn python_float_repr(value: f64) -> Option<String> {
+    if value.is_nan() {
+        return Some("nan".to_owned());
+    }
+    if value.is_infinite() {
+        return Some(if value > 0.0 { "inf" } else { "-inf" }.to_owned());
+    }
+    let sci = format!("{value:e}");
+    let (mantissa, exp) = sci.split_once('e')?;
+    let exp: i32 = exp.parse().ok()?;
+    let (sign, mantissa) = match mantissa.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", mantissa),
+    };
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let point = exp + 1;
+    if (-3..=16).contains(&point) {
+        let Ok(point) = usize::try_from(point) else {
+            let zeros = "0".repeat(usize::try_from(-point).ok()?);
+            return Some(format!("{sign}0.{zeros}{digits}"));
+        };
+        if point == 0 {
+            return Some(format!("{sign}0.{digits}"));
+        }
+        if point >= digits.len() {
+            let zeros = "0".repeat(point - digits.len());
+            return Some(format!("{sign}{digits}{zeros}.0"));
+        }
+        let (whole, frac) = digits.split_at(point);
+        return Some(format!("{sign}{whole}.{frac}"));
+    }
+    let (first, rest) = digits.split_at(1);
+    let frac = if rest.is_empty() {
+        String::new()
+    } else {
+        format!(".{rest}")
+    };
+    let exp_sign = if exp < 0 { '-' } else { '+' };
+    Some(format!(
+        "{sign}{first}{frac}e{exp_sign}{:02}",
+        exp.unsigned_abs()
+    ))
+}
+
+/// Python's `repr()` of a string: single-quoted unless only double quotes
+/// avoid escaping, with control and non-space whitespace characters escaped.
+fn python_str_repr(value: &str) -> String {
+    use std::fmt::Write as _;
+
+    let quote = if value.contains('\'') && !value.contains('"') {
+        '"'
+    } else {
+        '\''
+    };
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push(quote);
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c if c == quote => {
+                out.push('\\');
+                out.push(c);
+            }
+            c if c == ' ' || !(c.is_control() || c.is_whitespace()) => out.push(c),
+            c if u32::from(c) <= 0xff => {
+                let _ = write!(out, "\\x{:02x}", u32::from(c));
+            }
+            c if u32::from(c) <= 0xffff => {
+                let _ = write!(out, "\\u{:04x}", u32::from(c));
+            }
+            c => {
+                let _ = write!(out, "\\U{:08x}", u32::from(c));
+            }
+        }
+    }
+    out.push(quote);
+    out
+}
+
+fn extract_decorator_name(expr: &Expr, source: &str) -> Option<String> {
     let Expr::Call(call) = expr else {
         return None;
     };
     for kw in &call.arguments.keywords {
         if kw.arg.as_ref().is_some_and(|k| k.id.as_str() == "name")
-            && let Expr::StringLiteral(s) = &kw.value
+            && let Some(name) = name_text(&kw.value, source)
         {
-            return Some(s.value.to_str().to_owned());
+            return Some(name);
         }
     }
-    if let Some(first) = call.arguments.args.first()
-        && let Expr::StringLiteral(s) = first
-    {
-        return Some(s.value.to_str().to_owned());
+    call.arguments
+        .args
+        .first()
+        .and_then(|first| name_text(first, source))
+}
+
+/// Render a name argument as display text. Plain strings use their value;
+/// f-strings can't be evaluated statically, so each part is rendered from
+/// source with its quotes stripped and `{...}` interpolations kept as
+/// written (`f"case {n}"` becomes `case {n}`).
+fn name_text(expr: &Expr, source: &str) -> Option<String> {
+    match expr {
+        Expr::StringLiteral(s) => Some(s.value.to_str().to_owned()),
+        Expr::FString(f) => Some(
+            f.value
+                .iter()
+                .map(|part| strip_string_quotes(&src_text(source, part.range())))
+                .collect(),
+        ),
+        _ => None,
     }
-    None
+}
+
+/// Strip the prefix letters and surrounding quotes from a single string
+/// literal's source text.
+fn strip_string_quotes(text: &str) -> String {
+    let body = text.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+    for quote in ["\"\"\"", "'''", "\"", "'"] {
+        if let Some(inner) = body
+            .strip_prefix(quote)
+            .and_then(|rest| rest.strip_suffix(quote))
+        {
+            return inner.to_owned();
+        }
+    }
+    body.to_owned()
+}
+
+/// Collect every keyword argument on a call-form decorator as
+/// `(name, source text)` pairs. `**kwargs` splats have no name and are
+/// skipped.
+fn extract_decorator_kwargs(expr: &Expr, source: &str) -> Vec<(String, String)> {
+    let Expr::Call(call) = expr else {
+        return vec![];
+    };
+    call.arguments
+        .keywords
+        .iter()
+        .filter_map(|kw| {
+            let key = kw.arg.as_ref()?;
+            Some((
+                key.id.as_str().to_owned(),
+                src_text(source, kw.value.range()),
+            ))
+        })
+        .collect()
 }
 
 fn extract_docstring(body: &[Stmt]) -> Option<String> {
@@ -965,12 +1391,14 @@ fn relative_span(outer: TextRange, inner: TextRange) -> Option<(usize, usize)> {
 fn extract_expect_call_info(
     call: &ruff_python_ast::ExprCall,
     source: &str,
-) -> Option<(String, TextRange, Option<String>)> {
-    let is_expect = match call.func.as_ref() {
-        Expr::Name(n) => n.id.as_str() == "expect",
-        Expr::Attribute(a) => a.attr.id.as_str() == "expect",
+    expect_names: &[String],
+) -> Option<(String, TextRange, Option<String>, Option<String>)> {
+    let callee = match call.func.as_ref() {
+        Expr::Name(n) => n.id.as_str(),
+        Expr::Attribute(a) => a.attr.id.as_str(),
         _ => return None,
     };
+    let is_expect = expect_names.iter().any(|name| name == callee);
     let nargs = call.arguments.args.len();
     let expr_keywords: Vec<_> = call
         .arguments
@@ -1011,7 +1439,8 @@ fn extract_expect_call_info(
                 None
             }
         });
-    Some((subject, subject_range, label))
+    let group = extract_string_kwarg(&call.arguments.keywords, "group");
+    Some((subject, subject_range, label, group))
 }
 
 fn src_keyword_text(source: &str, keyword: &ruff_python_ast::Keyword) -> String {
@@ -1025,34 +1454,69 @@ struct MatcherArg {
     value: String,
 }
 
+/// Peels a `.pending()` link off an assertion chain, e.g. the one in
+/// `expect(x).pending().to_equal(1)`, returning the expression it was
+/// called on and whether the marker was there.
+fn strip_pending_marker(expr: &Expr) -> (&Expr, bool) {
+    if let Expr::Call(call) = expr
+        && call.arguments.args.is_empty()
+        && call.arguments.keywords.is_empty()
+        && let Expr::Attribute(attr) = call.func.as_ref()
+        && attr.attr.id.as_str() == "pending"
+    {
+        return (&attr.value, true);
+    }
+    (expr, false)
+}
+
+/// Whether the rest of the line after `end` carries a `# pending`
+/// comment. Mirrors the runtime check in `Expectation`, which reads the
+/// same line back through `linecache` and skips the assertion.
+fn has_pending_comment(source: &str, end: TextSize) -> bool {
+    let rest = source.get(usize::from(end)..).unwrap_or_default();
+    let line = rest.lines().next().unwrap_or_default();
+    line.split_once('#').is_some_and(|(_, comment)| {
+        comment
+            .trim_start()
+            .strip_prefix("pending")
+            .is_some_and(|tail| !tail.starts_with(|c: char| c.is_alphanumeric() || c == '_'))
+    })
+}
+
 fn try_extract_assertion(
     call: &ruff_python_ast::ExprCall,
     source: &str,
     line_index: &LineIndex,
+    expect_names: &[String],
 ) -> Option<ExpectedAssertion> {
     // Unwrap `.fatal()`: expect(x).to_equal(y).fatal() wraps the assertion call
     if let Expr::Attribute(attr) = call.func.as_ref()
         && attr.attr.id.as_str() == "fatal"
         && let Expr::Call(inner_call) = attr.value.as_ref()
     {
-        return try_extract_assertion(inner_call, source, line_index);
+        return try_extract_assertion(inner_call, source, line_index, expect_names);
     }
 
     let Expr::Attribute(outer_attr) = call.func.as_ref() else {
         return None;
     };
     let matcher = outer_attr.attr.id.as_str().to_owned();
-    let (subject, subject_range, negated, label) = match outer_attr.value.as_ref() {
+    let (chain, mut pending) = strip_pending_marker(&outer_attr.value);
+    let (subject, subject_range, negated, label, group) = match chain {
         Expr::Call(inner_call) => {
-            let (subject, subject_range, label) = extract_expect_call_info(inner_call, source)?;
-            (subject, subject_range, false, label)
+            let (subject, subject_range, label, group) =
+                extract_expect_call_info(inner_call, source, expect_names)?;
+            (subject, subject_range, false, label, group)
         }
         Expr::Attribute(inner_attr) if inner_attr.attr.id.as_str() == "not_" => {
-            let Expr::Call(inner_call) = inner_attr.value.as_ref() else {
+            let (inner, marked) = strip_pending_marker(&inner_attr.value);
+            pending |= marked;
+            let Expr::Call(inner_call) = inner else {
                 return None;
             };
-            let (subject, subject_range, label) = extract_expect_call_info(inner_call, source)?;
-            (subject, subject_range, true, label)
+            let (subject, subject_range, label, group) =
+                extract_expect_call_info(inner_call, source, expect_names)?;
+            (subject, subject_range, true, label, group)
         }
         _ => return None,
     };
@@ -1084,6 +1548,7 @@ fn try_extract_assertion(
     let expected_arg_value = args.first().map(|arg| arg.value.clone());
     let args = args.into_iter().map(|arg| arg.text).collect();
     let line = source_line(line_index, call_range.start());
+    pending |= has_pending_comment(source, call_range.end());
     Some(ExpectedAssertion {
         subject,
         matcher,
@@ -1098,26 +1563,141 @@ fn try_extract_assertion(
         subject_span: relative_span(call_range, subject_range),
         expected_arg_span,
         expected_arg_value,
+        group,
+        pending,
     })
 }
 
+/// What walking a test body for assertions turned up. The calls that
+/// didn't match feed `tryke test --debug-extraction`.
+#[derive(Default)]
+struct AssertionWalk {
+    assertions: Vec<ExpectedAssertion>,
+    /// Calls reached that aren't assertions, or aren't recognized as one.
+    unrecognized: usize,
+    /// Lines of the unrecognized calls rooted at an `expect(...)` call.
+    unmatched_expect_lines: Vec<u32>,
+}
+
+/// Records every complete `expect(...).matcher(...)` chain in `expr`.
+///
+/// Once a chain matches, only its matcher arguments are searched for
+/// further assertions. The subject is deliberately left alone: in
+/// `expect(expect(x).value).to_equal(1)` the inner `expect(x)` is just a
+/// value being inspected, and a bare `expect(...)` without a matcher is
+/// never an assertion on its own.
 fn collect_assertions_from_expr(
     expr: &Expr,
     source: &str,
     line_index: &LineIndex,
-    out: &mut Vec<ExpectedAssertion>,
+    expect_names: &[String],
+    out: &mut AssertionWalk,
 ) {
-    if let Expr::Call(call) = expr {
-        if let Some(a) = try_extract_assertion(call, source, line_index) {
-            out.push(a);
+    match expr {
+        Expr::Call(call) => {
+            if let Some(a) = try_extract_assertion(call, source, line_index, expect_names) {
+                out.assertions.push(a);
+                for arg in &call.arguments.args {
+                    collect_assertions_from_expr(arg, source, line_index, expect_names, out);
+                }
+                return;
+            }
+            out.unrecognized += 1;
+            if is_expect_rooted(expr, expect_names) {
+                out.unmatched_expect_lines
+                    .push(source_line(line_index, call.range.start()));
+            }
+            collect_assertions_from_expr(&call.func, source, line_index, expect_names, out);
             for arg in &call.arguments.args {
-                collect_assertions_from_expr(arg, source, line_index, out);
+                collect_assertions_from_expr(arg, source, line_index, expect_names, out);
             }
-            return;
         }
-        collect_assertions_from_expr(&call.func, source, line_index, out);
-        for arg in &call.arguments.args {
-            collect_assertions_from_expr(arg, source, line_index, out);
+        // `expect(a).to_be_truthy() and expect(b).to_be_truthy()`
+        Expr::BoolOp(b) => {
+            for value in &b.values {
+                collect_assertions_from_expr(value, source, line_index, expect_names, out);
+            }
+        }
+        // `expect(a).to_equal(1) if flag else expect(b).to_equal(2)`
+        Expr::If(i) => {
+            for part in [&i.test, &i.body, &i.orelse] {
+                collect_assertions_from_expr(part, source, line_index, expect_names, out);
+            }
+        }
+        Expr::Compare(c) => {
+            collect_assertions_from_expr(&c.left, source, line_index, expect_names, out);
+            for comparator in &c.comparators {
+                collect_assertions_from_expr(comparator, source, line_index, expect_names, out);
+            }
+        }
+        // `[expect(x).to_be_truthy() for x in xs]`, and the set and
+        // generator forms
+        Expr::ListComp(c) => {
+            collect_assertions_from_expr(&c.elt, source, line_index, expect_names, out);
+            collect_assertions_from_generators(
+                &c.generators,
+                source,
+                line_index,
+                expect_names,
+                out,
+            );
+        }
+        Expr::SetComp(c) => {
+            collect_assertions_from_expr(&c.elt, source, line_index, expect_names, out);
+            collect_assertions_from_generators(
+                &c.generators,
+                source,
+                line_index,
+                expect_names,
+                out,
+            );
+        }
+        Expr::Generator(g) => {
+            collect_assertions_from_expr(&g.elt, source, line_index, expect_names, out);
+            collect_assertions_from_generators(
+                &g.generators,
+                source,
+                line_index,
+                expect_names,
+                out,
+            );
+        }
+        Expr::DictComp(c) => {
+            collect_assertions_from_expr(&c.key, source, line_index, expect_names, out);
+            collect_assertions_from_expr(&c.value, source, line_index, expect_names, out);
+            collect_assertions_from_generators(
+                &c.generators,
+                source,
+                line_index,
+                expect_names,
+                out,
+            );
+        }
+        // `map(lambda x: expect(x).to_be_truthy(), xs)`
+        Expr::Lambda(l) => {
+            collect_assertions_from_expr(&l.body, source, line_index, expect_names, out);
+        }
+        // `await gather(expect(x).to_be_truthy())`
+        Expr::Await(a) => {
+            collect_assertions_from_expr(&a.value, source, line_index, expect_names, out);
+        }
+        _ => {}
+    }
+}
+
+/// The `for ... in <iter> if <cond>` clauses of a comprehension, in
+/// source order.
+fn collect_assertions_from_generators(
+    generators: &[Comprehension],
+    source: &str,
+    line_index: &LineIndex,
+    expect_names: &[String],
+    out: &mut AssertionWalk,
+) {
+    for generator in generators {
+        collect_assertions_from_expr(&generator.iter, source, line_index, expect_names, out);
+        for condition in &generator.ifs {
+            collect_assertions_from_expr(condition, source, line_index, expect_names, out);
         }
     }
 }
@@ -1126,42 +1706,65 @@ fn collect_assertions_from_stmt(
     stmt: &Stmt,
     source: &str,
     line_index: &LineIndex,
-    out: &mut Vec<ExpectedAssertion>,
+    expect_names: &[String],
+    out: &mut AssertionWalk,
 ) {
     match stmt {
-        Stmt::Expr(s) => collect_assertions_from_expr(&s.value, source, line_index, out),
+        Stmt::Expr(s) => {
+            collect_assertions_from_expr(&s.value, source, line_index, expect_names, out);
+        }
         Stmt::Return(s) => {
             if let Some(v) = &s.value {
-                collect_assertions_from_expr(v, source, line_index, out);
+                collect_assertions_from_expr(v, source, line_index, expect_names, out);
+            }
+        }
+        // `result = expect(x).to_equal(1)`
+        Stmt::Assign(s) => {
+            collect_assertions_from_expr(&s.value, source, line_index, expect_names, out);
+        }
+        Stmt::AnnAssign(s) => {
+            if let Some(v) = &s.value {
+                collect_assertions_from_expr(v, source, line_index, expect_names, out);
             }
         }
         Stmt::If(s) => {
-            collect_assertions_from_expr(&s.test, source, line_index, out);
+            collect_assertions_from_expr(&s.test, source, line_index, expect_names, out);
             for inner in &s.body {
-                collect_assertions_from_stmt(inner, source, line_index, out);
+                collect_assertions_from_stmt(inner, source, line_index, expect_names, out);
             }
             for clause in &s.elif_else_clauses {
                 if let Some(test) = &clause.test {
-                    collect_assertions_from_expr(test, source, line_index, out);
+                    collect_assertions_from_expr(test, source, line_index, expect_names, out);
                 }
                 for inner in &clause.body {
-                    collect_assertions_from_stmt(inner, source, line_index, out);
+                    collect_assertions_from_stmt(inner, source, line_index, expect_names, out);
                 }
             }
         }
         Stmt::For(s) => {
             for inner in s.body.iter().chain(s.orelse.iter()) {
-                collect_assertions_from_stmt(inner, source, line_index, out);
+                collect_assertions_from_stmt(inner, source, line_index, expect_names, out);
             }
         }
         Stmt::While(s) => {
             for inner in s.body.iter().chain(s.orelse.iter()) {
-                collect_assertions_from_stmt(inner, source, line_index, out);
+                collect_assertions_from_stmt(inner, source, line_index, expect_names, out);
             }
         }
         Stmt::With(s) => {
             for inner in &s.body {
-                collect_assertions_from_stmt(inner, source, line_index, out);
+                collect_assertions_from_stmt(inner, source, line_index, expect_names, out);
+            }
+        }
+        Stmt::Match(s) => {
+            collect_assertions_from_expr(&s.subject, source, line_index, expect_names, out);
+            for case in &s.cases {
+                if let Some(guard) = &case.guard {
+                    collect_assertions_from_expr(guard, source, line_index, expect_names, out);
+                }
+                for inner in &case.body {
+                    collect_assertions_from_stmt(inner, source, line_index, expect_names, out);
+                }
             }
         }
         Stmt::Try(s) => {
@@ -1171,25 +1774,121 @@ fn collect_assertions_from_stmt(
                 .chain(s.orelse.iter())
                 .chain(s.finalbody.iter())
             {
-                collect_assertions_from_stmt(inner, source, line_index, out);
+                collect_assertions_from_stmt(inner, source, line_index, expect_names, out);
             }
         }
         _ => {}
     }
 }
 
-fn extract_expected_assertions(
+fn walk_assertions(
     body: &[Stmt],
     source: &str,
     line_index: &LineIndex,
-) -> Vec<ExpectedAssertion> {
-    let mut out = Vec::new();
+    expect_names: &[String],
+) -> AssertionWalk {
+    let mut out = AssertionWalk::default();
     for stmt in body {
-        collect_assertions_from_stmt(stmt, source, line_index, &mut out);
+        collect_assertions_from_stmt(stmt, source, line_index, expect_names, &mut out);
     }
     out
 }
 
+fn extract_expected_assertions(
+    body: &[Stmt],
+    source: &str,
+    line_index: &LineIndex,
+    expect_names: &[String],
+) -> Vec<ExpectedAssertion> {
+    walk_assertions(body, source, line_index, expect_names).assertions
+}
+
+/// Returns `true` if `expr` is a call/attribute chain whose innermost call
+/// is one of `expect_names`, e.g. `expect(x).not_.to_equal(1).fatal()`.
+fn is_expect_rooted(expr: &Expr, expect_names: &[String]) -> bool {
+    match expr {
+        Expr::Call(call) => {
+            let callee = match call.func.as_ref() {
+                Expr::Name(n) => Some(n.id.as_str()),
+                Expr::Attribute(a) => Some(a.attr.id.as_str()),
+                _ => None,
+            };
+            callee.is_some_and(|callee| expect_names.iter().any(|name| name == callee))
+                || is_expect_rooted(&call.func, expect_names)
+        }
+        Expr::Attribute(a) => is_expect_rooted(&a.value, expect_names),
+        _ => false,
+    }
+}
+
+/// Walks test functions the way `collect_tests_from_body` does — through
+/// `describe()` blocks, classes, and `__TRYKE_TESTING__` guards — recording
+/// one [`ExtractionCoverage`] per `@test` / `@test.cases` function.
+fn collect_extraction_coverage(
+    stmts: &[Stmt],
+    top_body: &[Stmt],
+    source: &str,
+    line_index: &LineIndex,
+    aliases: &TrykeAliases,
+    expect_names: &[String],
+    out: &mut Vec<ExtractionCoverage>,
+) {
+    for stmt in stmts {
+        if let Stmt::FunctionDef(func) = stmt {
+            let is_test = func.decorator_list.iter().any(|d| {
+                is_tryke_test_decorator(&d.expression, top_body, aliases)
+                    || is_tryke_test_cases_decorator(&d.expression, top_body, aliases)
+            });
+            if is_test {
+                let walk = walk_assertions(&func.body, source, line_index, expect_names);
+                out.push(ExtractionCoverage {
+                    name: func.name.id.as_str().to_owned(),
+                    line_number: u32::try_from(line_index.line_index(func.range.start()).get())
+                        .ok(),
+                    recognized: walk.assertions.len(),
+                    unrecognized: walk.unrecognized,
+                    unmatched_expect_lines: walk.unmatched_expect_lines,
+                });
+            }
+        } else if let Stmt::ClassDef(class) = stmt {
+            let mut methods = Vec::new();
+            collect_extraction_coverage(
+                &class.body,
+                top_body,
+                source,
+                line_index,
+                aliases,
+                expect_names,
+                &mut methods,
+            );
+            for mut coverage in methods {
+                coverage.name = format!("{}::{}", class.name.id.as_str(), coverage.name);
+                out.push(coverage);
+            }
+        } else if let Stmt::With(with_stmt) = stmt {
+            collect_extraction_coverage(
+                &with_stmt.body,
+                top_body,
+                source,
+                line_index,
+                aliases,
+                expect_names,
+                out,
+            );
+        } else if let Some(inner) = testing_guard_body(stmt) {
+            collect_extraction_coverage(
+                inner,
+                top_body,
+                source,
+                line_index,
+                aliases,
+                expect_names,
+                out,
+            );
+        }
+    }
+}
+
 /// Returns `true` if any expression in the tree is a dynamic import call:
 /// `importlib.import_module(...)` or `__import__(...)`.
 fn expr_has_dynamic_import(expr: &Expr) -> bool {
@@ -1393,6 +2092,7 @@ fn collect_cases_from_func(
     source: &str,
     line_index: &LineIndex,
     aliases: &TrykeAliases,
+    expect_names: &[String],
     groups: &[String],
     tests_out: &mut Vec<TestItem>,
     errors_out: &mut Vec<String>,
@@ -1415,13 +2115,23 @@ fn collect_cases_from_func(
         return;
     }
 
-    let cases = match extract_cases(&cases_dec.expression, top_body, aliases) {
+    // `None` marks a `@test.each` table discovery can't read: the function
+    // collects as one item and the worker runs every row.
+    let (form, extracted) = if is_each_decorator(&cases_dec.expression) {
+        ("each", extract_each_cases(&cases_dec.expression))
+    } else {
+        (
+            "cases",
+            extract_cases(&cases_dec.expression, top_body, aliases).map(Some),
+        )
+    };
+    let cases = match extracted {
         Ok(cases) => cases,
         Err(msg) => {
             let display_file = file.strip_prefix(root).unwrap_or(file).display();
             let line = u32::try_from(line_index.line_index(func.range.start()).get()).unwrap_or(0);
             errors_out.push(format!(
-                "{display_file}:{line}: @test.cases on '{fn_name}': {msg}",
+                "{display_file}:{line}: @test.{form} on '{fn_name}': {msg}",
                 fn_name = func.name.id.as_str(),
             ));
             return;
@@ -1437,21 +2147,41 @@ fn collect_cases_from_func(
     });
     let modifier =
         modifier_dec.map_or(TestModifier::None, |d| extract_test_modifier(&d.expression));
-    let (fn_skip, fn_todo, fn_xfail) = match modifier {
+    let (mut fn_skip, fn_todo, mut fn_xfail) = match modifier {
         TestModifier::Skip(r) => (Some(r), None, None),
         TestModifier::Todo(d) => (None, Some(d), None),
         TestModifier::Xfail(r) => (None, None, Some(r)),
         TestModifier::SkipIf | TestModifier::None => (None, None, None),
     };
+    let mut fn_skip_category = modifier_dec.and_then(|d| extract_skip_category(&d.expression));
+    let siblings = extract_sibling_markers(func, top_body, aliases);
+    if fn_skip.is_none()
+        && fn_todo.is_none()
+        && let Some(reason) = siblings.skip
+    {
+        fn_skip = Some(reason);
+        fn_skip_category = siblings.skip_category;
+    }
+    if fn_xfail.is_none() {
+        fn_xfail = siblings.xfail;
+    }
 
-    let display_name =
-        extract_cases_display_name(&cases_dec.expression).or_else(|| extract_docstring(&func.body));
+    let display_name = extract_cases_display_name(&cases_dec.expression, source)
+        .or_else(|| extract_docstring(&func.body));
     let tags = extract_cases_tags(&cases_dec.expression);
+    let decorator_kwargs = extract_cases_kwargs(&cases_dec.expression, source);
+    let inner = cases_inner_call(&cases_dec.expression);
+    let disabled = inner.is_some_and(extract_disabled);
+    let benchmark = inner.is_some_and(extract_benchmark);
     let line_number = u32::try_from(line_index.line_index(func.range.start()).get()).ok();
     let file_path = Some(file.strip_prefix(root).unwrap_or(file).to_path_buf());
     let module_path = path_to_module(root, file);
-    let expected_assertions = extract_expected_assertions(&func.body, source, line_index);
+    let expected_assertions =
+        extract_expected_assertions(&func.body, source, line_index, expect_names);
+    let parameters = parameter_names(func);
 
+    let labelled = cases.is_some();
+    let cases = cases.unwrap_or_else(|| vec![CaseInfo::default()]);
     for (i, case) in cases.into_iter().enumerate() {
         tests_out.push(TestItem {
             name: func.name.id.as_str().to_owned(),
@@ -1460,13 +2190,26 @@ fn collect_cases_from_func(
             line_number,
             display_name: display_name.clone(),
             expected_assertions: expected_assertions.clone(),
-            skip: case.skip.or_else(|| fn_skip.clone()),
+            skip: case
+                .skip
+                .clone()
+                .or_else(|| fn_skip.clone())
+                .or_else(|| disabled.then(|| "disabled".to_owned())),
+            skip_category: if case.skip.is_some() {
+                None
+            } else {
+                fn_skip_category.clone()
+            },
             todo: case.todo.or_else(|| fn_todo.clone()),
             xfail: case.xfail.or_else(|| fn_xfail.clone()),
+            disabled,
+            benchmark,
             tags: tags.clone(),
             groups: groups.to_vec(),
-            case_label: Some(case.label),
-            case_index: u32::try_from(i).ok(),
+            case_label: labelled.then_some(case.label),
+            case_index: u32::try_from(i).ok().filter(|_| labelled),
+            decorator_kwargs: decorator_kwargs.clone(),
+            parameters: parameters.clone(),
             ..TestItem::default()
         });
     }
@@ -1475,32 +2218,34 @@ fn collect_cases_from_func(
 /// Extract a function-level display name from a `@test("label").cases(...)`
 /// decorator. Returns `None` for the bare `@test.cases(...)` form, which has
 /// no inner call to inspect.
-fn extract_cases_display_name(expr: &Expr) -> Option<String> {
-    let Expr::Call(call) = expr else {
-        return None;
-    };
-    let Expr::Attribute(attr) = &*call.func else {
-        return None;
-    };
-    if attr.attr.id.as_str() != "cases" {
-        return None;
-    }
-    extract_decorator_name(&attr.value)
+fn extract_cases_display_name(expr: &Expr, source: &str) -> Option<String> {
+    extract_decorator_name(cases_inner_call(expr)?, source)
 }
 
 /// Extract a `tags=[...]` kwarg from the inner `test(...)` call of a
 /// `@test(tags=[...]).cases(...)` decorator.
 fn extract_cases_tags(expr: &Expr) -> Vec<String> {
+    cases_inner_call(expr).map_or_else(Vec::new, extract_decorator_tags)
+}
+
+/// Extract keyword arguments from the inner `test(...)` call of a
+/// `@test(...).cases(...)` decorator.
+fn extract_cases_kwargs(expr: &Expr, source: &str) -> Vec<(String, String)> {
+    cases_inner_call(expr).map_or_else(Vec::new, |inner| extract_decorator_kwargs(inner, source))
+}
+
+/// The inner `test(...)` of a `@test(...).cases(...)` or
+/// `@test(...).each(...)` decorator. `None` for the bare forms.
+fn cases_inner_call(expr: &Expr) -> Option<&Expr> {
     let Expr::Call(call) = expr else {
-        return vec![];
+        return None;
     };
     let Expr::Attribute(attr) = &*call.func else {
-        return vec![];
+        return None;
     };
-    if attr.attr.id.as_str() != "cases" {
-        return vec![];
-    }
-    extract_decorator_tags(&attr.value)
+    CASES_ATTRS
+        .contains(&attr.attr.id.as_str())
+        .then_some(&*attr.value)
 }
 
 #[expect(clippy::too_many_arguments)]
@@ -1512,6 +2257,7 @@ fn collect_tests_from_body(
     source: &str,
     line_index: &LineIndex,
     aliases: &TrykeAliases,
+    expect_names: &[String],
     groups: &[String],
     tests_out: &mut Vec<TestItem>,
     hooks_out: &mut Vec<HookItem>,
@@ -1533,20 +2279,48 @@ fn collect_tests_from_body(
 
             if let Some(cases_dec) = cases_dec {
                 collect_cases_from_func(
-                    func, cases_dec, top_body, root, file, source, line_index, aliases, groups,
-                    tests_out, errors_out,
+                    func,
+                    cases_dec,
+                    top_body,
+                    root,
+                    file,
+                    source,
+                    line_index,
+                    aliases,
+                    expect_names,
+                    groups,
+                    tests_out,
+                    errors_out,
                 );
             } else if let Some(dec) = test_dec {
-                let display_name = extract_decorator_name(&dec.expression)
+                let dec_expr = decorator_target(&dec.expression);
+                let display_name = extract_decorator_name(dec_expr, source)
                     .or_else(|| extract_docstring(&func.body));
-                let modifier = extract_test_modifier(&dec.expression);
-                let tags = extract_decorator_tags(&dec.expression);
-                let (skip, todo, xfail) = match modifier {
+                let modifier = extract_test_modifier(dec_expr);
+                let tags = extract_decorator_tags(dec_expr);
+                let decorator_kwargs = extract_decorator_kwargs(dec_expr, source);
+                let mut skip_category = extract_skip_category(dec_expr);
+                let (mut skip, todo, mut xfail) = match modifier {
                     TestModifier::Skip(r) => (Some(r), None, None),
                     TestModifier::Todo(d) => (None, Some(d), None),
                     TestModifier::Xfail(r) => (None, None, Some(r)),
                     TestModifier::SkipIf | TestModifier::None => (None, None, None),
                 };
+                let siblings = extract_sibling_markers(func, top_body, aliases);
+                if skip.is_none()
+                    && todo.is_none()
+                    && let Some(reason) = siblings.skip
+                {
+                    skip = Some(reason);
+                    skip_category = siblings.skip_category;
+                }
+                if xfail.is_none() {
+                    xfail = siblings.xfail;
+                }
+                let disabled = extract_disabled(dec_expr);
+                if disabled && skip.is_none() {
+                    skip = Some("disabled".to_owned());
+                }
                 tests_out.push(TestItem {
                     name: func.name.id.as_str().to_owned(),
                     module_path: path_to_module(root, file),
@@ -1555,13 +2329,21 @@ fn collect_tests_from_body(
                         .ok(),
                     display_name,
                     expected_assertions: extract_expected_assertions(
-                        &func.body, source, line_index,
+                        &func.body,
+                        source,
+                        line_index,
+                        expect_names,
                     ),
                     skip,
+                    skip_category,
                     todo,
                     xfail,
+                    disabled,
+                    benchmark: extract_benchmark(dec_expr),
                     tags,
                     groups: groups.to_vec(),
+                    decorator_kwargs,
+                    parameters: parameter_names(func),
                     ..TestItem::default()
                 });
             }
@@ -1601,20 +2383,58 @@ fn collect_tests_from_body(
                     source,
                     line_index,
                     aliases,
+                    expect_names,
                     &nested_groups,
                     tests_out,
                     hooks_out,
                     errors_out,
                 );
             }
+        } else if let Stmt::ClassDef(class) = stmt {
+            // Decorated methods of a class are tests too. Each runs on a
+            // fresh instance, named `Class::method` so ids read like
+            // pytest node ids. Fixtures only resolve at module level, so
+            // any declared in the class body are dropped.
+            let mut methods = Vec::new();
+            collect_tests_from_body(
+                &class.body,
+                top_body,
+                root,
+                file,
+                source,
+                line_index,
+                aliases,
+                expect_names,
+                groups,
+                &mut methods,
+                &mut Vec::new(),
+                errors_out,
+            );
+            for mut test in methods {
+                test.name = format!("{}::{}", class.name.id.as_str(), test.name);
+                if test.parameters.first().is_some_and(|first| first == "self") {
+                    test.parameters.remove(0);
+                }
+                tests_out.push(test);
+            }
         } else if let Some(inner) = testing_guard_body(stmt) {
             // `if __TRYKE_TESTING__:` block — recurse with the same top_body
             // so decorator / fixture / describe resolution still sees
             // module-level imports, and with the same groups so tests inside
             // the guard keep their enclosing describe() context.
             collect_tests_from_body(
-                inner, top_body, root, file, source, line_index, aliases, groups, tests_out,
-                hooks_out, errors_out,
+                inner,
+                top_body,
+                root,
+                file,
+                source,
+                line_index,
+                aliases,
+                expect_names,
+                groups,
+                tests_out,
+                hooks_out,
+                errors_out,
             );
         }
     }
@@ -1730,25 +2550,135 @@ pub fn discover_file_from_source(
     file: &Path,
     source: &str,
 ) -> tryke_types::DiscoveredFile {
+    discover_file_from_source_with_names(
+        root,
+        src_roots,
+        &["expect".to_owned()],
+        "test",
+        file,
+        source,
+    )
+}
+
+/// [`discover_file_from_source`] with the `[tool.tryke] expect_names`
+/// assertion entrypoints and `decorator` test decorator name instead of
+/// the default `expect` and `test`.
+pub(crate) fn discover_file_from_source_with_names(
+    root: &Path,
+    src_roots: &[PathBuf],
+    expect_names: &[String],
+    decorator: &str,
+    file: &Path,
+    source: &str,
+) -> tryke_types::DiscoveredFile {
+    let parsed = match parse_module(source) {
+        Ok(parsed) => parsed,
+        Err(err) => return unparsable_file(file, source, &err),
+    };
+    discover_file_from_body(
+        root,
+        src_roots,
+        expect_names,
+        decorator,
+        file,
+        &parsed.syntax().body,
+        source,
+    )
+}
+
+/// The discovery result for a file that doesn't parse: no tests, and
+/// the parser's complaint with the line it stopped on.
+pub(crate) fn unparsable_file(
+    file: &Path,
+    source: &str,
+    err: &ParseError,
+) -> tryke_types::DiscoveredFile {
+    let line_index = LineIndex::from_source_text(source);
+    tryke_types::DiscoveredFile {
+        parsed: ParsedFile {
+            syntax_error: Some(DiscoveryError {
+                file_path: file.to_path_buf(),
+                message: err.error.to_string(),
+                line_number: Some(source_line(&line_index, err.location.start())),
+            }),
+            ..ParsedFile::default()
+        },
+        ..tryke_types::DiscoveredFile::default()
+    }
+}
+
+/// Per-test counts of which calls assertion extraction recognized, for
+/// `tryke test --debug-extraction`. Returns nothing for source that
+/// doesn't parse.
+#[must_use]
+pub fn extraction_coverage_from_source(
+    source: &str,
+    expect_names: &[String],
+    decorator: &str,
+) -> Vec<ExtractionCoverage> {
     let Ok(parsed) = parse_module(source) else {
-        return tryke_types::DiscoveredFile::default();
+        return Vec::new();
+    };
+    let body = &parsed.syntax().body;
+    let line_index = LineIndex::from_source_text(source);
+    let aliases = TrykeAliases::collect(body, decorator);
+    let mut out = Vec::new();
+    collect_extraction_coverage(
+        body,
+        body,
+        source,
+        &line_index,
+        &aliases,
+        expect_names,
+        &mut out,
+    );
+    out
+}
+
+/// Module names from the top-level `import` / `from ... import`
+/// statements of `body`, for [`ParsedFile::imports`]. Unlike
+/// `extract_local_import_candidate_groups`, these are left unresolved and
+/// include third-party and standard library modules.
+fn top_level_imports(body: &[Stmt]) -> Vec<String> {
+    let mut imports: Vec<String> = Vec::new();
+    let mut push = |name: String| {
+        if !imports.contains(&name) {
+            imports.push(name);
+        }
     };
-    discover_file_from_body(root, src_roots, file, &parsed.syntax().body, source)
+    for stmt in body {
+        match stmt {
+            Stmt::Import(import_stmt) => {
+                for alias in &import_stmt.names {
+                    push(alias.name.id.as_str().to_owned());
+                }
+            }
+            Stmt::ImportFrom(from_stmt) => {
+                let dots = ".".repeat(from_stmt.level as usize);
+                let module = from_stmt.module.as_ref().map_or("", |m| m.id.as_str());
+                push(format!("{dots}{module}"));
+            }
+            _ => {}
+        }
+    }
+    imports
 }
 
 /// Shared AST walk behind `discover_file_from_source` and
 /// the filesystem incremental discovery path: collects tests, hooks,
-/// guard-else lines, local import candidates, and the dynamic-import flag
-/// in a single pass.
+/// guard-else lines, local import candidates, top-level imports, and the
+/// dynamic-import flag in a single pass.
 pub(crate) fn discover_file_from_body(
     root: &Path,
     src_roots: &[PathBuf],
+    expect_names: &[String],
+    decorator: &str,
     file: &Path,
     body: &[Stmt],
     source: &str,
 ) -> tryke_types::DiscoveredFile {
     let line_index = LineIndex::from_source_text(source);
-    let aliases = TrykeAliases::collect(body);
+    let aliases = TrykeAliases::collect(body, decorator);
     let mut tests = Vec::new();
     let mut hooks = Vec::new();
     let mut errors = Vec::new();
@@ -1760,6 +2690,7 @@ pub(crate) fn discover_file_from_body(
         source,
         &line_index,
         &aliases,
+        expect_names,
         &[],
         &mut tests,
         &mut hooks,
@@ -1775,6 +2706,8 @@ pub(crate) fn discover_file_from_body(
             hooks,
             testing_guard_else_lines,
             errors,
+            imports: top_level_imports(body),
+            syntax_error: None,
         },
         import_candidates,
         dynamic_imports,
@@ -1812,6 +2745,26 @@ mod tests {
         parse_tests_from_source(root, src_roots, file, &source)
     }
 
+    #[test]
+    fn records_top_level_imports_in_source_order() {
+        let source = "import os
+import json, collections.abc as abc
+from pathlib import Path
+from .helpers import make_user
+from .. import shared
+from os import sep
+
+def helper():
+    import inner_only
+";
+        let (dir, file) = write_source(source);
+        let parsed = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file);
+        assert_eq!(
+            parsed.imports,
+            vec!["os", "json", "collections.abc", "pathlib", ".helpers", "..",]
+        );
+    }
+
     #[test]
     fn path_to_module_converts_correctly() {
         let root = Path::new("/proj");
@@ -1843,6 +2796,36 @@ def not_a_test():
         assert!(names.contains(&"test_two"));
     }
 
+    #[test]
+    fn collects_decorated_methods_of_a_class() {
+        let source = "from tryke import expect, test
+
+class TestMath:
+    def helper(self):
+        return 1
+
+    @test
+    def test_add(self):
+        expect(self.helper() + 1).to_equal(2)
+
+    @test(name=\"subtracts\")
+    def test_sub(self):
+        pass
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        let ids: Vec<_> = items.iter().map(TestItem::id).collect();
+        assert_eq!(
+            ids,
+            vec!["test.py::TestMath::test_add", "test.py::TestMath::test_sub"]
+        );
+        assert!(items.iter().all(|item| item.module_path == "test"));
+        assert!(items.iter().all(|item| item.parameters.is_empty()));
+        assert_eq!(items[0].expected_assertions.len(), 1);
+        assert_eq!(items[0].line_number, Some(7));
+        assert_eq!(items[1].display_name.as_deref(), Some("subtracts"));
+    }
+
     #[test]
     fn skips_non_test_decorators() {
         let source = "@pytest.mark.skip
@@ -2053,6 +3036,45 @@ def fn():
         }
     }
 
+    #[test]
+    fn skip_category_kwarg_is_captured() {
+        let source = "@test.skip(\"no GPU\", category=\"platform\")
+def test_cuda():
+    pass
+
+@test.skip(\"WIP\")
+def test_plain_skip():
+    pass
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].skip.as_deref(), Some("no GPU"));
+        assert_eq!(items[0].skip_category.as_deref(), Some("platform"));
+        assert_eq!(items[1].skip_category, None);
+    }
+
+    #[test]
+    fn cases_inherit_function_skip_category() {
+        let source = "@test.skip(reason=\"needs db\", category=\"missing-dependency\")
+@test.cases(
+    test.case(\"a\", n=1),
+    test.case(\"b\", n=2, skip=\"known bug\"),
+)
+def fn(n):
+    pass
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        assert_eq!(items.len(), 2);
+        assert_eq!(
+            items[0].skip_category.as_deref(),
+            Some("missing-dependency")
+        );
+        assert_eq!(items[1].skip.as_deref(), Some("known bug"));
+        assert_eq!(items[1].skip_category, None);
+    }
+
     #[test]
     fn cases_per_case_skip_from_typed_form() {
         let source = r#"@test.cases(
@@ -2260,6 +3282,147 @@ def fn():
         );
     }
 
+    #[test]
+    fn each_emits_one_item_per_row_labelled_by_repr() {
+        let source = "@test.each([(1, 2, 3), (2, 2, 4), (-1, 0.5, 'x'), None])
+def test_add(a, b, expected):
+    expect(a + b).to_equal(expected)
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        let ids: Vec<_> = items.iter().map(TestItem::id).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "test.py::test_add[1, 2, 3]",
+                "test.py::test_add[2, 2, 4]",
+                "test.py::test_add[-1, 0.5, 'x']",
+                "test.py::test_add[None]",
+            ]
+        );
+        let indices: Vec<_> = items.iter().map(|i| i.case_index).collect();
+        assert_eq!(indices, vec![Some(0), Some(1), Some(2), Some(3)]);
+        assert!(items.iter().all(|i| i.line_number == Some(2)));
+        assert!(items.iter().all(|i| i.file_path == items[0].file_path));
+    }
+
+    #[test]
+    fn each_non_literal_rows_collect_a_single_item() {
+        let source = "ROWS = [(1, 2)]
+@test.each(ROWS)
+def test_add(a, b):
+    pass
+
+@test.each([(1, object())])
+def test_mixed(a, b):
+    pass
+";
+        let (dir, file) = write_source(source);
+        let parsed = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file);
+        assert!(parsed.errors.is_empty(), "got {:?}", parsed.errors);
+        let names: Vec<_> = parsed.tests.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["test_add", "test_mixed"]);
+        assert!(parsed.tests.iter().all(|t| t.case_label.is_none()));
+        assert!(parsed.tests.iter().all(|t| t.case_index.is_none()));
+    }
+
+    #[test]
+    fn each_empty_rows_emits_error() {
+        let source = "@test.each([])
+def test_add(a, b):
+    pass
+";
+        let (dir, file) = write_source(source);
+        let parsed = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file);
+        assert!(parsed.tests.is_empty());
+        assert!(
+            parsed.errors.iter().any(|e| e.contains("@test.each")),
+            "expected an error mentioning @test.each, got {:?}",
+            parsed.errors
+        );
+    }
+
+    #[test]
+    fn each_applies_enabled_and_benchmark_from_inner_test_call() {
+        let source = "@test(enabled=False).each([1, 2])
+def test_off(n):
+    pass
+
+@test(benchmark=True).each([1])
+def test_bench(n):
+    pass
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        let flags: Vec<_> = items
+            .iter()
+            .map(|t| (t.id(), t.disabled, t.skip.as_deref(), t.benchmark))
+            .collect();
+        assert_eq!(
+            flags,
+            vec![
+                (
+                    "test.py::test_off[1]".to_owned(),
+                    true,
+                    Some("disabled"),
+                    false
+                ),
+                (
+                    "test.py::test_off[2]".to_owned(),
+                    true,
+                    Some("disabled"),
+                    false
+                ),
+                ("test.py::test_bench[1]".to_owned(), false, None, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn each_rows_discovery_cannot_label_emit_errors() {
+        let source = "@test.each([1, 18446744073709551616])
+def test_big(n):
+    pass
+
+@test.each([(1, -2j)])
+def test_complex(a, b):
+    pass
+";
+        let (dir, file) = write_source(source);
+        let parsed = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file);
+        assert!(parsed.tests.is_empty(), "got {:?}", parsed.tests);
+        assert_eq!(parsed.errors.len(), 2, "got {:?}", parsed.errors);
+        assert!(
+            parsed.errors[0].contains("test_big': row 1 holds an integer larger than 2**64 - 1"),
+            "got {:?}",
+            parsed.errors
+        );
+        assert!(
+            parsed.errors[1].contains("test_complex': row 0 holds a complex number"),
+            "got {:?}",
+            parsed.errors
+        );
+    }
+
+    #[test]
+    fn python_repr_matches_python() {
+        assert_eq!(python_float_repr(0.1).as_deref(), Some("0.1"));
+        assert_eq!(python_float_repr(1.0).as_deref(), Some("1.0"));
+        assert_eq!(python_float_repr(-0.0).as_deref(), Some("-0.0"));
+        assert_eq!(python_float_repr(1e16).as_deref(), Some("1e+16"));
+        assert_eq!(
+            python_float_repr(1e15).as_deref(),
+            Some("1000000000000000.0")
+        );
+        assert_eq!(python_float_repr(0.0001).as_deref(), Some("0.0001"));
+        assert_eq!(python_float_repr(1.5e-5).as_deref(), Some("1.5e-05"));
+        assert_eq!(python_str_repr("a"), "'a'");
+        assert_eq!(python_str_repr("it's"), "\"it's\"");
+        assert_eq!(python_str_repr("'\""), "'\\'\"'");
+        assert_eq!(python_str_repr("tab\there\n"), "'tab\\there\\n'");
+        assert_eq!(python_str_repr("\u{1b}"), "'\\x1b'");
+    }
+
     #[test]
     fn qualified_form_overrides_local_definition() {
         let source = "def test(fn):
@@ -2320,6 +3483,38 @@ def test_fn():
         assert!(a.args.is_empty());
     }
 
+    #[test]
+    fn keeps_positive_and_negated_assertions_on_same_subject() {
+        let source = "@test
+def test_fn():
+    expect(x).to_equal(1)
+    expect(x).not_.to_equal(2)
+    expect(x).not_.to_equal(1)
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        let assertions: Vec<_> = items[0]
+            .expected_assertions
+            .iter()
+            .map(|a| {
+                (
+                    a.subject.as_str(),
+                    a.matcher.as_str(),
+                    a.negated,
+                    a.args.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            assertions,
+            vec![
+                ("x", "to_equal", false, vec!["1".to_owned()]),
+                ("x", "to_equal", true, vec!["2".to_owned()]),
+                ("x", "to_equal", true, vec!["1".to_owned()]),
+            ]
+        );
+    }
+
     #[test]
     fn extracts_multiple_assertions() {
         let source = "@test
@@ -2332,6 +3527,37 @@ def test_fn():
         assert_eq!(items[0].expected_assertions.len(), 2);
     }
 
+    #[test]
+    fn marks_pending_assertions() {
+        let source = "@test
+def test_fn():
+    expect(a).to_equal(1)
+    expect(b).to_equal(2)  # pending: not wired up yet
+    expect(c).pending().not_.to_be_none()
+    expect(d).not_.pending().to_be_truthy()
+    expect(e).to_equal(5)  # pendingly
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        let assertions = &items[0].expected_assertions;
+        let pending: Vec<_> = assertions
+            .iter()
+            .map(|a| (a.subject.as_str(), a.pending))
+            .collect();
+        assert_eq!(
+            pending,
+            vec![
+                ("a", false),
+                ("b", true),
+                ("c", true),
+                ("d", true),
+                ("e", false)
+            ]
+        );
+        assert!(assertions[2].negated);
+        assert_eq!(assertions[3].matcher, "to_be_truthy");
+    }
+
     #[test]
     fn no_assertions_when_none_present() {
         let source = "@test
@@ -2357,6 +3583,181 @@ def test_fn():
         assert_eq!(items[0].expected_assertions[0].line, 4);
     }
 
+    #[test]
+    fn nested_expect_subject_records_only_outer_assertion() {
+        let source = "@test
+def test_fn():
+    expect(expect(x).value).to_equal(1)
+    expect(expect(y)).not_.to_be_none()
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        let assertions = &items[0].expected_assertions;
+        assert_eq!(assertions.len(), 2);
+        assert_eq!(assertions[0].subject, "expect(x).value");
+        assert_eq!(assertions[0].matcher, "to_equal");
+        assert_eq!(assertions[0].args, vec!["1"]);
+        assert_eq!(assertions[1].subject, "expect(y)");
+        assert_eq!(assertions[1].matcher, "to_be_none");
+        assert!(assertions[1].negated);
+    }
+
+    #[test]
+    fn extracts_assertions_joined_by_boolean_and_conditional_operators() {
+        let source = "@test
+def test_fn():
+    expect(a).to_be_truthy() and expect(b).to_be_truthy()
+    expect(c).to_equal(1) if flag else expect(d).to_equal(2)
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        let subjects: Vec<_> = items[0]
+            .expected_assertions
+            .iter()
+            .map(|a| (a.subject.as_str(), a.line))
+            .collect();
+        assert_eq!(subjects, vec![("a", 3), ("b", 3), ("c", 4), ("d", 4)]);
+    }
+
+    #[test]
+    fn extracts_assertions_on_the_right_of_assignments() {
+        let source = "@test
+def test_fn():
+    result = expect(a).to_equal(1)
+    checked: bool = expect(b).to_be_truthy()
+    wrapped = str(expect(c).to_be_none())
+    pending: int
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        let subjects: Vec<_> = items[0]
+            .expected_assertions
+            .iter()
+            .map(|a| (a.subject.as_str(), a.line))
+            .collect();
+        assert_eq!(subjects, vec![("a", 3), ("b", 4), ("c", 5)]);
+    }
+
+    #[test]
+    fn extracts_assertions_inside_match_cases() {
+        let source = "@test
+def test_fn():
+    match expect(a).to_be_truthy():
+        case 1:
+            expect(b).to_equal(1)
+        case [x, y] if expect(c).to_be_truthy():
+            expect(d).to_equal(2)
+            expect(e).to_equal(3)
+        case _:
+            expect(f).to_be_none()
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        let subjects: Vec<_> = items[0]
+            .expected_assertions
+            .iter()
+            .map(|a| (a.subject.as_str(), a.line))
+            .collect();
+        assert_eq!(
+            subjects,
+            vec![("a", 3), ("b", 5), ("c", 6), ("d", 7), ("e", 8), ("f", 10)]
+        );
+    }
+
+    #[test]
+    fn extracts_assertions_inside_comprehensions_and_lambdas() {
+        let source = "@test
+def test_fn():
+    [expect(a).to_be_truthy() for a in xs]
+    {expect(b).to_equal(1) for b in xs}
+    {k: expect(c).to_equal(k) for k in xs}
+    all(expect(d).to_be_truthy() for d in xs)
+    list(map(lambda e: expect(e).to_be_none(), xs))
+    [x for x in xs if expect(f).to_be_truthy()]
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        let subjects: Vec<_> = items[0]
+            .expected_assertions
+            .iter()
+            .map(|a| (a.subject.as_str(), a.matcher.as_str(), a.line))
+            .collect();
+        assert_eq!(
+            subjects,
+            vec![
+                ("a", "to_be_truthy", 3),
+                ("b", "to_equal", 4),
+                ("c", "to_equal", 5),
+                ("d", "to_be_truthy", 6),
+                ("e", "to_be_none", 7),
+                ("f", "to_be_truthy", 8),
+            ]
+        );
+    }
+
+    #[test]
+    fn extraction_coverage_reports_unmatched_expect_chain() {
+        let source = "@test
+def test_fn():
+    expect(x).to_equal(1)
+    setup()
+    expect(x).not_.not_.to_be_truthy()
+";
+        let coverage = extraction_coverage_from_source(source, &["expect".to_owned()], "test");
+        assert_eq!(
+            coverage,
+            vec![ExtractionCoverage {
+                name: "test_fn".into(),
+                line_number: Some(1),
+                recognized: 1,
+                unrecognized: 2,
+                unmatched_expect_lines: vec![5],
+            }]
+        );
+    }
+
+    #[test]
+    fn extraction_coverage_counts_what_extraction_collects() {
+        let source = "@test
+def test_fn():
+    result = expect(a).to_equal(1)
+    expect(b).to_be_truthy() and expect(c).to_be_truthy()
+    [expect(d).to_be_truthy() for d in xs]
+    match x:
+        case 1:
+            expect(e).to_equal(1)
+
+class TestThing:
+    @test
+    async def test_method(self):
+        await gather(expect(f).to_be_truthy())
+";
+        let coverage = extraction_coverage_from_source(source, &["expect".to_owned()], "test");
+        assert_eq!(
+            coverage,
+            vec![
+                ExtractionCoverage {
+                    name: "test_fn".into(),
+                    line_number: Some(1),
+                    recognized: 5,
+                    unrecognized: 0,
+                    unmatched_expect_lines: vec![],
+                },
+                ExtractionCoverage {
+                    name: "TestThing::test_method".into(),
+                    line_number: Some(11),
+                    recognized: 1,
+                    unrecognized: 1,
+                    unmatched_expect_lines: vec![],
+                },
+            ]
+        );
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        let extracted: Vec<_> = items.iter().map(|t| t.expected_assertions.len()).collect();
+        assert_eq!(extracted, vec![5, 1]);
+    }
+
     #[test]
     fn extracts_multiline_keyword_assertion() {
         let source = "@test
@@ -2477,6 +3878,25 @@ def test_fn():
         assert_eq!(items[0].display_name.as_deref(), Some("my label"));
     }
 
+    #[test]
+    fn display_name_from_fstring_keeps_interpolations() {
+        let source = "N = 1
+
+@test(name=f\"case {N}: \" \"done\")
+def test_fn():
+    pass
+
+@test(f'{N + 1} items')
+def test_other():
+    pass
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].display_name.as_deref(), Some("case {N}: done"));
+        assert_eq!(items[1].display_name.as_deref(), Some("{N + 1} items"));
+    }
+
     #[test]
     fn display_name_kwarg_beats_positional() {
         let source = "@test(\"pos\", name=\"kwarg\")
@@ -2571,6 +3991,25 @@ def test_fn():
         assert_eq!(items[0].expected_assertions[0].label, None);
     }
 
+    #[test]
+    fn expect_group_kwarg_is_captured() {
+        let source = "@test
+def test_fn():
+    expect(a, group=\"parsing\").to_equal(1)
+    expect(b, name=\"b\", group=\"parsing\").not_.to_be_none()
+    expect(c).to_equal(3)
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        let groups: Vec<_> = items[0]
+            .expected_assertions
+            .iter()
+            .map(|a| a.group.as_deref())
+            .collect();
+        assert_eq!(groups, vec![Some("parsing"), Some("parsing"), None]);
+        assert_eq!(items[0].expected_assertions[1].label.as_deref(), Some("b"));
+    }
+
     #[test]
     fn tryke_test_call_form_qualified() {
         let source = "import tryke
@@ -2584,6 +4023,61 @@ def my_func():
         assert_eq!(items[0].display_name.as_deref(), Some("foo"));
     }
 
+    #[test]
+    fn parenthesized_decorator_is_discovered() {
+        let source = "import tryke
+@(tryke.test)
+def test_plain():
+    pass
+
+@(tryke.test.skip(\"later\"))
+def test_skipped():
+    pass
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        let names: Vec<_> = items.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["test_plain", "test_skipped"]);
+        assert_eq!(items[1].skip.as_deref(), Some("later"));
+    }
+
+    #[test]
+    fn subscripted_decorator_is_discovered() {
+        let source = "from tryke import test
+decorators = {\"test\": test}
+
+@decorators[\"test\"]
+def test_plain():
+    pass
+
+@decorators[\"test\"].skip(\"later\")
+def test_skipped():
+    pass
+
+@decorators[\"other\"]
+def not_a_test():
+    pass
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        let names: Vec<_> = items.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["test_plain", "test_skipped"]);
+        assert_eq!(items[1].skip.as_deref(), Some("later"));
+    }
+
+    #[test]
+    fn walrus_decorator_is_discovered() {
+        let source = "from tryke import test
+@(t := test(name=\"aliased\"))
+def my_func():
+    pass
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].display_name.as_deref(), Some("aliased"));
+    }
+
     #[test]
     fn extract_local_imports_absolute() {
         let dir = tempfile::tempdir().expect("tempdir");
@@ -2954,6 +4448,57 @@ def test_fn():
         assert_eq!(items[0].skip.as_deref(), Some("broken"));
     }
 
+    #[test]
+    fn stacked_tryke_skip_marks_the_test_skipped() {
+        let source = "import tryke\n\
+                      @tryke.skip\n\
+                      @tryke.test\n\
+                      def test_bare(): pass\n\
+                      @tryke.skip(reason=\"flaky\", category=\"network\")\n\
+                      @tryke.test\n\
+                      def test_call(): pass\n";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].skip.as_deref(), Some(""));
+        assert_eq!(items[1].skip.as_deref(), Some("flaky"));
+        assert_eq!(items[1].skip_category.as_deref(), Some("network"));
+        assert!(items.iter().all(|item| item.xfail.is_none()));
+    }
+
+    #[test]
+    fn stacked_tryke_xfail_marks_the_test_expected_to_fail() {
+        let source = "from tryke import test, xfail\n\
+                      @test\n\
+                      @xfail\n\
+                      def test_bare(): pass\n\
+                      @xfail(\"bug #42\")\n\
+                      @test.cases(a={}, b={})\n\
+                      def test_cases(): pass\n";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].xfail.as_deref(), Some(""));
+        assert_eq!(items[1].xfail.as_deref(), Some("bug #42"));
+        assert_eq!(items[2].xfail.as_deref(), Some("bug #42"));
+        assert!(items.iter().all(|item| item.skip.is_none()));
+    }
+
+    #[test]
+    fn sibling_markers_need_a_test_decorator_and_a_tryke_import() {
+        let source = "import tryke\n\
+                      @tryke.skip\n\
+                      def helper(): pass\n\
+                      @skip\n\
+                      @test\n\
+                      def test_local_skip(): pass\n";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].name, "test_local_skip");
+        assert!(items[0].skip.is_none());
+    }
+
     #[test]
     fn recognizes_test_skip_if() {
         let source = "@test.skip_if(True, reason=\"always\")\ndef test_fn(): pass\n";
@@ -2983,6 +4528,74 @@ def test_fn():
         assert_eq!(items[0].skip.as_deref(), Some("broken"));
     }
 
+    #[test]
+    fn captures_arbitrary_decorator_kwargs() {
+        let source =
+            "@test(\"named\", priority=1, flaky=True, owner=\"qa\")\ndef test_fn(): pass\n";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        assert_eq!(
+            items[0].decorator_kwargs,
+            vec![
+                ("priority".to_owned(), "1".to_owned()),
+                ("flaky".to_owned(), "True".to_owned()),
+                ("owner".to_owned(), "\"qa\"".to_owned()),
+            ]
+        );
+        assert_eq!(items[0].display_name.as_deref(), Some("named"));
+    }
+
+    #[test]
+    fn enabled_false_marks_test_disabled_and_skipped() {
+        let source = "@test(enabled=False)\ndef test_off(): pass\n\n\
+                      @test(enabled=True)\ndef test_on(): pass\n\n\
+                      @test\ndef test_plain(): pass\n";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        assert_eq!(items.len(), 3);
+        assert!(items[0].disabled);
+        assert_eq!(items[0].skip.as_deref(), Some("disabled"));
+        for item in &items[1..] {
+            assert!(!item.disabled, "{} should be enabled", item.name);
+            assert_eq!(item.skip, None);
+        }
+    }
+
+    #[test]
+    fn benchmark_true_marks_test_as_benchmark() {
+        let source = "@test(benchmark=True)\ndef test_fast(): pass\n\n\
+                      @test(benchmark=False)\ndef test_off(): pass\n\n\
+                      @test\ndef test_plain(): pass\n";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        assert_eq!(items.len(), 3);
+        assert!(items[0].benchmark);
+        for item in &items[1..] {
+            assert!(!item.benchmark, "{} should not be a benchmark", item.name);
+        }
+    }
+
+    #[test]
+    fn records_test_parameters_and_arity() {
+        let source = "@test\ndef test_pair(db, client=Depends(make_client), *args, **kwargs): pass\n\n\
+                      @test\ndef test_none(): pass\n";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].arity(), 2);
+        assert_eq!(items[0].parameters, ["db", "client"]);
+        assert_eq!(items[1].arity(), 0);
+        assert!(items[1].parameters.is_empty());
+    }
+
+    #[test]
+    fn bare_decorator_has_no_kwargs() {
+        let source = "@test\ndef test_fn(): pass\n";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        assert!(items[0].decorator_kwargs.is_empty());
+    }
+
     #[test]
     fn no_tags_by_default() {
         let source = "@test\ndef test_fn(): pass\n";
@@ -3968,6 +5581,42 @@ def fn():
         assert!(items.is_empty(), "expected shadowed alias to not match");
     }
 
+    #[test]
+    fn recognizes_star_import() {
+        let source = "\
+from tryke import *
+
+with describe(\"math\"):
+    @test
+    def addition():
+        expect(1 + 1).to_equal(2)
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].name, "addition");
+        assert_eq!(items[0].groups, vec!["math".to_string()]);
+        assert_eq!(items[0].expected_assertions.len(), 1);
+        assert_eq!(items[0].expected_assertions[0].subject, "1 + 1");
+    }
+
+    #[test]
+    fn local_def_shadows_star_import() {
+        let source = "\
+from tryke import *
+
+def test(fn):
+    return fn
+
+@test
+def fn():
+    pass
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        assert!(items.is_empty(), "expected local def to shadow star import");
+    }
+
     #[test]
     fn recognizes_aliased_depends() {
         let source = "\
@@ -3989,4 +5638,22 @@ def child(p=Dep(parent)):
             .expect("child fixture not found");
         assert_eq!(child.depends_on, vec!["parent".to_string()]);
     }
+
+    #[test]
+    fn extracts_assertions_with_awaited_subjects_and_inside_awaits() {
+        let source = "@test
+async def test_fn():
+    expect(await fetch(x)).to_equal(y)
+    await gather(expect(a).to_be_truthy())
+    result = await run(expect(b).to_equal(1))
+";
+        let (dir, file) = write_source(source);
+        let items = parse_tests_from_file(dir.path(), &[dir.path().to_path_buf()], &file).tests;
+        let subjects: Vec<_> = items[0]
+            .expected_assertions
+            .iter()
+            .map(|a| (a.subject.as_str(), a.line))
+            .collect();
+        assert_eq!(subjects, vec![("await fetch(x)", 3), ("a", 4), ("b", 5)]);
+    }
 }
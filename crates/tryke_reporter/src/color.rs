@@ -0,0 +1,183 @@
+use std::fs::File;
+use std::io;
+
+/// When reporters color their output (`--color`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Color only when the reporter writes to a terminal and `NO_COLOR`
+    /// is unset.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    /// Resolve the choice for a reporter writing to `stream`. `Auto` asks
+    /// that stream, not stdout, so a reporter writing to a file stays
+    /// plain while one on the terminal is colored. An explicit `Always`
+    /// or `Never` wins over `NO_COLOR`, per <https://no-color.org>.
+    #[must_use]
+    pub fn enabled_for(self, stream: &impl Terminal) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Auto => {
+                std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty()) && stream.is_terminal()
+            }
+        }
+    }
+}
+
+/// A stream a reporter writes to, asked by [`ColorChoice::enabled_for`]
+/// whether it is attached to a terminal.
+pub trait Terminal {
+    fn is_terminal(&self) -> bool;
+}
+
+impl Terminal for io::Stdout {
+    fn is_terminal(&self) -> bool {
+        crate::clear::stdout_is_terminal()
+    }
+}
+
+impl Terminal for io::Stderr {
+    fn is_terminal(&self) -> bool {
+        io::IsTerminal::is_terminal(self)
+    }
+}
+
+impl Terminal for File {
+    fn is_terminal(&self) -> bool {
+        io::IsTerminal::is_terminal(self)
+    }
+}
+
+impl Terminal for Vec<u8> {
+    fn is_terminal(&self) -> bool {
+        false
+    }
+}
+
+/// Where [`ColorWriter`] is within an escape sequence, carried across
+/// writes so a sequence split between two of them is still dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Escape {
+    None,
+    /// Just saw `ESC`.
+    Start,
+    /// In a CSI sequence (`ESC [ ...`), which ends at a byte in `@..=~`.
+    Csi,
+    /// In an OSC sequence (`ESC ] ...`), which ends at `BEL` or `ESC \`.
+    Osc,
+    /// Saw `ESC` inside an OSC sequence; a `\` ends it.
+    OscEsc,
+}
+
+/// Wraps a reporter's writer and, with color disabled, drops ANSI escape
+/// sequences from everything written through it. Reporters color text
+/// unconditionally with `owo_colors`; filtering here turns every
+/// `.green()` / `.dimmed()` into a no-op without touching the call sites.
+pub struct ColorWriter<W> {
+    inner: W,
+    color: bool,
+    escape: Escape,
+}
+
+impl<W> ColorWriter<W> {
+    /// A writer that passes color through until [`set_color`] says
+    /// otherwise.
+    ///
+    /// [`set_color`]: ColorWriter::set_color
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            color: true,
+            escape: Escape::None,
+        }
+    }
+
+    pub fn set_color(&mut self, color: bool) {
+        self.color = color;
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: io::Write> io::Write for ColorWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.color {
+            return self.inner.write(buf);
+        }
+        let mut plain = Vec::with_capacity(buf.len());
+        for &byte in buf {
+            self.escape = match (self.escape, byte) {
+                (Escape::None, 0x1b) => Escape::Start,
+                (Escape::None, _) => {
+                    plain.push(byte);
+                    Escape::None
+                }
+                (Escape::Start, b'[') => Escape::Csi,
+                (Escape::Start, b']') => Escape::Osc,
+                (Escape::Csi, 0x40..=0x7e)
+                | (Escape::Osc, 0x07)
+                | (Escape::Start | Escape::OscEsc, _) => Escape::None,
+                (Escape::Osc, 0x1b) => Escape::OscEsc,
+                (state, _) => state,
+            };
+        }
+        self.inner.write_all(&plain)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use owo_colors::OwoColorize;
+
+    use super::*;
+
+    fn plain(chunks: &[&str]) -> String {
+        let mut writer = ColorWriter::new(Vec::new());
+        writer.set_color(false);
+        for chunk in chunks {
+            writer.write_all(chunk.as_bytes()).expect("write");
+        }
+        String::from_utf8(writer.into_inner()).expect("utf-8")
+    }
+
+    #[test]
+    fn strips_styles_when_color_is_off() {
+        let styled = format!("{} {}", "ok".green().bold(), "[1ms]".dimmed());
+        assert_eq!(plain(&[&styled]), "ok [1ms]");
+    }
+
+    #[test]
+    fn strips_sequences_split_across_writes() {
+        assert_eq!(plain(&["a\x1b", "[3", "1mb\x1b[0m", "c"]), "abc");
+        assert_eq!(
+            plain(&["\x1b]8;;https://x\x1b", "\\link\x1b]8;;\x07"]),
+            "link"
+        );
+    }
+
+    #[test]
+    fn passes_color_through_by_default() {
+        let mut writer = ColorWriter::new(Vec::new());
+        let styled = "ok".green().to_string();
+        writer.write_all(styled.as_bytes()).expect("write");
+        assert_eq!(writer.get_ref(), styled.as_bytes());
+    }
+}
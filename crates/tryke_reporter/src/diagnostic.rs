@@ -4,7 +4,13 @@ use miette::{
     Diagnostic, GraphicalReportHandler, GraphicalTheme, LabeledSpan, MietteError,
     MietteSpanContents, NamedSource, Report, Severity, SourceCode, SourceSpan, SpanContents,
 };
-use tryke_types::Assertion;
+use owo_colors::OwoColorize;
+use similar::{ChangeTag, TextDiff};
+use tryke_types::{Assertion, LogRecord, TestItem};
+
+/// Single-line values shorter than this keep the compact `expected x,
+/// received y` labels; a diff of `2` against `3` adds nothing.
+const DIFF_MIN_LEN: usize = 40;
 
 /// Wraps a source string with a line offset so miette reports the correct
 /// line number instead of always starting at line 1.
@@ -70,10 +76,18 @@ impl Diagnostic for AssertionReport {
 pub fn render_assertion(test_file: Option<&str>, assertion: &Assertion, buf: &mut String) {
     let handler = GraphicalReportHandler::new_themed(assertion_theme());
     render_one(&handler, test_file, assertion, buf);
+    render_value_diff(&assertion.expected, &assertion.received, true, buf);
 }
 
-pub fn render_assertions(test_file: Option<&str>, assertions: &[Assertion], buf: &mut String) {
-    render_assertions_themed(test_file, assertions, assertion_theme(), true, buf);
+/// Render the diagnostic for each failed assertion, followed by an
+/// `N/N assertions failed` footer unless `footer` is off.
+pub fn render_assertions(
+    test_file: Option<&str>,
+    assertions: &[Assertion],
+    footer: bool,
+    buf: &mut String,
+) {
+    render_assertions_themed(test_file, assertions, assertion_theme(), true, footer, buf);
 }
 
 pub fn render_assertions_plain(
@@ -86,6 +100,7 @@ pub fn render_assertions_plain(
         assertions,
         GraphicalTheme::unicode_nocolor(),
         false,
+        true,
         buf,
     );
 }
@@ -162,6 +177,7 @@ fn render_assertions_themed(
     assertions: &[Assertion],
     theme: GraphicalTheme,
     highlight: bool,
+    footer: bool,
     buf: &mut String,
 ) {
     use fmt::Write;
@@ -179,14 +195,119 @@ fn render_assertions_themed(
 
     for assertion in assertions {
         render_one(&handler, test_file, assertion, buf);
+        render_value_diff(&assertion.expected, &assertion.received, highlight, buf);
     }
 
-    let _ = writeln!(
-        buf,
-        "  {}/{} assertions failed",
-        assertions.len(),
-        assertions.len()
-    );
+    if footer {
+        let _ = writeln!(
+            buf,
+            "  {}/{} assertions failed",
+            assertions.len(),
+            assertions.len()
+        );
+    }
+}
+
+/// Decode a Python string repr (`'a\\nb'`) to its text, for line diffs.
+/// Returns `None` for anything that isn't a plain quoted string; escapes
+/// other than quotes, backslashes, `\\n`, `\\r`, and `\\t` are kept as
+/// written.
+fn unquote_repr(value: &str) -> Option<String> {
+    let quote = value.chars().next().filter(|c| matches!(c, '\'' | '"'))?;
+    let inner = value
+        .strip_prefix(quote)?
+        .strip_suffix(quote)
+        .filter(|_| value.len() >= 2)?;
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('t') => out.push('\t'),
+            Some(escaped @ ('\\' | '\'' | '"')) => out.push(escaped),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    Some(out)
+}
+
+/// Render a `-`/`+` diff of a failed assertion's values beneath its
+/// diagnostic. Strings spanning several lines are diffed line by line;
+/// other values are diffed character by character once both are at least
+/// [`DIFF_MIN_LEN`] long. Shorter values render nothing, leaving the
+/// compact labels on their own.
+fn render_value_diff(expected: &str, received: &str, color: bool, buf: &mut String) {
+    use fmt::Write;
+
+    if let (Some(expected), Some(received)) = (unquote_repr(expected), unquote_repr(received))
+        && (expected.contains('\n') || received.contains('\n'))
+    {
+        let diff = TextDiff::from_lines(&expected, &received);
+        write_diff_legend(color, buf);
+        for change in diff.iter_all_changes() {
+            let line = change.value().trim_end_matches(['\r', '\n']);
+            let _ = match change.tag() {
+                ChangeTag::Equal => writeln!(buf, "    {line}"),
+                ChangeTag::Delete if color => writeln!(buf, "  {}", format!("- {line}").red()),
+                ChangeTag::Insert if color => writeln!(buf, "  {}", format!("+ {line}").green()),
+                ChangeTag::Delete => writeln!(buf, "  - {line}"),
+                ChangeTag::Insert => writeln!(buf, "  + {line}"),
+            };
+        }
+        let _ = writeln!(buf);
+        return;
+    }
+    if expected.chars().count() < DIFF_MIN_LEN || received.chars().count() < DIFF_MIN_LEN {
+        return;
+    }
+    let diff = TextDiff::from_chars(expected, received);
+    let mut old = String::new();
+    let mut new = String::new();
+    for change in diff.iter_all_changes() {
+        let value = change.value();
+        match change.tag() {
+            ChangeTag::Equal => {
+                old.push_str(value);
+                new.push_str(value);
+            }
+            ChangeTag::Delete if color => {
+                let _ = write!(old, "{}", value.red().bold().underline());
+            }
+            ChangeTag::Insert if color => {
+                let _ = write!(new, "{}", value.green().bold().underline());
+            }
+            ChangeTag::Delete => old.push_str(value),
+            ChangeTag::Insert => new.push_str(value),
+        }
+    }
+    write_diff_legend(color, buf);
+    if color {
+        let _ = writeln!(buf, "  {} {old}", "-".red());
+        let _ = writeln!(buf, "  {} {new}", "+".green());
+    } else {
+        let _ = writeln!(buf, "  - {old}");
+        let _ = writeln!(buf, "  + {new}");
+    }
+    let _ = writeln!(buf);
+}
+
+fn write_diff_legend(color: bool, buf: &mut String) {
+    use fmt::Write;
+
+    if color {
+        let _ = writeln!(buf, "  {} {}", "- expected".red(), "+ received".green());
+    } else {
+        let _ = writeln!(buf, "  - expected + received");
+    }
 }
 
 /// Extract the last frame from a Python traceback string.
@@ -229,6 +350,48 @@ pub fn render_failure_message(
     }
 }
 
+/// Fill a `[tool.tryke] failure_template` for one failed assertion.
+/// Recognized placeholders are `{id}`, `{name}`, `{file}`, `{line}`,
+/// `{expression}`, `{expected}`, and `{received}`; any other braces are
+/// kept as written. `{file}` is `file`, the test's file as the reporter
+/// prints it.
+#[must_use]
+pub fn render_failure_template(
+    template: &str,
+    test: &TestItem,
+    file: Option<&str>,
+    assertion: &Assertion,
+) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let open = &rest[start..];
+        let filled = open.find('}').and_then(|end| {
+            let value = match &open[1..end] {
+                "id" => test.id(),
+                "name" => test.display_label(),
+                "file" => file.unwrap_or_default().to_owned(),
+                "line" => assertion.line.to_string(),
+                "expression" => assertion.expression.clone(),
+                "expected" => assertion.expected.clone(),
+                "received" => assertion.received.clone(),
+                _ => return None,
+            };
+            Some((value, end))
+        });
+        if let Some((value, end)) = filled {
+            out.push_str(&value);
+            rest = &open[end + 1..];
+        } else {
+            out.push('{');
+            rest = &open[1..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
 /// Render an error message for worker/infrastructure errors.
 pub fn render_error_message(message: &str, buf: &mut String) {
     use fmt::Write;
@@ -248,6 +411,23 @@ pub fn render_captured_output(label: &str, content: &str, buf: &mut String) {
     }
 }
 
+/// Render captured `logging` records under a `logs` rule, one per line
+/// with the level in a fixed-width column. Continuation lines of a
+/// multi-line message line up under the message.
+pub fn render_captured_logs(records: &[LogRecord], buf: &mut String) {
+    use fmt::Write;
+
+    let _ = writeln!(buf, "  ── logs ──");
+    for record in records {
+        let mut lines = record.message.lines();
+        let first = lines.next().unwrap_or_default();
+        let _ = writeln!(buf, "    {:<8} {first}", record.level);
+        for line in lines {
+            let _ = writeln!(buf, "    {:<8} {line}", "");
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -276,7 +456,7 @@ mod tests {
     fn single_assertion() {
         let assertions = vec![make_assertion("assert_eq!(a, 2)", 14, 1)];
         let mut buf = String::new();
-        render_assertions(Some("tests/math.py"), &assertions, &mut buf);
+        render_assertions(Some("tests/math.py"), &assertions, true, &mut buf);
 
         assert!(buf.contains("assertion failed"));
         assert!(buf.contains("expected 2, received 3"));
@@ -284,6 +464,69 @@ mod tests {
         assert!(buf.contains("1/1 assertions failed"));
     }
 
+    #[test]
+    fn multi_line_strings_render_a_line_diff() {
+        let mut assertion = make_assertion("expect(doc).to_equal(want)", 7, 3);
+        assertion.expected = "'title\\nfirst line\\nlast line'".into();
+        assertion.received = "'title\\nfirst lime\\nlast line'".into();
+        let mut buf = String::new();
+        render_assertions_plain(Some("tests/doc.py"), &[assertion], &mut buf);
+
+        let diff: Vec<&str> = buf
+            .lines()
+            .skip_while(|line| !line.contains("- expected + received"))
+            .skip(1)
+            .take(4)
+            .collect();
+        assert_eq!(
+            diff,
+            [
+                "    title",
+                "  - first line",
+                "  + first lime",
+                "    last line"
+            ],
+            "{buf}"
+        );
+    }
+
+    #[test]
+    fn long_strings_render_a_character_diff() {
+        let mut assertion = make_assertion("expect(msg).to_equal(want)", 7, 3);
+        assertion.expected = "'the quick brown fox jumps over the lazy dog'".into();
+        assertion.received = "'the quick brown fox leaps over the lazy dog'".into();
+        let mut buf = String::new();
+        render_assertions_plain(Some("tests/msg.py"), &[assertion.clone()], &mut buf);
+        assert!(
+            buf.contains("  - 'the quick brown fox jumps over the lazy dog'\n"),
+            "{buf}"
+        );
+        assert!(
+            buf.contains("  + 'the quick brown fox leaps over the lazy dog'\n"),
+            "{buf}"
+        );
+
+        let mut colored = String::new();
+        render_assertions(Some("tests/msg.py"), &[assertion], true, &mut colored);
+        let plus = colored
+            .lines()
+            .rfind(|line| line.ends_with("over the lazy dog'"))
+            .expect("received diff line");
+        assert!(line_has_sgr_code(plus, "4"), "{plus:?}");
+    }
+
+    #[test]
+    fn short_values_skip_the_diff() {
+        let mut buf = String::new();
+        render_assertions_plain(
+            Some("tests/math.py"),
+            &[make_assertion("assert_eq!(a, 2)", 14, 1)],
+            &mut buf,
+        );
+        assert!(buf.contains("expected 2, received 3"));
+        assert!(!buf.contains("- expected + received"), "{buf}");
+    }
+
     #[test]
     fn multiple_assertions() {
         let assertions = vec![
@@ -291,7 +534,7 @@ mod tests {
             make_assertion("assert_eq!(b, 5)", 14, 1),
         ];
         let mut buf = String::new();
-        render_assertions(Some("tests/math.py"), &assertions, &mut buf);
+        render_assertions(Some("tests/math.py"), &assertions, true, &mut buf);
 
         assert!(buf.contains("2/2 assertions failed"));
     }
@@ -299,7 +542,7 @@ mod tests {
     #[test]
     fn empty_assertions() {
         let mut buf = String::new();
-        render_assertions(Some("tests/math.py"), &[], &mut buf);
+        render_assertions(Some("tests/math.py"), &[], true, &mut buf);
 
         assert!(buf.is_empty());
     }
@@ -308,7 +551,7 @@ mod tests {
     fn no_file_path() {
         let assertions = vec![make_assertion("assert_eq!(x, 1)", 14, 1)];
         let mut buf = String::new();
-        render_assertions(None, &assertions, &mut buf);
+        render_assertions(None, &assertions, true, &mut buf);
 
         assert!(buf.contains("<unknown>"));
         assert!(buf.contains("assertion failed"));
@@ -327,7 +570,7 @@ mod tests {
             expected_arg_span: None,
         }];
         let mut buf = String::new();
-        render_assertions(Some("tests/math.py"), &assertions, &mut buf);
+        render_assertions(Some("tests/math.py"), &assertions, true, &mut buf);
 
         assert!(buf.contains("helpers/utils.py"));
         assert!(!buf.contains("tests/math.py"));
@@ -506,7 +749,7 @@ ZeroDivisionError: division by zero";
             expected_arg_span: Some((19, 1)),
         }];
         let mut buf = String::new();
-        render_assertions(None, &assertions, &mut buf);
+        render_assertions(None, &assertions, true, &mut buf);
 
         let received_line = buf
             .lines()
@@ -1,12 +1,14 @@
-use std::io;
+use std::io::{self, Write};
 
 use owo_colors::OwoColorize;
 use tryke_types::{DiscoveryWarning, RunSummary, TestItem, TestOutcome, TestResult};
 
 use crate::Reporter;
+use crate::color::{ColorChoice, ColorWriter, Terminal};
+use crate::relative::DisplayPaths;
 
 pub struct DotReporter<W: io::Write = io::Stdout> {
-    writer: W,
+    writer: ColorWriter<W>,
     watch_hint: Option<String>,
     clear_armed: bool,
     clear_enabled: bool,
@@ -14,17 +16,20 @@ pub struct DotReporter<W: io::Write = io::Stdout> {
     /// header until the first content event so an armed cycle keeps
     /// the previous run on screen through worker warmup.
     header_pending: bool,
+    /// How file paths print; re-rooted under `--relative-to`.
+    display_paths: DisplayPaths,
 }
 
 impl DotReporter {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            writer: io::stdout(),
+            writer: ColorWriter::new(io::stdout()),
             watch_hint: None,
             clear_armed: false,
             clear_enabled: crate::clear::stdout_is_terminal(),
             header_pending: false,
+            display_paths: DisplayPaths::default(),
         }
     }
 }
@@ -38,16 +43,35 @@ impl Default for DotReporter {
 impl<W: io::Write> DotReporter<W> {
     pub fn with_writer(writer: W) -> Self {
         Self {
-            writer,
+            writer: ColorWriter::new(writer),
             watch_hint: None,
             clear_armed: false,
             clear_enabled: false,
             header_pending: false,
+            display_paths: DisplayPaths::default(),
         }
     }
 
     pub fn into_writer(self) -> W {
-        self.writer
+        self.writer.into_inner()
+    }
+
+    /// Drop ANSI colors and styles from the output when `color` is false.
+    #[must_use]
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.writer.set_color(color);
+        self
+    }
+
+    /// Color the output as `choice` says for this reporter's own writer,
+    /// so `--color auto` colors a terminal but not a file.
+    #[must_use]
+    pub fn with_color_choice(self, choice: ColorChoice) -> Self
+    where
+        W: Terminal,
+    {
+        let color = choice.enabled_for(self.writer.get_ref());
+        self.with_color(color)
     }
 
     fn flush_pending_clear(&mut self) {
@@ -113,7 +137,13 @@ impl<W: io::Write> Reporter for DotReporter<W> {
     }
 
     fn on_collect_complete(&mut self, tests: &[TestItem]) {
-        crate::summary::write_collect_list(&mut self.writer, "tryke test", tests);
+        crate::summary::write_collect_list(
+            &mut self.writer,
+            "tryke test",
+            tests,
+            &self.display_paths,
+            false,
+        );
     }
 
     fn set_watch_hint(&mut self, hint: Option<String>) {
@@ -124,6 +154,10 @@ impl<W: io::Write> Reporter for DotReporter<W> {
         self.clear_armed = true;
     }
 
+    fn set_display_paths(&mut self, paths: &DisplayPaths) {
+        self.display_paths = paths.clone();
+    }
+
     fn on_discovery_warning(&mut self, warning: &DiscoveryWarning) {
         self.flush_pending_header();
         let _ = writeln!(
@@ -163,7 +197,7 @@ mod tests {
     }
 
     fn output(r: &DotReporter<Vec<u8>>) -> String {
-        String::from_utf8_lossy(&r.writer).into_owned()
+        String::from_utf8_lossy(r.writer.get_ref()).into_owned()
     }
 
     fn test_item(name: &str) -> TestItem {
@@ -177,44 +211,59 @@ mod tests {
     #[test]
     fn on_test_complete_passed() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: test_item("t"),
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+        r.on_test_complete(&TestResult::new(
+            test_item("t"),
+            TestOutcome::Passed,
+            Duration::from_millis(1),
+        ));
         assert!(output(&r).contains('.'));
     }
 
+    #[test]
+    fn color_disabled_writes_no_escape_codes() {
+        let mut r = reporter().with_color(false);
+        let result = TestResult::new(
+            test_item("t"),
+            TestOutcome::Failed {
+                message: "bad".into(),
+                traceback: None,
+                assertions: vec![],
+                executed_lines: vec![],
+            },
+            Duration::from_millis(1),
+        );
+        r.on_run_start(&[]);
+        r.on_test_complete(&result);
+        r.on_run_complete(&RunSummary::from_results(&[result]));
+        let out = output(&r);
+        assert!(out.contains('F') && out.contains("tryke test"), "{out}");
+        assert!(!out.contains('\x1b'), "{out:?}");
+    }
+
     #[test]
     fn on_test_complete_failed() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: test_item("t"),
-            outcome: TestOutcome::Failed {
+        r.on_test_complete(&TestResult::new(
+            test_item("t"),
+            TestOutcome::Failed {
                 message: "bad".into(),
                 traceback: None,
                 assertions: vec![],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(1),
+        ));
         assert!(output(&r).contains('F'));
     }
 
     #[test]
     fn on_test_complete_skipped() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: test_item("t"),
-            outcome: TestOutcome::Skipped { reason: None },
-            duration: Duration::from_millis(0),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+        r.on_test_complete(&TestResult::new(
+            test_item("t"),
+            TestOutcome::Skipped { reason: None },
+            Duration::from_millis(0),
+        ));
         assert!(output(&r).contains('s'));
     }
 
@@ -225,15 +274,8 @@ mod tests {
             passed: 3,
             failed: 1,
             skipped: 2,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(100),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         let out = output(&r);
         assert!(out.contains("FAIL"));
@@ -275,26 +317,15 @@ mod tests {
         let tests = vec![test_item("test_one")];
 
         r.on_run_start(&tests);
-        r.on_test_complete(&TestResult {
-            test: tests[0].clone(),
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(10),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+        r.on_test_complete(&TestResult::new(
+            tests[0].clone(),
+            TestOutcome::Passed,
+            Duration::from_millis(10),
+        ));
         r.on_run_complete(&RunSummary {
             passed: 1,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(10),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
 
         let out = output(&r);
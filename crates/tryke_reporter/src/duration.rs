@@ -27,6 +27,20 @@ pub fn format_duration(d: Duration) -> String {
     }
 }
 
+/// Format a duration that may be far below a millisecond, as benchmark
+/// timings often are.
+///
+/// Sub-millisecond durations render as microseconds (`3.25µs`);
+/// anything longer falls back to [`format_duration`].
+#[must_use]
+pub fn format_precise_duration(d: Duration) -> String {
+    if d < Duration::from_millis(1) {
+        format!("{:.2}µs", d.as_secs_f64() * 1_000_000.0)
+    } else {
+        format_duration(d)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -60,4 +74,16 @@ mod tests {
     fn rounds_with_carry_through_minutes() {
         assert_eq!(format_duration(Duration::from_millis(119_999)), "2:00.00");
     }
+
+    #[test]
+    fn precise_sub_millisecond_uses_microseconds() {
+        assert_eq!(
+            format_precise_duration(Duration::from_nanos(3_250)),
+            "3.25µs"
+        );
+        assert_eq!(
+            format_precise_duration(Duration::from_millis(48)),
+            "48.00ms"
+        );
+    }
 }
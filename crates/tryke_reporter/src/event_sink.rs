@@ -0,0 +1,159 @@
+//! Writers for streaming reporter events to an external listener
+//! (`tryke test --reporter json --event-socket <PATH>`), so an IDE can
+//! consume NDJSON live instead of scraping stdout.
+
+use std::fs::OpenOptions;
+use std::io;
+use std::path::Path;
+
+/// Open `path` as an event sink.
+///
+/// On Unix a FIFO is opened for writing; anything else is treated as a
+/// Unix domain socket and connected to. Elsewhere (e.g. Windows named
+/// pipes such as `\\.\pipe\tryke`) the path is opened as a writable file.
+///
+/// # Errors
+/// Returns the underlying I/O error when the pipe or socket can't be
+/// opened — typically because no listener is attached yet.
+pub fn connect(path: &Path) -> io::Result<Box<dyn io::Write + Send>> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::FileTypeExt;
+        use std::os::unix::net::UnixStream;
+
+        if std::fs::metadata(path)?.file_type().is_fifo() {
+            return open_fifo(path).map(|fifo| Box::new(fifo) as Box<dyn io::Write + Send>);
+        }
+        Ok(Box::new(UnixStream::connect(path)?))
+    }
+    #[cfg(not(unix))]
+    {
+        Ok(Box::new(OpenOptions::new().write(true).open(path)?))
+    }
+}
+
+/// Open a FIFO for writing without waiting for a reader. A blocking open
+/// would hang the run until one attaches, so the open is non-blocking and
+/// fails with `ENXIO` when nobody is listening. Writes block as usual
+/// once it succeeds.
+#[cfg(unix)]
+fn open_fifo(path: &Path) -> io::Result<std::fs::File> {
+    use std::os::fd::AsRawFd;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let fifo = OpenOptions::new()
+        .write(true)
+        .custom_flags(libc::O_NONBLOCK)
+        .open(path)
+        .map_err(|err| {
+            if err.raw_os_error() == Some(libc::ENXIO) {
+                io::Error::new(io::ErrorKind::NotConnected, "no reader on the pipe")
+            } else {
+                err
+            }
+        })?;
+    let fd = fifo.as_raw_fd();
+    // SAFETY: `fd` is an open descriptor owned by `fifo` for the whole
+    // call, and F_GETFL/F_SETFL only read and set its status flags.
+    let cleared = unsafe {
+        let flags = libc::fcntl(fd, libc::F_GETFL);
+        flags >= 0 && libc::fcntl(fd, libc::F_SETFL, flags & !libc::O_NONBLOCK) >= 0
+    };
+    if !cleared {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(fifo)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixListener;
+    use std::time::Duration;
+
+    use tryke_types::{RunSummary, TestItem, TestOutcome, TestResult};
+
+    use super::*;
+    use crate::{JSONReporter, Reporter};
+
+    #[test]
+    fn json_events_stream_to_unix_socket() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("events.sock");
+        let listener = UnixListener::bind(&path).expect("bind listener");
+
+        let writer = connect(&path).expect("connect to listener");
+        let (stream, _) = listener.accept().expect("accept");
+
+        let test = TestItem {
+            name: "test_add".into(),
+            module_path: "tests.math".into(),
+            ..Default::default()
+        };
+        let mut reporter = JSONReporter::with_writer(writer);
+        reporter.on_run_start(std::slice::from_ref(&test));
+        reporter.on_test_complete(&TestResult::new(
+            test,
+            TestOutcome::Passed,
+            Duration::from_millis(1),
+        ));
+        reporter.on_run_complete(&RunSummary::from_results(&[]));
+        drop(reporter);
+
+        let events: Vec<serde_json::Value> = BufReader::new(stream)
+            .lines()
+            .map(|line| serde_json::from_str(&line.expect("read line")).expect("valid json"))
+            .collect();
+        let kinds: Vec<_> = events.iter().map(|e| e["event"].clone()).collect();
+        assert_eq!(kinds, vec!["run_start", "test_complete", "run_complete"]);
+    }
+
+    #[test]
+    fn fifo_without_reader_fails_instead_of_blocking() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("events.fifo");
+        let status = std::process::Command::new("mkfifo")
+            .arg(&path)
+            .status()
+            .expect("run mkfifo");
+        assert!(status.success());
+
+        let err = connect(&path).err().expect("no reader attached");
+        assert_eq!(err.kind(), io::ErrorKind::NotConnected);
+    }
+
+    #[test]
+    fn fifo_with_reader_streams_events() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("events.fifo");
+        let status = std::process::Command::new("mkfifo")
+            .arg(&path)
+            .status()
+            .expect("run mkfifo");
+        assert!(status.success());
+
+        let reader = {
+            let path = path.clone();
+            std::thread::spawn(move || std::fs::read_to_string(path).expect("read fifo"))
+        };
+        // The reader's open blocks until a writer shows up, and ours fails
+        // until the reader is there, so retry briefly.
+        let mut writer = (0..200)
+            .find_map(|_| {
+                connect(&path).ok().or_else(|| {
+                    std::thread::sleep(Duration::from_millis(10));
+                    None
+                })
+            })
+            .expect("reader attached");
+        writer.write_all(b"{}\n").expect("write event");
+        drop(writer);
+        assert_eq!(reader.join().expect("reader thread"), "{}\n");
+    }
+
+    #[test]
+    fn connect_fails_without_listener() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        assert!(connect(&dir.path().join("missing.sock")).is_err());
+    }
+}
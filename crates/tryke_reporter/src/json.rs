@@ -1,12 +1,22 @@
 use std::io;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use serde::Serialize;
-use tryke_types::{DiscoveryWarning, RunSummary, TestItem, TestResult};
+use tryke_types::{
+    Assertion, AssertionEvent, BenchmarkStats, DiscoveryWarning, LogRecord, RunSummary,
+    SelectionSummary, TestItem, TestOutcome, TestResult,
+};
 
 use crate::Reporter;
 
 pub struct JSONReporter<W: io::Write = io::Stdout> {
     writer: W,
+    selection: Option<SelectionSummary>,
+    assertions: bool,
+    minimal: bool,
+    run_id: Option<String>,
+    heartbeat: Option<Duration>,
+    final_report: Option<Vec<TestResult>>,
 }
 
 impl JSONReporter {
@@ -14,6 +24,12 @@ impl JSONReporter {
     pub fn new() -> Self {
         Self {
             writer: io::stdout(),
+            selection: None,
+            assertions: false,
+            minimal: false,
+            run_id: None,
+            heartbeat: None,
+            final_report: None,
         }
     }
 }
@@ -26,31 +42,255 @@ impl Default for JSONReporter {
 
 impl<W: io::Write> JSONReporter<W> {
     pub fn with_writer(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer,
+            selection: None,
+            assertions: false,
+            minimal: false,
+            run_id: None,
+            heartbeat: None,
+            final_report: None,
+        }
     }
 
     pub fn into_writer(self) -> W {
         self.writer
     }
 
+    /// Also emit an `assertion` event for each `expect(...)` as it's
+    /// evaluated, ahead of its test's `test_complete`.
+    #[must_use]
+    pub fn with_assertions(mut self, enabled: bool) -> Self {
+        self.assertions = enabled;
+        self
+    }
+
+    /// Serialize assertions as `expression`, `line`, `expected`, and
+    /// `received` only, leaving out the source span and file.
+    #[must_use]
+    pub fn with_minimal_assertions(mut self, enabled: bool) -> Self {
+        self.minimal = enabled;
+        self
+    }
+
+    /// Emit a `heartbeat` event every `interval` for each test that is
+    /// still running, so a consumer can tell a slow test from a stalled
+    /// stream.
+    #[must_use]
+    pub fn with_heartbeat(mut self, interval: Option<Duration>) -> Self {
+        self.heartbeat = interval;
+        self
+    }
+
+    /// Close a finished run with a `run_report` event carrying every
+    /// result and the summary, for consumers that only read the last
+    /// line. Results are kept in memory until then.
+    #[must_use]
+    pub fn with_final_report(mut self, enabled: bool) -> Self {
+        self.final_report = enabled.then(Vec::new);
+        self
+    }
+
+    /// Tag every event with `run_id` so artifacts from the same run can
+    /// be matched up.
+    #[must_use]
+    pub fn with_run_id(mut self, run_id: Option<String>) -> Self {
+        self.run_id = run_id;
+        self
+    }
+
     fn write_event<T: Serialize>(&mut self, event: &T) {
-        // Ignore write errors to match typical reporter behavior
-        let _ = serde_json::to_writer(&mut self.writer, event)
-            .map_err(io::Error::from)
-            .and_then(|()| self.writer.write_all(b"\n"));
+        let event = Tagged {
+            event,
+            timestamp: epoch_millis(SystemTime::now()),
+            run_id: self.run_id.as_deref(),
+        };
+        // Serialize first so each event reaches an unbuffered sink (an
+        // `--event-socket`) as one write. Ignore write errors to match
+        // typical reporter behavior
+        let Ok(mut line) = serde_json::to_vec(&event) else {
+            return;
+        };
+        line.push(b'\n');
+        let _ = self.writer.write_all(&line);
     }
 }
 
+/// Milliseconds since the Unix epoch, or 0 for a clock set before it.
+fn epoch_millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |since| {
+        u64::try_from(since.as_millis()).unwrap_or(u64::MAX)
+    })
+}
+
+/// An event with the time it was written and the reporter's run id
+/// appended.
+#[derive(Serialize)]
+struct Tagged<'a, T> {
+    #[serde(flatten)]
+    event: &'a T,
+    /// When the event was written, in milliseconds since the Unix epoch.
+    timestamp: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    run_id: Option<&'a str>,
+}
+
 #[derive(Serialize)]
 struct RunStartEvent<'a> {
     event: &'static str,
     tests: &'a [TestItem],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collected: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    selected: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cached: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
 }
 
 #[derive(Serialize)]
 struct TestCompleteEvent<'a> {
     event: &'static str,
-    result: &'a TestResult,
+    result: ResultView<'a>,
+}
+
+#[derive(Serialize)]
+struct AssertionEventLine<'a> {
+    event: &'static str,
+    id: String,
+    passed: bool,
+    assertion: AssertionView<'a>,
+}
+
+#[derive(Serialize)]
+struct HeartbeatEvent {
+    event: &'static str,
+    id: String,
+    elapsed_ms: u64,
+}
+
+/// The `--json-minimal` shape of an [`Assertion`].
+#[derive(Serialize)]
+struct MinimalAssertion<'a> {
+    expression: &'a str,
+    line: usize,
+    expected: &'a str,
+    received: &'a str,
+}
+
+impl<'a> From<&'a Assertion> for MinimalAssertion<'a> {
+    fn from(assertion: &'a Assertion) -> Self {
+        Self {
+            expression: &assertion.expression,
+            line: assertion.line,
+            expected: &assertion.expected,
+            received: &assertion.received,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum AssertionView<'a> {
+    Full(&'a Assertion),
+    Minimal(MinimalAssertion<'a>),
+}
+
+impl<'a> AssertionView<'a> {
+    fn new(assertion: &'a Assertion, minimal: bool) -> Self {
+        if minimal {
+            Self::Minimal(assertion.into())
+        } else {
+            Self::Full(assertion)
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ResultView<'a> {
+    Full(&'a TestResult),
+    Minimal(MinimalResult<'a>),
+}
+
+impl<'a> ResultView<'a> {
+    fn new(result: &'a TestResult, minimal: bool) -> Self {
+        if !minimal {
+            return Self::Full(result);
+        }
+        Self::Minimal(MinimalResult {
+            test: &result.test,
+            outcome: OutcomeView::new(&result.outcome),
+            duration: result.duration,
+            stdout: &result.stdout,
+            stderr: &result.stderr,
+            logs: &result.logs,
+            benchmark: result.benchmark.as_ref(),
+            timed_out: result.timed_out,
+            slow: result.slow,
+        })
+    }
+}
+
+/// Mirrors [`TestResult`]'s serialized form with the outcome's
+/// assertions swapped for [`MinimalAssertion`]s.
+#[derive(Serialize)]
+struct MinimalResult<'a> {
+    test: &'a TestItem,
+    outcome: OutcomeView<'a>,
+    #[serde(with = "tryke_types::serde_millis")]
+    duration: Duration,
+    stdout: &'a str,
+    stderr: &'a str,
+    #[serde(skip_serializing_if = "<[LogRecord]>::is_empty")]
+    logs: &'a [LogRecord],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    benchmark: Option<&'a BenchmarkStats>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    timed_out: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    slow: bool,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum OutcomeView<'a> {
+    Failed {
+        status: &'static str,
+        detail: MinimalFailure<'a>,
+    },
+    Other(&'a TestOutcome),
+}
+
+impl<'a> OutcomeView<'a> {
+    fn new(outcome: &'a TestOutcome) -> Self {
+        match outcome {
+            TestOutcome::Failed {
+                message,
+                traceback,
+                assertions,
+                executed_lines,
+            } => Self::Failed {
+                status: "failed",
+                detail: MinimalFailure {
+                    message,
+                    traceback: traceback.as_deref(),
+                    assertions: assertions.iter().map(MinimalAssertion::from).collect(),
+                    executed_lines,
+                },
+            },
+            other => Self::Other(other),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct MinimalFailure<'a> {
+    message: &'a str,
+    traceback: Option<&'a str>,
+    assertions: Vec<MinimalAssertion<'a>>,
+    executed_lines: &'a [u32],
 }
 
 #[derive(Serialize)]
@@ -59,6 +299,19 @@ struct RunCompleteEvent<'a> {
     summary: &'a RunSummary,
 }
 
+#[derive(Serialize)]
+struct RunReportEvent<'a> {
+    event: &'static str,
+    results: Vec<ResultView<'a>>,
+    summary: &'a RunSummary,
+}
+
+#[derive(Serialize)]
+struct RunInterruptedEvent<'a> {
+    event: &'static str,
+    summary: &'a RunSummary,
+}
+
 #[derive(Serialize)]
 struct CollectCompleteEvent<'a> {
     event: &'static str,
@@ -73,24 +326,78 @@ struct DiscoveryWarningEvent<'a> {
 
 impl<W: io::Write> Reporter for JSONReporter<W> {
     fn on_run_start(&mut self, tests: &[TestItem]) {
+        if let Some(results) = &mut self.final_report {
+            results.clear();
+        }
+        let selection = self.selection.take();
         self.write_event(&RunStartEvent {
             event: "run_start",
             tests,
+            collected: selection.as_ref().map(|s| s.collected),
+            selected: selection.as_ref().map(|s| s.selected),
+            cached: selection.as_ref().and_then(|s| s.cached),
+            seed: selection.as_ref().and_then(|s| s.seed),
         });
     }
 
     fn on_test_complete(&mut self, result: &TestResult) {
         self.write_event(&TestCompleteEvent {
             event: "test_complete",
-            result,
+            result: ResultView::new(result, self.minimal),
+        });
+        if let Some(results) = &mut self.final_report {
+            results.push(result.clone());
+        }
+    }
+
+    fn on_assertion(&mut self, event: &AssertionEvent) {
+        self.write_event(&AssertionEventLine {
+            event: "assertion",
+            id: event.test.id(),
+            passed: event.passed,
+            assertion: AssertionView::new(&event.assertion, self.minimal),
+        });
+    }
+
+    fn streams_assertions(&self) -> bool {
+        self.assertions
+    }
+
+    fn on_heartbeat(&mut self, test: &TestItem, elapsed: Duration) {
+        self.write_event(&HeartbeatEvent {
+            event: "heartbeat",
+            id: test.id(),
+            elapsed_ms: u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX),
         });
     }
 
+    fn heartbeat_interval(&self) -> Option<Duration> {
+        self.heartbeat
+    }
+
     fn on_run_complete(&mut self, summary: &RunSummary) {
         self.write_event(&RunCompleteEvent {
             event: "run_complete",
             summary,
         });
+        if let Some(results) = self.final_report.take() {
+            self.write_event(&RunReportEvent {
+                event: "run_report",
+                results: results
+                    .iter()
+                    .map(|result| ResultView::new(result, self.minimal))
+                    .collect(),
+                summary,
+            });
+            self.final_report = Some(Vec::new());
+        }
+    }
+
+    fn on_run_interrupted(&mut self, summary: &RunSummary) {
+        self.write_event(&RunInterruptedEvent {
+            event: "run_interrupted",
+            summary,
+        });
     }
 
     fn on_collect_complete(&mut self, tests: &[TestItem]) {
@@ -100,6 +407,10 @@ impl<W: io::Write> Reporter for JSONReporter<W> {
         });
     }
 
+    fn set_selection(&mut self, selection: &SelectionSummary) {
+        self.selection = Some(selection.clone());
+    }
+
     fn on_discovery_warning(&mut self, warning: &DiscoveryWarning) {
         self.write_event(&DiscoveryWarningEvent {
             event: "discovery_warning",
@@ -147,20 +458,87 @@ mod tests {
         assert_eq!(lines[0]["tests"][0]["module_path"], "tests.mod_a");
     }
 
+    #[test]
+    fn every_event_carries_a_wall_clock_timestamp() {
+        let before = epoch_millis(SystemTime::now());
+        let mut r = reporter();
+        r.on_collect_complete(&[]);
+        r.on_run_start(&[]);
+        r.on_run_complete(&RunSummary::from_results(&[]));
+        let after = epoch_millis(SystemTime::now());
+
+        let lines = output_lines(&r);
+        assert_eq!(lines.len(), 3);
+        for line in &lines {
+            let timestamp = line["timestamp"]
+                .as_u64()
+                .expect("timestamp is epoch millis");
+            assert!((before..=after).contains(&timestamp), "{line}");
+        }
+    }
+
+    #[test]
+    fn run_id_tags_run_start_and_run_complete() {
+        let mut r = reporter().with_run_id(Some("20261016T120000.000-42".into()));
+        r.on_run_start(&[]);
+        r.on_run_complete(&RunSummary::from_results(&[]));
+        let lines = output_lines(&r);
+
+        assert_eq!(lines[0]["event"], "run_start");
+        assert_eq!(lines[1]["event"], "run_complete");
+        assert_eq!(lines[0]["run_id"], "20261016T120000.000-42");
+        assert_eq!(lines[0]["run_id"], lines[1]["run_id"]);
+
+        let mut r = reporter();
+        r.on_run_start(&[]);
+        assert!(output_lines(&r)[0].get("run_id").is_none());
+    }
+
+    #[test]
+    fn run_start_includes_selection_counts() {
+        let mut r = reporter();
+        r.set_selection(&SelectionSummary {
+            collected: 3,
+            selected: 1,
+            via: Some("-k add".into()),
+            cached: Some(2),
+            seed: Some(7),
+        });
+        r.on_run_start(&[TestItem {
+            name: "test_add".into(),
+            module_path: "tests.math".into(),
+            ..Default::default()
+        }]);
+        let lines = output_lines(&r);
+        assert_eq!(lines[0]["collected"], 3);
+        assert_eq!(lines[0]["selected"], 1);
+        assert_eq!(lines[0]["cached"], 2);
+        assert_eq!(lines[0]["seed"], 7);
+    }
+
+    #[test]
+    fn run_start_omits_selection_counts_when_unset() {
+        let mut r = reporter();
+        r.on_run_start(&[]);
+        let lines = output_lines(&r);
+        assert!(lines[0].get("collected").is_none());
+        assert!(lines[0].get("selected").is_none());
+        assert!(lines[0].get("cached").is_none());
+        assert!(lines[0].get("seed").is_none());
+    }
+
     #[test]
     fn emits_test_complete_passed() {
         let mut r = reporter();
-        let result = TestResult {
-            test: TestItem {
+        let result = TestResult::new(
+            TestItem {
                 name: "test_add".into(),
                 module_path: "tests.math".into(),
                 ..Default::default()
             },
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(42),
-            stdout: String::new(),
-            stderr: String::new(),
-        };
+            TestOutcome::Passed,
+            Duration::from_millis(42),
+        );
 
         r.on_test_complete(&result);
         let lines = output_lines(&r);
@@ -169,27 +547,105 @@ mod tests {
         assert_eq!(lines[0]["event"], "test_complete");
         assert_eq!(lines[0]["result"]["test"]["name"], "test_add");
         assert_eq!(lines[0]["result"]["outcome"]["status"], "passed");
+        assert_eq!(lines[0]["result"]["duration"], 42.0);
     }
 
     #[test]
-    fn emits_test_complete_failed() {
+    fn test_complete_flags_slow_tests() {
+        let mut result = TestResult {
+            slow: true,
+            ..TestResult::new(
+                TestItem {
+                    name: "test_add".into(),
+                    module_path: "tests.math".into(),
+                    ..Default::default()
+                },
+                TestOutcome::Passed,
+                Duration::from_millis(900),
+            )
+        };
+        for minimal in [false, true] {
+            let mut r = reporter().with_minimal_assertions(minimal);
+            r.on_test_complete(&result);
+            assert_eq!(output_lines(&r)[0]["result"]["slow"], true);
+        }
+        result.slow = false;
         let mut r = reporter();
-        let result = TestResult {
+        r.on_test_complete(&result);
+        assert!(output_lines(&r)[0]["result"].get("slow").is_none());
+    }
+
+    #[test]
+    fn emits_assertion_events_only_when_enabled() {
+        assert!(!reporter().streams_assertions());
+        let mut r = reporter().with_assertions(true);
+        assert!(r.streams_assertions());
+        r.on_assertion(&AssertionEvent {
             test: TestItem {
+                name: "test_add".into(),
+                module_path: "tests.math".into(),
+                file_path: Some("tests/math.py".into()),
+                ..Default::default()
+            },
+            passed: false,
+            assertion: Assertion {
+                expression: "expect(x).to_equal(2)".into(),
+                file: None,
+                line: 7,
+                span_offset: 0,
+                span_length: 21,
+                expected: "2".into(),
+                received: "3".into(),
+                expected_arg_span: None,
+            },
+        });
+        let lines = output_lines(&r);
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0]["event"], "assertion");
+        assert_eq!(lines[0]["id"], "tests/math.py::test_add");
+        assert_eq!(lines[0]["passed"], false);
+        assert_eq!(lines[0]["assertion"]["line"], 7);
+        assert_eq!(lines[0]["assertion"]["received"], "3");
+    }
+
+    #[test]
+    fn heartbeat_carries_running_test_id() {
+        assert_eq!(reporter().heartbeat_interval(), None);
+        let mut r = reporter().with_heartbeat(Some(Duration::from_secs(15)));
+        assert_eq!(r.heartbeat_interval(), Some(Duration::from_secs(15)));
+        let test = TestItem {
+            name: "test_slow".into(),
+            module_path: "tests.math".into(),
+            file_path: Some("tests/math.py".into()),
+            ..Default::default()
+        };
+        r.on_heartbeat(&test, Duration::from_secs(45));
+        let lines = output_lines(&r);
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0]["event"], "heartbeat");
+        assert_eq!(lines[0]["id"], "tests/math.py::test_slow");
+        assert_eq!(lines[0]["elapsed_ms"], 45_000);
+    }
+
+    #[test]
+    fn emits_test_complete_failed() {
+        let mut r = reporter();
+        let result = TestResult::new(
+            TestItem {
                 name: "test_sub".into(),
                 module_path: "tests.math".into(),
                 ..Default::default()
             },
-            outcome: TestOutcome::Failed {
+            TestOutcome::Failed {
                 message: "expected 1, got 2".into(),
                 traceback: None,
                 assertions: vec![],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(5),
-            stdout: String::new(),
-            stderr: String::new(),
-        };
+            Duration::from_millis(5),
+        );
 
         r.on_test_complete(&result);
         let lines = output_lines(&r);
@@ -201,22 +657,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn minimal_assertions_omit_span_fields() {
+        let assertion = Assertion {
+            expression: "expect(x).to_equal(2)".into(),
+            file: Some("tests/math.py".into()),
+            line: 7,
+            span_offset: 4,
+            span_length: 21,
+            expected: "2".into(),
+            received: "3".into(),
+            expected_arg_span: Some((19, 1)),
+        };
+        let mut r = reporter()
+            .with_assertions(true)
+            .with_minimal_assertions(true);
+        r.on_assertion(&AssertionEvent {
+            test: TestItem {
+                name: "test_add".into(),
+                module_path: "tests.math".into(),
+                ..Default::default()
+            },
+            passed: false,
+            assertion: assertion.clone(),
+        });
+        r.on_test_complete(&TestResult::new(
+            TestItem {
+                name: "test_add".into(),
+                module_path: "tests.math".into(),
+                ..Default::default()
+            },
+            TestOutcome::Failed {
+                message: "expected 2, got 3".into(),
+                traceback: None,
+                assertions: vec![assertion],
+                executed_lines: vec![7],
+            },
+            Duration::from_millis(5),
+        ));
+        let lines = output_lines(&r);
+
+        let streamed = &lines[0]["assertion"];
+        let outcome = &lines[1]["result"]["outcome"];
+        assert_eq!(outcome["status"], "failed");
+        assert_eq!(outcome["detail"]["message"], "expected 2, got 3");
+        assert_eq!(outcome["detail"]["executed_lines"][0], 7);
+        assert_eq!(lines[1]["result"]["duration"], 5.0);
+        for assertion in [streamed, &outcome["detail"]["assertions"][0]] {
+            assert_eq!(assertion["expression"], "expect(x).to_equal(2)");
+            assert_eq!(assertion["line"], 7);
+            assert_eq!(assertion["expected"], "2");
+            assert_eq!(assertion["received"], "3");
+            for field in ["span_offset", "span_length", "file", "expected_arg_span"] {
+                assert!(assertion.get(field).is_none(), "{field} in {assertion}");
+            }
+        }
+    }
+
     #[test]
     fn emits_test_complete_skipped() {
         let mut r = reporter();
-        let result = TestResult {
-            test: TestItem {
+        let result = TestResult::new(
+            TestItem {
                 name: "test_skip".into(),
                 module_path: "tests.misc".into(),
                 ..Default::default()
             },
-            outcome: TestOutcome::Skipped {
+            TestOutcome::Skipped {
                 reason: Some("not implemented".into()),
             },
-            duration: Duration::from_millis(0),
-            stdout: String::new(),
-            stderr: String::new(),
-        };
+            Duration::from_millis(0),
+        );
 
         r.on_test_complete(&result);
         let lines = output_lines(&r);
@@ -235,15 +746,8 @@ mod tests {
             passed: 5,
             failed: 1,
             skipped: 2,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(100),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         };
 
         r.on_run_complete(&summary);
@@ -254,6 +758,70 @@ mod tests {
         assert_eq!(lines[0]["summary"]["passed"], 5);
         assert_eq!(lines[0]["summary"]["failed"], 1);
         assert_eq!(lines[0]["summary"]["skipped"], 2);
+        assert_eq!(lines[0]["summary"]["duration"], 100.0);
+    }
+
+    #[test]
+    fn final_report_closes_the_run_with_every_result() {
+        let result = |name: &str, outcome| {
+            TestResult::new(
+                TestItem {
+                    name: name.into(),
+                    module_path: "tests.math".into(),
+                    ..Default::default()
+                },
+                outcome,
+                Duration::from_millis(3),
+            )
+        };
+        let results = vec![
+            result("test_add", TestOutcome::Passed),
+            result(
+                "test_sub",
+                TestOutcome::Skipped {
+                    reason: Some("later".into()),
+                },
+            ),
+        ];
+        let mut r = reporter().with_final_report(true);
+        r.on_run_start(&[]);
+        for result in &results {
+            r.on_test_complete(result);
+        }
+        r.on_run_complete(&RunSummary::from_results(&results));
+        let lines = output_lines(&r);
+
+        assert_eq!(lines.len(), 5);
+        let report = &lines[4];
+        assert_eq!(report["event"], "run_report");
+        assert_eq!(report["results"].as_array().map(Vec::len), Some(2));
+        assert_eq!(report["results"][0]["test"]["name"], "test_add");
+        assert_eq!(report["results"][1]["test"]["name"], "test_sub");
+        assert_eq!(report["results"][1]["outcome"]["status"], "skipped");
+        assert_eq!(report["summary"]["passed"], 1);
+        assert_eq!(report["summary"]["skipped"], 1);
+
+        let mut r = reporter();
+        r.on_run_complete(&RunSummary::from_results(&results));
+        assert_eq!(output_lines(&r).len(), 1);
+    }
+
+    #[test]
+    fn emits_run_interrupted_with_partial_counts() {
+        let mut r = reporter();
+        r.on_run_interrupted(&RunSummary {
+            passed: 3,
+            failed: 1,
+            duration: Duration::from_millis(40),
+            file_count: 2,
+            ..Default::default()
+        });
+        let lines = output_lines(&r);
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0]["event"], "run_interrupted");
+        assert_eq!(lines[0]["summary"]["passed"], 3);
+        assert_eq!(lines[0]["summary"]["failed"], 1);
     }
 
     #[test]
@@ -261,20 +829,13 @@ mod tests {
         let mut r = reporter();
         r.on_run_complete(&RunSummary {
             passed: 2,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(10),
-            discovery_duration: None,
-            test_duration: None,
             file_count: 1,
-            start_time: None,
             changed_selection: Some(tryke_types::ChangedSelectionSummary {
                 changed_files: 3,
                 affected_tests: 2,
             }),
+            ..Default::default()
         });
 
         let lines = output_lines(&r);
@@ -304,40 +865,28 @@ mod tests {
 
         r.on_run_start(&tests);
 
-        r.on_test_complete(&TestResult {
-            test: tests[0].clone(),
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(10),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+        r.on_test_complete(&TestResult::new(
+            tests[0].clone(),
+            TestOutcome::Passed,
+            Duration::from_millis(10),
+        ));
 
-        r.on_test_complete(&TestResult {
-            test: tests[1].clone(),
-            outcome: TestOutcome::Failed {
+        r.on_test_complete(&TestResult::new(
+            tests[1].clone(),
+            TestOutcome::Failed {
                 message: "boom".into(),
                 traceback: None,
                 assertions: vec![],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(5),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(5),
+        ));
 
         r.on_run_complete(&RunSummary {
             passed: 1,
             failed: 1,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(15),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
 
         let lines = output_lines(&r);
@@ -371,6 +920,27 @@ mod tests {
         assert_eq!(lines[0]["tests"][1]["name"], "test_sub");
     }
 
+    #[test]
+    fn collect_complete_exposes_decorator_kwargs() {
+        let mut r = reporter();
+        let tests = vec![TestItem {
+            name: "test_add".into(),
+            module_path: "tests.math".into(),
+            decorator_kwargs: vec![
+                ("priority".into(), "1".into()),
+                ("flaky".into(), "True".into()),
+            ],
+            ..Default::default()
+        }];
+        r.on_collect_complete(&tests);
+        let lines = output_lines(&r);
+        let kwargs = &lines[0]["tests"][0]["decorator_kwargs"];
+        assert_eq!(kwargs[0][0], "priority");
+        assert_eq!(kwargs[0][1], "1");
+        assert_eq!(kwargs[1][0], "flaky");
+        assert_eq!(kwargs[1][1], "True");
+    }
+
     #[test]
     fn emits_discovery_warning() {
         use std::path::PathBuf;
@@ -396,15 +966,15 @@ mod tests {
     #[test]
     fn failed_with_assertions_includes_data() {
         let mut r = reporter();
-        let result = TestResult {
-            test: TestItem {
+        let result = TestResult::new(
+            TestItem {
                 name: "test_add".into(),
                 module_path: "tests.math".into(),
                 file_path: Some("tests/math.py".into()),
                 line_number: Some(10),
                 ..Default::default()
             },
-            outcome: TestOutcome::Failed {
+            TestOutcome::Failed {
                 message: "assertion failed".into(),
                 traceback: None,
                 assertions: vec![Assertion {
@@ -419,10 +989,8 @@ mod tests {
                 }],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(5),
-            stdout: String::new(),
-            stderr: String::new(),
-        };
+            Duration::from_millis(5),
+        );
 
         r.on_test_complete(&result);
         let lines = output_lines(&r);
@@ -4,9 +4,42 @@ use tryke_types::{RunSummary, TestItem, TestOutcome, TestResult};
 
 use crate::Reporter;
 
+/// CI environment variables copied into the suite's `<properties>` when
+/// set, keyed by their own name. Covers the commit, branch, and job
+/// identifiers of the common hosted CI services.
+const CI_PROPERTY_VARS: [&str; 13] = [
+    "GITHUB_SHA",
+    "GITHUB_REF_NAME",
+    "GITHUB_RUN_ID",
+    "GITHUB_REPOSITORY",
+    "CI_COMMIT_SHA",
+    "CI_COMMIT_REF_NAME",
+    "CI_JOB_URL",
+    "BUILDKITE_COMMIT",
+    "BUILDKITE_BRANCH",
+    "BUILDKITE_BUILD_URL",
+    "CIRCLE_SHA1",
+    "CIRCLE_BRANCH",
+    "CIRCLE_BUILD_URL",
+];
+
+/// Run-level properties read from [`CI_PROPERTY_VARS`], skipping unset or
+/// empty variables.
+#[must_use]
+pub fn ci_properties() -> Vec<(String, String)> {
+    CI_PROPERTY_VARS
+        .iter()
+        .filter_map(|&name| {
+            let value = std::env::var(name).ok().filter(|value| !value.is_empty())?;
+            Some((name.to_owned(), value))
+        })
+        .collect()
+}
+
 pub struct JUnitReporter<W: io::Write = io::Stdout> {
     writer: W,
     results: Vec<TestResult>,
+    properties: Vec<(String, String)>,
 }
 
 impl JUnitReporter {
@@ -15,6 +48,7 @@ impl JUnitReporter {
         Self {
             writer: io::stdout(),
             results: Vec::new(),
+            properties: Vec::new(),
         }
     }
 }
@@ -30,7 +64,32 @@ impl<W: io::Write> JUnitReporter<W> {
         Self {
             writer,
             results: Vec::new(),
+            properties: Vec::new(),
+        }
+    }
+
+    /// Emit `properties` as name/value pairs in a suite-level
+    /// `<properties>` block, in order.
+    #[must_use]
+    pub fn with_properties(mut self, properties: Vec<(String, String)>) -> Self {
+        self.properties = properties;
+        self
+    }
+
+    fn write_properties(&mut self) {
+        if self.properties.is_empty() {
+            return;
         }
+        let _ = writeln!(self.writer, "  <properties>");
+        for (name, value) in &self.properties {
+            let _ = writeln!(
+                self.writer,
+                r#"    <property name="{}" value="{}"/>"#,
+                xml_escape(name),
+                xml_escape(value)
+            );
+        }
+        let _ = writeln!(self.writer, "  </properties>");
     }
 
     pub fn into_writer(self) -> W {
@@ -53,6 +112,35 @@ fn xml_escape(s: &str) -> String {
     out
 }
 
+/// Suite-level attribute counts, tallied per emitted child element so
+/// `failures`, `errors`, and `skipped` always match the `<failure>`,
+/// `<error>`, and `<skipped>` elements below them. The schema
+/// keeps errors (the test couldn't run to completion) apart from
+/// failures (an assertion didn't hold).
+#[derive(Debug, Default, PartialEq, Eq)]
+struct SuiteCounts {
+    failures: usize,
+    errors: usize,
+    skipped: usize,
+}
+
+impl SuiteCounts {
+    fn from_results(results: &[TestResult]) -> Self {
+        let mut counts = Self::default();
+        for result in results {
+            match &result.outcome {
+                TestOutcome::Passed => {}
+                TestOutcome::Failed { .. } | TestOutcome::XPassed => counts.failures += 1,
+                TestOutcome::Error { .. } => counts.errors += 1,
+                TestOutcome::Skipped { .. }
+                | TestOutcome::XFailed { .. }
+                | TestOutcome::Todo { .. } => counts.skipped += 1,
+            }
+        }
+        counts
+    }
+}
+
 impl<W: io::Write> Reporter for JUnitReporter<W> {
     fn on_run_start(&mut self, _tests: &[TestItem]) {}
 
@@ -69,6 +157,7 @@ impl<W: io::Write> Reporter for JUnitReporter<W> {
             r#"<testsuite name="tryke" tests="{0}" failures="0" errors="0" skipped="{0}" time="0.000">"#,
             tests.len()
         );
+        self.write_properties();
         for test in tests {
             let name = xml_escape(&test.display_label());
             let classname = if test.groups.is_empty() {
@@ -91,20 +180,20 @@ impl<W: io::Write> Reporter for JUnitReporter<W> {
     }
 
     fn on_run_complete(&mut self, summary: &RunSummary) {
-        let total = summary.passed
-            + summary.failed
-            + summary.skipped
-            + summary.errors
-            + summary.xfailed
-            + summary.todo;
+        let counts = SuiteCounts::from_results(&self.results);
         let suite_time = summary.duration.as_secs_f64();
 
         let _ = writeln!(self.writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
         let _ = writeln!(
             self.writer,
             r#"<testsuite name="tryke" tests="{}" failures="{}" errors="{}" skipped="{}" time="{:.3}">"#,
-            total, summary.failed, summary.errors, summary.skipped, suite_time
+            self.results.len(),
+            counts.failures,
+            counts.errors,
+            counts.skipped,
+            suite_time
         );
+        self.write_properties();
 
         for result in &self.results {
             let display = result.test.display_label();
@@ -195,45 +284,32 @@ mod tests {
     }
 
     fn run_suite(r: &mut JUnitReporter<Vec<u8>>) {
-        r.on_test_complete(&TestResult {
-            test: test_item("test_add", "tests.math"),
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(12),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
-        r.on_test_complete(&TestResult {
-            test: test_item("test_sub", "tests.math"),
-            outcome: TestOutcome::Failed {
+        r.on_test_complete(&TestResult::new(
+            test_item("test_add", "tests.math"),
+            TestOutcome::Passed,
+            Duration::from_millis(12),
+        ));
+        r.on_test_complete(&TestResult::new(
+            test_item("test_sub", "tests.math"),
+            TestOutcome::Failed {
                 message: "assertion failed: 3 - 1 == 3".into(),
                 traceback: None,
                 assertions: vec![],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(5),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
-        r.on_test_complete(&TestResult {
-            test: test_item("test_skip", "tests.parser"),
-            outcome: TestOutcome::Skipped { reason: None },
-            duration: Duration::from_millis(0),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(5),
+        ));
+        r.on_test_complete(&TestResult::new(
+            test_item("test_skip", "tests.parser"),
+            TestOutcome::Skipped { reason: None },
+            Duration::from_millis(0),
+        ));
         r.on_run_complete(&RunSummary {
             passed: 1,
             failed: 1,
             skipped: 1,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(15),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
     }
 
@@ -313,34 +389,85 @@ mod tests {
         assert!(out.contains("</testsuite>"));
     }
 
+    #[test]
+    fn errors_are_counted_apart_from_failures() {
+        let mut r = reporter();
+        r.on_test_complete(&TestResult::new(
+            test_item("test_fails", "tests.misc"),
+            TestOutcome::Failed {
+                message: "expected 1, got 2".into(),
+                traceback: None,
+                assertions: vec![],
+                executed_lines: vec![],
+            },
+            Duration::from_millis(1),
+        ));
+        r.on_test_complete(&TestResult::new(
+            test_item("test_errors", "tests.misc"),
+            TestOutcome::Error {
+                message: "worker crashed".into(),
+            },
+            Duration::from_millis(1),
+        ));
+        r.on_run_complete(&RunSummary {
+            failed: 1,
+            errors: 1,
+            duration: Duration::from_millis(2),
+            ..Default::default()
+        });
+        let out = output(&r);
+        assert!(out.contains(r#"tests="2" failures="1" errors="1" skipped="0""#));
+        assert_eq!(out.matches("<failure ").count(), 1);
+        assert_eq!(out.matches("<error ").count(), 1);
+        assert!(out.contains(r#"<failure message="expected 1, got 2"/>"#));
+        assert!(out.contains(r#"<error message="worker crashed"/>"#));
+    }
+
+    #[test]
+    fn properties_block_lists_run_metadata() {
+        let mut r = reporter().with_properties(vec![
+            ("git_sha".into(), "abc123".into()),
+            ("job_url".into(), "https://ci.example/job?id=1&try=2".into()),
+        ]);
+        run_suite(&mut r);
+        let out = output(&r);
+        let expected = concat!(
+            "  <properties>\n",
+            "    <property name=\"git_sha\" value=\"abc123\"/>\n",
+            "    <property name=\"job_url\" value=\"https://ci.example/job?id=1&amp;try=2\"/>\n",
+            "  </properties>\n",
+        );
+        assert!(out.contains(expected), "{out}");
+        let suite = out.find("<testsuite").expect("testsuite");
+        let properties = out.find("<properties>").expect("properties");
+        let first_case = out.find("<testcase").expect("testcase");
+        assert!(suite < properties && properties < first_case);
+    }
+
+    #[test]
+    fn no_properties_block_without_properties() {
+        let mut r = reporter();
+        run_suite(&mut r);
+        assert!(!output(&r).contains("<properties>"));
+    }
+
     #[test]
     fn xml_escape_in_failure_message() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: test_item("test_amp", "tests.misc"),
-            outcome: TestOutcome::Failed {
+        r.on_test_complete(&TestResult::new(
+            test_item("test_amp", "tests.misc"),
+            TestOutcome::Failed {
                 message: "a & b".into(),
                 traceback: None,
                 assertions: vec![],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(1),
+        ));
         r.on_run_complete(&RunSummary {
-            passed: 0,
             failed: 1,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(1),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(output(&r).contains("a &amp; b"));
     }
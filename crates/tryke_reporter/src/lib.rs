@@ -1,7 +1,9 @@
 pub mod clear;
+pub mod color;
 pub mod diagnostic;
 pub mod dot;
 pub mod duration;
+pub mod event_sink;
 pub mod json;
 pub mod junit;
 pub mod live;
@@ -9,11 +11,16 @@ pub mod llm;
 pub mod next;
 #[cfg(feature = "terminal")]
 pub mod progress;
+pub mod relative;
 pub mod reporter;
+pub mod results_dir;
 pub mod sugar;
 pub mod summary;
 pub mod text;
+#[cfg(feature = "webhook")]
+pub mod webhook;
 
+pub use color::ColorChoice;
 pub use dot::DotReporter;
 pub use json::JSONReporter;
 pub use junit::JUnitReporter;
@@ -21,6 +28,10 @@ pub use llm::LlmReporter;
 pub use next::NextReporter;
 #[cfg(feature = "terminal")]
 pub use progress::ProgressReporter;
+pub use relative::DisplayPaths;
 pub use reporter::Reporter;
+pub use results_dir::ResultsDirReporter;
 pub use sugar::SugarReporter;
 pub use text::{TextReporter, Verbosity};
+#[cfg(feature = "webhook")]
+pub use webhook::WebhookReporter;
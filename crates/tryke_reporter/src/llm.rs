@@ -5,9 +5,12 @@ use tryke_types::{DiscoveryError, RunSummary, TestItem, TestOutcome, TestResult}
 use crate::Reporter;
 use crate::diagnostic::render_assertions_plain;
 use crate::duration::format_duration;
+use crate::relative::DisplayPaths;
 
 pub struct LlmReporter<W: io::Write = io::Stdout> {
     writer: W,
+    /// How file paths print; re-rooted under `--relative-to`.
+    display_paths: DisplayPaths,
 }
 
 impl LlmReporter {
@@ -15,6 +18,7 @@ impl LlmReporter {
     pub fn new() -> Self {
         Self {
             writer: io::stdout(),
+            display_paths: DisplayPaths::default(),
         }
     }
 }
@@ -27,7 +31,10 @@ impl Default for LlmReporter {
 
 impl<W: io::Write> LlmReporter<W> {
     pub fn with_writer(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer,
+            display_paths: DisplayPaths::default(),
+        }
     }
 
     pub fn into_writer(self) -> W {
@@ -35,8 +42,8 @@ impl<W: io::Write> LlmReporter<W> {
     }
 }
 
-fn write_location<W: io::Write>(writer: &mut W, result: &TestResult) {
-    if let Some(path) = &result.test.file_path {
+fn write_location<W: io::Write>(writer: &mut W, paths: &DisplayPaths, result: &TestResult) {
+    if let Some(path) = paths.file(&result.test) {
         if let Some(line) = result.test.line_number {
             let _ = write!(writer, " ({path}:{line})", path = path.display());
         } else {
@@ -64,14 +71,13 @@ impl<W: io::Write> Reporter for LlmReporter<W> {
                 ..
             } => {
                 let _ = write!(self.writer, "FAIL {display}");
-                write_location(&mut self.writer, result);
+                write_location(&mut self.writer, &self.display_paths, result);
                 let _ = writeln!(self.writer);
 
                 if !assertions.is_empty() {
-                    let test_file = result
-                        .test
-                        .file_path
-                        .as_ref()
+                    let test_file = self
+                        .display_paths
+                        .file(&result.test)
                         .map(|p| p.to_string_lossy().into_owned());
                     let mut buf = String::new();
                     render_assertions_plain(test_file.as_deref(), assertions, &mut buf);
@@ -102,13 +108,13 @@ impl<W: io::Write> Reporter for LlmReporter<W> {
             }
             TestOutcome::XPassed => {
                 let _ = write!(self.writer, "XPASS {display}");
-                write_location(&mut self.writer, result);
+                write_location(&mut self.writer, &self.display_paths, result);
                 let _ = writeln!(self.writer);
                 let _ = writeln!(self.writer, "  unexpected pass");
             }
             TestOutcome::Error { message } => {
                 let _ = write!(self.writer, "ERROR {display}");
-                write_location(&mut self.writer, result);
+                write_location(&mut self.writer, &self.display_paths, result);
                 let _ = writeln!(self.writer);
                 let _ = writeln!(self.writer, "  {message}");
 
@@ -161,10 +167,14 @@ impl<W: io::Write> Reporter for LlmReporter<W> {
         let _ = writeln!(
             self.writer,
             "DISCOVERY ERROR: {}: {}",
-            error.file_path.display(),
+            self.display_paths.path(&error.file_path).display(),
             error.message
         );
     }
+
+    fn set_display_paths(&mut self, paths: &DisplayPaths) {
+        self.display_paths = paths.clone();
+    }
 }
 
 #[cfg(test)]
@@ -205,46 +215,40 @@ mod tests {
     #[test]
     fn passed_produces_no_output() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: test_item("test_add"),
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+        r.on_test_complete(&TestResult::new(
+            test_item("test_add"),
+            TestOutcome::Passed,
+            Duration::from_millis(1),
+        ));
         assert!(output(&r).is_empty());
     }
 
     #[test]
     fn skipped_produces_no_output() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: test_item("test_skip"),
-            outcome: TestOutcome::Skipped {
+        r.on_test_complete(&TestResult::new(
+            test_item("test_skip"),
+            TestOutcome::Skipped {
                 reason: Some("not ready".into()),
             },
-            duration: Duration::from_millis(0),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(0),
+        ));
         assert!(output(&r).is_empty());
     }
 
     #[test]
     fn failed_shows_name_and_location() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: test_item_with_file("test_sub", "tests/math.py", 15),
-            outcome: TestOutcome::Failed {
+        r.on_test_complete(&TestResult::new(
+            test_item_with_file("test_sub", "tests/math.py", 15),
+            TestOutcome::Failed {
                 message: "bad".into(),
                 traceback: None,
                 assertions: vec![],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(5),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(5),
+        ));
         let out = output(&r);
         assert!(out.contains("FAIL test_sub (tests/math.py:15)"));
         assert!(out.contains("bad"));
@@ -253,9 +257,9 @@ mod tests {
     #[test]
     fn failed_with_assertions_plain_text() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: test_item_with_file("test_add", "tests/math.py", 10),
-            outcome: TestOutcome::Failed {
+        r.on_test_complete(&TestResult::new(
+            test_item_with_file("test_add", "tests/math.py", 10),
+            TestOutcome::Failed {
                 message: "assertion failed".into(),
                 traceback: None,
                 assertions: vec![Assertion {
@@ -270,10 +274,8 @@ mod tests {
                 }],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(5),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(5),
+        ));
         let out = output(&r);
         assert!(out.contains("FAIL test_add"));
         assert!(out.contains("received 3"));
@@ -285,18 +287,16 @@ mod tests {
     fn failed_with_traceback_shows_full_traceback() {
         let traceback = "File \"tests/test_math.py\", line 10, in test_div\n  result = divide(1, 0)\nFile \"math_utils.py\", line 3, in divide\n  return a / b\nZeroDivisionError: division by zero";
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: test_item_with_file("test_div", "tests/test_math.py", 10),
-            outcome: TestOutcome::Failed {
+        r.on_test_complete(&TestResult::new(
+            test_item_with_file("test_div", "tests/test_math.py", 10),
+            TestOutcome::Failed {
                 message: "division by zero".into(),
                 traceback: Some(traceback.into()),
                 assertions: vec![],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(5),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(5),
+        ));
         let out = output(&r);
         assert!(out.contains("Traceback:"));
         assert!(out.contains("File \"tests/test_math.py\", line 10, in test_div"));
@@ -309,16 +309,18 @@ mod tests {
     fn failed_with_captured_output() {
         let mut r = reporter();
         r.on_test_complete(&TestResult {
-            test: test_item("test_out"),
-            outcome: TestOutcome::Failed {
-                message: "fail".into(),
-                traceback: None,
-                assertions: vec![],
-                executed_lines: vec![],
-            },
-            duration: Duration::from_millis(1),
             stdout: "debug output here".into(),
             stderr: "warning here".into(),
+            ..TestResult::new(
+                test_item("test_out"),
+                TestOutcome::Failed {
+                    message: "fail".into(),
+                    traceback: None,
+                    assertions: vec![],
+                    executed_lines: vec![],
+                },
+                Duration::from_millis(1),
+            )
         });
         let out = output(&r);
         assert!(out.contains("[stdout]"));
@@ -330,15 +332,13 @@ mod tests {
     #[test]
     fn error_shows_name_and_message() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: test_item_with_file("test_broken", "tests/broken.py", 1),
-            outcome: TestOutcome::Error {
+        r.on_test_complete(&TestResult::new(
+            test_item_with_file("test_broken", "tests/broken.py", 1),
+            TestOutcome::Error {
                 message: "worker spawn failed: No such file".into(),
             },
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(1),
+        ));
         let out = output(&r);
         assert!(out.contains("ERROR test_broken (tests/broken.py:1)"));
         assert!(out.contains("worker spawn failed: No such file"));
@@ -349,17 +349,8 @@ mod tests {
         let mut r = reporter();
         r.on_run_complete(&RunSummary {
             passed: 47,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(35),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         let out = output(&r);
         assert_eq!(out.trim(), "47 passed [35.00ms]");
@@ -370,17 +361,8 @@ mod tests {
         let mut r = reporter();
         r.on_run_complete(&RunSummary {
             passed: 1,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(65_500),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         let out = output(&r);
         assert_eq!(out.trim(), "1 passed [1:05.50]");
@@ -394,14 +376,8 @@ mod tests {
             failed: 2,
             skipped: 3,
             errors: 1,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(35),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         let out = output(&r);
         assert_eq!(
@@ -414,31 +390,20 @@ mod tests {
     fn no_ansi_codes_in_output() {
         let mut r = reporter();
         r.on_run_start(&[test_item("t")]);
-        r.on_test_complete(&TestResult {
-            test: test_item_with_file("test_fail", "tests/a.py", 1),
-            outcome: TestOutcome::Failed {
+        r.on_test_complete(&TestResult::new(
+            test_item_with_file("test_fail", "tests/a.py", 1),
+            TestOutcome::Failed {
                 message: "bad".into(),
                 traceback: None,
                 assertions: vec![],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(1),
+        ));
         r.on_run_complete(&RunSummary {
-            passed: 0,
             failed: 1,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(1),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         let out = output(&r);
         assert!(
@@ -473,18 +438,16 @@ mod tests {
     #[test]
     fn failed_without_file_path() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: test_item("test_no_file"),
-            outcome: TestOutcome::Failed {
+        r.on_test_complete(&TestResult::new(
+            test_item("test_no_file"),
+            TestOutcome::Failed {
                 message: "oops".into(),
                 traceback: None,
                 assertions: vec![],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(1),
+        ));
         let out = output(&r);
         assert!(out.starts_with("FAIL test_no_file\n"));
         assert!(!out.contains('('));
@@ -500,45 +463,32 @@ mod tests {
         ];
 
         r.on_run_start(&items);
-        r.on_test_complete(&TestResult {
-            test: items[0].clone(),
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(10),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
-        r.on_test_complete(&TestResult {
-            test: items[1].clone(),
-            outcome: TestOutcome::Failed {
+        r.on_test_complete(&TestResult::new(
+            items[0].clone(),
+            TestOutcome::Passed,
+            Duration::from_millis(10),
+        ));
+        r.on_test_complete(&TestResult::new(
+            items[1].clone(),
+            TestOutcome::Failed {
                 message: "bad".into(),
                 traceback: None,
                 assertions: vec![],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(5),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
-        r.on_test_complete(&TestResult {
-            test: items[2].clone(),
-            outcome: TestOutcome::Skipped { reason: None },
-            duration: Duration::from_millis(0),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(5),
+        ));
+        r.on_test_complete(&TestResult::new(
+            items[2].clone(),
+            TestOutcome::Skipped { reason: None },
+            Duration::from_millis(0),
+        ));
         r.on_run_complete(&RunSummary {
             passed: 1,
             failed: 1,
             skipped: 1,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(15),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
 
         let out = output(&r);
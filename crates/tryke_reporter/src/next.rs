@@ -17,6 +17,7 @@ use tryke_types::{DiscoveryWarning, RunSummary, TestItem, TestOutcome, TestResul
 use crate::Reporter;
 use crate::diagnostic::{render_assertions, render_error_message, render_failure_message};
 use crate::live::LiveArea;
+use crate::relative::DisplayPaths;
 use crate::summary;
 
 const BADGE_WIDTH: usize = 5;
@@ -51,6 +52,8 @@ pub struct NextReporter<W: Write = io::Stdout> {
     clear_armed: bool,
     clear_enabled: bool,
     header_pending: bool,
+    /// How file paths print; re-rooted under `--relative-to`.
+    display_paths: DisplayPaths,
 }
 
 impl NextReporter {
@@ -71,6 +74,7 @@ impl NextReporter {
             clear_armed: false,
             clear_enabled: crate::clear::stdout_is_terminal(),
             header_pending: false,
+            display_paths: DisplayPaths::default(),
         }
     }
 }
@@ -100,6 +104,7 @@ impl<W: Write> NextReporter<W> {
             clear_armed: false,
             clear_enabled: false,
             header_pending: false,
+            display_paths: DisplayPaths::default(),
         }
     }
 
@@ -300,14 +305,13 @@ impl<W: Write> Reporter for NextReporter<W> {
                 assertions,
                 ..
             } => {
-                let test_file = result
-                    .test
-                    .file_path
-                    .as_deref()
+                let test_file = self
+                    .display_paths
+                    .file(&result.test)
                     .map(|p| p.to_string_lossy().into_owned());
                 let mut buf = String::new();
                 if !assertions.is_empty() {
-                    render_assertions(test_file.as_deref(), assertions, &mut buf);
+                    render_assertions(test_file.as_deref(), assertions, true, &mut buf);
                 } else if !message.is_empty() {
                     render_failure_message(message, traceback.as_deref(), false, &mut buf);
                 }
@@ -336,7 +340,13 @@ impl<W: Write> Reporter for NextReporter<W> {
     }
 
     fn on_collect_complete(&mut self, tests: &[TestItem]) {
-        summary::write_collect_list(&mut self.writer, self.subcommand_label, tests);
+        summary::write_collect_list(
+            &mut self.writer,
+            self.subcommand_label,
+            tests,
+            &self.display_paths,
+            false,
+        );
     }
 
     fn set_subcommand_label(&mut self, label: &'static str) {
@@ -351,6 +361,10 @@ impl<W: Write> Reporter for NextReporter<W> {
         self.clear_armed = true;
     }
 
+    fn set_display_paths(&mut self, paths: &DisplayPaths) {
+        self.display_paths = paths.clone();
+    }
+
     fn on_discovery_warning(&mut self, warning: &DiscoveryWarning) {
         self.flush_pending_header();
         let line = format!(
@@ -396,18 +410,16 @@ mod tests {
     }
 
     fn passed(name: &str) -> TestResult {
-        TestResult {
-            test: TestItem {
+        TestResult::new(
+            TestItem {
                 name: name.into(),
                 module_path: "tests.m".into(),
                 file_path: Some(PathBuf::from(format!("tests/{name}.py"))),
                 ..Default::default()
             },
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(9),
-            stdout: String::new(),
-            stderr: String::new(),
-        }
+            TestOutcome::Passed,
+            Duration::from_millis(9),
+        )
     }
 
     #[test]
@@ -457,23 +469,21 @@ mod tests {
     #[test]
     fn fail_line_has_fail_badge_and_diagnostics() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(
+            TestItem {
                 name: "test_bad".into(),
                 module_path: "tests.m".into(),
                 file_path: Some(PathBuf::from("tests/m.py")),
                 ..Default::default()
             },
-            outcome: TestOutcome::Failed {
+            TestOutcome::Failed {
                 message: "expected 1, got 2".into(),
                 traceback: None,
                 assertions: vec![],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(5),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(5),
+        ));
         let out = output(r);
         assert!(out.contains("FAIL"));
         assert!(out.contains("test_bad"));
@@ -483,19 +493,17 @@ mod tests {
     #[test]
     fn skip_line_has_skip_badge_and_reason() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(
+            TestItem {
                 name: "test_skip".into(),
                 module_path: "tests.m".into(),
                 ..Default::default()
             },
-            outcome: TestOutcome::Skipped {
+            TestOutcome::Skipped {
                 reason: Some("not on linux".into()),
             },
-            duration: Duration::ZERO,
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::ZERO,
+        ));
         let out = output(r);
         assert!(out.contains("SKIP"));
         assert!(out.contains("not on linux"));
@@ -510,17 +518,8 @@ mod tests {
         r.on_test_complete(&passed("a"));
         r.on_run_complete(&RunSummary {
             passed: 1,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(10),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         let out = output(r);
         assert!(!out.contains("\x1b[2K"));
@@ -534,16 +533,8 @@ mod tests {
         r.on_run_complete(&RunSummary {
             passed: 3,
             failed: 1,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(50),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         let out = output(r);
         assert!(out.contains("FAIL"));
@@ -554,19 +545,17 @@ mod tests {
     #[test]
     fn case_label_appears_in_test_id() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(
+            TestItem {
                 name: "square".into(),
                 module_path: "tests.m".into(),
                 case_label: Some("zero".into()),
                 case_index: Some(0),
                 ..Default::default()
             },
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            TestOutcome::Passed,
+            Duration::from_millis(1),
+        ));
         let out = output(r);
         assert!(out.contains("square[zero]"), "out: {out}");
     }
@@ -574,14 +563,14 @@ mod tests {
     #[test]
     fn failed_with_assertion_renders_diagnostic() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(
+            TestItem {
                 name: "t".into(),
                 module_path: "tests.m".into(),
                 file_path: Some(PathBuf::from("tests/m.py")),
                 ..Default::default()
             },
-            outcome: TestOutcome::Failed {
+            TestOutcome::Failed {
                 message: "x".into(),
                 traceback: None,
                 assertions: vec![Assertion {
@@ -596,10 +585,8 @@ mod tests {
                 }],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(1),
+        ));
         let out = output(r);
         assert!(out.contains("expected 2, received 1"));
     }
@@ -1,7 +1,9 @@
 use std::io::{self, Write};
+use std::time::Duration;
 
 use tryke_types::{
-    DiscoveryError, DiscoveryWarning, RunSummary, TestItem, TestOutcome, TestResult,
+    AssertionEvent, DiscoveryError, DiscoveryWarning, RunSummary, SelectionSummary, TestItem,
+    TestOutcome, TestResult,
 };
 
 use crate::Reporter;
@@ -114,6 +116,22 @@ impl<R: Reporter> Reporter for ProgressReporter<R> {
         self.inner.on_test_complete(result);
     }
 
+    fn on_assertion(&mut self, event: &AssertionEvent) {
+        self.inner.on_assertion(event);
+    }
+
+    fn streams_assertions(&self) -> bool {
+        self.inner.streams_assertions()
+    }
+
+    fn on_heartbeat(&mut self, test: &TestItem, elapsed: Duration) {
+        self.inner.on_heartbeat(test, elapsed);
+    }
+
+    fn heartbeat_interval(&self) -> Option<Duration> {
+        self.inner.heartbeat_interval()
+    }
+
     fn on_run_complete(&mut self, summary: &RunSummary) {
         if self.has_failure {
             emit_osc(2, 0);
@@ -143,6 +161,10 @@ impl<R: Reporter> Reporter for ProgressReporter<R> {
         self.inner.set_watch_hint(hint);
     }
 
+    fn set_selection(&mut self, selection: &SelectionSummary) {
+        self.inner.set_selection(selection);
+    }
+
     fn arm_clear(&mut self) {
         self.inner.arm_clear();
     }
@@ -154,6 +176,10 @@ impl<R: Reporter> Reporter for ProgressReporter<R> {
     fn on_watch_results_cleared(&mut self, info: &crate::reporter::WatchIdleInfo<'_>) {
         self.inner.on_watch_results_cleared(info);
     }
+
+    fn set_display_paths(&mut self, paths: &crate::relative::DisplayPaths) {
+        self.inner.set_display_paths(paths);
+    }
 }
 
 #[cfg(test)]
@@ -213,44 +239,32 @@ mod tests {
         assert_eq!(reporter.total, 2);
         assert_eq!(reporter.completed, 0);
 
-        reporter.on_test_complete(&TestResult {
-            test: tests[0].clone(),
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(10),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+        reporter.on_test_complete(&TestResult::new(
+            tests[0].clone(),
+            TestOutcome::Passed,
+            Duration::from_millis(10),
+        ));
         assert_eq!(reporter.completed, 1);
         assert!(!reporter.has_failure);
 
-        reporter.on_test_complete(&TestResult {
-            test: tests[1].clone(),
-            outcome: TestOutcome::Failed {
+        reporter.on_test_complete(&TestResult::new(
+            tests[1].clone(),
+            TestOutcome::Failed {
                 message: "bad".into(),
                 traceback: None,
                 assertions: vec![],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(5),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(5),
+        ));
         assert_eq!(reporter.completed, 2);
         assert!(reporter.has_failure);
 
         reporter.on_run_complete(&RunSummary {
             passed: 1,
             failed: 1,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(15),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(reporter.inner.completed);
         assert_eq!(reporter.inner.results.len(), 2);
@@ -265,18 +279,7 @@ mod tests {
         assert_eq!(reporter.total, 0);
 
         reporter.on_run_complete(&RunSummary {
-            passed: 0,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
-            duration: Duration::from_millis(0),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(reporter.inner.completed);
     }
@@ -288,15 +291,13 @@ mod tests {
         let tests = vec![test_item("test_err")];
 
         reporter.on_run_start(&tests);
-        reporter.on_test_complete(&TestResult {
-            test: tests[0].clone(),
-            outcome: TestOutcome::Error {
+        reporter.on_test_complete(&TestResult::new(
+            tests[0].clone(),
+            TestOutcome::Error {
                 message: "boom".into(),
             },
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(1),
+        ));
         assert!(reporter.has_failure);
     }
 
@@ -0,0 +1,140 @@
+use std::borrow::Cow;
+use std::path::{Component, Path, PathBuf};
+
+use tryke_types::TestItem;
+
+/// How a reporter prints file paths. By default they print as recorded,
+/// relative to the project root; under `--relative-to` they're re-rooted
+/// to read relative to another directory, for running from a
+/// subdirectory.
+///
+/// Only printed paths change: [`TestItem::id`] keeps the root-relative
+/// `file_path`, so ids still match other runs.
+#[derive(Clone, Debug, Default)]
+pub struct DisplayPaths {
+    /// `(root, base)`: paths recorded relative to `root` print relative
+    /// to `base`.
+    relative_to: Option<(PathBuf, PathBuf)>,
+}
+
+impl DisplayPaths {
+    /// `root` is the directory test `file_path`s are relative to; `base`
+    /// is the directory they should be shown relative to. Both should be
+    /// absolute.
+    #[must_use]
+    pub fn relative_to(root: impl Into<PathBuf>, base: impl Into<PathBuf>) -> Self {
+        Self {
+            relative_to: Some((root.into(), base.into())),
+        }
+    }
+
+    /// `path`, recorded relative to the project root, as it should be
+    /// printed.
+    #[must_use]
+    pub fn path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        match &self.relative_to {
+            Some((root, base)) => Cow::Owned(relative_path(&root.join(path), base)),
+            None => Cow::Borrowed(path),
+        }
+    }
+
+    /// `test`'s file as it should be printed, when it has one.
+    #[must_use]
+    pub fn file<'a>(&self, test: &'a TestItem) -> Option<Cow<'a, Path>> {
+        test.file_path.as_deref().map(|path| self.path(path))
+    }
+}
+
+/// Lexically express `path` relative to `base`, climbing out with `..`
+/// where needed. Both are expected to be absolute; when they share no
+/// prefix at all (e.g. different Windows drives) `path` is returned as is.
+fn relative_path(path: &Path, base: &Path) -> PathBuf {
+    let path_parts: Vec<Component<'_>> = path.components().collect();
+    let base_parts: Vec<Component<'_>> = base.components().collect();
+    let shared = path_parts
+        .iter()
+        .zip(&base_parts)
+        .take_while(|(a, b)| a == b)
+        .count();
+    if shared == 0 {
+        return path.to_path_buf();
+    }
+    let mut relative: PathBuf = base_parts[shared..]
+        .iter()
+        .map(|_| Component::ParentDir)
+        .collect();
+    relative.extend(&path_parts[shared..]);
+    relative
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tryke_types::{TestOutcome, TestResult};
+
+    use super::*;
+    use crate::reporter::testing::SharedBuf;
+    use crate::{Reporter, TextReporter};
+
+    fn passed(file: &str, name: &str) -> TestResult {
+        TestResult::new(
+            TestItem {
+                name: name.into(),
+                module_path: "tests.test_math".into(),
+                file_path: Some(file.into()),
+                ..TestItem::default()
+            },
+            TestOutcome::Passed,
+            Duration::from_millis(1),
+        )
+    }
+
+    #[test]
+    fn relative_path_climbs_out_of_sibling_directories() {
+        assert_eq!(
+            relative_path(Path::new("/repo/tests/test_a.py"), Path::new("/repo/tests")),
+            PathBuf::from("test_a.py")
+        );
+        assert_eq!(
+            relative_path(
+                Path::new("/repo/tests/test_a.py"),
+                Path::new("/repo/src/pkg")
+            ),
+            PathBuf::from("../../tests/test_a.py")
+        );
+        assert_eq!(
+            relative_path(Path::new("/repo/tests/test_a.py"), Path::new("/repo")),
+            PathBuf::from("tests/test_a.py")
+        );
+    }
+
+    #[test]
+    fn file_headers_are_relative_to_the_chosen_directory() {
+        let buf = SharedBuf::default();
+        let mut reporter = TextReporter::with_writer(buf.clone());
+        reporter.set_display_paths(&DisplayPaths::relative_to("/repo", "/repo/tests"));
+        let result = passed("tests/unit/test_math.py", "test_add");
+        reporter.on_run_start(std::slice::from_ref(&result.test));
+        reporter.on_test_complete(&result);
+
+        let out = buf.contents();
+        assert!(out.contains("unit/test_math.py:"), "{out}");
+        assert!(!out.contains("tests/unit/test_math.py"), "{out}");
+    }
+
+    #[test]
+    fn only_printed_paths_change() {
+        let test = passed("tests/test_math.py", "test_add").test;
+        assert_eq!(
+            DisplayPaths::default().file(&test).as_deref(),
+            Some(Path::new("tests/test_math.py"))
+        );
+        let paths = DisplayPaths::relative_to("/repo", "/repo/tests");
+        assert_eq!(
+            paths.file(&test).as_deref(),
+            Some(Path::new("test_math.py"))
+        );
+        assert_eq!(test.id(), "tests/test_math.py::test_add");
+    }
+}
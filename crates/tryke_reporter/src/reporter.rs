@@ -1,6 +1,11 @@
 use std::time::Duration;
 
-use tryke_types::{DiscoveryError, DiscoveryWarning, RunSummary, TestItem, TestResult};
+use tryke_types::{
+    AssertionEvent, DiscoveryError, DiscoveryWarning, RunSummary, SelectionSummary, TestItem,
+    TestResult,
+};
+
+use crate::relative::DisplayPaths;
 
 /// Snapshot of state shown to the user when watch mode is idle —
 /// after startup or after a no-op cycle, before the first save.
@@ -15,7 +20,33 @@ pub struct WatchIdleInfo<'a> {
 pub trait Reporter {
     fn on_run_start(&mut self, tests: &[TestItem]);
     fn on_test_complete(&mut self, result: &TestResult);
+    /// Called as each `expect(...)` in a running test is evaluated, ahead
+    /// of that test's `on_test_complete`. Only delivered when
+    /// `streams_assertions` returns true, since streaming costs the
+    /// worker a message per assertion.
+    fn on_assertion(&mut self, _event: &AssertionEvent) {}
+    /// Whether the runner should ask workers to stream assertion results
+    /// to [`Reporter::on_assertion`].
+    fn streams_assertions(&self) -> bool {
+        false
+    }
+    /// Called every [`Reporter::heartbeat_interval`] for each test that
+    /// is still running, with how long it has been running so far.
+    fn on_heartbeat(&mut self, _test: &TestItem, _elapsed: Duration) {}
+    /// How often the runner should call [`Reporter::on_heartbeat`] while
+    /// tests are running. `None`, the default, sends no heartbeats.
+    fn heartbeat_interval(&self) -> Option<Duration> {
+        None
+    }
     fn on_run_complete(&mut self, summary: &RunSummary);
+    /// Called instead of `on_run_complete` when the run was cut short
+    /// (Ctrl-C). `summary` holds the counts reached before the interrupt.
+    /// Defaults to rendering it like a completed run.
+    fn on_run_interrupted(&mut self, summary: &RunSummary) {
+        self.on_run_complete(summary);
+    }
+    /// Called instead of running anything under `--collect-only`, with the
+    /// tests that would have run. Defaults to printing nothing.
     fn on_collect_complete(&mut self, _tests: &[TestItem]) {}
     fn on_discovery_error(&mut self, _error: &DiscoveryError) {}
     /// Surface a non-fatal warning discovered while collecting or planning a
@@ -31,6 +62,10 @@ pub trait Reporter {
     /// changes..."). Reporters that don't render the summary line can
     /// ignore this.
     fn set_watch_hint(&mut self, _hint: Option<String>) {}
+    /// Tells the reporter how many tests were collected and how many
+    /// the CLI filters selected. Called before `on_run_start`; reporters
+    /// that don't surface selection counts can ignore this.
+    fn set_selection(&mut self, _selection: &SelectionSummary) {}
     /// Arm the reporter to clear the terminal once, immediately before
     /// the next visible output (typically `on_run_start`). Watch mode
     /// uses this to defer the clear until results are about to stream
@@ -48,6 +83,162 @@ pub trait Reporter {
     /// reporters clear the screen and paint a compact IDLE frame;
     /// structured reporters can ignore this.
     fn on_watch_results_cleared(&mut self, _info: &WatchIdleInfo<'_>) {}
+    /// Sets how the reporter prints file paths (`--relative-to`).
+    /// Reporters that print no paths can ignore this.
+    fn set_display_paths(&mut self, _paths: &DisplayPaths) {}
+}
+
+/// Implements the listed [`Reporter`] methods by handing each call on to
+/// `self.inner` unchanged, so a wrapping reporter only spells out the
+/// events it acts on:
+///
+/// ```ignore
+/// impl Reporter for Wrapper {
+///     fn on_run_start(&mut self, tests: &[TestItem]) { ... }
+///
+///     forward_to_inner!(on_test_complete, on_run_complete, arm_clear);
+/// }
+/// ```
+macro_rules! forward_to_inner {
+    ($($method:ident),* $(,)?) => {
+        $($crate::reporter::forward_to_inner!(@ $method);)*
+    };
+    (@ on_run_start) => {
+        fn on_run_start(&mut self, tests: &[::tryke_types::TestItem]) {
+            self.inner.on_run_start(tests);
+        }
+    };
+    (@ on_test_complete) => {
+        fn on_test_complete(&mut self, result: &::tryke_types::TestResult) {
+            self.inner.on_test_complete(result);
+        }
+    };
+    (@ on_assertion) => {
+        fn on_assertion(&mut self, event: &::tryke_types::AssertionEvent) {
+            self.inner.on_assertion(event);
+        }
+    };
+    (@ streams_assertions) => {
+        fn streams_assertions(&self) -> bool {
+            self.inner.streams_assertions()
+        }
+    };
+    (@ on_heartbeat) => {
+        fn on_heartbeat(&mut self, test: &::tryke_types::TestItem, elapsed: ::std::time::Duration) {
+            self.inner.on_heartbeat(test, elapsed);
+        }
+    };
+    (@ heartbeat_interval) => {
+        fn heartbeat_interval(&self) -> Option<::std::time::Duration> {
+            self.inner.heartbeat_interval()
+        }
+    };
+    (@ on_run_complete) => {
+        fn on_run_complete(&mut self, summary: &::tryke_types::RunSummary) {
+            self.inner.on_run_complete(summary);
+        }
+    };
+    (@ on_run_interrupted) => {
+        fn on_run_interrupted(&mut self, summary: &::tryke_types::RunSummary) {
+            self.inner.on_run_interrupted(summary);
+        }
+    };
+    (@ on_collect_complete) => {
+        fn on_collect_complete(&mut self, tests: &[::tryke_types::TestItem]) {
+            self.inner.on_collect_complete(tests);
+        }
+    };
+    (@ on_discovery_error) => {
+        fn on_discovery_error(&mut self, error: &::tryke_types::DiscoveryError) {
+            self.inner.on_discovery_error(error);
+        }
+    };
+    (@ on_discovery_warning) => {
+        fn on_discovery_warning(&mut self, warning: &::tryke_types::DiscoveryWarning) {
+            self.inner.on_discovery_warning(warning);
+        }
+    };
+    (@ set_subcommand_label) => {
+        fn set_subcommand_label(&mut self, label: &'static str) {
+            self.inner.set_subcommand_label(label);
+        }
+    };
+    (@ set_watch_hint) => {
+        fn set_watch_hint(&mut self, hint: Option<String>) {
+            self.inner.set_watch_hint(hint);
+        }
+    };
+    (@ set_selection) => {
+        fn set_selection(&mut self, selection: &::tryke_types::SelectionSummary) {
+            self.inner.set_selection(selection);
+        }
+    };
+    (@ arm_clear) => {
+        fn arm_clear(&mut self) {
+            self.inner.arm_clear();
+        }
+    };
+    (@ on_watch_idle) => {
+        fn on_watch_idle(&mut self, info: &$crate::reporter::WatchIdleInfo<'_>) {
+            self.inner.on_watch_idle(info);
+        }
+    };
+    (@ on_watch_results_cleared) => {
+        fn on_watch_results_cleared(&mut self, info: &$crate::reporter::WatchIdleInfo<'_>) {
+            self.inner.on_watch_results_cleared(info);
+        }
+    };
+    (@ set_display_paths) => {
+        fn set_display_paths(&mut self, paths: &$crate::relative::DisplayPaths) {
+            self.inner.set_display_paths(paths);
+        }
+    };
+}
+
+pub(crate) use forward_to_inner;
+
+/// Reporters shared by the test modules of wrapping reporters.
+#[cfg(test)]
+pub(crate) mod testing {
+    use std::cell::RefCell;
+    use std::io;
+    use std::rc::Rc;
+
+    use tryke_types::{RunSummary, TestItem, TestResult};
+
+    use super::Reporter;
+
+    /// Discards every event, for wrappers whose own side effects are
+    /// under test.
+    pub(crate) struct NullReporter;
+
+    impl Reporter for NullReporter {
+        fn on_run_start(&mut self, _tests: &[TestItem]) {}
+        fn on_test_complete(&mut self, _result: &TestResult) {}
+        fn on_run_complete(&mut self, _summary: &RunSummary) {}
+    }
+
+    /// Writer whose bytes stay readable after the reporter owning it has
+    /// been boxed.
+    #[derive(Clone, Default)]
+    pub(crate) struct SharedBuf(Rc<RefCell<Vec<u8>>>);
+
+    impl SharedBuf {
+        pub(crate) fn contents(&self) -> String {
+            String::from_utf8(self.0.borrow().clone()).expect("utf8")
+        }
+    }
+
+    impl io::Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
 }
 
 #[cfg(test)]
@@ -56,7 +247,9 @@ mod tests {
 
     use tryke_types::TestOutcome;
 
+    use super::testing::SharedBuf;
     use super::*;
+    use crate::{DotReporter, JUnitReporter};
 
     struct RecordingReporter {
         started: bool,
@@ -108,42 +301,30 @@ mod tests {
         reporter.on_run_start(&tests);
         assert!(reporter.started);
 
-        reporter.on_test_complete(&TestResult {
-            test: tests[0].clone(),
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(10),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+        reporter.on_test_complete(&TestResult::new(
+            tests[0].clone(),
+            TestOutcome::Passed,
+            Duration::from_millis(10),
+        ));
 
-        reporter.on_test_complete(&TestResult {
-            test: tests[1].clone(),
-            outcome: TestOutcome::Failed {
+        reporter.on_test_complete(&TestResult::new(
+            tests[1].clone(),
+            TestOutcome::Failed {
                 message: "expected 1, got 2".into(),
                 traceback: None,
                 assertions: vec![],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(5),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(5),
+        ));
 
         assert_eq!(reporter.results.len(), 2);
 
         reporter.on_run_complete(&RunSummary {
             passed: 1,
             failed: 1,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(15),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
 
         let summary = reporter.summary.as_ref().expect("summary should be set");
@@ -151,4 +332,54 @@ mod tests {
         assert_eq!(summary.failed, 1);
         assert_eq!(summary.skipped, 0);
     }
+
+    fn collected() -> Vec<TestItem> {
+        vec![
+            TestItem {
+                name: "test_add".into(),
+                module_path: "tests.math".into(),
+                file_path: Some("tests/math.py".into()),
+                ..Default::default()
+            },
+            TestItem {
+                name: "test_sub".into(),
+                module_path: "tests.math".into(),
+                file_path: Some("tests/math.py".into()),
+                ..Default::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn collect_only_defaults_to_printing_nothing() {
+        let mut reporter: Box<dyn Reporter> = Box::new(RecordingReporter::new());
+        reporter.on_collect_complete(&collected());
+    }
+
+    #[test]
+    fn dot_reporter_lists_collected_tests_through_the_trait() {
+        let buf = SharedBuf::default();
+        let mut reporter: Box<dyn Reporter> = Box::new(DotReporter::with_writer(buf.clone()));
+        reporter.on_collect_complete(&collected());
+        let out = buf.contents();
+        assert!(out.contains("test_add"));
+        assert!(out.contains("test_sub"));
+        assert!(out.contains("2 tests collected."));
+    }
+
+    #[test]
+    fn junit_reporter_lists_collected_tests_through_the_trait() {
+        let buf = SharedBuf::default();
+        let mut reporter: Box<dyn Reporter> = Box::new(JUnitReporter::with_writer(buf.clone()));
+        reporter.on_collect_complete(&collected());
+        let out = buf.contents();
+        assert!(out.starts_with("<?xml"));
+        assert_eq!(out.matches("<testsuite ").count(), 1);
+        assert_eq!(out.matches("<testcase ").count(), 2);
+        assert_eq!(
+            out.matches("<testcase ").count(),
+            out.matches("</testcase>").count()
+        );
+        assert!(out.trim_end().ends_with("</testsuite>"));
+    }
 }
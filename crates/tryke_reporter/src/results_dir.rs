@@ -0,0 +1,266 @@
+use std::collections::HashSet;
+use std::fs;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::PathBuf;
+
+use log::warn;
+use tryke_types::{TestItem, TestResult};
+
+use crate::Reporter;
+use crate::reporter::forward_to_inner;
+
+/// Wraps another reporter and writes each finished test's full
+/// [`TestResult`] to `<dir>/<sanitized-id>.json`, for CI systems that
+/// collect one artifact per test.
+///
+/// Ids are made filesystem-safe by [`result_file_stem`]; when two ids
+/// sanitize to the same name, later files get a `-2`, `-3`, ... suffix.
+/// Each run starts by removing the `.json` files an earlier run left in
+/// `dir`, so the directory only ever holds one run's results. Writing is
+/// best effort: a file that can't be written is logged as a warning and
+/// never changes the run's outcome.
+pub struct ResultsDirReporter {
+    inner: Box<dyn Reporter>,
+    dir: PathBuf,
+    /// Lowercased file names written this run, so names that differ only
+    /// in case are also disambiguated on case-insensitive filesystems.
+    written: HashSet<String>,
+}
+
+impl ResultsDirReporter {
+    #[must_use]
+    pub fn new(inner: Box<dyn Reporter>, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            inner,
+            dir: dir.into(),
+            written: HashSet::new(),
+        }
+    }
+
+    fn file_name(&mut self, result: &TestResult) -> String {
+        let stem = result_file_stem(&result.test.id());
+        let mut name = format!("{stem}.json");
+        let mut n = 2;
+        while !self.written.insert(name.to_lowercase()) {
+            name = format!("{stem}-{n}.json");
+            n += 1;
+        }
+        name
+    }
+
+    /// Remove the result files an earlier run left behind.
+    fn clear_stale(&self) {
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return;
+        };
+        for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+            if path.is_file()
+                && path.extension().is_some_and(|ext| ext == "json")
+                && let Err(err) = fs::remove_file(&path)
+            {
+                warn!("--results-dir: could not remove {}: {err}", path.display());
+            }
+        }
+    }
+
+    fn write(&mut self, result: &TestResult) {
+        let name = self.file_name(result);
+        let path = self.dir.join(name);
+        let written = serde_json::to_string_pretty(result)
+            .map_err(std::io::Error::from)
+            .and_then(|json| fs::write(&path, json));
+        if let Err(err) = written {
+            warn!("--results-dir: could not write {}: {err}", path.display());
+        }
+    }
+}
+
+/// Longest stem [`result_file_stem`] returns, in bytes. With room left
+/// for a collision suffix and `.json`, file names stay under the 255-byte
+/// limit common filesystems impose.
+const MAX_STEM_LEN: usize = 200;
+
+/// Turn a test id into a file name stem: `::` becomes `__`, and path
+/// separators, characters Windows reserves, and control characters become
+/// `_`. `tests/test_math.py::test_add` becomes
+/// `tests_test_math.py__test_add`. Stems longer than [`MAX_STEM_LEN`] are
+/// cut short and end in a hash of the whole id, so they stay distinct.
+#[must_use]
+pub fn result_file_stem(id: &str) -> String {
+    let stem: String = id
+        .replace("::", "__")
+        .chars()
+        .map(|c| {
+            if c.is_control() || matches!(c, '/' | '\\' | ':' | '<' | '>' | '"' | '|' | '?' | '*') {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    if stem.len() <= MAX_STEM_LEN {
+        return stem;
+    }
+    let mut hasher = DefaultHasher::new();
+    id.hash(&mut hasher);
+    let hash = format!("-{:016x}", hasher.finish());
+    let mut end = MAX_STEM_LEN - hash.len();
+    while !stem.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}{hash}", &stem[..end])
+}
+
+impl Reporter for ResultsDirReporter {
+    fn on_run_start(&mut self, tests: &[TestItem]) {
+        self.written.clear();
+        if let Err(err) = fs::create_dir_all(&self.dir) {
+            warn!(
+                "--results-dir: could not create {}: {err}",
+                self.dir.display()
+            );
+        }
+        self.clear_stale();
+        self.inner.on_run_start(tests);
+    }
+
+    fn on_test_complete(&mut self, result: &TestResult) {
+        self.write(result);
+        self.inner.on_test_complete(result);
+    }
+
+    forward_to_inner!(
+        on_assertion,
+        streams_assertions,
+        on_heartbeat,
+        heartbeat_interval,
+        on_run_complete,
+        on_run_interrupted,
+        on_collect_complete,
+        on_discovery_error,
+        on_discovery_warning,
+        set_subcommand_label,
+        set_watch_hint,
+        set_selection,
+        arm_clear,
+        on_watch_idle,
+        on_watch_results_cleared,
+        set_display_paths,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tryke_types::TestOutcome;
+
+    use super::*;
+    use crate::reporter::testing::NullReporter;
+
+    fn result(name: &str, outcome: TestOutcome) -> TestResult {
+        TestResult {
+            stdout: "out\n".into(),
+            ..TestResult::new(
+                TestItem {
+                    name: name.into(),
+                    module_path: "tests.test_math".into(),
+                    file_path: Some("tests/test_math.py".into()),
+                    ..TestItem::default()
+                },
+                outcome,
+                Duration::from_millis(3),
+            )
+        }
+    }
+
+    fn read_result(path: &std::path::Path) -> TestResult {
+        let json = fs::read_to_string(path).expect("read result file");
+        serde_json::from_str(&json).expect("parse result file")
+    }
+
+    #[test]
+    fn writes_one_file_per_test() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let out = dir.path().join("results");
+        let mut reporter = ResultsDirReporter::new(Box::new(NullReporter), &out);
+        reporter.on_run_start(&[]);
+        reporter.on_test_complete(&result("test_add", TestOutcome::Passed));
+        reporter.on_test_complete(&result(
+            "test_sub",
+            TestOutcome::Failed {
+                message: "expected 2".into(),
+                traceback: None,
+                assertions: vec![],
+                executed_lines: vec![],
+            },
+        ));
+
+        let add = read_result(&out.join("tests_test_math.py__test_add.json"));
+        assert_eq!(add.test.id(), "tests/test_math.py::test_add");
+        assert!(matches!(add.outcome, TestOutcome::Passed));
+        assert_eq!(add.stdout, "out\n");
+
+        let sub = read_result(&out.join("tests_test_math.py__test_sub.json"));
+        assert_eq!(sub.test.id(), "tests/test_math.py::test_sub");
+        assert!(
+            matches!(sub.outcome, TestOutcome::Failed { ref message, .. } if message == "expected 2")
+        );
+        assert_eq!(fs::read_dir(&out).expect("read dir").count(), 2);
+    }
+
+    #[test]
+    fn colliding_ids_get_distinct_files() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut reporter = ResultsDirReporter::new(Box::new(NullReporter), dir.path());
+        reporter.on_run_start(&[]);
+        // `a/b` and `a:b` both sanitize to `a_b`.
+        reporter.on_test_complete(&result("a/b", TestOutcome::Passed));
+        reporter.on_test_complete(&result("a:b", TestOutcome::Passed));
+
+        let first = read_result(&dir.path().join("tests_test_math.py__a_b.json"));
+        let second = read_result(&dir.path().join("tests_test_math.py__a_b-2.json"));
+        assert_eq!(first.test.name, "a/b");
+        assert_eq!(second.test.name, "a:b");
+    }
+
+    #[test]
+    fn run_start_removes_stale_result_files() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        fs::write(dir.path().join("tests_old.py__test_gone.json"), "{}").expect("write stale");
+        fs::write(dir.path().join("README.txt"), "keep").expect("write other");
+        let mut reporter = ResultsDirReporter::new(Box::new(NullReporter), dir.path());
+        reporter.on_run_start(&[]);
+        reporter.on_test_complete(&result("test_add", TestOutcome::Passed));
+
+        let mut names: Vec<_> = fs::read_dir(dir.path())
+            .expect("read dir")
+            .map(|entry| entry.expect("entry").file_name())
+            .collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec!["README.txt", "tests_test_math.py__test_add.json"]
+        );
+    }
+
+    #[test]
+    fn long_ids_get_short_distinct_stems() {
+        let long = format!("tests/test_math.py::square[{}]", "é".repeat(200));
+        let other = format!("{long}x");
+        let stem = result_file_stem(&long);
+        assert!(stem.len() <= MAX_STEM_LEN, "{} bytes", stem.len());
+        assert!(stem.starts_with("tests_test_math.py__square[é"));
+        assert_ne!(stem, result_file_stem(&other));
+        assert_eq!(stem, result_file_stem(&long));
+    }
+
+    #[test]
+    fn result_file_stem_replaces_unsafe_characters() {
+        assert_eq!(
+            result_file_stem("tests/test_math.py::square[a|b]"),
+            "tests_test_math.py__square[a_b]"
+        );
+        assert_eq!(result_file_stem("pkg\\mod.py::t"), "pkg_mod.py__t");
+    }
+}
@@ -17,6 +17,7 @@ use tryke_types::{DiscoveryWarning, RunSummary, TestItem, TestOutcome, TestResul
 use crate::Reporter;
 use crate::diagnostic::{render_assertions, render_error_message, render_failure_message};
 use crate::live::{LiveArea, render_bar};
+use crate::relative::DisplayPaths;
 use crate::summary;
 
 const SUFFIX_BAR_WIDTH: usize = 12;
@@ -63,6 +64,8 @@ pub struct SugarReporter<W: Write = io::Stdout> {
     clear_armed: bool,
     clear_enabled: bool,
     header_pending: bool,
+    /// How file paths print; re-rooted under `--relative-to`.
+    display_paths: DisplayPaths,
 }
 
 impl SugarReporter {
@@ -85,6 +88,7 @@ impl SugarReporter {
             clear_armed: false,
             clear_enabled: crate::clear::stdout_is_terminal(),
             header_pending: false,
+            display_paths: DisplayPaths::default(),
         }
     }
 }
@@ -114,6 +118,7 @@ impl<W: Write> SugarReporter<W> {
             clear_armed: false,
             clear_enabled: false,
             header_pending: false,
+            display_paths: DisplayPaths::default(),
         }
     }
 
@@ -250,9 +255,9 @@ impl<W: Write> SugarReporter<W> {
 /// without a `file_path` (e.g. dynamically-loaded modules) still get
 /// a row and an accurate file count instead of being silently
 /// dropped.
-fn file_label(test: &TestItem) -> String {
-    test.file_path
-        .as_deref()
+fn file_label(test: &TestItem, paths: &DisplayPaths) -> String {
+    paths
+        .file(test)
         .map_or_else(|| test.module_path.clone(), |p| p.display().to_string())
 }
 
@@ -291,7 +296,11 @@ impl<W: Write> Reporter for SugarReporter<W> {
     fn on_run_start(&mut self, tests: &[TestItem]) {
         self.total_tests = tests.len() as u64;
         self.completed_tests = 0;
-        self.total_files = tests.iter().map(file_label).collect::<HashSet<_>>().len() as u64;
+        self.total_files = tests
+            .iter()
+            .map(|test| file_label(test, &self.display_paths))
+            .collect::<HashSet<_>>()
+            .len() as u64;
         self.completed_files = 0;
         self.current_file = None;
         self.current_marks.clear();
@@ -314,7 +323,7 @@ impl<W: Write> Reporter for SugarReporter<W> {
         // as a single row, then start fresh. The execution layer
         // guarantees a file's tests arrive contiguously, so a change in
         // the file label reliably means "previous file done."
-        let new_label = file_label(&result.test);
+        let new_label = file_label(&result.test, &self.display_paths);
         if Some(&new_label) != self.current_file.as_ref() {
             self.commit_current_file();
             self.current_file = Some(new_label);
@@ -335,7 +344,13 @@ impl<W: Write> Reporter for SugarReporter<W> {
     }
 
     fn on_collect_complete(&mut self, tests: &[TestItem]) {
-        summary::write_collect_list(&mut self.writer, self.subcommand_label, tests);
+        summary::write_collect_list(
+            &mut self.writer,
+            self.subcommand_label,
+            tests,
+            &self.display_paths,
+            false,
+        );
     }
 
     fn on_run_complete(&mut self, run_summary: &RunSummary) {
@@ -349,7 +364,7 @@ impl<W: Write> Reporter for SugarReporter<W> {
             let header = format!("{}", "Failures".red().bold().underline());
             self.live.println(&mut self.writer, &header);
             for fail in &self.failures {
-                write_failure(&self.live, &mut self.writer, fail);
+                write_failure(&self.live, &mut self.writer, &self.display_paths, fail);
             }
         }
 
@@ -368,6 +383,10 @@ impl<W: Write> Reporter for SugarReporter<W> {
         self.clear_armed = true;
     }
 
+    fn set_display_paths(&mut self, paths: &DisplayPaths) {
+        self.display_paths = paths.clone();
+    }
+
     fn on_discovery_warning(&mut self, warning: &DiscoveryWarning) {
         self.flush_pending_header();
         let line = format!(
@@ -399,11 +418,13 @@ impl<W: Write> Reporter for SugarReporter<W> {
     }
 }
 
-fn write_failure<W: Write>(live: &LiveArea, writer: &mut W, fail: &TestResult) {
-    let location = fail.test.file_path.as_deref().map_or_else(
-        || fail.test.module_path.clone(),
-        |p| p.display().to_string(),
-    );
+fn write_failure<W: Write>(
+    live: &LiveArea,
+    writer: &mut W,
+    paths: &DisplayPaths,
+    fail: &TestResult,
+) {
+    let location = file_label(&fail.test, paths);
     live.println(writer, "");
     let header = format!(
         "{} {} {}",
@@ -413,10 +434,8 @@ fn write_failure<W: Write>(live: &LiveArea, writer: &mut W, fail: &TestResult) {
     );
     live.println(writer, &header);
 
-    let test_file = fail
-        .test
-        .file_path
-        .as_deref()
+    let test_file = paths
+        .file(&fail.test)
         .map(|p| p.to_string_lossy().into_owned());
     let detail = match &fail.outcome {
         TestOutcome::Failed {
@@ -427,7 +446,7 @@ fn write_failure<W: Write>(live: &LiveArea, writer: &mut W, fail: &TestResult) {
         } => {
             let mut buf = String::new();
             if !assertions.is_empty() {
-                render_assertions(test_file.as_deref(), assertions, &mut buf);
+                render_assertions(test_file.as_deref(), assertions, true, &mut buf);
             } else if !message.is_empty() {
                 render_failure_message(message, traceback.as_deref(), false, &mut buf);
             }
@@ -462,38 +481,34 @@ mod tests {
     }
 
     fn passed(name: &str, file: &str) -> TestResult {
-        TestResult {
-            test: TestItem {
+        TestResult::new(
+            TestItem {
                 name: name.into(),
                 module_path: "tests.m".into(),
                 file_path: Some(PathBuf::from(file)),
                 ..Default::default()
             },
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        }
+            TestOutcome::Passed,
+            Duration::from_millis(1),
+        )
     }
 
     fn failed(name: &str, file: &str) -> TestResult {
-        TestResult {
-            test: TestItem {
+        TestResult::new(
+            TestItem {
                 name: name.into(),
                 module_path: "tests.m".into(),
                 file_path: Some(PathBuf::from(file)),
                 ..Default::default()
             },
-            outcome: TestOutcome::Failed {
+            TestOutcome::Failed {
                 message: "boom".into(),
                 traceback: None,
                 assertions: vec![],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        }
+            Duration::from_millis(1),
+        )
     }
 
     #[test]
@@ -541,17 +556,9 @@ mod tests {
         r.on_test_complete(&passed("b", "tests/x.py"));
         r.on_run_complete(&RunSummary {
             passed: 2,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(2),
-            discovery_duration: None,
-            test_duration: None,
             file_count: 1,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         let out = output(r);
         assert!(out.contains("tests/x.py"), "out: {out}");
@@ -573,17 +580,9 @@ mod tests {
         r.on_test_complete(&passed("b", "tests/y.py"));
         r.on_run_complete(&RunSummary {
             passed: 2,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(2),
-            discovery_duration: None,
-            test_duration: None,
             file_count: 2,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         let out = output(r);
         assert!(out.contains("tests/x.py"));
@@ -603,16 +602,9 @@ mod tests {
         r.on_run_complete(&RunSummary {
             passed: 1,
             failed: 1,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(2),
-            discovery_duration: None,
-            test_duration: None,
             file_count: 1,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         let out = output(r);
         let failures_idx = out.find("Failures").expect("Failures section present");
@@ -635,17 +627,9 @@ mod tests {
         r.on_test_complete(&passed("a", "tests/x.py"));
         r.on_run_complete(&RunSummary {
             passed: 1,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(1),
-            discovery_duration: None,
-            test_duration: None,
             file_count: 1,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         let out = output(r);
         assert!(!out.contains("\x1b[2K"));
@@ -657,18 +641,8 @@ mod tests {
         let mut r = reporter();
         r.on_run_start(&[]);
         r.on_run_complete(&RunSummary {
-            passed: 0,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(1),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         let out = output(r);
         assert!(out.contains("PASS"));
@@ -698,17 +672,17 @@ mod tests {
         // `current_file = None`. They should now group under the
         // module path instead.
         let mut r = reporter();
-        let make = |name: &str| TestResult {
-            test: TestItem {
-                name: name.into(),
-                module_path: "tests.dynamic".into(),
-                file_path: None,
-                ..Default::default()
-            },
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
+        let make = |name: &str| {
+            TestResult::new(
+                TestItem {
+                    name: name.into(),
+                    module_path: "tests.dynamic".into(),
+                    file_path: None,
+                    ..Default::default()
+                },
+                TestOutcome::Passed,
+                Duration::from_millis(1),
+            )
         };
         let tests = vec![make("a").test.clone(), make("b").test.clone()];
         r.on_run_start(&tests);
@@ -716,17 +690,9 @@ mod tests {
         r.on_test_complete(&make("b"));
         r.on_run_complete(&RunSummary {
             passed: 2,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(2),
-            discovery_duration: None,
-            test_duration: None,
             file_count: 1,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         let out = output(r);
         let line = out
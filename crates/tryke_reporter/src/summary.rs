@@ -1,9 +1,13 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
 use std::io;
+use std::path::Path;
 
 use owo_colors::OwoColorize;
-use tryke_types::{RunSummary, TestItem};
+use tryke_types::{ExpectedAssertion, RunSummary, TestItem};
 
 use crate::duration::format_duration;
+use crate::relative::DisplayPaths;
 use crate::reporter::WatchIdleInfo;
 
 /// Keyboard shortcuts shown beneath the summary/idle badge in watch
@@ -41,11 +45,22 @@ pub fn write_summary<W: io::Write>(writer: &mut W, summary: &RunSummary) {
     write_summary_with_hint(writer, summary, None);
 }
 
-#[expect(clippy::too_many_lines)]
 pub fn write_summary_with_hint<W: io::Write>(
     writer: &mut W,
     summary: &RunSummary,
     watch_hint: Option<&str>,
+) {
+    write_summary_with_options(writer, summary, watch_hint, true);
+}
+
+/// Like `write_summary_with_hint`, but `show_duration = false` drops the
+/// `Duration` line so the summary is stable across runs (`--no-duration`).
+#[expect(clippy::too_many_lines)]
+pub fn write_summary_with_options<W: io::Write>(
+    writer: &mut W,
+    summary: &RunSummary,
+    watch_hint: Option<&str>,
+    show_duration: bool,
 ) {
     let total = summary.passed
         + summary.failed
@@ -88,6 +103,9 @@ pub fn write_summary_with_hint<W: io::Write>(
     if summary.todo > 0 {
         parts.push(format!("{}", format!("{} todo", summary.todo).cyan()));
     }
+    if summary.slow > 0 {
+        parts.push(format!("{}", format!("{} slow", summary.slow).yellow()));
+    }
 
     if parts.is_empty() {
         parts.push(format!("{}", "0 passed".green().bold()));
@@ -145,27 +163,39 @@ pub fn write_summary_with_hint<W: io::Write>(
     }
 
     // "  Duration" = 10 chars
-    let mut breakdown_parts: Vec<String> = Vec::new();
-    if let Some(d) = summary.discovery_duration {
-        breakdown_parts.push(format!("discover {}", format_duration(d)));
-    }
-    if let Some(d) = summary.test_duration {
-        breakdown_parts.push(format!("tests {}", format_duration(d)));
-    }
+    if show_duration {
+        let mut breakdown_parts: Vec<String> = Vec::new();
+        if let Some(d) = summary.discovery_duration {
+            breakdown_parts.push(format!("discover {}", format_duration(d)));
+        }
+        if let Some(d) = summary.test_duration {
+            breakdown_parts.push(format!("tests {}", format_duration(d)));
+        }
 
-    let breakdown = if breakdown_parts.is_empty() {
-        String::new()
-    } else {
-        format!(" {}", format!("({})", breakdown_parts.join(", ")).dimmed())
-    };
+        let breakdown = if breakdown_parts.is_empty() {
+            String::new()
+        } else {
+            format!(" {}", format!("({})", breakdown_parts.join(", ")).dimmed())
+        };
 
-    let _ = writeln!(
-        writer,
-        "   {}  {}{}",
-        "Duration".dimmed(),
-        format_duration(summary.duration),
-        breakdown
-    );
+        let _ = writeln!(
+            writer,
+            "   {}  {}{}",
+            "Duration".dimmed(),
+            format_duration(summary.duration),
+            breakdown
+        );
+    }
+
+    if let Some(max) = summary.stopped_after {
+        let note = if max == 1 {
+            "stopped after first failure".to_owned()
+        } else {
+            format!("stopped after {max} failures")
+        };
+        let _ = writeln!(writer);
+        let _ = writeln!(writer, " {}", note.yellow());
+    }
 
     let badge = if has_failures {
         format!("{}", " FAIL ".on_red().black().bold())
@@ -247,11 +277,14 @@ pub fn write_cleared_summary<W: io::Write>(writer: &mut W, info: &WatchIdleInfo<
 /// share this implementation so the `--collect-only` output is
 /// identical regardless of `--reporter`, with the sole exception of
 /// machine-readable formats (json, junit) that have their own
-/// representation.
+/// representation. With `show_assertions`, each test is followed by the
+/// checklist of assertions discovery extracted from it.
 pub fn write_collect_list<W: io::Write>(
     writer: &mut W,
     subcommand_label: &str,
     tests: &[TestItem],
+    paths: &DisplayPaths,
+    show_assertions: bool,
 ) {
     let _ = writeln!(
         writer,
@@ -260,15 +293,19 @@ pub fn write_collect_list<W: io::Write>(
         format!("v{}", env!("CARGO_PKG_VERSION")).dimmed()
     );
     let _ = writeln!(writer);
-    let mut current_file: Option<&std::path::Path> = None;
+    let duplicates: HashSet<usize> = tryke_types::duplicate_tests(tests)
+        .iter()
+        .map(|duplicate| duplicate.index)
+        .collect();
+    let mut current_file: Option<Cow<'_, Path>> = None;
     let mut current_groups: Vec<String> = Vec::new();
-    for test in tests {
-        let file = test.file_path.as_deref();
+    for (index, test) in tests.iter().enumerate() {
+        let file = paths.file(test);
         if file != current_file {
             if current_file.is_some() {
                 let _ = writeln!(writer);
             }
-            if let Some(path) = file {
+            if let Some(path) = &file {
                 let _ = writeln!(writer, "{}:", path.display());
             }
             current_file = file;
@@ -288,7 +325,199 @@ pub fn write_collect_list<W: io::Write>(
         }
         let group_indent = "  ".repeat(test.groups.len());
         let display = test.display_label();
-        let _ = writeln!(writer, "  {group_indent}{}", display.dimmed());
+        let duplicate = if duplicates.contains(&index) {
+            format!(" {}", "(duplicate)".yellow())
+        } else {
+            String::new()
+        };
+        let _ = writeln!(writer, "  {group_indent}{}{duplicate}", display.dimmed());
+        if show_assertions {
+            let assert_indent = "  ".repeat(test.groups.len() + 2);
+            write_assertion_checklist(writer, &assert_indent, &test.expected_assertions, None);
+        }
+    }
+    let _ = writeln!(writer);
+    let _ = writeln!(writer, "{} tests collected.", tests.len());
+}
+
+/// Render one line per expected assertion, under `describe`-style
+/// headings for `expect.group(...)` blocks. `failed_lines` marks each
+/// entry passed or failed; `None` means the test hasn't run, so entries
+/// get a neutral bullet.
+pub(crate) fn write_assertion_checklist<W: io::Write>(
+    writer: &mut W,
+    indent: &str,
+    assertions: &[ExpectedAssertion],
+    failed_lines: Option<&HashSet<usize>>,
+) {
+    let mut current_group: Option<&str> = None;
+    for a in assertions {
+        let group = a.group.as_deref();
+        // Only consecutive assertions share a heading; a group that
+        // reappears later in the test gets a fresh one.
+        if group != current_group
+            && let Some(name) = group
+        {
+            let _ = writeln!(writer, "{indent}{}", name.bold());
+        }
+        current_group = group;
+        let item_indent = if group.is_some() {
+            format!("{indent}  ")
+        } else {
+            indent.to_owned()
+        };
+        let not_part = if a.negated { "not_." } else { "" };
+        let args_str = a.args.join(", ");
+        let assertion = format!(
+            "expect({}).{}{}({})",
+            a.subject, not_part, a.matcher, args_str
+        );
+        let text = a.label.as_deref().unwrap_or(&assertion);
+        let glyph = if a.pending {
+            "○".cyan().to_string()
+        } else {
+            match failed_lines {
+                None => "•".dimmed().to_string(),
+                Some(lines) if lines.contains(&(a.line as usize)) => "✗".red().to_string(),
+                Some(_) => "✓".green().to_string(),
+            }
+        };
+        let _ = writeln!(writer, "{item_indent}{glyph} {}", text.dimmed());
+    }
+}
+
+/// Glyphs used to draw the branches of `write_collect_tree`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeGlyphs {
+    Unicode,
+    /// Plain `|--` / `` `-- `` branches for terminals and logs that
+    /// mangle box-drawing characters.
+    Ascii,
+}
+
+impl TreeGlyphs {
+    fn branch(self, last: bool) -> &'static str {
+        match (self, last) {
+            (Self::Unicode, false) => "├── ",
+            (Self::Unicode, true) => "└── ",
+            (Self::Ascii, false) => "|-- ",
+            (Self::Ascii, true) => "`-- ",
+        }
+    }
+
+    fn indent(self, last: bool) -> &'static str {
+        match (self, last) {
+            (Self::Unicode, false) => "│   ",
+            (Self::Ascii, false) => "|   ",
+            (_, true) => "    ",
+        }
+    }
+}
+
+/// One directory, file, or group in the collect tree. `count` is the
+/// number of tests at or below this node.
+#[derive(Default)]
+struct CollectNode<'a> {
+    children: BTreeMap<String, CollectNode<'a>>,
+    tests: Vec<&'a TestItem>,
+    count: usize,
+}
+
+impl<'a> CollectNode<'a> {
+    fn insert(&mut self, path: &[String], test: &'a TestItem) {
+        self.count += 1;
+        match path.split_first() {
+            Some((head, rest)) => self
+                .children
+                .entry(head.clone())
+                .or_default()
+                .insert(rest, test),
+            None => self.tests.push(test),
+        }
+    }
+}
+
+/// Path from the tree root to a test's leaf: each directory of its file
+/// (with a trailing `/`), the file name, then its `describe` groups.
+/// Tests without a file fall back to the dotted parts of their module.
+fn collect_tree_path(test: &TestItem, paths: &DisplayPaths) -> Vec<String> {
+    let mut path: Vec<String> = match paths.file(test) {
+        Some(file) => {
+            let parts: Vec<String> = file
+                .components()
+                .map(|part| part.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            let last = parts.len().saturating_sub(1);
+            parts
+                .into_iter()
+                .enumerate()
+                .map(|(i, part)| if i < last { format!("{part}/") } else { part })
+                .collect()
+        }
+        None => test.module_path.split('.').map(str::to_owned).collect(),
+    };
+    path.extend(test.groups.iter().cloned());
+    path
+}
+
+fn write_collect_node<W: io::Write>(
+    writer: &mut W,
+    node: &CollectNode<'_>,
+    prefix: &str,
+    glyphs: TreeGlyphs,
+) {
+    let total = node.children.len() + node.tests.len();
+    for (i, (name, child)) in node.children.iter().enumerate() {
+        let last = i + 1 == total;
+        let _ = writeln!(
+            writer,
+            "{prefix}{}{name} {}",
+            glyphs.branch(last),
+            format!("({})", child.count).dimmed()
+        );
+        let child_prefix = format!("{prefix}{}", glyphs.indent(last));
+        write_collect_node(writer, child, &child_prefix, glyphs);
+    }
+    for (i, test) in node.tests.iter().enumerate() {
+        let last = node.children.len() + i + 1 == total;
+        let _ = writeln!(
+            writer,
+            "{prefix}{}{}",
+            glyphs.branch(last),
+            test.display_label().dimmed()
+        );
+    }
+}
+
+/// `--collect-only --tree`: the collected tests as a directory / file /
+/// group tree with a test count on every node, instead of the flat
+/// per-file listing of `write_collect_list`.
+pub fn write_collect_tree<W: io::Write>(
+    writer: &mut W,
+    subcommand_label: &str,
+    tests: &[TestItem],
+    paths: &DisplayPaths,
+    glyphs: TreeGlyphs,
+) {
+    let _ = writeln!(
+        writer,
+        "{} {}",
+        subcommand_label.bold(),
+        format!("v{}", env!("CARGO_PKG_VERSION")).dimmed()
+    );
+    let _ = writeln!(writer);
+    let mut root = CollectNode::default();
+    for test in tests {
+        root.insert(&collect_tree_path(test, paths), test);
+    }
+    // Top-level nodes are printed flush left; only their descendants
+    // get branch glyphs.
+    for (name, child) in &root.children {
+        let _ = writeln!(writer, "{name} {}", format!("({})", child.count).dimmed());
+        write_collect_node(writer, child, "", glyphs);
+    }
+    for test in &root.tests {
+        let _ = writeln!(writer, "{}", test.display_label().dimmed());
     }
     let _ = writeln!(writer);
     let _ = writeln!(writer, "{} tests collected.", tests.len());
@@ -308,21 +537,28 @@ mod tests {
         String::from_utf8(buf).expect("valid utf-8")
     }
 
+    #[test]
+    fn stopped_run_says_why_it_ended_early() {
+        let mut summary = RunSummary::from_results(&[]);
+        summary.failed = 1;
+        summary.stopped_after = Some(1);
+        let out = render(&summary);
+        assert!(out.contains("stopped after first failure"), "{out}");
+        assert!(out.contains("FAIL"), "{out}");
+
+        summary.stopped_after = Some(3);
+        assert!(render(&summary).contains("stopped after 3 failures"));
+
+        summary.stopped_after = None;
+        assert!(!render(&summary).contains("stopped after"));
+    }
+
     #[test]
     fn all_passed_shows_pass_badge() {
         let out = render(&RunSummary {
             passed: 5,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(50),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(out.contains("PASS"));
         assert!(out.contains("5 passed"));
@@ -338,16 +574,8 @@ mod tests {
         let out = render(&RunSummary {
             passed: 3,
             failed: 1,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(100),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(out.contains("FAIL"));
         assert!(out.contains("1 failed"));
@@ -358,17 +586,9 @@ mod tests {
     fn errors_show_fail_badge() {
         let out = render(&RunSummary {
             passed: 3,
-            failed: 0,
-            skipped: 0,
             errors: 1,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(100),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(out.contains("FAIL"));
         assert!(out.contains("1 error"));
@@ -380,15 +600,8 @@ mod tests {
             passed: 3,
             failed: 1,
             skipped: 2,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(100),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(out.contains("1 failed"));
         assert!(out.contains("3 passed"));
@@ -406,11 +619,8 @@ mod tests {
             xfailed: 1,
             todo: 1,
             duration: Duration::from_millis(200),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            slow: 1,
+            ..Default::default()
         });
         assert!(out.contains("1 failed"));
         assert!(out.contains("1 error"));
@@ -418,24 +628,15 @@ mod tests {
         assert!(out.contains("1 skipped"));
         assert!(out.contains("1 xfail"));
         assert!(out.contains("1 todo"));
+        assert!(out.contains("1 slow"));
         assert!(out.contains("(6)"));
     }
 
     #[test]
     fn zero_tests() {
         let out = render(&RunSummary {
-            passed: 0,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(1),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(out.contains("PASS"));
         assert!(out.contains("0 passed"));
@@ -446,17 +647,8 @@ mod tests {
     fn duration_seconds() {
         let out = render(&RunSummary {
             passed: 1,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(1500),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(out.contains("1.50s"));
     }
@@ -465,17 +657,8 @@ mod tests {
     fn duration_minutes_seconds() {
         let out = render(&RunSummary {
             passed: 1,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(65_500),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(out.contains("1:05.50"), "expected M:SS.SS, got: {out}");
         assert!(!out.contains("65.50s"));
@@ -485,17 +668,8 @@ mod tests {
     fn duration_exactly_one_minute() {
         let out = render(&RunSummary {
             passed: 1,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_secs(60),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(out.contains("1:00.00"));
     }
@@ -505,17 +679,8 @@ mod tests {
         // 119.999s should round up to 2:00.00, not truncate to 1:59.99.
         let out = render(&RunSummary {
             passed: 1,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(119_999),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(out.contains("2:00.00"), "expected carry, got: {out}");
         assert!(!out.contains("1:59.99"));
@@ -525,17 +690,10 @@ mod tests {
     fn duration_breakdown_uses_minutes_seconds() {
         let out = render(&RunSummary {
             passed: 5,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_secs(125),
             discovery_duration: Some(Duration::from_millis(30)),
             test_duration: Some(Duration::from_secs(95)),
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(out.contains("2:05.00"));
         assert!(out.contains("tests 1:35.00"));
@@ -547,16 +705,8 @@ mod tests {
         let out = render(&RunSummary {
             passed: 3,
             failed: 1,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(50),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         let failed_pos = out.find("failed").expect("should contain failed");
         let passed_pos = out.find("passed").expect("should contain passed");
@@ -570,17 +720,10 @@ mod tests {
     fn duration_breakdown_shown() {
         let out = render(&RunSummary {
             passed: 5,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(100),
             discovery_duration: Some(Duration::from_millis(30)),
             test_duration: Some(Duration::from_millis(70)),
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(out.contains("discover 30.00ms"));
         assert!(out.contains("tests 70.00ms"));
@@ -590,17 +733,8 @@ mod tests {
     fn no_breakdown_when_durations_absent() {
         let out = render(&RunSummary {
             passed: 5,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(100),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(!out.contains("discover"));
         assert!(!out.contains("tests "));
@@ -627,17 +761,8 @@ mod tests {
     fn labels_right_aligned() {
         let out = render(&RunSummary {
             passed: 1,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(10),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         let lines: Vec<&str> = out.lines().collect();
         let tests_line = lines.iter().find(|l| l.contains("Tests")).unwrap();
@@ -657,17 +782,8 @@ mod tests {
     fn badge_on_separate_line() {
         let out = render(&RunSummary {
             passed: 1,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(10),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         let lines: Vec<&str> = out.lines().collect();
         let tests_idx = lines.iter().position(|l| l.contains("Tests")).unwrap();
@@ -686,17 +802,9 @@ mod tests {
     fn file_count_shown() {
         let out = render(&RunSummary {
             passed: 5,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(50),
-            discovery_duration: None,
-            test_duration: None,
             file_count: 3,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(out.contains("Test Files"));
         assert!(out.contains("3 passed"));
@@ -706,17 +814,8 @@ mod tests {
     fn file_count_hidden_when_zero() {
         let out = render(&RunSummary {
             passed: 5,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(50),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(!out.contains("Test Files"));
     }
@@ -725,17 +824,9 @@ mod tests {
     fn start_time_shown() {
         let out = render(&RunSummary {
             passed: 1,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(10),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
             start_time: Some("16:28:06".into()),
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(out.contains("Start at"));
         assert!(out.contains("16:28:06"));
@@ -745,17 +836,8 @@ mod tests {
     fn start_time_hidden_when_absent() {
         let out = render(&RunSummary {
             passed: 1,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(10),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(!out.contains("Start at"));
     }
@@ -767,17 +849,8 @@ mod tests {
             &mut buf,
             &RunSummary {
                 passed: 1,
-                failed: 0,
-                skipped: 0,
-                errors: 0,
-                xfailed: 0,
-                todo: 0,
                 duration: Duration::from_millis(10),
-                discovery_duration: None,
-                test_duration: None,
-                file_count: 0,
-                start_time: None,
-                changed_selection: None,
+                ..Default::default()
             },
             Some("Waiting for file changes..."),
         );
@@ -818,17 +891,8 @@ mod tests {
     fn no_watch_hint_when_none() {
         let out = render(&RunSummary {
             passed: 1,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(10),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
         assert!(!out.contains("Waiting"));
     }
@@ -837,23 +901,77 @@ mod tests {
     fn changed_summary_shown() {
         let out = render(&RunSummary {
             passed: 2,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(10),
-            discovery_duration: None,
-            test_duration: None,
             file_count: 1,
-            start_time: None,
             changed_selection: Some(tryke_types::ChangedSelectionSummary {
                 changed_files: 3,
                 affected_tests: 2,
             }),
+            ..Default::default()
         });
         assert!(out.contains("Changed"));
         assert!(out.contains("3 files"));
         assert!(out.contains("2 tests"));
     }
+
+    fn collected(file: &str, name: &str) -> TestItem {
+        TestItem {
+            name: name.into(),
+            module_path: file.trim_end_matches(".py").replace('/', "."),
+            file_path: Some(file.into()),
+            ..TestItem::default()
+        }
+    }
+
+    #[test]
+    fn collect_tree_groups_files_under_shared_directory() {
+        let tests = [
+            collected("tests/test_a.py", "test_add"),
+            collected("tests/test_a.py", "test_sub"),
+            collected("tests/test_b.py", "test_mul"),
+        ];
+        let mut buf = Vec::new();
+        write_collect_tree(
+            &mut buf,
+            "tryke test",
+            &tests,
+            &DisplayPaths::default(),
+            TreeGlyphs::Unicode,
+        );
+        let out = strip_ansi(&String::from_utf8(buf).expect("valid utf-8"));
+        let tree: Vec<&str> = out
+            .lines()
+            .skip_while(|line| !line.starts_with("tests/"))
+            .take_while(|line| !line.is_empty())
+            .collect();
+        assert_eq!(
+            tree,
+            [
+                "tests/ (3)",
+                "├── test_a.py (2)",
+                "│   ├── test_add",
+                "│   └── test_sub",
+                "└── test_b.py (1)",
+                "    └── test_mul",
+            ]
+        );
+        assert!(out.contains("3 tests collected."));
+    }
+
+    #[test]
+    fn collect_tree_ascii_glyphs() {
+        let tests = [collected("tests/test_a.py", "test_add")];
+        let mut buf = Vec::new();
+        write_collect_tree(
+            &mut buf,
+            "tryke test",
+            &tests,
+            &DisplayPaths::default(),
+            TreeGlyphs::Ascii,
+        );
+        let out = strip_ansi(&String::from_utf8(buf).expect("valid utf-8"));
+        assert!(out.contains("`-- test_a.py (1)"));
+        assert!(out.contains("    `-- test_add"));
+        assert!(!out.contains('└'));
+    }
 }
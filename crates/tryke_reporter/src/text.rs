@@ -1,18 +1,25 @@
-use std::collections::HashSet;
-use std::io;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
+use std::io::{self, Write};
 use std::path::PathBuf;
+use std::time::Duration;
 
 use owo_colors::OwoColorize;
-use tryke_types::{RunSummary, TestItem, TestOutcome, TestResult};
+use tryke_types::{
+    BenchmarkStats, RunSummary, SelectionSummary, TestItem, TestOutcome, TestResult, TimingStats,
+};
 
 use tryke_types::{DiscoveryError, DiscoveryWarning, DiscoveryWarningKind};
 
 use crate::Reporter;
+use crate::color::{ColorChoice, ColorWriter, Terminal};
 use crate::diagnostic::{
-    render_assertion, render_assertions, render_captured_output, render_error_message,
-    render_failure_message,
+    render_assertion, render_assertions, render_captured_logs, render_captured_output,
+    render_error_message, render_failure_message, render_failure_template,
 };
-use crate::duration::format_duration;
+use crate::duration::{format_duration, format_precise_duration};
+use crate::relative::DisplayPaths;
+use crate::summary::TreeGlyphs;
 
 #[derive(Debug, Clone, Copy, Default)]
 pub enum Verbosity {
@@ -40,8 +47,12 @@ impl Verbosity {
     }
 }
 
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "Clear state and the --no-duration toggle are independent; no two flags form a state machine."
+)]
 pub struct TextReporter<W: io::Write = io::Stdout> {
-    writer: W,
+    writer: ColorWriter<W>,
     current_file: Option<PathBuf>,
     current_groups: Vec<String>,
     verbosity: Verbosity,
@@ -60,13 +71,133 @@ pub struct TextReporter<W: io::Write = io::Stdout> {
     /// between "save" and "first new result is on screen" by keeping
     /// the previous run visible until results are actually ready.
     header_pending: bool,
+    /// Ids of tests that failed, errored, or unexpectedly passed during
+    /// the current run, each with its `--blame` author when known. Quiet
+    /// mode prints them as a recap above the summary so a long run
+    /// doesn't have to be scrolled back through.
+    failed_ids: Vec<(String, Option<String>)>,
+    /// Collected-vs-selected counts for the next run header. Only
+    /// rendered when a filter narrowed the run.
+    selection: Option<SelectionSummary>,
+    /// `Some` under `--timings`: passing tests get a duration bar scaled
+    /// to the slowest test, so results are buffered until the run
+    /// completes.
+    timings_buffer: Option<Vec<TestResult>>,
+    /// Slowest duration in the run. `Some` only while `on_run_complete`
+    /// replays `timings_buffer`, which is how `on_test_complete` tells a
+    /// replay apart from a live result it should buffer.
+    timings_scale: Option<Duration>,
+    /// `skip_category` of every skipped test in the current run, rendered
+    /// as a per-category tally above the summary.
+    skip_categories: Vec<Option<String>>,
+    /// `Some` under `--show-skipped-summary`: the reason of every skipped
+    /// test in the current run, grouped into a recap above the summary.
+    skip_reasons: Option<Vec<Option<String>>>,
+    /// `Some` under `--collect-only --tree`: collected tests render as a
+    /// directory tree drawn with these glyphs instead of a flat list.
+    collect_tree: Option<TreeGlyphs>,
+    /// `--collect-only --show-assertions`: list each collected test's
+    /// expected assertions beneath it.
+    collect_assertions: bool,
+    /// `false` under `--no-duration`: per-test `[12ms]` suffixes and the
+    /// summary's `Duration` line are left out, for diffable output.
+    show_durations: bool,
+    /// `[tool.tryke] failure_template`: when set, each failed assertion
+    /// prints as one filled-in line instead of a source diagnostic.
+    failure_template: Option<String>,
+    /// `Some` under `--group-summary`: outcomes of the current file's
+    /// tests, printed as a footer when the next file starts.
+    file_tally: Option<FileTally>,
+    /// `--timing-stats`: print the run's mean and p50/p95/p99 test
+    /// durations above the summary.
+    timing_stats: bool,
+    /// `--durations N`: list the N slowest tests above the summary. `0`
+    /// leaves the list out.
+    slowest_count: usize,
+    /// Duration and id of each test in the current run, recorded only
+    /// while `slowest_count` is non-zero.
+    test_durations: Vec<(Duration, String)>,
+    /// `Some` under `--blame`: names the last editor of a failed test,
+    /// shown beside it in the failure recap, which then prints at every
+    /// verbosity.
+    blame: Option<BlameFn>,
+    /// `--show-capture`: print passing tests' captured stdout and stderr
+    /// too, not just failing tests'.
+    show_capture: bool,
+    /// `--quiet-pass-dot`: a passing test prints a single `.` instead of
+    /// its line, so only failures and other outcomes stand out.
+    pass_dots: bool,
+    /// Off under `--no-assertion-footer`: a failure's diagnostics aren't
+    /// followed by its `N/M assertions failed` line.
+    assertion_footer: bool,
+    /// A run of pass dots has been written without its closing newline.
+    dot_line_open: bool,
+    /// `Some` under `--max-failures-shown N`: only the first N failures
+    /// render their diagnostics; later ones print just their `✗` line.
+    max_failures_shown: Option<usize>,
+    /// Failed tests reported so far in the current run.
+    failures_seen: usize,
+    /// How file paths print; re-rooted under `--relative-to`.
+    display_paths: DisplayPaths,
+}
+
+/// Looks up who last edited a test's source, for the `--blame` recap.
+pub type BlameFn = Box<dyn Fn(&TestItem) -> Option<String>>;
+
+/// Per-file outcome counts for the `--group-summary` footer.
+#[derive(Debug, Default)]
+struct FileTally {
+    passed: usize,
+    failed: usize,
+    errors: usize,
+    skipped: usize,
+    xfailed: usize,
+    todo: usize,
+}
+
+impl FileTally {
+    fn record(&mut self, outcome: &TestOutcome) {
+        let count = match outcome {
+            TestOutcome::Passed => &mut self.passed,
+            TestOutcome::Failed { .. } | TestOutcome::XPassed => &mut self.failed,
+            TestOutcome::Error { .. } => &mut self.errors,
+            TestOutcome::Skipped { .. } => &mut self.skipped,
+            TestOutcome::XFailed { .. } => &mut self.xfailed,
+            TestOutcome::Todo { .. } => &mut self.todo,
+        };
+        *count += 1;
+    }
+
+    /// `5 passed, 1 failed`, leaving out zero counts.
+    fn render(&self) -> String {
+        let mut parts = Vec::new();
+        if self.passed > 0 {
+            parts.push(format!("{} passed", self.passed).green().to_string());
+        }
+        if self.failed > 0 {
+            parts.push(format!("{} failed", self.failed).red().to_string());
+        }
+        if self.errors > 0 {
+            parts.push(format!("{} errors", self.errors).red().to_string());
+        }
+        if self.skipped > 0 {
+            parts.push(format!("{} skipped", self.skipped).yellow().to_string());
+        }
+        if self.xfailed > 0 {
+            parts.push(format!("{} xfailed", self.xfailed).dimmed().to_string());
+        }
+        if self.todo > 0 {
+            parts.push(format!("{} todo", self.todo).cyan().to_string());
+        }
+        parts.join(", ")
+    }
 }
 
 impl TextReporter {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            writer: io::stdout(),
+            writer: ColorWriter::new(io::stdout()),
             current_file: None,
             current_groups: Vec::new(),
             verbosity: Verbosity::Normal,
@@ -75,13 +206,35 @@ impl TextReporter {
             clear_armed: false,
             clear_enabled: crate::clear::stdout_is_terminal(),
             header_pending: false,
+            failed_ids: Vec::new(),
+            selection: None,
+            timings_buffer: None,
+            timings_scale: None,
+            skip_categories: Vec::new(),
+            skip_reasons: None,
+            collect_tree: None,
+            collect_assertions: false,
+            show_durations: true,
+            failure_template: None,
+            file_tally: None,
+            timing_stats: false,
+            slowest_count: 0,
+            test_durations: Vec::new(),
+            blame: None,
+            show_capture: false,
+            pass_dots: false,
+            assertion_footer: true,
+            dot_line_open: false,
+            max_failures_shown: None,
+            failures_seen: 0,
+            display_paths: DisplayPaths::default(),
         }
     }
 
     #[must_use]
     pub fn with_verbosity(verbosity: Verbosity) -> Self {
         Self {
-            writer: io::stdout(),
+            writer: ColorWriter::new(io::stdout()),
             current_file: None,
             current_groups: Vec::new(),
             verbosity,
@@ -90,6 +243,28 @@ impl TextReporter {
             clear_armed: false,
             clear_enabled: crate::clear::stdout_is_terminal(),
             header_pending: false,
+            failed_ids: Vec::new(),
+            selection: None,
+            timings_buffer: None,
+            timings_scale: None,
+            skip_categories: Vec::new(),
+            skip_reasons: None,
+            collect_tree: None,
+            collect_assertions: false,
+            show_durations: true,
+            failure_template: None,
+            file_tally: None,
+            timing_stats: false,
+            slowest_count: 0,
+            test_durations: Vec::new(),
+            blame: None,
+            show_capture: false,
+            pass_dots: false,
+            assertion_footer: true,
+            dot_line_open: false,
+            max_failures_shown: None,
+            failures_seen: 0,
+            display_paths: DisplayPaths::default(),
         }
     }
 }
@@ -103,7 +278,7 @@ impl Default for TextReporter {
 impl<W: io::Write> TextReporter<W> {
     pub fn with_writer(writer: W) -> Self {
         Self {
-            writer,
+            writer: ColorWriter::new(writer),
             current_file: None,
             current_groups: Vec::new(),
             verbosity: Verbosity::Normal,
@@ -112,12 +287,34 @@ impl<W: io::Write> TextReporter<W> {
             clear_armed: false,
             clear_enabled: false,
             header_pending: false,
+            failed_ids: Vec::new(),
+            selection: None,
+            timings_buffer: None,
+            timings_scale: None,
+            skip_categories: Vec::new(),
+            skip_reasons: None,
+            collect_tree: None,
+            collect_assertions: false,
+            show_durations: true,
+            failure_template: None,
+            file_tally: None,
+            timing_stats: false,
+            slowest_count: 0,
+            test_durations: Vec::new(),
+            blame: None,
+            show_capture: false,
+            pass_dots: false,
+            assertion_footer: true,
+            dot_line_open: false,
+            max_failures_shown: None,
+            failures_seen: 0,
+            display_paths: DisplayPaths::default(),
         }
     }
 
     pub fn with_writer_and_verbosity(writer: W, verbosity: Verbosity) -> Self {
         Self {
-            writer,
+            writer: ColorWriter::new(writer),
             current_file: None,
             current_groups: Vec::new(),
             verbosity,
@@ -126,11 +323,204 @@ impl<W: io::Write> TextReporter<W> {
             clear_armed: false,
             clear_enabled: false,
             header_pending: false,
+            failed_ids: Vec::new(),
+            selection: None,
+            timings_buffer: None,
+            timings_scale: None,
+            skip_categories: Vec::new(),
+            skip_reasons: None,
+            collect_tree: None,
+            collect_assertions: false,
+            show_durations: true,
+            failure_template: None,
+            file_tally: None,
+            timing_stats: false,
+            slowest_count: 0,
+            test_durations: Vec::new(),
+            blame: None,
+            show_capture: false,
+            pass_dots: false,
+            assertion_footer: true,
+            dot_line_open: false,
+            max_failures_shown: None,
+            failures_seen: 0,
+            display_paths: DisplayPaths::default(),
+        }
+    }
+
+    /// Render a proportional duration bar next to each passing test.
+    #[must_use]
+    pub fn with_timings(mut self, timings: bool) -> Self {
+        self.timings_buffer = timings.then(Vec::new);
+        self
+    }
+
+    /// Omit per-test durations and the summary's `Duration` line.
+    #[must_use]
+    pub fn with_durations(mut self, show: bool) -> Self {
+        self.show_durations = show;
+        self
+    }
+
+    /// Render `--collect-only` output as a directory tree.
+    #[must_use]
+    pub fn with_collect_tree(mut self, glyphs: Option<TreeGlyphs>) -> Self {
+        self.collect_tree = glyphs;
+        self
+    }
+
+    /// List each test's expected assertions in `--collect-only` output.
+    #[must_use]
+    pub fn with_collect_assertions(mut self, show: bool) -> Self {
+        self.collect_assertions = show;
+        self
+    }
+
+    /// Attribute each failed test in the failure recap to the author
+    /// `blame` returns for it. `None` leaves the recap to quiet mode.
+    #[must_use]
+    pub fn with_blame(mut self, blame: Option<BlameFn>) -> Self {
+        self.blame = blame;
+        self
+    }
+
+    /// Print captured stdout and stderr under passing tests as well as
+    /// failing ones.
+    #[must_use]
+    pub fn with_show_capture(mut self, enabled: bool) -> Self {
+        self.show_capture = enabled;
+        self
+    }
+
+    /// Print failed assertions through `template` (see
+    /// [`render_failure_template`]). `None` keeps the default diagnostics.
+    #[must_use]
+    pub fn with_failure_template(mut self, template: Option<String>) -> Self {
+        self.failure_template = template;
+        self
+    }
+
+    /// Close each file's results with a footer of its pass/fail counts.
+    #[must_use]
+    pub fn with_group_summary(mut self, show: bool) -> Self {
+        self.file_tally = show.then(FileTally::default);
+        self
+    }
+
+    /// Print the distribution of test durations above the summary.
+    #[must_use]
+    pub fn with_timing_stats(mut self, show: bool) -> Self {
+        self.timing_stats = show;
+        self
+    }
+
+    /// Recap skipped tests grouped by their skip reason above the summary.
+    #[must_use]
+    pub fn with_skipped_summary(mut self, show: bool) -> Self {
+        self.skip_reasons = show.then(Vec::new);
+        self
+    }
+
+    /// List the `count` slowest tests above the summary. `0` disables the
+    /// list.
+    #[must_use]
+    pub fn with_slowest(mut self, count: usize) -> Self {
+        self.slowest_count = count;
+        self
+    }
+
+    /// Collapse each passing test to a `.` on a shared line; every other
+    /// outcome still prints in full.
+    #[must_use]
+    pub fn with_pass_dots(mut self, enabled: bool) -> Self {
+        self.pass_dots = enabled;
+        self
+    }
+
+    /// Print the `N/M assertions failed` line under a failure's
+    /// diagnostics. On by default.
+    #[must_use]
+    pub fn with_assertion_footer(mut self, enabled: bool) -> Self {
+        self.assertion_footer = enabled;
+        self
+    }
+
+    /// End the current line of pass dots, if any, so the next output
+    /// starts on a line of its own.
+    fn end_dot_line(&mut self) {
+        if self.dot_line_open {
+            let _ = writeln!(self.writer);
+            self.dot_line_open = false;
+        }
+    }
+
+    /// Under `--group-summary`, print the current file's counts and start
+    /// a fresh tally. Nothing is printed before the first file.
+    fn write_file_summary(&mut self) {
+        let Some(tally) = self.file_tally.as_mut().map(std::mem::take) else {
+            return;
+        };
+        if matches!(self.verbosity, Verbosity::Quiet) {
+            return;
+        }
+        if let Some(path) = &self.current_file {
+            let _ = writeln!(
+                self.writer,
+                "  {}: {}",
+                path.display().dimmed(),
+                tally.render()
+            );
         }
     }
 
+    /// The dimmed ` [12ms]` suffix after a test name, or nothing under
+    /// `--no-duration`.
+    /// The ` [12ms]` after a test's name, plus a `slow` marker when the
+    /// test ran past `--slow-threshold`.
+    fn duration_suffix(&self, result: &TestResult) -> String {
+        let mut suffix = if self.show_durations {
+            format!(
+                " {}",
+                format!("[{}]", format_duration(result.duration)).dimmed()
+            )
+        } else {
+            String::new()
+        };
+        if result.slow {
+            suffix.push(' ');
+            suffix.push_str(&"slow".yellow().to_string());
+        }
+        suffix
+    }
+
+    /// Render full diagnostics for only the first `max` failures of a
+    /// run and close it with a count of the rest. `None` shows them all.
+    #[must_use]
+    pub fn with_max_failures_shown(mut self, max: Option<usize>) -> Self {
+        self.max_failures_shown = max;
+        self
+    }
+
     pub fn into_writer(self) -> W {
-        self.writer
+        self.writer.into_inner()
+    }
+
+    /// Drop ANSI colors and styles from the output when `color` is false.
+    #[must_use]
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.writer.set_color(color);
+        self
+    }
+
+    /// Color the output as `choice` says for this reporter's own writer,
+    /// so `--color auto` colors a terminal but not a file.
+    #[must_use]
+    pub fn with_color_choice(self, choice: ColorChoice) -> Self
+    where
+        W: Terminal,
+    {
+        let color = choice.enabled_for(self.writer.get_ref());
+        self.with_color(color)
     }
 
     /// Honor the most recent `arm_clear()` before producing any new
@@ -154,6 +544,38 @@ impl<W: io::Write> TextReporter<W> {
             self.subcommand_label.bold(),
             format!("v{}", env!("CARGO_PKG_VERSION")).dimmed()
         );
+        if let Some(selection) = &self.selection
+            && let Some(cached) = selection.cached
+            && cached > 0
+        {
+            let _ = writeln!(
+                self.writer,
+                "{}",
+                format!("discovered {} tests ({cached} cached)", selection.collected).dimmed()
+            );
+        }
+        if let Some(selection) = &self.selection
+            && let Some(via) = &selection.via
+        {
+            let _ = writeln!(
+                self.writer,
+                "{}",
+                format!(
+                    "collected {}, selected {} (via {via})",
+                    selection.collected, selection.selected
+                )
+                .dimmed()
+            );
+        }
+        if let Some(selection) = &self.selection
+            && let Some(seed) = selection.seed
+        {
+            let _ = writeln!(
+                self.writer,
+                "{}",
+                format!("shuffled with seed {seed} (rerun with --shuffle --seed {seed})").dimmed()
+            );
+        }
         let _ = writeln!(self.writer);
     }
 
@@ -178,22 +600,147 @@ fn write_expected_assertions<W: io::Write>(writer: &mut W, indent: &str, result:
         } else {
             HashSet::new()
         };
-    for a in &result.test.expected_assertions {
-        let not_part = if a.negated { "not_." } else { "" };
-        let args_str = a.args.join(", ");
-        let assertion = format!(
-            "expect({}).{}{}({})",
-            a.subject, not_part, a.matcher, args_str
+    // Collection order can drift from source order once assertions come
+    // from helpers or comprehensions; the checklist reads top-to-bottom.
+    let mut expected = result.test.expected_assertions.clone();
+    expected.sort_by_key(|a| a.line);
+    crate::summary::write_assertion_checklist(writer, indent, &expected, Some(&failed_lines));
+}
+
+/// Width of a `--timings` bar for the slowest test, in cells.
+const TIMINGS_BAR_WIDTH: u128 = 20;
+
+/// Render `duration` as a bar of block characters proportional to `max`,
+/// using eighth-blocks so short tests still get a visible sliver.
+fn duration_bar(duration: Duration, max: Duration) -> String {
+    const PARTIAL: [&str; 8] = ["", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+    let max = max.as_nanos();
+    if max == 0 {
+        return String::new();
+    }
+    let eighths = (duration.as_nanos().min(max) * TIMINGS_BAR_WIDTH * 8 + max / 2) / max;
+    let full = usize::try_from(eighths / 8).unwrap_or_default();
+    let partial = usize::try_from(eighths % 8).unwrap_or_default();
+    let mut bar = "█".repeat(full);
+    bar.push_str(PARTIAL[partial]);
+    bar
+}
+
+/// Tally skipped tests by `skip_category`. Skipped entirely when no skip
+/// in the run declared a category, so plain `@test.skip` suites keep
+/// their existing output.
+fn write_skip_categories<W: io::Write>(writer: &mut W, categories: &[Option<String>]) {
+    if categories.iter().all(Option::is_none) {
+        return;
+    }
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut uncategorized = 0;
+    for category in categories {
+        match category {
+            Some(category) => *counts.entry(category).or_default() += 1,
+            None => uncategorized += 1,
+        }
+    }
+    let _ = writeln!(writer);
+    let _ = writeln!(writer, "{}", "skipped by category:".yellow().bold());
+    for (category, count) in counts {
+        let _ = writeln!(writer, "  {category} {}", format!("({count})").dimmed());
+    }
+    if uncategorized > 0 {
+        let _ = writeln!(
+            writer,
+            "  {} {}",
+            "uncategorized".dimmed(),
+            format!("({uncategorized})").dimmed()
         );
-        let text = a.label.as_deref().unwrap_or(&assertion);
-        if failed_lines.contains(&(a.line as usize)) {
-            let _ = writeln!(writer, "{indent}{} {}", "✗".red(), text.dimmed());
-        } else {
-            let _ = writeln!(writer, "{indent}{} {}", "✓".green(), text.dimmed());
+    }
+}
+
+/// Group skipped tests by reason, most common first, as
+/// `<reason> — <count> tests` lines. Tests skipped without a reason share
+/// a `no reason` group, listed after reasons with the same count.
+fn write_skip_reasons<W: io::Write>(writer: &mut W, reasons: &[Option<String>]) {
+    if reasons.is_empty() {
+        return;
+    }
+    let mut counts: BTreeMap<Option<&str>, usize> = BTreeMap::new();
+    for reason in reasons {
+        *counts.entry(reason.as_deref()).or_default() += 1;
+    }
+    let mut groups: Vec<(Option<&str>, usize)> = counts.into_iter().collect();
+    groups.sort_by(|(a_reason, a), (b_reason, b)| {
+        b.cmp(a)
+            .then_with(|| a_reason.is_none().cmp(&b_reason.is_none()))
+    });
+    let _ = writeln!(writer);
+    let _ = writeln!(
+        writer,
+        "{}",
+        format!("skipped ({}):", reasons.len()).yellow().bold()
+    );
+    for (reason, count) in groups {
+        let noun = if count == 1 { "test" } else { "tests" };
+        let tally = format!("— {count} {noun}");
+        match reason {
+            Some(reason) => {
+                let _ = writeln!(writer, "  {reason} {}", tally.dimmed());
+            }
+            None => {
+                let _ = writeln!(writer, "  {} {}", "no reason".dimmed(), tally.dimmed());
+            }
         }
     }
 }
 
+fn write_timing_stats<W: io::Write>(writer: &mut W, stats: &TimingStats) {
+    let _ = writeln!(writer);
+    let _ = writeln!(
+        writer,
+        "{} mean {} {} p50 {} {} p95 {} {} p99 {}",
+        "timing:".bold(),
+        format_duration(stats.mean),
+        "·".dimmed(),
+        format_duration(stats.p50),
+        "·".dimmed(),
+        format_duration(stats.p95),
+        "·".dimmed(),
+        format_duration(stats.p99),
+    );
+}
+
+/// List the `count` longest of `durations`, slowest first. Equal
+/// durations are ordered by test id so the list is stable across runs.
+fn write_slowest<W: io::Write>(writer: &mut W, durations: &[(Duration, String)], count: usize) {
+    let mut ranked: Vec<&(Duration, String)> = durations.iter().collect();
+    ranked.sort_by(|(a, a_id), (b, b_id)| b.cmp(a).then_with(|| a_id.cmp(b_id)));
+    ranked.truncate(count);
+    if ranked.is_empty() {
+        return;
+    }
+    let formatted: Vec<String> = ranked.iter().map(|(d, _)| format_duration(*d)).collect();
+    let width = formatted.iter().map(String::len).max().unwrap_or(0);
+    let _ = writeln!(writer);
+    let _ = writeln!(
+        writer,
+        "{}",
+        format!("slowest {} tests:", ranked.len()).bold()
+    );
+    for (duration, (_, id)) in formatted.iter().zip(&ranked) {
+        let _ = writeln!(writer, "  {} {id}", format!("{duration:>width$}").dimmed());
+    }
+}
+
+fn write_benchmark<W: io::Write>(writer: &mut W, indent: &str, stats: &BenchmarkStats) {
+    let line = format!(
+        "min {} · mean {} · stddev {} over {} runs",
+        format_precise_duration(stats.min),
+        format_precise_duration(stats.mean),
+        format_precise_duration(stats.stddev),
+        stats.iterations,
+    );
+    let _ = writeln!(writer, "{indent}{}", line.dimmed());
+}
+
 fn write_captured<W: io::Write>(writer: &mut W, label: &str, content: &str) {
     let mut buf = String::new();
     render_captured_output(label, content, &mut buf);
@@ -204,6 +751,20 @@ impl<W: io::Write> Reporter for TextReporter<W> {
     fn on_run_start(&mut self, _tests: &[TestItem]) {
         self.current_file = None;
         self.current_groups.clear();
+        self.failed_ids.clear();
+        self.skip_categories.clear();
+        self.test_durations.clear();
+        self.dot_line_open = false;
+        self.failures_seen = 0;
+        if let Some(reasons) = &mut self.skip_reasons {
+            reasons.clear();
+        }
+        if let Some(tally) = &mut self.file_tally {
+            *tally = FileTally::default();
+        }
+        if let Some(buffer) = &mut self.timings_buffer {
+            buffer.clear();
+        }
         if self.clear_armed {
             // Hold the header until the first content event lands —
             // see `header_pending` doc on the struct.
@@ -215,24 +776,39 @@ impl<W: io::Write> Reporter for TextReporter<W> {
 
     #[expect(clippy::too_many_lines)]
     fn on_test_complete(&mut self, result: &TestResult) {
+        if self.timings_scale.is_none()
+            && let Some(buffer) = &mut self.timings_buffer
+        {
+            buffer.push(result.clone());
+            return;
+        }
         self.flush_pending_header();
-        let file = result.test.file_path.as_ref();
-        if file != self.current_file.as_ref() {
+        let dotted = self.pass_dots
+            && matches!(result.outcome, TestOutcome::Passed)
+            && !matches!(self.verbosity, Verbosity::Quiet);
+        if !dotted {
+            self.end_dot_line();
+        }
+        let file = self.display_paths.file(&result.test);
+        if file.as_deref() != self.current_file.as_deref() {
+            self.end_dot_line();
+            self.write_file_summary();
             if !matches!(self.verbosity, Verbosity::Quiet) {
                 if self.current_file.is_some() {
                     let _ = writeln!(self.writer);
                 }
-                if let Some(path) = file {
+                if let Some(path) = &file {
                     let _ = writeln!(self.writer, "{}:", path.display());
                 }
             }
-            self.current_file = file.cloned();
+            self.current_file = file.map(Cow::into_owned);
             self.current_groups.clear();
         }
 
         // Print group headers when groups change
         let test_groups = &result.test.groups;
         if !matches!(self.verbosity, Verbosity::Quiet) && test_groups != &self.current_groups {
+            self.end_dot_line();
             // Find where the current and new group paths diverge
             let common = self
                 .current_groups
@@ -254,20 +830,56 @@ impl<W: io::Write> Reporter for TextReporter<W> {
             "  ".repeat(test_groups.len() + 1)
         };
 
+        if matches!(
+            result.outcome,
+            TestOutcome::Failed { .. } | TestOutcome::Error { .. } | TestOutcome::XPassed
+        ) {
+            let author = self.blame.as_ref().and_then(|blame| blame(&result.test));
+            self.failed_ids.push((result.test.id(), author));
+        }
+        if let Some(tally) = &mut self.file_tally {
+            tally.record(&result.outcome);
+        }
+        if self.slowest_count > 0 {
+            self.test_durations
+                .push((result.duration, result.test.id()));
+        }
+
         let display = result.test.display_label();
         let display = display.as_str();
         match &result.outcome {
+            TestOutcome::Passed if dotted => {
+                let _ = write!(self.writer, "{}", ".".green());
+                let _ = self.writer.flush();
+                self.dot_line_open = true;
+            }
             TestOutcome::Passed => {
                 if !matches!(self.verbosity, Verbosity::Quiet) {
+                    let bar = self
+                        .timings_scale
+                        .map(|max| format!(" {}", duration_bar(result.duration, max)))
+                        .unwrap_or_default();
                     let _ = writeln!(
                         self.writer,
-                        "{group_indent}{} {} {}",
+                        "{group_indent}{} {}{}{}",
                         "✓".green(),
                         display,
-                        format!("[{}]", format_duration(result.duration)).dimmed()
+                        bar.cyan(),
+                        self.duration_suffix(result)
                     );
                     let assert_indent = "  ".repeat(test_groups.len() + 2);
                     write_expected_assertions(&mut self.writer, &assert_indent, result);
+                    if let Some(stats) = &result.benchmark {
+                        write_benchmark(&mut self.writer, &assert_indent, stats);
+                    }
+                    if self.show_capture {
+                        if !result.stdout.is_empty() {
+                            write_captured(&mut self.writer, "stdout", &result.stdout);
+                        }
+                        if !result.stderr.is_empty() {
+                            write_captured(&mut self.writer, "stderr", &result.stderr);
+                        }
+                    }
                 }
             }
             TestOutcome::Failed {
@@ -276,20 +888,41 @@ impl<W: io::Write> Reporter for TextReporter<W> {
                 assertions,
                 executed_lines,
             } => {
+                // A timed-out test gets its own glyph so it stands out
+                // from ordinary assertion failures.
+                let glyph = if result.timed_out { "⏱" } else { "✗" };
                 let _ = writeln!(
                     self.writer,
-                    "{group_indent}{} {} {}",
-                    "✗".red(),
+                    "{group_indent}{} {}{}",
+                    glyph.red(),
                     display,
-                    format!("[{}]", format_duration(result.duration)).dimmed()
+                    self.duration_suffix(result)
                 );
-                let test_file = result
-                    .test
-                    .file_path
-                    .as_ref()
+                self.failures_seen += 1;
+                if self
+                    .max_failures_shown
+                    .is_some_and(|max| self.failures_seen > max)
+                {
+                    return;
+                }
+                let test_file = self
+                    .display_paths
+                    .file(&result.test)
                     .map(|p| p.to_string_lossy().into_owned());
                 let show_full_traceback = !matches!(self.verbosity, Verbosity::Quiet);
-                if !matches!(self.verbosity, Verbosity::Quiet)
+                if let Some(template) = &self.failure_template
+                    && !assertions.is_empty()
+                {
+                    for assertion in assertions {
+                        let line = render_failure_template(
+                            template,
+                            &result.test,
+                            test_file.as_deref(),
+                            assertion,
+                        );
+                        let _ = writeln!(self.writer, "{group_indent}  {line}");
+                    }
+                } else if !matches!(self.verbosity, Verbosity::Quiet)
                     && !result.test.expected_assertions.is_empty()
                 {
                     // Interleave expectation lines with inline diagnostics.
@@ -309,6 +942,17 @@ impl<W: io::Write> Reporter for TextReporter<W> {
                             ea.subject, not_part, ea.matcher, args_str
                         );
                         let text = ea.label.as_deref().unwrap_or(&full);
+                        // Pending assertions never ran, so they can't be
+                        // matched against a failure or an executed line.
+                        if ea.pending {
+                            let _ = writeln!(
+                                self.writer,
+                                "{assert_indent}{} {}",
+                                "○".cyan(),
+                                text.dimmed()
+                            );
+                            continue;
+                        }
                         let ea_line = ea.line as usize;
                         let matched_index =
                             assertions
@@ -354,9 +998,14 @@ impl<W: io::Write> Reporter for TextReporter<W> {
                             let _ = writeln!(self.writer, "{group_indent}  {line}");
                         }
                     }
-                    if !assertions.is_empty() {
-                        let total_assertions =
-                            result.test.expected_assertions.len() + unmatched_failures.len();
+                    if self.assertion_footer && !assertions.is_empty() {
+                        let total_assertions = result
+                            .test
+                            .expected_assertions
+                            .iter()
+                            .filter(|ea| !ea.pending)
+                            .count()
+                            + unmatched_failures.len();
                         let _ = writeln!(
                             self.writer,
                             "{group_indent}  {}/{} assertions failed",
@@ -381,7 +1030,12 @@ impl<W: io::Write> Reporter for TextReporter<W> {
                     }
                 } else if !assertions.is_empty() {
                     let mut buf = String::new();
-                    render_assertions(test_file.as_deref(), assertions, &mut buf);
+                    render_assertions(
+                        test_file.as_deref(),
+                        assertions,
+                        self.assertion_footer,
+                        &mut buf,
+                    );
                     for line in buf.lines() {
                         let _ = writeln!(self.writer, "{group_indent}{line}");
                     }
@@ -401,6 +1055,11 @@ impl<W: io::Write> Reporter for TextReporter<W> {
                 if !result.stderr.is_empty() {
                     write_captured(&mut self.writer, "stderr", &result.stderr);
                 }
+                if matches!(self.verbosity, Verbosity::Verbose) && !result.logs.is_empty() {
+                    let mut buf = String::new();
+                    render_captured_logs(&result.logs, &mut buf);
+                    let _ = write!(self.writer, "{buf}");
+                }
             }
             TestOutcome::Error { message } => {
                 let _ = writeln!(
@@ -418,6 +1077,10 @@ impl<W: io::Write> Reporter for TextReporter<W> {
                 }
             }
             TestOutcome::Skipped { reason } => {
+                self.skip_categories.push(result.test.skip_category.clone());
+                if let Some(reasons) = &mut self.skip_reasons {
+                    reasons.push(reason.clone());
+                }
                 if !matches!(self.verbosity, Verbosity::Quiet) {
                     let suffix = reason
                         .as_deref()
@@ -475,15 +1138,80 @@ impl<W: io::Write> Reporter for TextReporter<W> {
     }
 
     fn on_collect_complete(&mut self, tests: &[TestItem]) {
-        crate::summary::write_collect_list(&mut self.writer, self.subcommand_label, tests);
+        match self.collect_tree {
+            Some(glyphs) => crate::summary::write_collect_tree(
+                &mut self.writer,
+                self.subcommand_label,
+                tests,
+                &self.display_paths,
+                glyphs,
+            ),
+            None => {
+                crate::summary::write_collect_list(
+                    &mut self.writer,
+                    self.subcommand_label,
+                    tests,
+                    &self.display_paths,
+                    self.collect_assertions,
+                );
+            }
+        }
     }
 
     fn on_run_complete(&mut self, summary: &RunSummary) {
+        if let Some(buffered) = self.timings_buffer.as_mut().map(std::mem::take) {
+            let max = buffered.iter().map(|r| r.duration).max();
+            self.timings_scale = Some(max.unwrap_or_default());
+            for result in &buffered {
+                self.on_test_complete(result);
+            }
+            self.timings_scale = None;
+        }
         self.flush_pending_header();
-        crate::summary::write_summary_with_hint(
+        self.end_dot_line();
+        self.write_file_summary();
+        let hidden = self
+            .max_failures_shown
+            .map_or(0, |max| self.failures_seen.saturating_sub(max));
+        if hidden > 0 {
+            let noun = if hidden == 1 { "failure" } else { "failures" };
+            let _ = writeln!(self.writer);
+            let _ = writeln!(
+                self.writer,
+                "{}",
+                format!("… and {hidden} more {noun}").dimmed()
+            );
+        }
+        if (matches!(self.verbosity, Verbosity::Quiet) || self.blame.is_some())
+            && !self.failed_ids.is_empty()
+        {
+            let _ = writeln!(self.writer);
+            let _ = writeln!(self.writer, "{}", "failures:".red().bold());
+            for (id, author) in &self.failed_ids {
+                let author = author
+                    .as_ref()
+                    .map(|author| format!(" {}", format!("(last edited by {author})").dimmed()))
+                    .unwrap_or_default();
+                let _ = writeln!(self.writer, "  {} {id}{author}", "✗".red());
+            }
+        }
+        if !matches!(self.verbosity, Verbosity::Quiet) {
+            write_skip_categories(&mut self.writer, &self.skip_categories);
+            if let Some(reasons) = &self.skip_reasons {
+                write_skip_reasons(&mut self.writer, reasons);
+            }
+            if self.timing_stats
+                && let Some(stats) = &summary.timing_stats
+            {
+                write_timing_stats(&mut self.writer, stats);
+            }
+            write_slowest(&mut self.writer, &self.test_durations, self.slowest_count);
+        }
+        crate::summary::write_summary_with_options(
             &mut self.writer,
             summary,
             self.watch_hint.as_deref(),
+            self.show_durations,
         );
     }
 
@@ -493,7 +1221,11 @@ impl<W: io::Write> Reporter for TextReporter<W> {
             self.writer,
             "{} {}: {}",
             "!".red(),
-            error.file_path.display().to_string().yellow(),
+            self.display_paths
+                .path(&error.file_path)
+                .display()
+                .to_string()
+                .yellow(),
             error.message
         );
     }
@@ -506,10 +1238,18 @@ impl<W: io::Write> Reporter for TextReporter<W> {
         self.watch_hint = hint;
     }
 
+    fn set_selection(&mut self, selection: &SelectionSummary) {
+        self.selection = Some(selection.clone());
+    }
+
     fn arm_clear(&mut self) {
         self.clear_armed = true;
     }
 
+    fn set_display_paths(&mut self, paths: &DisplayPaths) {
+        self.display_paths = paths.clone();
+    }
+
     fn on_watch_idle(&mut self, info: &crate::reporter::WatchIdleInfo<'_>) {
         // Honor any pending clear (and only then). Discovery warnings
         // emitted just before this call already flushed the clear and
@@ -543,7 +1283,11 @@ impl<W: io::Write> Reporter for TextReporter<W> {
                     self.writer,
                     "{} {} — dynamic imports found; this file will always re-run with {}",
                     "warning:".yellow().bold(),
-                    warning.file_path.display().to_string().yellow(),
+                    self.display_paths
+                        .path(&warning.file_path)
+                        .display()
+                        .to_string()
+                        .yellow(),
                     "--changed".bold(),
                 );
                 let _ = writeln!(
@@ -554,7 +1298,10 @@ impl<W: io::Write> Reporter for TextReporter<W> {
                 );
             }
             DiscoveryWarningKind::TestingGuardHasElseBranch
-            | DiscoveryWarningKind::DistModeUpgrade => {
+            | DiscoveryWarningKind::DistModeUpgrade
+            | DiscoveryWarningKind::AssertionCount
+            | DiscoveryWarningKind::ShadowedTest
+            | DiscoveryWarningKind::SyntaxError => {
                 let _ = writeln!(
                     self.writer,
                     "{} {}",
@@ -571,7 +1318,7 @@ mod tests {
     use std::path::PathBuf;
     use std::time::Duration;
 
-    use tryke_types::{Assertion, TestOutcome};
+    use tryke_types::{Assertion, LogRecord, TestOutcome};
 
     use super::*;
 
@@ -580,7 +1327,75 @@ mod tests {
     }
 
     fn output(reporter: &TextReporter<Vec<u8>>) -> String {
-        String::from_utf8_lossy(&reporter.writer).into_owned()
+        String::from_utf8_lossy(reporter.writer.get_ref()).into_owned()
+    }
+
+    /// A writer that reports itself as a terminal.
+    #[derive(Default)]
+    struct FakeTerminal(Vec<u8>);
+
+    impl io::Write for FakeTerminal {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Terminal for FakeTerminal {
+        fn is_terminal(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn auto_color_is_decided_per_writer() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("report.txt");
+        let file = std::fs::File::create(&path).expect("create report file");
+        let mut on_terminal =
+            TextReporter::with_writer(FakeTerminal::default()).with_color_choice(ColorChoice::Auto);
+        let mut on_file = TextReporter::with_writer(file).with_color_choice(ColorChoice::Auto);
+        let results = [make_passed("test_ok", vec![])];
+        for reporter in [&mut on_terminal as &mut dyn Reporter, &mut on_file] {
+            reporter.on_run_start(&[]);
+            reporter.on_test_complete(&results[0]);
+            reporter.on_run_complete(&RunSummary::from_results(&results));
+        }
+        drop(on_file);
+
+        let terminal_out = String::from_utf8(on_terminal.into_writer().0).expect("utf8");
+        let file_out = std::fs::read_to_string(&path).expect("read report file");
+        assert!(terminal_out.contains('\x1b'), "{terminal_out:?}");
+        assert!(file_out.contains("test_ok"), "{file_out}");
+        assert!(!file_out.contains('\x1b'), "{file_out:?}");
+    }
+
+    #[test]
+    fn color_disabled_writes_no_escape_codes() {
+        let mut r = reporter().with_color(false);
+        let passed = make_passed("test_ok", vec![]);
+        let mut failed = make_passed("test_bad", vec![]);
+        failed.outcome = TestOutcome::Failed {
+            message: "boom".into(),
+            traceback: None,
+            assertions: vec![],
+            executed_lines: vec![],
+        };
+        let results = [passed, failed];
+        r.on_run_start(&[]);
+        for result in &results {
+            r.on_test_complete(result);
+        }
+        r.on_run_complete(&RunSummary::from_results(&results));
+        let out = output(&r);
+        assert!(
+            out.contains("✓ test_ok") && out.contains("test_bad"),
+            "{out}"
+        );
+        assert!(!out.contains('\x1b'), "{out:?}");
     }
 
     #[test]
@@ -613,17 +1428,15 @@ mod tests {
             "header must not be written until the first test event"
         );
 
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(
+            TestItem {
                 name: "t".into(),
                 module_path: "m".into(),
                 ..Default::default()
             },
-            outcome: TestOutcome::Passed,
-            duration: std::time::Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            TestOutcome::Passed,
+            std::time::Duration::from_millis(1),
+        ));
         assert!(!r.clear_armed && !r.header_pending);
         assert!(
             output(&r).contains("tryke test"),
@@ -703,17 +1516,15 @@ mod tests {
     #[test]
     fn test_complete_passed() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(
+            TestItem {
                 name: "test_add".into(),
                 module_path: "tests.math".into(),
                 ..Default::default()
             },
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(12),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            TestOutcome::Passed,
+            Duration::from_millis(12),
+        ));
 
         let out = output(&r);
         assert!(out.contains("✓"));
@@ -723,22 +1534,20 @@ mod tests {
     #[test]
     fn test_complete_failed() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(
+            TestItem {
                 name: "test_sub".into(),
                 module_path: "tests.math".into(),
                 ..Default::default()
             },
-            outcome: TestOutcome::Failed {
+            TestOutcome::Failed {
                 message: "bad".into(),
                 traceback: None,
                 assertions: vec![],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(5),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(5),
+        ));
 
         let out = output(&r);
         assert!(out.contains("✗"));
@@ -748,23 +1557,91 @@ mod tests {
     #[test]
     fn test_complete_skipped() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(
+            TestItem {
                 name: "test_skip".into(),
                 module_path: "tests.misc".into(),
                 ..Default::default()
             },
-            outcome: TestOutcome::Skipped { reason: None },
-            duration: Duration::from_millis(0),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            TestOutcome::Skipped { reason: None },
+            Duration::from_millis(0),
+        ));
 
         let out = output(&r);
         assert!(out.contains("»"));
         assert!(out.contains("test_skip"));
     }
 
+    #[test]
+    fn header_shows_collected_and_selected_counts() {
+        let mut r = reporter();
+        r.set_selection(&SelectionSummary {
+            collected: 100,
+            selected: 12,
+            via: Some("-k foo".into()),
+            cached: None,
+            seed: None,
+        });
+        r.on_run_start(&[]);
+        assert!(output(&r).contains("collected 100, selected 12 (via -k foo)"));
+    }
+
+    #[test]
+    fn header_omits_selection_without_filter() {
+        let mut r = reporter();
+        r.set_selection(&SelectionSummary {
+            collected: 3,
+            selected: 3,
+            via: None,
+            cached: None,
+            seed: None,
+        });
+        r.on_run_start(&[]);
+        assert!(!output(&r).contains("collected"));
+    }
+
+    #[test]
+    fn header_shows_cached_test_count() {
+        let mut r = reporter();
+        r.set_selection(&SelectionSummary {
+            collected: 42,
+            selected: 42,
+            via: None,
+            cached: Some(30),
+            seed: None,
+        });
+        r.on_run_start(&[]);
+        assert!(output(&r).contains("discovered 42 tests (30 cached)"));
+    }
+
+    #[test]
+    fn header_omits_cache_line_when_nothing_cached() {
+        let mut r = reporter();
+        r.set_selection(&SelectionSummary {
+            collected: 42,
+            selected: 42,
+            via: None,
+            cached: Some(0),
+            seed: None,
+        });
+        r.on_run_start(&[]);
+        assert!(!output(&r).contains("cached"));
+    }
+
+    #[test]
+    fn header_shows_shuffle_seed() {
+        let mut r = reporter();
+        r.set_selection(&SelectionSummary {
+            collected: 3,
+            selected: 3,
+            via: None,
+            cached: None,
+            seed: Some(42),
+        });
+        r.on_run_start(&[]);
+        assert!(output(&r).contains("shuffled with seed 42 (rerun with --shuffle --seed 42)"));
+    }
+
     #[test]
     fn run_complete_shows_summary() {
         let mut r = reporter();
@@ -772,15 +1649,8 @@ mod tests {
             passed: 3,
             failed: 1,
             skipped: 2,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(100),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
 
         let out = output(&r);
@@ -798,17 +1668,8 @@ mod tests {
         let mut r = reporter();
         r.on_run_complete(&RunSummary {
             passed: 5,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(50),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
 
         let out = output(&r);
@@ -829,26 +1690,15 @@ mod tests {
         }];
 
         r.on_run_start(&tests);
-        r.on_test_complete(&TestResult {
-            test: tests[0].clone(),
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(10),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+        r.on_test_complete(&TestResult::new(
+            tests[0].clone(),
+            TestOutcome::Passed,
+            Duration::from_millis(10),
+        ));
         r.on_run_complete(&RunSummary {
             passed: 1,
-            failed: 0,
-            skipped: 0,
-            errors: 0,
-            xfailed: 0,
-            todo: 0,
             duration: Duration::from_millis(10),
-            discovery_duration: None,
-            test_duration: None,
-            file_count: 0,
-            start_time: None,
-            changed_selection: None,
+            ..Default::default()
         });
 
         let out = output(&r);
@@ -862,15 +1712,15 @@ mod tests {
     #[test]
     fn failed_with_assertions_renders_diagnostics() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(
+            TestItem {
                 name: "test_add".into(),
                 module_path: "tests.math".into(),
                 file_path: Some(PathBuf::from("tests/math.py")),
                 line_number: Some(10),
                 ..Default::default()
             },
-            outcome: TestOutcome::Failed {
+            TestOutcome::Failed {
                 message: "assertion failed".into(),
                 traceback: None,
                 assertions: vec![Assertion {
@@ -885,10 +1735,8 @@ mod tests {
                 }],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(5),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(5),
+        ));
 
         let out = output(&r);
         assert!(out.contains("✗"));
@@ -897,33 +1745,142 @@ mod tests {
     }
 
     #[test]
-    fn failed_with_empty_assertions_no_diagnostics() {
-        let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: TestItem {
-                name: "test_sub".into(),
-                module_path: "tests.math".into(),
-                ..Default::default()
-            },
-            outcome: TestOutcome::Failed {
-                message: "bad".into(),
-                traceback: None,
-                assertions: vec![],
-                executed_lines: vec![],
-            },
-            duration: Duration::from_millis(5),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+    fn assertion_footer_can_be_turned_off() {
+        let failed = |count: usize| {
+            let assertion = Assertion {
+                expression: "expect(a).to_equal(2)".into(),
+                file: None,
+                line: 10,
+                span_offset: 7,
+                span_length: 1,
+                expected: "2".into(),
+                received: "3".into(),
+                expected_arg_span: Some((19, 1)),
+            };
+            TestResult::new(
+                TestItem {
+                    name: "test_add".into(),
+                    module_path: "tests.math".into(),
+                    file_path: Some(PathBuf::from("tests/math.py")),
+                    line_number: Some(10),
+                    ..Default::default()
+                },
+                TestOutcome::Failed {
+                    message: "assertion failed".into(),
+                    traceback: None,
+                    assertions: vec![assertion; count],
+                    executed_lines: vec![],
+                },
+                Duration::from_millis(5),
+            )
+        };
 
+        let mut r = reporter().with_assertion_footer(false);
+        r.on_test_complete(&failed(1));
         let out = output(&r);
-        assert!(out.contains("✗"));
-        assert!(!out.contains("assertions failed"));
+        assert!(out.contains("expected 2"), "{out}");
+        assert!(!out.contains("assertions failed"), "{out}");
+
+        let mut r = reporter();
+        r.on_test_complete(&failed(2));
+        assert!(output(&r).contains("2/2 assertions failed"));
     }
 
     #[test]
-    fn collect_only_lists_test_ids() {
-        let mut r = reporter();
+    fn failure_template_replaces_assertion_diagnostics() {
+        let mut r = reporter().with_failure_template(Some(
+            "{id}: expected {expected}, got {received} ({unknown})".into(),
+        ));
+        let test = TestItem {
+            name: "test_add".into(),
+            module_path: "tests.math".into(),
+            file_path: Some(PathBuf::from("tests/math.py")),
+            line_number: Some(10),
+            ..Default::default()
+        };
+        let id = test.id();
+        r.on_test_complete(&TestResult::new(
+            test,
+            TestOutcome::Failed {
+                message: "assertion failed".into(),
+                traceback: None,
+                assertions: vec![Assertion {
+                    expression: "expect(a).to_equal(2)".into(),
+                    file: None,
+                    line: 10,
+                    span_offset: 19,
+                    span_length: 1,
+                    expected: "2".into(),
+                    received: "3".into(),
+                    expected_arg_span: None,
+                }],
+                executed_lines: vec![],
+            },
+            Duration::from_millis(5),
+        ));
+
+        let out = output(&r);
+        assert!(
+            out.contains(&format!("  {id}: expected 2, got 3 ({{unknown}})\n")),
+            "{out}"
+        );
+        assert!(!out.contains("expected 2, received 3"));
+    }
+
+    #[test]
+    fn max_failures_shown_caps_diagnostics_and_counts_the_rest() {
+        let mut r = reporter().with_max_failures_shown(Some(2));
+        let results: Vec<TestResult> = (1..=3)
+            .map(|i| {
+                let mut result = make_passed(&format!("test_{i}"), vec![]);
+                result.outcome = TestOutcome::Failed {
+                    message: format!("boom {i}"),
+                    traceback: None,
+                    assertions: vec![],
+                    executed_lines: vec![],
+                };
+                result
+            })
+            .collect();
+        r.on_run_start(&[]);
+        for result in &results {
+            r.on_test_complete(result);
+        }
+        r.on_run_complete(&RunSummary::from_results(&results));
+        let out = output(&r);
+        assert!(out.contains("boom 1") && out.contains("boom 2"), "{out}");
+        assert!(!out.contains("boom 3"), "{out}");
+        assert!(out.contains("test_3"), "{out}");
+        assert!(out.contains("… and 1 more failure"), "{out}");
+        assert!(out.contains("3 failed"), "{out}");
+    }
+
+    #[test]
+    fn failed_with_empty_assertions_no_diagnostics() {
+        let mut r = reporter();
+        r.on_test_complete(&TestResult::new(
+            TestItem {
+                name: "test_sub".into(),
+                module_path: "tests.math".into(),
+                ..Default::default()
+            },
+            TestOutcome::Failed {
+                message: "bad".into(),
+                traceback: None,
+                assertions: vec![],
+                executed_lines: vec![],
+            },
+            Duration::from_millis(5),
+        ));
+
+        let out = output(&r);
+        assert!(out.contains("✗"));
+        assert!(!out.contains("assertions failed"));
+    }
+
+    #[test]
+    fn collect_only_lists_test_ids() {
+        let mut r = reporter();
         let tests = vec![
             TestItem {
                 name: "test_add".into(),
@@ -953,6 +1910,53 @@ mod tests {
         assert!(header_pos < sub_pos);
     }
 
+    #[test]
+    fn collect_assertions_lists_checklist_under_each_test() {
+        let test = TestItem {
+            name: "test_add".into(),
+            module_path: "tests.math".into(),
+            file_path: Some("tests/math.py".into()),
+            expected_assertions: vec![make_assertion("add(1, 1)", "to_equal", vec!["2"])],
+            ..Default::default()
+        };
+
+        let mut r = reporter();
+        r.on_collect_complete(std::slice::from_ref(&test));
+        assert!(!output(&r).contains("expect("));
+
+        let mut r = reporter().with_collect_assertions(true);
+        r.on_collect_complete(&[test]);
+        let out = output(&r);
+        assert!(out.contains('•'));
+        assert!(out.contains("expect(add(1, 1)).to_equal(2)"));
+        assert!(out.find("test_add").unwrap() < out.find("expect(").unwrap());
+        assert!(!out.contains('✓'), "nothing ran, so nothing passed");
+    }
+
+    #[test]
+    fn collect_flags_duplicate_test_ids() {
+        let mut r = reporter().with_color(false);
+        let make = |name: &str, line: u32| TestItem {
+            name: name.into(),
+            module_path: "tests.m".into(),
+            file_path: Some(PathBuf::from("tests/m.py")),
+            line_number: Some(line),
+            ..Default::default()
+        };
+        r.on_collect_complete(&[
+            make("test_adds", 3),
+            make("test_subs", 5),
+            make("test_adds", 7),
+        ]);
+        let out = output(&r);
+        assert_eq!(out.matches("test_adds (duplicate)").count(), 1, "{out}");
+        assert!(
+            out.find("test_subs").unwrap() < out.find("test_adds (duplicate)").unwrap(),
+            "only the repeat is marked: {out}"
+        );
+        assert!(!out.contains("test_subs (duplicate)"), "{out}");
+    }
+
     #[test]
     fn collect_groups_by_file() {
         let mut r = reporter();
@@ -983,17 +1987,17 @@ mod tests {
     #[test]
     fn groups_by_file() {
         let mut r = reporter();
-        let make = |name: &str, file: &str| TestResult {
-            test: TestItem {
-                name: name.into(),
-                module_path: "tests.m".into(),
-                file_path: Some(PathBuf::from(file)),
-                ..Default::default()
-            },
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
+        let make = |name: &str, file: &str| {
+            TestResult::new(
+                TestItem {
+                    name: name.into(),
+                    module_path: "tests.m".into(),
+                    file_path: Some(PathBuf::from(file)),
+                    ..Default::default()
+                },
+                TestOutcome::Passed,
+                Duration::from_millis(1),
+            )
         };
         r.on_test_complete(&make("test_a", "tests/a.py"));
         r.on_test_complete(&make("test_b", "tests/a.py"));
@@ -1014,19 +2018,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn group_summary_closes_each_file_with_its_counts() {
+        let mut r = reporter().with_group_summary(true);
+        let make = |name: &str, file: &str, outcome: TestOutcome| {
+            TestResult::new(
+                TestItem {
+                    name: name.into(),
+                    module_path: "tests.m".into(),
+                    file_path: Some(PathBuf::from(file)),
+                    ..Default::default()
+                },
+                outcome,
+                Duration::from_millis(1),
+            )
+        };
+        r.on_run_start(&[]);
+        r.on_test_complete(&make("test_a", "tests/a.py", TestOutcome::Passed));
+        r.on_test_complete(&make(
+            "test_b",
+            "tests/a.py",
+            TestOutcome::Failed {
+                message: "boom".into(),
+                traceback: None,
+                assertions: vec![],
+                executed_lines: vec![],
+            },
+        ));
+        r.on_test_complete(&make("test_c", "tests/b.py", TestOutcome::Passed));
+        r.on_test_complete(&make(
+            "test_d",
+            "tests/b.py",
+            TestOutcome::Skipped { reason: None },
+        ));
+        r.on_run_complete(&RunSummary {
+            passed: 2,
+            failed: 1,
+            skipped: 1,
+            duration: Duration::from_millis(10),
+            file_count: 2,
+            ..Default::default()
+        });
+
+        let out = output(&r);
+        let footer_a = out.find("1 passed").expect("a.py footer");
+        let b_header = out.find("tests/b.py:").expect("b.py header");
+        let footer_b = b_header + out[b_header..].find("1 skipped").expect("b.py footer");
+        assert!(out.find("test_b").expect("test_b") < footer_a);
+        assert!(footer_a < b_header, "{out}");
+        assert!(out[footer_a..b_header].contains("1 failed"));
+        assert!(!out[footer_a..b_header].contains("skipped"));
+        assert!(out.find("test_d").expect("test_d") < footer_b);
+        assert!(out[b_header..footer_b].contains("1 passed"));
+        assert!(!out[b_header..footer_b].contains("failed"));
+    }
+
     fn make_passed(name: &str, assertions: Vec<tryke_types::ExpectedAssertion>) -> TestResult {
-        TestResult {
-            test: TestItem {
+        TestResult::new(
+            TestItem {
                 name: name.into(),
                 module_path: "tests.m".into(),
                 expected_assertions: assertions,
                 ..Default::default()
             },
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        }
+            TestOutcome::Passed,
+            Duration::from_millis(1),
+        )
     }
 
     fn make_assertion(
@@ -1057,6 +2114,33 @@ mod tests {
         assert!(out.contains("expect(add(1, 1)).to_equal(2)"));
     }
 
+    #[test]
+    fn expected_assertions_render_in_line_order() {
+        let mut r = reporter();
+        r.on_test_complete(&make_passed(
+            "test_add",
+            vec![
+                tryke_types::ExpectedAssertion {
+                    line: 9,
+                    ..make_assertion("third", "to_equal", vec!["3"])
+                },
+                tryke_types::ExpectedAssertion {
+                    line: 3,
+                    ..make_assertion("first", "to_equal", vec!["1"])
+                },
+                tryke_types::ExpectedAssertion {
+                    line: 5,
+                    ..make_assertion("second", "to_equal", vec!["2"])
+                },
+            ],
+        ));
+        let out = String::from_utf8_lossy(&r.into_writer()).into_owned();
+        let first = out.find("expect(first)").expect("first rendered");
+        let second = out.find("expect(second)").expect("second rendered");
+        let third = out.find("expect(third)").expect("third rendered");
+        assert!(first < second && second < third, "got:\n{out}");
+    }
+
     #[test]
     fn quiet_hides_expected_assertions_on_pass() {
         let mut r = TextReporter::with_writer_and_verbosity(Vec::new(), Verbosity::Quiet);
@@ -1080,46 +2164,487 @@ mod tests {
     #[test]
     fn quiet_still_shows_failures() {
         let mut r = TextReporter::with_writer_and_verbosity(Vec::new(), Verbosity::Quiet);
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(
+            TestItem {
                 name: "test_fail".into(),
                 module_path: "tests.m".into(),
                 ..Default::default()
             },
-            outcome: TestOutcome::Failed {
+            TestOutcome::Failed {
                 message: "oops".into(),
                 traceback: None,
                 assertions: vec![],
                 executed_lines: vec![],
             },
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(1),
+        ));
         let out = String::from_utf8_lossy(&r.into_writer()).into_owned();
         assert!(out.contains("✗"));
         assert!(out.contains("test_fail"));
     }
 
+    #[test]
+    fn pass_dots_collapse_passes_and_keep_failures_in_full() {
+        let mut r = reporter()
+            .with_pass_dots(true)
+            .with_durations(false)
+            .with_color(false);
+        let mut failed = make_passed("test_bad", vec![]);
+        failed.outcome = TestOutcome::Failed {
+            message: "boom".into(),
+            traceback: None,
+            assertions: vec![],
+            executed_lines: vec![],
+        };
+        let results = [
+            make_passed("test_a", vec![]),
+            make_passed("test_b", vec![]),
+            failed,
+            make_passed("test_c", vec![]),
+        ];
+        for result in &results {
+            r.on_test_complete(result);
+        }
+        r.on_run_complete(&RunSummary::from_results(&results));
+        let out = output(&r);
+
+        assert!(out.starts_with("..\n✗ test_bad\n"), "{out}");
+        assert!(out.contains("boom"), "{out}");
+        assert!(out.contains("\n.\n"), "{out}");
+        assert!(!out.contains("✓"), "{out}");
+    }
+
+    #[test]
+    fn show_capture_prints_passing_test_output() {
+        let mut passed = make_passed("test_ok", vec![]);
+        passed.stdout = "hello from test_ok\n".into();
+
+        let mut r = reporter();
+        r.on_test_complete(&passed);
+        assert!(!output(&r).contains("hello from test_ok"));
+
+        let mut r = reporter().with_show_capture(true);
+        r.on_test_complete(&passed);
+        let out = output(&r);
+        assert!(out.contains("── stdout ──"), "{out}");
+        assert!(out.contains("hello from test_ok"), "{out}");
+    }
+
+    #[test]
+    fn blame_adds_authors_to_recap_at_normal_verbosity() {
+        let mut r = reporter().with_blame(Some(Box::new(|test: &TestItem| {
+            (test.name == "test_fail").then(|| "Ada Lovelace".to_owned())
+        })));
+        r.on_run_start(&[]);
+        let mut failed = make_passed("test_fail", vec![]);
+        failed.outcome = TestOutcome::Error {
+            message: "boom".into(),
+        };
+        r.on_test_complete(&failed);
+        r.on_run_complete(&RunSummary::from_results(&[failed]));
+        let out = output(&r);
+        let recap = out.find("failures:").expect("recap header");
+        let line = out[recap..]
+            .lines()
+            .find(|line| line.contains("tests.m::test_fail"))
+            .expect("recap entry");
+        assert!(line.contains("(last edited by Ada Lovelace)"), "{line}");
+    }
+
+    #[test]
+    fn quiet_recaps_failed_ids_at_end_of_run() {
+        let mut r = TextReporter::with_writer_and_verbosity(Vec::new(), Verbosity::Quiet);
+        r.on_run_start(&[]);
+        r.on_test_complete(&make_passed("test_ok", vec![]));
+        r.on_test_complete(&TestResult::new(
+            TestItem {
+                name: "test_fail".into(),
+                module_path: "tests.m".into(),
+                ..Default::default()
+            },
+            TestOutcome::Failed {
+                message: "oops".into(),
+                traceback: None,
+                assertions: vec![],
+                executed_lines: vec![],
+            },
+            Duration::from_millis(1),
+        ));
+        r.on_run_complete(&RunSummary {
+            passed: 1,
+            failed: 1,
+            duration: Duration::from_millis(10),
+            file_count: 1,
+            ..Default::default()
+        });
+        let out = output(&r);
+        let recap = out.find("failures:").expect("quiet recap header");
+        assert!(out[recap..].contains("tests.m::test_fail"));
+        assert!(!out[recap..].contains("test_ok"));
+    }
+
+    #[test]
+    fn timings_scale_bars_to_slowest_test() {
+        let mut r = reporter().with_timings(true);
+        r.on_run_start(&[]);
+        let mut results = Vec::new();
+        for (name, millis) in [("test_fast", 1), ("test_slow", 40), ("test_mid", 10)] {
+            let mut result = make_passed(name, vec![]);
+            result.duration = Duration::from_millis(millis);
+            r.on_test_complete(&result);
+            results.push(result);
+        }
+        assert!(
+            !output(&r).contains("test_fast"),
+            "results are buffered until the run completes"
+        );
+        r.on_run_complete(&RunSummary::from_results(&results));
+
+        let out = output(&r);
+        let bar_len = |name: &str| {
+            let line = out
+                .lines()
+                .find(|l| l.contains(name))
+                .unwrap_or_else(|| panic!("missing line for {name}"));
+            assert!(line.contains("ms]"), "duration shown: {line}");
+            line.chars().filter(|c| ('█'..='▏').contains(c)).count()
+        };
+        let (fast, mid, slow) = (
+            bar_len("test_fast"),
+            bar_len("test_mid"),
+            bar_len("test_slow"),
+        );
+        assert_eq!(slow, 20);
+        assert!(slow > mid && mid > fast, "{fast} {mid} {slow}");
+    }
+
+    #[test]
+    fn normal_omits_timing_bars() {
+        let mut r = reporter();
+        r.on_run_start(&[]);
+        r.on_test_complete(&make_passed("test_ok", vec![]));
+        assert!(!output(&r).contains('█'));
+    }
+
+    #[test]
+    fn verbose_failure_shows_captured_logs() {
+        let failing = |verbosity| {
+            let mut r = TextReporter::with_writer_and_verbosity(Vec::new(), verbosity);
+            let mut result = make_passed("test_bad", vec![]);
+            result.outcome = TestOutcome::Failed {
+                message: "boom".into(),
+                traceback: None,
+                assertions: vec![],
+                executed_lines: vec![],
+            };
+            result.logs = vec![
+                LogRecord {
+                    level: "WARNING".into(),
+                    message: "cache miss for user 7".into(),
+                },
+                LogRecord {
+                    level: "ERROR".into(),
+                    message: "retry failed".into(),
+                },
+            ];
+            r.on_test_complete(&result);
+            output(&r)
+        };
+
+        let out = failing(Verbosity::Verbose);
+        let rule = out.find("── logs ──").expect("logs section");
+        assert!(out[rule..].contains("WARNING  cache miss for user 7"));
+        assert!(out[rule..].contains("ERROR    retry failed"));
+        assert!(!failing(Verbosity::Normal).contains("── logs ──"));
+    }
+
+    #[test]
+    fn no_duration_omits_test_and_summary_durations() {
+        let mut r = reporter().with_durations(false);
+        r.on_run_start(&[]);
+        let mut passed = make_passed("test_ok", vec![]);
+        passed.duration = Duration::from_millis(12);
+        let mut failed = make_passed("test_bad", vec![]);
+        failed.duration = Duration::from_millis(1500);
+        failed.outcome = TestOutcome::Failed {
+            message: "boom".into(),
+            traceback: None,
+            assertions: vec![],
+            executed_lines: vec![],
+        };
+        let results = [passed, failed];
+        for result in &results {
+            r.on_test_complete(result);
+        }
+        r.on_run_complete(&RunSummary::from_results(&results));
+
+        let out = output(&r);
+        assert!(out.contains("test_ok") && out.contains("test_bad"));
+        assert!(!out.contains("ms]") && !out.contains("s]"), "{out}");
+        assert!(!out.contains("Duration"), "{out}");
+    }
+
+    #[test]
+    fn timing_stats_footer_reports_percentiles() {
+        let results: Vec<TestResult> = [10, 20, 30, 40]
+            .into_iter()
+            .map(|ms| {
+                let mut result = make_passed(&format!("test_{ms}"), vec![]);
+                result.duration = Duration::from_millis(ms);
+                result
+            })
+            .collect();
+        let summary = RunSummary::from_results(&results);
+
+        let mut r = reporter();
+        r.on_run_complete(&summary);
+        assert!(!output(&r).contains("timing:"));
+
+        let mut r = reporter().with_timing_stats(true);
+        r.on_run_complete(&summary);
+        let out = output(&r);
+        let line = out
+            .lines()
+            .find(|line| line.contains("timing:"))
+            .expect("timing footer");
+        for part in ["mean 25.00ms", "p50 20.00ms", "p95 40.00ms", "p99 40.00ms"] {
+            assert!(line.contains(part), "{line}");
+        }
+    }
+
+    #[test]
+    fn durations_lists_slowest_tests_first() {
+        let results: Vec<TestResult> = [
+            ("test_b", 30),
+            ("test_fast", 1),
+            ("test_slow", 90),
+            ("test_a", 30),
+        ]
+        .into_iter()
+        .map(|(name, ms)| {
+            let mut result = make_passed(name, vec![]);
+            result.duration = Duration::from_millis(ms);
+            result
+        })
+        .collect();
+        let summary = RunSummary::from_results(&results);
+
+        let mut r = reporter();
+        r.on_run_start(&[]);
+        for result in &results {
+            r.on_test_complete(result);
+        }
+        r.on_run_complete(&summary);
+        assert!(!output(&r).contains("slowest"));
+
+        let mut r = reporter().with_slowest(3);
+        r.on_run_start(&[]);
+        for result in &results {
+            r.on_test_complete(result);
+        }
+        r.on_run_complete(&summary);
+        let out = output(&r);
+        let list = &out[out.find("slowest 3 tests:").expect("slowest list")..];
+        let lines: Vec<&str> = list.lines().skip(1).take(3).collect();
+        // Ties on duration fall back to id order.
+        for (line, (duration, id)) in lines.iter().zip([
+            ("90.00ms", "tests.m::test_slow"),
+            ("30.00ms", "tests.m::test_a"),
+            ("30.00ms", "tests.m::test_b"),
+        ]) {
+            assert!(line.contains(duration) && line.ends_with(id), "{line}");
+        }
+        assert!(!list.contains("test_fast"), "{list}");
+    }
+
+    #[test]
+    fn timed_out_test_renders_with_timer_glyph() {
+        let mut r = reporter();
+        let mut result = make_passed("test_hangs", vec![]);
+        result.outcome = TestOutcome::Failed {
+            message: "timed out after 5s".into(),
+            traceback: None,
+            assertions: vec![],
+            executed_lines: vec![],
+        };
+        result.timed_out = true;
+        r.on_test_complete(&result);
+        let out = output(&r);
+        assert!(out.contains("⏱"), "{out}");
+        assert!(!out.contains("✗"), "{out}");
+        assert!(out.contains("timed out after 5s"), "{out}");
+    }
+
+    #[test]
+    fn slow_test_gets_a_slow_marker() {
+        let mut r = reporter();
+        let mut result = make_passed("test_sluggish", vec![]);
+        result.slow = true;
+        r.on_test_complete(&result);
+        r.on_test_complete(&make_passed("test_quick", vec![]));
+        let out = output(&r);
+        let line = |name: &str| {
+            out.lines()
+                .find(|l| l.contains(name))
+                .expect("test line")
+                .to_owned()
+        };
+        assert!(line("test_sluggish").contains("slow"), "{out}");
+        assert!(!line("test_quick").contains("slow"), "{out}");
+    }
+
+    #[test]
+    fn benchmark_stats_render_under_passing_test() {
+        let mut r = reporter();
+        let mut result = make_passed("test_fast", vec![]);
+        result.benchmark = Some(BenchmarkStats {
+            iterations: 20,
+            min: Duration::from_nanos(1_500),
+            mean: Duration::from_micros(2),
+            stddev: Duration::from_nanos(250),
+        });
+        r.on_test_complete(&result);
+        let out = output(&r);
+        assert!(
+            out.contains("min 1.50µs · mean 2.00µs · stddev 0.25µs over 20 runs"),
+            "{out}"
+        );
+    }
+
+    #[test]
+    fn run_complete_groups_skips_by_category() {
+        let mut r = reporter();
+        r.on_run_start(&[]);
+        let mut results = Vec::new();
+        for (name, category) in [
+            ("test_cuda", Some("platform")),
+            ("test_db", Some("missing-dependency")),
+            ("test_metal", Some("platform")),
+            ("test_wip", None),
+        ] {
+            let result = TestResult::new(
+                TestItem {
+                    name: name.into(),
+                    module_path: "tests.m".into(),
+                    skip_category: category.map(String::from),
+                    ..Default::default()
+                },
+                TestOutcome::Skipped { reason: None },
+                Duration::ZERO,
+            );
+            r.on_test_complete(&result);
+            results.push(result);
+        }
+        r.on_run_complete(&RunSummary::from_results(&results));
+
+        let out = output(&r);
+        let start = out.find("skipped by category:").expect("category tally");
+        let tally: Vec<&str> = out[start..].lines().skip(1).take(3).collect();
+        assert!(tally[0].contains("missing-dependency") && tally[0].contains("(1)"));
+        assert!(tally[1].contains("platform") && tally[1].contains("(2)"));
+        assert!(tally[2].contains("uncategorized") && tally[2].contains("(1)"));
+    }
+
+    #[test]
+    fn skipped_summary_groups_skips_by_reason() {
+        let results: Vec<TestResult> = [
+            ("test_cuda", Some("requires GPU")),
+            ("test_sqlite", Some("no database")),
+            ("test_metal", Some("requires GPU")),
+            ("test_wip", None),
+        ]
+        .into_iter()
+        .map(|(name, reason)| {
+            let mut result = make_passed(name, vec![]);
+            result.outcome = TestOutcome::Skipped {
+                reason: reason.map(String::from),
+            };
+            result
+        })
+        .collect();
+        let summary = RunSummary::from_results(&results);
+
+        let mut r = reporter();
+        r.on_run_start(&[]);
+        for result in &results {
+            r.on_test_complete(result);
+        }
+        r.on_run_complete(&summary);
+        assert!(!output(&r).contains("skipped (4):"));
+
+        let mut r = reporter().with_skipped_summary(true);
+        r.on_run_start(&[]);
+        for result in &results {
+            r.on_test_complete(result);
+        }
+        r.on_run_complete(&summary);
+        let out = output(&r);
+        let start = out.find("skipped (4):").expect("skip reason recap");
+        let groups: Vec<&str> = out[start..].lines().skip(1).take(3).collect();
+        for (group, (reason, tally)) in groups.iter().zip([
+            ("requires GPU", "— 2 tests"),
+            ("no database", "— 1 test"),
+            ("no reason", "— 1 test"),
+        ]) {
+            assert!(group.contains(reason) && group.contains(tally), "{out}");
+        }
+    }
+
+    #[test]
+    fn run_complete_omits_tally_without_categories() {
+        let mut r = reporter();
+        r.on_run_start(&[]);
+        let result = TestResult::new(
+            TestItem {
+                name: "test_wip".into(),
+                module_path: "tests.m".into(),
+                ..Default::default()
+            },
+            TestOutcome::Skipped { reason: None },
+            Duration::ZERO,
+        );
+        r.on_test_complete(&result);
+        r.on_run_complete(&RunSummary::from_results(&[result]));
+        assert!(!output(&r).contains("skipped by category"));
+    }
+
+    #[test]
+    fn normal_omits_failure_recap() {
+        let mut r = reporter();
+        r.on_run_start(&[]);
+        r.on_test_complete(&TestResult::new(
+            TestItem {
+                name: "test_fail".into(),
+                module_path: "tests.m".into(),
+                ..Default::default()
+            },
+            TestOutcome::Error {
+                message: "boom".into(),
+            },
+            Duration::from_millis(1),
+        ));
+        r.on_run_complete(&RunSummary {
+            errors: 1,
+            duration: Duration::from_millis(10),
+            file_count: 1,
+            ..Default::default()
+        });
+        assert!(!output(&r).contains("failures:"));
+    }
+
     #[test]
     fn quiet_shortens_tracebacks() {
         let mut r = TextReporter::with_writer_and_verbosity(Vec::new(), Verbosity::Quiet);
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(TestItem {
                 name: "test_raise".into(),
                 module_path: "tests.m".into(),
                 ..Default::default()
-            },
-            outcome: TestOutcome::Failed {
+            }, TestOutcome::Failed {
                 message: "Exception: boom".into(),
                 traceback: Some("Traceback (most recent call last):\n  File \"outer.py\", line 10, in caller\n    run_test()\n  File \"inner.py\", line 2, in test_raise\n    raise Exception(\"boom\")\nException: boom".into()),
                 assertions: vec![],
                 executed_lines: vec![],
-            },
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            }, Duration::from_millis(1)));
         let out = String::from_utf8_lossy(&r.into_writer()).into_owned();
         assert!(
             !out.contains("outer.py"),
@@ -1154,19 +2679,17 @@ mod tests {
     #[test]
     fn case_label_appended_to_name_in_output() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(
+            TestItem {
                 name: "square".into(),
                 module_path: "tests.test_math".into(),
                 case_label: Some("zero".into()),
                 case_index: Some(0),
                 ..Default::default()
             },
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            TestOutcome::Passed,
+            Duration::from_millis(1),
+        ));
         let out = output(&r);
         assert!(out.contains("square[zero]"), "out: {out}");
     }
@@ -1201,18 +2724,16 @@ mod tests {
     #[test]
     fn display_name_shown_instead_of_name() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(
+            TestItem {
                 name: "test_fn".into(),
                 module_path: "tests.m".into(),
                 display_name: Some("my fancy test".into()),
                 ..Default::default()
             },
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            TestOutcome::Passed,
+            Duration::from_millis(1),
+        ));
         let out = output(&r);
         assert!(out.contains("my fancy test"));
         assert!(!out.contains("test_fn"));
@@ -1239,11 +2760,44 @@ mod tests {
         assert!(!out.contains("expect(x)"));
     }
 
+    #[test]
+    fn normal_renders_assertion_group_headers() {
+        let grouped = |subject: &str, group: Option<&str>| tryke_types::ExpectedAssertion {
+            group: group.map(String::from),
+            ..make_assertion(subject, "to_equal", vec!["1"])
+        };
+        let mut r = reporter();
+        r.on_test_complete(&make_passed(
+            "test_parse",
+            vec![
+                grouped("a", Some("parsing")),
+                grouped("b", Some("parsing")),
+                grouped("c", None),
+            ],
+        ));
+        let out = String::from_utf8_lossy(&r.into_writer()).into_owned();
+        let lines: Vec<&str> = out.lines().collect();
+        let header = lines
+            .iter()
+            .position(|l| l.contains("parsing") && !l.contains("expect("))
+            .expect("group header");
+        assert_eq!(out.matches("parsing").count(), 1);
+        let a = lines[header + 1];
+        let c = lines
+            .iter()
+            .find(|l| l.contains("expect(c)"))
+            .expect("ungrouped assertion");
+        assert!(a.contains("expect(a)"));
+        assert!(lines[header + 2].contains("expect(b)"));
+        let leading = |l: &str| l.len() - l.trim_start().len();
+        assert_eq!(leading(a), leading(c) + 2);
+    }
+
     #[test]
     fn normal_shows_failed_assertion_with_x() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(
+            TestItem {
                 name: "test_fail".into(),
                 module_path: "tests.m".into(),
                 expected_assertions: vec![tryke_types::ExpectedAssertion {
@@ -1257,7 +2811,7 @@ mod tests {
                 }],
                 ..Default::default()
             },
-            outcome: TestOutcome::Failed {
+            TestOutcome::Failed {
                 message: "assertion failed".into(),
                 traceback: None,
                 assertions: vec![Assertion {
@@ -1272,10 +2826,8 @@ mod tests {
                 }],
                 executed_lines: vec![5],
             },
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(1),
+        ));
         let out = String::from_utf8_lossy(&r.into_writer()).into_owned();
         assert!(out.contains("✗"));
         assert!(out.contains("expect(x).to_equal(1)"));
@@ -1284,8 +2836,8 @@ mod tests {
     #[test]
     fn normal_shows_unmatched_failed_assertion_diagnostic() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(
+            TestItem {
                 name: "test_helper".into(),
                 module_path: "tests.m".into(),
                 expected_assertions: vec![tryke_types::ExpectedAssertion {
@@ -1300,7 +2852,7 @@ mod tests {
                 file_path: Some(PathBuf::from("tests/m.py")),
                 ..Default::default()
             },
-            outcome: TestOutcome::Failed {
+            TestOutcome::Failed {
                 message: "assertion failed".into(),
                 traceback: None,
                 assertions: vec![Assertion {
@@ -1315,10 +2867,8 @@ mod tests {
                 }],
                 executed_lines: vec![5, 10],
             },
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(1),
+        ));
         let out = String::from_utf8_lossy(&r.into_writer()).into_owned();
         assert!(out.contains("expect(x).to_equal(1)"));
         assert!(out.contains("expected 1"));
@@ -1326,11 +2876,76 @@ mod tests {
         assert!(out.contains("1/2 assertions failed"));
     }
 
+    #[test]
+    fn pending_assertions_render_apart_and_are_not_counted() {
+        let mut r = reporter();
+        r.on_test_complete(&TestResult::new(
+            TestItem {
+                name: "test_tdd".into(),
+                module_path: "tests.m".into(),
+                expected_assertions: vec![
+                    tryke_types::ExpectedAssertion {
+                        subject: "x".into(),
+                        matcher: "to_equal".into(),
+                        args: vec!["1".into()],
+                        line: 5,
+                        ..Default::default()
+                    },
+                    tryke_types::ExpectedAssertion {
+                        subject: "y".into(),
+                        matcher: "to_equal".into(),
+                        args: vec!["2".into()],
+                        line: 6,
+                        pending: true,
+                        ..Default::default()
+                    },
+                ],
+                file_path: Some(PathBuf::from("tests/m.py")),
+                ..Default::default()
+            },
+            TestOutcome::Failed {
+                message: "assertion failed".into(),
+                traceback: None,
+                assertions: vec![Assertion {
+                    expression: "expect(x).to_equal(1)".into(),
+                    file: None,
+                    line: 5,
+                    span_offset: 7,
+                    span_length: 1,
+                    expected: "1".into(),
+                    received: "3".into(),
+                    expected_arg_span: Some((19, 1)),
+                }],
+                executed_lines: vec![5],
+            },
+            Duration::from_millis(1),
+        ));
+        let out = String::from_utf8_lossy(&r.into_writer()).into_owned();
+        let pending_line = out
+            .lines()
+            .find(|line| line.contains("expect(y).to_equal(2)"))
+            .expect("pending assertion is listed");
+        assert!(pending_line.contains('○'), "{pending_line}");
+        assert!(!pending_line.contains('✓') && !pending_line.contains('✗'));
+        assert!(out.contains("1/1 assertions failed"));
+
+        let mut r = reporter();
+        let mut pending = make_assertion("z", "to_be_truthy", vec![]);
+        pending.pending = true;
+        r.on_test_complete(&make_passed("test_later", vec![pending]));
+        let out = String::from_utf8_lossy(&r.into_writer()).into_owned();
+        let pending_line = out
+            .lines()
+            .find(|line| line.contains("expect(z).to_be_truthy()"))
+            .expect("pending assertion is listed");
+        assert!(pending_line.contains('○') && !pending_line.contains('✓'));
+    }
+
     #[test]
     fn normal_pairs_same_line_failures_without_duplication() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(
+            TestItem {
                 name: "test_same_line".into(),
                 module_path: "tests.m".into(),
                 expected_assertions: vec![
@@ -1356,7 +2971,7 @@ mod tests {
                 file_path: Some(PathBuf::from("tests/m.py")),
                 ..Default::default()
             },
-            outcome: TestOutcome::Failed {
+            TestOutcome::Failed {
                 message: "assertion failed".into(),
                 traceback: None,
                 assertions: vec![
@@ -1393,10 +3008,8 @@ mod tests {
                 ],
                 executed_lines: vec![5],
             },
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(1),
+        ));
         let out = String::from_utf8_lossy(&r.into_writer()).into_owned();
         assert!(out.contains("first check"));
         assert!(out.contains("second check"));
@@ -1409,8 +3022,8 @@ mod tests {
     #[test]
     fn normal_shows_mixed_pass_fail() {
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(
+            TestItem {
                 name: "test_mixed".into(),
                 module_path: "tests.m".into(),
                 expected_assertions: vec![
@@ -1435,7 +3048,7 @@ mod tests {
                 ],
                 ..Default::default()
             },
-            outcome: TestOutcome::Failed {
+            TestOutcome::Failed {
                 message: "assertion failed".into(),
                 traceback: None,
                 assertions: vec![Assertion {
@@ -1450,10 +3063,8 @@ mod tests {
                 }],
                 executed_lines: vec![3, 4],
             },
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            Duration::from_millis(1),
+        ));
         let out = String::from_utf8_lossy(&r.into_writer()).into_owned();
         let line_a = out.lines().find(|l| l.contains("expect(a)")).unwrap();
         let line_b = out.lines().find(|l| l.contains("expect(b)")).unwrap();
@@ -1467,8 +3078,7 @@ mod tests {
         // would render all expected assertions as ✓ (because nothing
         // failed) and suppress the exception traceback entirely.
         let mut r = reporter();
-        r.on_test_complete(&TestResult {
-            test: TestItem {
+        r.on_test_complete(&TestResult::new(TestItem {
                 name: "test_raise".into(),
                 module_path: "tests.m".into(),
                 expected_assertions: vec![
@@ -1492,17 +3102,12 @@ mod tests {
                     },
                 ],
                 ..Default::default()
-            },
-            outcome: TestOutcome::Failed {
+            }, TestOutcome::Failed {
                 message: "Exception: ".into(),
                 traceback: Some("Traceback (most recent call last):\n  File \"outer.py\", line 10, in caller\n    run_test()\n  File \"x.py\", line 2\n    raise Exception\nException".into()),
                 assertions: vec![],
                 executed_lines: vec![],
-            },
-            duration: Duration::from_millis(1),
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            }, Duration::from_millis(1)));
         let out = String::from_utf8_lossy(&r.into_writer()).into_owned();
         assert!(
             !out.contains("✓"),
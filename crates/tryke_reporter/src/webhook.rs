@@ -0,0 +1,199 @@
+use std::time::Duration;
+
+use log::warn;
+use tryke_types::{RunSummary, TestItem, TestOutcome, TestResult};
+
+use crate::Reporter;
+use crate::reporter::forward_to_inner;
+
+/// How long the POST may take end to end. The run is already over when
+/// it's sent, so a slow endpoint only delays the process exit.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Wraps another reporter and, once the run finishes, POSTs its summary
+/// and the ids of failing tests to a webhook as JSON:
+///
+/// ```json
+/// {"summary": {"passed": 3, "failed": 1, ...}, "failed_ids": ["tests/test_math.py::test_sub"], "interrupted": false}
+/// ```
+///
+/// Delivery is best effort: an unreachable endpoint or a non-2xx
+/// response is logged as a warning and never changes the run's outcome.
+pub struct WebhookReporter {
+    inner: Box<dyn Reporter>,
+    url: String,
+    failed_ids: Vec<String>,
+}
+
+impl WebhookReporter {
+    #[must_use]
+    pub fn new(inner: Box<dyn Reporter>, url: impl Into<String>) -> Self {
+        Self {
+            inner,
+            url: url.into(),
+            failed_ids: Vec::new(),
+        }
+    }
+
+    fn post(&self, summary: &RunSummary, interrupted: bool) {
+        let payload = serde_json::json!({
+            "summary": summary,
+            "failed_ids": self.failed_ids,
+            "interrupted": interrupted,
+        });
+        let agent: ureq::Agent = ureq::Agent::config_builder()
+            .timeout_global(Some(WEBHOOK_TIMEOUT))
+            .build()
+            .into();
+        if let Err(err) = agent
+            .post(&self.url)
+            .header("Content-Type", "application/json")
+            .send(payload.to_string())
+        {
+            warn!(
+                "--webhook: could not deliver run summary to {}: {err}",
+                self.url
+            );
+        }
+    }
+}
+
+impl Reporter for WebhookReporter {
+    fn on_run_start(&mut self, tests: &[TestItem]) {
+        self.failed_ids.clear();
+        self.inner.on_run_start(tests);
+    }
+
+    fn on_test_complete(&mut self, result: &TestResult) {
+        if matches!(
+            result.outcome,
+            TestOutcome::Failed { .. } | TestOutcome::Error { .. } | TestOutcome::XPassed
+        ) {
+            self.failed_ids.push(result.test.id());
+        }
+        self.inner.on_test_complete(result);
+    }
+
+    fn on_run_complete(&mut self, summary: &RunSummary) {
+        self.inner.on_run_complete(summary);
+        self.post(summary, false);
+    }
+
+    fn on_run_interrupted(&mut self, summary: &RunSummary) {
+        self.inner.on_run_interrupted(summary);
+        self.post(summary, true);
+    }
+
+    forward_to_inner!(
+        on_assertion,
+        streams_assertions,
+        on_heartbeat,
+        heartbeat_interval,
+        on_collect_complete,
+        on_discovery_error,
+        on_discovery_warning,
+        set_subcommand_label,
+        set_watch_hint,
+        set_selection,
+        arm_clear,
+        on_watch_idle,
+        on_watch_results_cleared,
+        set_display_paths,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::*;
+    use crate::reporter::testing::NullReporter;
+
+    /// Accept one request, answer `200 OK`, and return its body.
+    fn serve_once(listener: TcpListener) -> thread::JoinHandle<String> {
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().expect("accept");
+            let mut reader = BufReader::new(stream);
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).expect("read header");
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':')
+                    && name.eq_ignore_ascii_case("content-length")
+                {
+                    content_length = value.trim().parse().expect("content length");
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).expect("read body");
+            reader
+                .get_mut()
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+                .expect("respond");
+            String::from_utf8(body).expect("utf-8 body")
+        })
+    }
+
+    fn summary(passed: usize, failed: usize) -> RunSummary {
+        RunSummary {
+            passed,
+            failed,
+            duration: Duration::from_millis(5),
+            file_count: 1,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn posts_summary_counts_and_failed_ids() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let url = format!("http://{}/hook", listener.local_addr().expect("addr"));
+        let server = serve_once(listener);
+
+        let mut reporter = WebhookReporter::new(Box::new(NullReporter), url);
+        reporter.on_run_start(&[]);
+        reporter.on_test_complete(&TestResult::new(
+            TestItem {
+                name: "test_sub".into(),
+                module_path: "tests.test_math".into(),
+                file_path: Some("tests/test_math.py".into()),
+                ..TestItem::default()
+            },
+            TestOutcome::Failed {
+                message: "expected 2".into(),
+                traceback: None,
+                assertions: vec![],
+                executed_lines: vec![],
+            },
+            Duration::from_millis(1),
+        ));
+        reporter.on_run_complete(&summary(3, 1));
+
+        let body: serde_json::Value =
+            serde_json::from_str(&server.join().expect("server")).expect("json body");
+        assert_eq!(body["summary"]["passed"], 3);
+        assert_eq!(body["summary"]["failed"], 1);
+        assert_eq!(
+            body["failed_ids"],
+            serde_json::json!(["tests/test_math.py::test_sub"])
+        );
+        assert_eq!(body["interrupted"], false);
+    }
+
+    #[test]
+    fn unreachable_webhook_does_not_panic() {
+        // Bind then drop so the port is very likely closed.
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .expect("bind")
+            .local_addr()
+            .expect("addr");
+        let mut reporter = WebhookReporter::new(Box::new(NullReporter), format!("http://{addr}/"));
+        reporter.on_run_complete(&summary(1, 0));
+    }
+}
@@ -3,6 +3,6 @@ pub mod protocol;
 pub mod schedule;
 pub mod worker;
 
-pub use pool::{WorkerPool, path_to_module};
+pub use pool::{PoolEvent, SubmitOptions, WorkerPool, path_to_module};
 pub use schedule::{DistMode, WorkUnit, partition, partition_with_hooks};
 pub use worker::WorkerProcess;
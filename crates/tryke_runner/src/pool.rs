@@ -6,9 +6,9 @@ use anyhow::{Result, anyhow};
 use log::{LevelFilter, debug, trace, warn};
 
 use tokio::sync::{mpsc, oneshot};
-use tokio_stream::Stream;
 use tokio_stream::wrappers::UnboundedReceiverStream;
-use tryke_types::{HookItem, TestOutcome, TestResult};
+use tokio_stream::{Stream, StreamExt};
+use tryke_types::{AssertionEvent, HookItem, TestItem, TestOutcome, TestResult};
 
 use crate::protocol::RegisterHooksParams;
 use crate::schedule::WorkUnit;
@@ -61,8 +61,39 @@ fn format_worker_failure(prefix: &str, err: &dyn std::fmt::Display, stderr: &str
     msg
 }
 
+/// Something a worker reports while running submitted work.
+#[derive(Debug)]
+pub enum PoolEvent {
+    /// A test a worker has started running. Its `Result` follows once it
+    /// finishes.
+    Started(TestItem),
+    /// One `expect(...)` evaluated by a test that is still running. Only
+    /// sent when asked for through [`WorkerPool::submit_events`].
+    Assertion(AssertionEvent),
+    /// A finished test.
+    Result(TestResult),
+}
+
+/// How the tests of one [`WorkerPool::submit_events`] call are run.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SubmitOptions {
+    /// Workers report each test's assertions as they're evaluated, each
+    /// ahead of the result of the test it ran in.
+    pub stream_assertions: bool,
+    /// How long a test may run before its worker is killed and the test
+    /// fails, from `tryke test --timeout`. `None` waits forever.
+    pub timeout: Option<Duration>,
+}
+
+/// Where a work unit's events go, and how its tests are run.
+#[derive(Clone)]
+struct EventSink {
+    tx: mpsc::UnboundedSender<PoolEvent>,
+    options: SubmitOptions,
+}
+
 enum WorkerMsg {
-    Unit(WorkUnit, mpsc::UnboundedSender<TestResult>),
+    Unit(WorkUnit, EventSink),
     Shutdown,
 }
 
@@ -160,12 +191,30 @@ impl WorkerPool {
     /// A `WorkUnit` is an atomic group of tests to be run sequentially on a single worker
     /// Returns a stream
     pub fn submit(&self, units: Vec<WorkUnit>) -> impl Stream<Item = TestResult> + use<> {
+        self.submit_events(units, SubmitOptions::default())
+            .filter_map(|event| match event {
+                PoolEvent::Result(result) => Some(result),
+                PoolEvent::Started(_) | PoolEvent::Assertion(_) => None,
+            })
+    }
+
+    /// Like [`Self::submit`], but the stream carries [`PoolEvent`]s and
+    /// the tests run under `options`.
+    pub fn submit_events(
+        &self,
+        units: Vec<WorkUnit>,
+        options: SubmitOptions,
+    ) -> impl Stream<Item = PoolEvent> + use<> {
         let (stream_tx, stream_rx) = mpsc::unbounded_channel();
+        let sink = EventSink {
+            tx: stream_tx,
+            options,
+        };
 
         for unit in units {
             let _ = self
                 .work_tx
-                .send_blocking(WorkerMsg::Unit(unit, stream_tx.clone()));
+                .send_blocking(WorkerMsg::Unit(unit, sink.clone()));
         }
 
         UnboundedReceiverStream::new(stream_rx)
@@ -332,33 +381,72 @@ async fn ensure_worker<'a>(
 /// failing test — a retry could double-execute side effects if the test
 /// partially ran before the crash. The failing test is surfaced as
 /// `TestOutcome::Error` with the worker's stderr attached for diagnosis.
+/// A test that outlives its [`SubmitOptions::timeout`] is handled the same
+/// way, except it's reported as a timed-out `TestOutcome::Failed`.
 async fn run_single_test(
     state: &mut WorkerState,
     python_bin: &str,
     path_refs: &[&Path],
     root: &Path,
     log_level: LevelFilter,
-    test: tryke_types::TestItem,
-    result_tx: &mpsc::UnboundedSender<TestResult>,
+    test: TestItem,
+    events: &EventSink,
 ) {
     let Some(w) = ensure_worker(state, python_bin, path_refs, root, log_level).await else {
         let message = state
             .last_failure
             .clone()
             .unwrap_or_else(|| "worker unavailable (spawn or hook replay failed)".into());
-        let _ = result_tx.send(TestResult {
+        let _ = events.tx.send(PoolEvent::Result(TestResult::new(
             test,
-            outcome: TestOutcome::Error { message },
-            duration: Duration::ZERO,
-            stdout: String::new(),
-            stderr: String::new(),
-        });
+            TestOutcome::Error { message },
+            Duration::ZERO,
+        )));
         return;
     };
-    match w.run_test(&test).await {
+    let _ = events.tx.send(PoolEvent::Started(test.clone()));
+    let run = async {
+        if events.options.stream_assertions {
+            w.run_test_with_assertions(&test, &mut |event| {
+                let _ = events.tx.send(PoolEvent::Assertion(event));
+            })
+            .await
+        } else {
+            w.run_test(&test).await
+        }
+    };
+    let outcome = match events.options.timeout {
+        Some(limit) => {
+            let Ok(outcome) = tokio::time::timeout(limit, run).await else {
+                debug!("worker_task: {} timed out after {limit:?}", test.name);
+                // The worker is still inside the test; kill it so the
+                // next test gets a fresh process with hooks replayed.
+                let stderr_output = w.drain_stderr().await;
+                state.process = None;
+                let _ = events.tx.send(PoolEvent::Result(TestResult {
+                    stderr: stderr_output,
+                    timed_out: true,
+                    ..TestResult::new(
+                        test,
+                        TestOutcome::Failed {
+                            message: format!("timed out after {limit:?}"),
+                            traceback: None,
+                            assertions: Vec::new(),
+                            executed_lines: Vec::new(),
+                        },
+                        limit,
+                    )
+                }));
+                return;
+            };
+            outcome
+        }
+        None => run.await,
+    };
+    match outcome {
         Ok(result) => {
             trace!("worker_task: test {} done", test.name);
-            let _ = result_tx.send(result);
+            let _ = events.tx.send(PoolEvent::Result(result));
         }
         Err(err) => {
             debug!("worker_task: run_test error for {}: {err}", test.name);
@@ -368,13 +456,10 @@ async fn run_single_test(
             // tests in this unit keep their fixtures.
             state.process = None;
             let message = format_worker_failure("worker error", &err, &stderr_output);
-            let _ = result_tx.send(TestResult {
-                test,
-                outcome: TestOutcome::Error { message },
-                duration: Duration::ZERO,
-                stdout: String::new(),
+            let _ = events.tx.send(PoolEvent::Result(TestResult {
                 stderr: stderr_output,
-            });
+                ..TestResult::new(test, TestOutcome::Error { message }, Duration::ZERO)
+            }));
         }
     }
 }
@@ -477,8 +562,13 @@ async fn handle_unit(
     root: &Path,
     log_level: LevelFilter,
     unit: WorkUnit,
-    result_tx: mpsc::UnboundedSender<TestResult>,
+    events: EventSink,
 ) {
+    // Nobody is listening once a run has been abandoned (e.g. `--maxfail`
+    // stopped it), so the rest of its queued units are skipped.
+    if events.tx.is_closed() {
+        return;
+    }
     if !unit.hooks.is_empty() {
         register_hooks_for_unit(
             state,
@@ -494,11 +584,11 @@ async fn handle_unit(
     let finalize_modules: std::collections::HashSet<String> =
         unit.tests.iter().map(|t| t.module_path.clone()).collect();
     for test in unit.tests {
+        if events.tx.is_closed() {
+            break;
+        }
         trace!("worker_task: running test {}", test.name);
-        run_single_test(
-            state, python_bin, path_refs, root, log_level, test, &result_tx,
-        )
-        .await;
+        run_single_test(state, python_bin, path_refs, root, log_level, test, &events).await;
     }
     for module in finalize_modules {
         if let Some(w) = state.process.as_mut()
@@ -534,7 +624,7 @@ async fn worker_task(
             }
             msg = work_rx.recv() => {
                 match msg {
-                    Ok(WorkerMsg::Unit(unit, result_tx)) => {
+                    Ok(WorkerMsg::Unit(unit, events)) => {
                         handle_unit(
                             &mut state,
                             &python_bin,
@@ -542,7 +632,7 @@ async fn worker_task(
                             &root,
                             log_level,
                             unit,
-                            result_tx,
+                            events,
                         )
                         .await;
                     }
@@ -772,6 +862,159 @@ def test_noop() -> None:
         pool.shutdown();
     }
 
+    /// With assertion streaming on, every `expect(...)` surfaces as a
+    /// `PoolEvent::Assertion` between the test's start and its final
+    /// result.
+    #[tokio::test]
+    async fn submit_events_streams_assertions_before_the_result() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        std::fs::write(dir.path().join("pyproject.toml"), "").expect("write pyproject.toml");
+
+        let test_file = dir.path().join("test_stream.py");
+        std::fs::write(
+            &test_file,
+            r"from tryke import test, expect
+
+@test
+def test_two() -> None:
+    expect(1).to_equal(1)
+    expect(2).to_equal(3)
+",
+        )
+        .expect("write test file");
+
+        let unit = WorkUnit {
+            tests: vec![make_test_item("test_stream", "test_two", &test_file)],
+            hooks: vec![],
+        };
+
+        let python_path = [dir.path().to_path_buf(), python_package_dir()];
+        let pool = WorkerPool::spawn(
+            1,
+            &test_python_bin(),
+            dir.path(),
+            Some(&python_path),
+            LevelFilter::Off,
+            true,
+        )
+        .await;
+
+        let events: Vec<PoolEvent> = pool
+            .submit_events(
+                vec![unit],
+                SubmitOptions {
+                    stream_assertions: true,
+                    ..SubmitOptions::default()
+                },
+            )
+            .collect()
+            .await;
+        let passed: Vec<bool> = events
+            .iter()
+            .filter_map(|event| match event {
+                PoolEvent::Assertion(a) => Some(a.passed),
+                PoolEvent::Started(_) | PoolEvent::Result(_) => None,
+            })
+            .collect();
+        assert_eq!(passed, vec![true, false], "{events:?}");
+        assert!(
+            matches!(events.first(), Some(PoolEvent::Started(t)) if t.name == "test_two"),
+            "start must come first, got {events:?}"
+        );
+        assert!(
+            matches!(events.last(), Some(PoolEvent::Result(r)) if matches!(r.outcome, TestOutcome::Failed { .. })),
+            "result must come last, got {events:?}"
+        );
+
+        pool.shutdown();
+    }
+
+    /// A test that sleeps past its timeout fails as timed out, its worker
+    /// is killed, and the next test in the unit runs on a fresh worker.
+    #[tokio::test]
+    async fn timed_out_test_fails_and_next_test_runs_on_fresh_worker() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        std::fs::write(dir.path().join("pyproject.toml"), "").expect("write pyproject.toml");
+
+        let test_file = dir.path().join("test_slow.py");
+        std::fs::write(
+            &test_file,
+            r"import time
+
+from tryke import test, expect
+
+@test
+def test_sleeps() -> None:
+    time.sleep(60)
+
+@test
+def test_quick() -> None:
+    expect(1).to_equal(1)
+",
+        )
+        .expect("write test file");
+
+        let limit = Duration::from_millis(500);
+        let tests = ["test_sleeps", "test_quick"]
+            .into_iter()
+            .map(|name| make_test_item("test_slow", name, &test_file))
+            .collect();
+        let unit = WorkUnit {
+            tests,
+            hooks: vec![],
+        };
+
+        let python_path = [dir.path().to_path_buf(), python_package_dir()];
+        let pool = WorkerPool::spawn(
+            1,
+            &test_python_bin(),
+            dir.path(),
+            Some(&python_path),
+            LevelFilter::Off,
+            true,
+        )
+        .await;
+
+        let events = pool.submit_events(
+            vec![unit],
+            SubmitOptions {
+                timeout: Some(limit),
+                ..SubmitOptions::default()
+            },
+        );
+        let results: Vec<TestResult> = tokio::time::timeout(
+            Duration::from_secs(30),
+            events
+                .filter_map(|event| match event {
+                    PoolEvent::Result(result) => Some(result),
+                    PoolEvent::Started(_) | PoolEvent::Assertion(_) => None,
+                })
+                .collect(),
+        )
+        .await
+        .expect("timed-out test must not block the unit");
+        assert_eq!(results.len(), 2, "expected 2 results, got {results:?}");
+
+        let slow = &results[0];
+        assert!(slow.timed_out);
+        assert!(
+            matches!(&slow.outcome, TestOutcome::Failed { message, .. } if message == "timed out after 500ms"),
+            "got {:?}",
+            slow.outcome
+        );
+        assert_eq!(slow.duration, limit);
+
+        let quick = &results[1];
+        assert!(!quick.timed_out);
+        assert!(
+            matches!(quick.outcome, TestOutcome::Passed),
+            "got {:?}",
+            quick.outcome
+        );
+
+        pool.shutdown();
+    }
+
     /// When the worker python dies during startup (e.g. project venv
     /// without `tryke` installed prints `ModuleNotFoundError` and
     /// exits), the user-facing error must include the python stderr —
@@ -14,6 +14,11 @@
 //!    `HookExecutor` by looking up each hook name as an attribute of the
 //!    imported module and reading its `per=test`/`per=scope` kind. Fixtures
 //!    are resolved and injected before the test function runs.
+//!    With `stream_assertions` set, the worker also writes an `assertion`
+//!    notification for each `expect(...)` as it's evaluated, ahead of the
+//!    response (see [`tryke_types::ASSERTION_NOTIFICATION`]). With
+//!    `benchmark` set, a passing test is called again for warmup and
+//!    measured runs, and the response carries their timing.
 //! 3. `finalize_hooks`   — after the last test in a module, Rust sends
 //!    [`FinalizeHooksParams`] so `per="scope"` teardown runs.
 //!
@@ -30,8 +35,10 @@
 //! worker never needs to re-walk the AST itself.
 
 use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tryke_types::TestItem;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum RPCRequestMethod {
     RegisterHooks,
@@ -65,7 +72,7 @@ pub struct RPCErrorDetail {
     pub traceback: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RunTestParams {
     pub module: String,
     pub function: String,
@@ -78,6 +85,30 @@ pub struct RunTestParams {
     /// stored kwargs when invoking the test function.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub case_label: Option<String>,
+    /// Ask the worker to send a [`tryke_types::ASSERTION_NOTIFICATION`]
+    /// for every `expect(...)` it evaluates before it responds.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub stream_assertions: bool,
+    /// Time repeated calls of a passing test and report them as the
+    /// result's `benchmark`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub benchmark: bool,
+}
+
+impl RunTestParams {
+    /// The `run_test` params that ask a worker to run `test`.
+    #[must_use]
+    pub fn for_test(test: &TestItem, stream_assertions: bool) -> Self {
+        Self {
+            module: test.module_path.clone(),
+            function: test.name.clone(),
+            xfail: test.xfail.clone(),
+            groups: test.groups.clone(),
+            case_label: test.case_label.clone(),
+            stream_assertions,
+            benchmark: test.benchmark,
+        }
+    }
 }
 
 /// Wire format for a single fixture sent to the Python worker.
@@ -132,3 +163,115 @@ pub struct RunDoctestParams {
 }
 
 pub use tryke_types::{AssertionWire, RunTestResultWire};
+
+/// A canonical pair of `run_test` exchanges, printed by `tryke
+/// worker-protocol --emit-sample` for developing the Python worker on its
+/// own. Each entry holds a request as the runner writes it to a worker's
+/// stdin and the response the runner expects back on stdout: the first
+/// test passes, the second fails an `expect(...)`.
+///
+/// # Errors
+///
+/// Fails only if the request types stop serializing to JSON.
+pub fn sample_exchanges() -> serde_json::Result<serde_json::Value> {
+    let request = |id: u64, function: &str| {
+        let test = TestItem {
+            name: function.into(),
+            module_path: "tests.test_math".into(),
+            ..TestItem::default()
+        };
+        serde_json::to_value(RPCRequest {
+            jsonrpc: "2.0",
+            id,
+            method: RPCRequestMethod::RunTest,
+            params: Some(serde_json::to_value(RunTestParams::for_test(&test, false))?),
+        })
+    };
+    Ok(json!([
+        {
+            "request": request(1, "test_add")?,
+            "response": {
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "outcome": "passed",
+                    "duration_ms": 2,
+                    "stdout": "",
+                    "stderr": "",
+                },
+            },
+        },
+        {
+            "request": request(2, "test_sub")?,
+            "response": {
+                "jsonrpc": "2.0",
+                "id": 2,
+                "result": {
+                    "outcome": "failed",
+                    "duration_ms": 3,
+                    "message": "expected 1, received 2",
+                    "traceback": null,
+                    "assertions": [{
+                        "expression": "expect(sub(3, 1)).to_equal(1)",
+                        "expected": "1",
+                        "received": "2",
+                        "line": 9,
+                        "column": 5,
+                        "file": "tests/test_math.py",
+                    }],
+                    "executed_lines": [8, 9],
+                    "stdout": "",
+                    "stderr": "",
+                },
+            },
+        },
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use tryke_types::{TestOutcome, convert_wire_result};
+
+    use super::*;
+
+    #[test]
+    fn sample_exchanges_round_trip_through_the_protocol_types() {
+        let sample = sample_exchanges().expect("sample serializes");
+        let exchanges = sample.as_array().expect("a list of exchanges");
+        assert_eq!(exchanges.len(), 2);
+
+        let mut outcomes = Vec::new();
+        for exchange in exchanges {
+            let request = &exchange["request"];
+            let method: RPCRequestMethod =
+                serde_json::from_value(request["method"].clone()).expect("method");
+            assert_eq!(method, RPCRequestMethod::RunTest);
+            let params: RunTestParams =
+                serde_json::from_value(request["params"].clone()).expect("params");
+            assert_eq!(
+                serde_json::to_value(&params).expect("params serialize"),
+                request["params"]
+            );
+
+            let response: RPCResponse =
+                serde_json::from_value(exchange["response"].clone()).expect("response");
+            assert_eq!(Some(response.id), request["id"].as_u64());
+            assert!(response.error.is_none());
+            let wire: RunTestResultWire =
+                serde_json::from_value(response.result.expect("result")).expect("result wire");
+            let test = TestItem {
+                name: params.function,
+                module_path: params.module,
+                ..TestItem::default()
+            };
+            outcomes.push(convert_wire_result(test, wire).outcome);
+        }
+
+        assert!(matches!(outcomes[0], TestOutcome::Passed));
+        assert!(matches!(
+            &outcomes[1],
+            TestOutcome::Failed { assertions, .. }
+                if assertions.len() == 1 && assertions[0].line == 9
+        ));
+    }
+}
@@ -7,7 +7,10 @@ use anyhow::{Result, anyhow};
 use log::{debug, trace};
 use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
 use tokio::process::{Child, ChildStdin, ChildStdout, Command};
-use tryke_types::{TestItem, TestResult, convert_wire_result};
+use tryke_types::{
+    AssertionEvent, AssertionEventWire, TestItem, TestResult, convert_assertion_event,
+    convert_wire_result, parse_assertion_notification,
+};
 
 use crate::protocol::{
     FinalizeHooksParams, RPCRequest, RPCRequestMethod, RPCResponse, RegisterHooksParams,
@@ -109,6 +112,19 @@ impl WorkerProcess {
         &mut self,
         method: RPCRequestMethod,
         params: Option<serde_json::Value>,
+    ) -> Result<R> {
+        self.call_with_notifications(method, params, &mut |_| {})
+            .await
+    }
+
+    /// Like [`Self::call`], but hands every assertion notification the
+    /// worker sends for this request to `on_assertion` while waiting for
+    /// the response.
+    async fn call_with_notifications<R: for<'de> serde::Deserialize<'de>>(
+        &mut self,
+        method: RPCRequestMethod,
+        params: Option<serde_json::Value>,
+        on_assertion: &mut (dyn FnMut(AssertionEventWire) + Send),
     ) -> Result<R> {
         let id = self.next_id;
         self.next_id += 1;
@@ -144,6 +160,14 @@ impl WorkerProcess {
             }
             trace!("worker rpc <- {}", resp_line.trim());
             let trimmed = resp_line.trim();
+            if let Some(event) = parse_assertion_notification(trimmed) {
+                // A notification left over from an earlier request that
+                // errored out mid-test belongs to nobody; drop it.
+                if event.id == id {
+                    on_assertion(event);
+                }
+                continue;
+            }
             if !trimmed.is_empty()
                 && let Ok(resp) = serde_json::from_str::<RPCResponse>(trimmed)
             {
@@ -184,17 +208,45 @@ impl WorkerProcess {
     /// Returns an error if the request cannot be serialized, if worker I/O
     /// fails, or if the worker returns a JSON-RPC error.
     pub async fn run_test(&mut self, test: &TestItem) -> Result<TestResult> {
+        self.run_test_inner(test, None).await
+    }
+
+    /// Run a test like [`Self::run_test`], asking the worker to report
+    /// each `expect(...)` as it's evaluated. `on_assertion` sees those
+    /// results in order, all before this returns. Doctests have no
+    /// `expect(...)` calls and report nothing.
+    ///
+    /// # Errors
+    /// Returns an error if the request cannot be serialized, if worker I/O
+    /// fails, or if the worker returns a JSON-RPC error.
+    pub async fn run_test_with_assertions(
+        &mut self,
+        test: &TestItem,
+        on_assertion: &mut (dyn FnMut(AssertionEvent) + Send),
+    ) -> Result<TestResult> {
+        self.run_test_inner(test, Some(on_assertion)).await
+    }
+
+    async fn run_test_inner(
+        &mut self,
+        test: &TestItem,
+        on_assertion: Option<&mut (dyn FnMut(AssertionEvent) + Send)>,
+    ) -> Result<TestResult> {
         if let Some(object_path) = &test.doctest_object {
             return self.run_doctest(test, object_path).await;
         }
-        let params = serde_json::to_value(RunTestParams {
-            module: test.module_path.clone(),
-            function: test.name.clone(),
-            xfail: test.xfail.clone(),
-            groups: test.groups.clone(),
-            case_label: test.case_label.clone(),
-        })?;
-        let wire: RunTestResultWire = self.call(RPCRequestMethod::RunTest, Some(params)).await?;
+        let params = serde_json::to_value(RunTestParams::for_test(test, on_assertion.is_some()))?;
+        let wire: RunTestResultWire = match on_assertion {
+            Some(on_assertion) => {
+                self.call_with_notifications(
+                    RPCRequestMethod::RunTest,
+                    Some(params),
+                    &mut |event| on_assertion(convert_assertion_event(test, event)),
+                )
+                .await?
+            }
+            None => self.call(RPCRequestMethod::RunTest, Some(params)).await?,
+        };
         Ok(convert_wire_result(test.clone(), wire))
     }
 
@@ -451,6 +503,8 @@ mod tests {
             duration_ms: 10,
             stdout: "out".into(),
             stderr: "err".into(),
+            logs: Vec::new(),
+            benchmark: None,
         };
         let result = convert_wire_result(test, wire);
         assert!(matches!(result.outcome, TestOutcome::Passed));
@@ -470,6 +524,7 @@ mod tests {
             executed_lines: vec![],
             stdout: String::new(),
             stderr: String::new(),
+            logs: Vec::new(),
         };
         let result = convert_wire_result(test, wire);
         assert!(matches!(
@@ -486,6 +541,7 @@ mod tests {
             reason: Some("not ready".into()),
             stdout: String::new(),
             stderr: String::new(),
+            logs: Vec::new(),
         };
         let result = convert_wire_result(test, wire);
         assert!(matches!(
@@ -528,6 +584,8 @@ mod tests {
                     .find("other=1")
                     .map(|offset| (offset, "other=1".len())),
                 expected_arg_value: Some("1".into()),
+                group: None,
+                pending: false,
             }],
             ..Default::default()
         };
@@ -548,6 +606,7 @@ mod tests {
                 executed_lines: vec![10],
                 stdout: String::new(),
                 stderr: String::new(),
+                logs: Vec::new(),
             },
         );
         let TestOutcome::Failed {
@@ -587,6 +646,8 @@ mod tests {
                     expected_arg_span: None,
                     expected_arg_value: None,
                     label: None,
+                    group: None,
+                    pending: false,
                 },
                 ExpectedAssertion {
                     subject: "0".into(),
@@ -602,6 +663,8 @@ mod tests {
                     expected_arg_span: Some((19, 1)),
                     expected_arg_value: Some("1".into()),
                     label: None,
+                    group: None,
+                    pending: false,
                 },
             ],
             ..Default::default()
@@ -623,6 +686,7 @@ mod tests {
                 executed_lines: vec![3],
                 stdout: String::new(),
                 stderr: String::new(),
+                logs: Vec::new(),
             },
         );
         let TestOutcome::Failed { assertions, .. } = result.outcome else {
@@ -686,6 +750,7 @@ mod tests {
                 executed_lines: vec![5],
                 stdout: String::new(),
                 stderr: String::new(),
+                logs: Vec::new(),
             },
         );
         let TestOutcome::Failed { assertions, .. } = result.outcome else {
@@ -749,6 +814,7 @@ mod tests {
                 executed_lines: vec![5],
                 stdout: String::new(),
                 stderr: String::new(),
+                logs: Vec::new(),
             },
         );
         let TestOutcome::Failed { assertions, .. } = result.outcome else {
@@ -360,6 +360,9 @@ async fn execute_run(
         file_count,
         start_time: Some(start_time),
         changed_selection: None,
+        timing_stats: None,
+        slow: 0,
+        stopped_after: None,
     };
     send_notification(
         outbound_tx,
@@ -1,6 +1,6 @@
 use std::path::{Path, PathBuf};
 
-use crate::TestItem;
+use crate::{SelectionSummary, TestItem};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum PathSpec {
@@ -21,6 +21,12 @@ pub struct TestFilter {
     pub path_specs: Vec<PathSpec>,
     pub expr: Option<FilterExpr>,
     pub marker_expr: Option<FilterExpr>,
+    /// `--tag-not` names: tests carrying any of these tags are dropped,
+    /// after the other filters have selected them.
+    pub excluded_tags: Vec<String>,
+    /// The CLI arguments this filter was built from, rendered back as
+    /// flags (e.g. `-k foo -m slow`). `None` for an empty filter.
+    pub description: Option<String>,
 }
 
 #[derive(Debug)]
@@ -320,13 +326,43 @@ impl TestFilter {
             .collect::<Result<Vec<_>, _>>()?;
         let expr = filter.map(FilterExpr::parse).transpose()?;
         let marker_expr = markers.map(FilterExpr::parse).transpose()?;
+        let mut parts: Vec<String> = paths.to_vec();
+        if let Some(filter) = filter {
+            parts.push(format!("-k {filter}"));
+        }
+        if let Some(markers) = markers {
+            parts.push(format!("-m {markers}"));
+        }
+        let description = (!parts.is_empty()).then(|| parts.join(" "));
         Ok(Self {
             path_specs,
             expr,
             marker_expr,
+            excluded_tags: Vec::new(),
+            description,
         })
     }
 
+    /// Also drop tests tagged with any of `tags`, which must match a tag
+    /// exactly.
+    #[must_use]
+    pub fn with_excluded_tags(mut self, tags: &[String]) -> Self {
+        if tags.is_empty() {
+            return self;
+        }
+        let flags = tags
+            .iter()
+            .map(|tag| format!("--tag-not {tag}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.description = Some(match self.description.take() {
+            Some(description) => format!("{description} {flags}"),
+            None => flags,
+        });
+        self.excluded_tags = tags.to_vec();
+        self
+    }
+
     #[must_use]
     pub fn matches(&self, test: &TestItem) -> bool {
         let path_ok =
@@ -336,7 +372,8 @@ impl TestFilter {
             .marker_expr
             .as_ref()
             .is_none_or(|expr| expr.matches_tags(&test.tags));
-        path_ok && expr_ok && marker_ok
+        let not_excluded = !test.tags.iter().any(|tag| self.excluded_tags.contains(tag));
+        path_ok && expr_ok && marker_ok && not_excluded
     }
 
     #[must_use]
@@ -347,9 +384,28 @@ impl TestFilter {
         tests.into_iter().filter(|t| self.matches(t)).collect()
     }
 
+    /// Like [`apply`](Self::apply), but also reports how many tests were
+    /// collected before filtering and how many were selected.
+    #[must_use]
+    pub fn select(&self, tests: Vec<TestItem>) -> (Vec<TestItem>, SelectionSummary) {
+        let collected = tests.len();
+        let selected_tests = self.apply(tests);
+        let summary = SelectionSummary {
+            collected,
+            selected: selected_tests.len(),
+            via: self.description.clone(),
+            cached: None,
+            seed: None,
+        };
+        (selected_tests, summary)
+    }
+
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.path_specs.is_empty() && self.expr.is_none() && self.marker_expr.is_none()
+        self.path_specs.is_empty()
+            && self.expr.is_none()
+            && self.marker_expr.is_none()
+            && self.excluded_tags.is_empty()
     }
 }
 
@@ -637,6 +693,52 @@ mod tests {
         assert_eq!(filtered[0].name, "test_add");
     }
 
+    #[test]
+    fn filter_apply_combinators_match_display_names_case_insensitively() {
+        let filter = TestFilter::from_args(&[], Some("ADD and not Slow"), None).unwrap();
+        let mut quick = make_test("test_one", "tests/math.py", 10);
+        quick.display_name = Some("adds two numbers".into());
+        let mut slow = make_test("test_two", "tests/math.py", 20);
+        slow.display_name = Some("adds slowly".into());
+        let other = make_test("test_sub", "tests/math.py", 30);
+        let (selected, summary) = filter.select(vec![quick, slow, other]);
+        let names: Vec<_> = selected.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["test_one"]);
+        assert_eq!(summary.selected, 1);
+    }
+
+    #[test]
+    fn filter_select_reports_collected_and_selected_counts() {
+        let filter = TestFilter::from_args(&[], Some("add"), None).unwrap();
+        let tests = vec![
+            make_test("test_add", "tests/math.py", 10),
+            make_test("test_sub", "tests/math.py", 20),
+            make_test("test_mul", "tests/math.py", 30),
+        ];
+        let (selected, summary) = filter.select(tests);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(
+            summary,
+            SelectionSummary {
+                collected: 3,
+                selected: 1,
+                via: Some("-k add".into()),
+                cached: None,
+                seed: None,
+            }
+        );
+    }
+
+    #[test]
+    fn filter_select_without_filter_selects_everything() {
+        let filter = TestFilter::from_args(&[], None, None).unwrap();
+        let tests = vec![make_test("test_add", "tests/math.py", 10)];
+        let (_, summary) = filter.select(tests);
+        assert_eq!(summary.collected, 1);
+        assert_eq!(summary.selected, 1);
+        assert_eq!(summary.via, None);
+    }
+
     #[test]
     fn filter_apply_path_and_expr_intersect() {
         let filter = TestFilter::from_args(&["tests/math.py".into()], Some("add"), None).unwrap();
@@ -726,6 +828,44 @@ mod tests {
         assert!(!expr.matches_tags(&["slow".into()]));
     }
 
+    #[test]
+    fn matches_tags_or() {
+        let expr = FilterExpr::parse("db or cache").unwrap();
+        assert!(expr.matches_tags(&["cache".into()]));
+        assert!(expr.matches_tags(&["db".into(), "slow".into()]));
+        assert!(!expr.matches_tags(&["network".into()]));
+    }
+
+    #[test]
+    fn matches_tags_parenthesized_precedence() {
+        let grouped = FilterExpr::parse("(db or cache) and integration").unwrap();
+        let ungrouped = FilterExpr::parse("db or cache and integration").unwrap();
+        let db_only: Vec<String> = vec!["db".into()];
+        assert!(!grouped.matches_tags(&db_only));
+        assert!(ungrouped.matches_tags(&db_only));
+        assert!(grouped.matches_tags(&["cache".into(), "integration".into()]));
+        assert!(!grouped.matches_tags(&["integration".into()]));
+    }
+
+    #[test]
+    fn filter_with_markers_combines_and_or_not() {
+        let filter =
+            TestFilter::from_args(&[], None, Some("(db or cache) and not network")).unwrap();
+        let tagged = |name: &str, tags: &[&str]| {
+            let mut t = make_test(name, "tests/a.py", 1);
+            t.tags = tags.iter().map(|&tag| tag.into()).collect();
+            t
+        };
+        let filtered = filter.apply(vec![
+            tagged("test_db", &["db"]),
+            tagged("test_cache_network", &["cache", "network"]),
+            tagged("test_cache", &["cache", "slow"]),
+            tagged("test_plain", &[]),
+        ]);
+        let names: Vec<&str> = filtered.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["test_db", "test_cache"]);
+    }
+
     #[test]
     fn filter_with_markers_restricts_by_tags() {
         let filter = TestFilter::from_args(&[], None, Some("slow")).unwrap();
@@ -738,6 +878,36 @@ mod tests {
         assert_eq!(filtered[0].name, "test_a");
     }
 
+    #[test]
+    fn excluded_tags_drop_tagged_tests() {
+        let tagged = |name: &str, tags: &[&str]| {
+            let mut t = make_test(name, "tests/a.py", 1);
+            t.tags = tags.iter().map(|&tag| tag.into()).collect();
+            t
+        };
+        let tests = vec![
+            tagged("test_slow", &["slow"]),
+            tagged("test_fast", &["fast"]),
+            tagged("test_slow_db", &["db", "slow"]),
+            tagged("test_plain", &[]),
+        ];
+
+        let filter = TestFilter::from_args(&[], None, None)
+            .unwrap()
+            .with_excluded_tags(&["slow".into()]);
+        assert!(!filter.is_empty());
+        let (selected, summary) = filter.select(tests.clone());
+        let names: Vec<&str> = selected.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["test_fast", "test_plain"]);
+        assert_eq!(summary.via.as_deref(), Some("--tag-not slow"));
+
+        let filter = TestFilter::from_args(&[], None, Some("db or slow"))
+            .unwrap()
+            .with_excluded_tags(&["db".into()]);
+        let names: Vec<String> = filter.apply(tests).into_iter().map(|t| t.name).collect();
+        assert_eq!(names, ["test_slow"]);
+    }
+
     #[test]
     fn filter_with_markers_is_not_empty() {
         let filter = TestFilter::from_args(&[], None, Some("slow")).unwrap();
@@ -1,20 +1,33 @@
 pub mod filter;
+pub mod matchers;
+pub mod serde_millis;
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
-/// Convert a file path to a Python module name relative to `root`.
-/// e.g. `/project/tests/test_math.py` → `"tests.test_math"`
+/// Convert a file path to the dotted name a worker imports it by, relative
+/// to `root`. e.g. `/project/tests/test_math.py` → `"tests.test_math"` and
+/// `/project/pkg/__init__.py` → `"pkg"`.
 ///
-/// Returns `None` if `path` is not under `root` or has no components.
+/// Directories without an `__init__.py` are joined all the same: workers
+/// run with `root` on `sys.path`, where they import as namespace packages,
+/// whereas a bare file name would only import with its directory on the
+/// path too.
+///
+/// Returns `None` if `path` is not under `root` or names no module.
 #[must_use]
 pub fn path_to_module(root: &Path, path: &Path) -> Option<String> {
     let relative = path.strip_prefix(root).ok()?;
     let without_ext = relative.with_extension("");
-    let parts: Vec<String> = without_ext
+    let mut parts: Vec<String> = without_ext
         .components()
         .map(|c| c.as_os_str().to_string_lossy().into_owned())
         .collect();
+    // A package is imported by its directory's name, not as `pkg.__init__`.
+    if parts.last().is_some_and(|last| last == "__init__") {
+        parts.pop();
+    }
     if parts.is_empty() {
         return None;
     }
@@ -43,6 +56,31 @@ pub struct ExpectedAssertion {
     pub expected_arg_span: Option<(usize, usize)>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub expected_arg_value: Option<String>,
+    /// Checklist group from `expect(..., group="...")`. Consecutive
+    /// assertions sharing a group render under one subheading.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    /// Marked with `.pending()` or a trailing `# pending` comment: the
+    /// worker skips it, and reporters show it apart from passed and
+    /// failed assertions.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pending: bool,
+}
+
+impl ExpectedAssertion {
+    /// The assertion as Python source, `expect(x).not_.to_equal(2)`, put
+    /// back together from its parts for when the discovered `expression`
+    /// text isn't available. Labels and `.pending()` are left out.
+    #[must_use]
+    pub fn reconstructed_expression(&self) -> String {
+        let not = if self.negated { "not_." } else { "" };
+        format!(
+            "expect({}).{not}{}({})",
+            self.subject,
+            self.matcher,
+            self.args.join(", ")
+        )
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -71,10 +109,24 @@ pub struct TestItem {
     pub expected_assertions: Vec<ExpectedAssertion>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub skip: Option<String>,
+    /// `category=` on `@test.skip(...)` (e.g. `"platform"`), so reports
+    /// can group skips by why they happen rather than by free-text reason.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_category: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub todo: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub xfail: Option<String>,
+    /// `enabled=False` on the `@test(...)` decorator. Disabled tests are
+    /// collected as skipped with reason `"disabled"`, or left out entirely
+    /// under `[tool.tryke] exclude_disabled`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+    /// `benchmark=True` on the `@test(...)` decorator. Under
+    /// `tryke test --benchmark` the test is called repeatedly and its
+    /// result carries [`BenchmarkStats`].
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub benchmark: bool,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -94,6 +146,21 @@ pub struct TestItem {
     /// declaration order. `None` when `case_label` is `None`.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub case_index: Option<u32>,
+    /// Every keyword argument on the `@test(...)` decorator, in source
+    /// order, with the value kept as its source text (e.g.
+    /// `("priority", "1")`). Lets tooling read new markers without each
+    /// one needing a dedicated field.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub decorator_kwargs: Vec<(String, String)>,
+    /// Names of the test function's parameters in declaration order,
+    /// leaving out `*args` and `**kwargs`, so the runner knows what a
+    /// call has to supply. See [`TestItem::arity`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<String>,
+    /// Project root this test must execute under. Only set when one
+    /// invocation combines several `--root`s; see [`TestItem::qualify_root`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root: Option<PathBuf>,
 }
 
 impl TestItem {
@@ -109,6 +176,45 @@ impl TestItem {
         }
     }
 
+    /// The pytest-style node id, e.g. `tests/test_math.py::test_add` or
+    /// `tests/test_math.py::square[zero]`. Like [`TestItem::id`], but the
+    /// path is always `/`-separated, as pytest writes it on every
+    /// platform.
+    #[must_use]
+    pub fn nodeid(&self) -> String {
+        let Some(path) = &self.file_path else {
+            return self.id();
+        };
+        let path = path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        match &self.case_label {
+            Some(label) => format!("{path}::{}[{label}]", self.name),
+            None => format!("{path}::{}", self.name),
+        }
+    }
+
+    /// How many arguments the test function takes, not counting
+    /// `*args` or `**kwargs`.
+    #[must_use]
+    pub fn arity(&self) -> usize {
+        self.parameters.len()
+    }
+
+    /// Namespace this test for a run that spans several project roots.
+    ///
+    /// `file_path` gains `label` (the root as the user spelled it) as a
+    /// prefix, so ids and per-file output stay distinct when two roots
+    /// share a layout, and `root` records where the test has to run.
+    pub fn qualify_root(&mut self, label: &Path, root: &Path) {
+        if let Some(path) = &self.file_path {
+            self.file_path = Some(label.join(path));
+        }
+        self.root = Some(root.to_path_buf());
+    }
+
     /// Human-readable label for reporters.
     ///
     /// Returns the `display_name` override if present, otherwise the bare
@@ -154,13 +260,75 @@ pub enum TestOutcome {
     },
 }
 
+/// A `logging` record the worker captured while a test ran.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LogRecord {
+    /// Python level name, e.g. `WARNING`.
+    pub level: String,
+    pub message: String,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct TestResult {
     pub test: TestItem,
     pub outcome: TestOutcome,
+    #[serde(with = "serde_millis")]
     pub duration: Duration,
     pub stdout: String,
     pub stderr: String,
+    /// Records emitted through Python's `logging` during the test, in
+    /// order. Only records that pass the loggers' own levels are kept.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub logs: Vec<LogRecord>,
+    /// Timing of repeated calls of a `benchmark=True` test run under
+    /// `tryke test --benchmark`. `duration` is still the first call's.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub benchmark: Option<BenchmarkStats>,
+    /// `true` when the test was killed for running past the run's
+    /// `--timeout`. The outcome is then `Failed`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub timed_out: bool,
+    /// `true` when the test ran longer than the run's `--slow-threshold`.
+    /// Set by the run loop once the result arrives.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub slow: bool,
+}
+
+impl TestResult {
+    /// A result with no captured output, logs, or benchmark stats.
+    #[must_use]
+    pub fn new(test: TestItem, outcome: TestOutcome, duration: Duration) -> Self {
+        Self {
+            test,
+            outcome,
+            duration,
+            stdout: String::new(),
+            stderr: String::new(),
+            logs: Vec::new(),
+            benchmark: None,
+            timed_out: false,
+            slow: false,
+        }
+    }
+
+    /// Whether the test ran longer than `threshold`. `None` flags
+    /// nothing.
+    #[must_use]
+    pub fn exceeds_slow_threshold(&self, threshold: Option<Duration>) -> bool {
+        threshold.is_some_and(|threshold| self.duration > threshold)
+    }
+}
+
+/// Timing of a benchmark test's measured calls. Warmup calls are left out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BenchmarkStats {
+    pub iterations: u32,
+    #[serde(with = "serde_millis")]
+    pub min: Duration,
+    #[serde(with = "serde_millis")]
+    pub mean: Duration,
+    #[serde(with = "serde_millis")]
+    pub stddev: Duration,
 }
 
 /// Flat wire format produced by the Python worker's ``run_test`` function.
@@ -175,6 +343,10 @@ pub enum RunTestResultWire {
         duration_ms: u64,
         stdout: String,
         stderr: String,
+        #[serde(default)]
+        logs: Vec<LogRecord>,
+        #[serde(default)]
+        benchmark: Option<BenchmarkWire>,
     },
     Failed {
         duration_ms: u64,
@@ -187,6 +359,8 @@ pub enum RunTestResultWire {
         executed_lines: Vec<u32>,
         stdout: String,
         stderr: String,
+        #[serde(default)]
+        logs: Vec<LogRecord>,
     },
     Skipped {
         duration_ms: u64,
@@ -194,6 +368,8 @@ pub enum RunTestResultWire {
         reason: Option<String>,
         stdout: String,
         stderr: String,
+        #[serde(default)]
+        logs: Vec<LogRecord>,
     },
     #[serde(rename = "xfailed")]
     XFailed {
@@ -202,12 +378,16 @@ pub enum RunTestResultWire {
         reason: Option<String>,
         stdout: String,
         stderr: String,
+        #[serde(default)]
+        logs: Vec<LogRecord>,
     },
     #[serde(rename = "xpassed")]
     XPassed {
         duration_ms: u64,
         stdout: String,
         stderr: String,
+        #[serde(default)]
+        logs: Vec<LogRecord>,
     },
     Todo {
         duration_ms: u64,
@@ -215,9 +395,31 @@ pub enum RunTestResultWire {
         description: Option<String>,
         stdout: String,
         stderr: String,
+        #[serde(default)]
+        logs: Vec<LogRecord>,
     },
 }
 
+/// Benchmark timing as serialized by the Python worker, in nanoseconds.
+#[derive(Debug, serde::Deserialize)]
+pub struct BenchmarkWire {
+    pub iterations: u32,
+    pub min_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl From<BenchmarkWire> for BenchmarkStats {
+    fn from(wire: BenchmarkWire) -> Self {
+        Self {
+            iterations: wire.iterations,
+            min: Duration::from_nanos(wire.min_ns),
+            mean: Duration::from_nanos(wire.mean_ns),
+            stddev: Duration::from_nanos(wire.stddev_ns),
+        }
+    }
+}
+
 /// A single assertion result as serialized by the Python worker.
 #[derive(Debug, serde::Deserialize)]
 pub struct AssertionWire {
@@ -231,6 +433,65 @@ pub struct AssertionWire {
     pub file: Option<String>,
 }
 
+/// JSON-RPC method of the notification a worker sends for each
+/// `expect(...)` it evaluates while a `run_test` request with
+/// `stream_assertions` set is in flight. Each is one line on the worker's
+/// stdout, written before the request's response:
+///
+/// ```json
+/// {"jsonrpc": "2.0", "method": "assertion", "params": {"id": 7, "passed": false, "expression": "expect(x).to_equal(2)", "expected": "2", "received": "3", "line": 12}}
+/// ```
+///
+/// Apart from `id` and `passed`, `params` has the fields of an
+/// [`AssertionWire`].
+pub const ASSERTION_NOTIFICATION: &str = "assertion";
+
+/// `params` of an [`ASSERTION_NOTIFICATION`] line.
+#[derive(Debug, serde::Deserialize)]
+pub struct AssertionEventWire {
+    /// Id of the `run_test` request the assertion ran under.
+    pub id: u64,
+    pub passed: bool,
+    #[serde(flatten)]
+    pub assertion: AssertionWire,
+}
+
+/// One `expect(...)` result, delivered while its test is still running.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct AssertionEvent {
+    pub test: TestItem,
+    pub passed: bool,
+    pub assertion: Assertion,
+}
+
+/// Parse one line of worker stdout as an [`ASSERTION_NOTIFICATION`].
+///
+/// Returns `None` for anything else, RPC responses and stray output
+/// included, so callers can try this first on every line.
+#[must_use]
+pub fn parse_assertion_notification(line: &str) -> Option<AssertionEventWire> {
+    #[derive(serde::Deserialize)]
+    struct Notification {
+        method: String,
+        params: AssertionEventWire,
+    }
+    let notification: Notification = serde_json::from_str(line.trim()).ok()?;
+    (notification.method == ASSERTION_NOTIFICATION).then_some(notification.params)
+}
+
+/// Convert a streamed [`AssertionEventWire`] into an [`AssertionEvent`]
+/// for `test`, enriched with its statically discovered
+/// [`ExpectedAssertion`] the same way a failed result's assertions are.
+#[must_use]
+pub fn convert_assertion_event(test: &TestItem, wire: AssertionEventWire) -> AssertionEvent {
+    let expected_assertion = select_expected_assertion(&test.expected_assertions, &wire.assertion);
+    AssertionEvent {
+        test: test.clone(),
+        passed: wire.passed,
+        assertion: convert_assertion(wire.assertion, expected_assertion),
+    }
+}
+
 /// Convert a [`RunTestResultWire`] (flat Python worker format) into a
 /// [`TestResult`] (the structured format reporters consume).
 ///
@@ -241,18 +502,21 @@ pub struct AssertionWire {
 /// Used by both the native worker path and the WASM/playground path.
 #[must_use]
 pub fn convert_wire_result(test: TestItem, wire: RunTestResultWire) -> TestResult {
-    match wire {
+    let (outcome, duration_ms, stdout, stderr, logs, benchmark) = match wire {
         RunTestResultWire::Passed {
             duration_ms,
             stdout,
             stderr,
-        } => TestResult {
-            test,
-            outcome: TestOutcome::Passed,
-            duration: Duration::from_millis(duration_ms),
+            logs,
+            benchmark,
+        } => (
+            TestOutcome::Passed,
+            duration_ms,
             stdout,
             stderr,
-        },
+            logs,
+            benchmark.map(BenchmarkStats::from),
+        ),
         RunTestResultWire::Failed {
             duration_ms,
             message,
@@ -261,83 +525,106 @@ pub fn convert_wire_result(test: TestItem, wire: RunTestResultWire) -> TestResul
             executed_lines,
             stdout,
             stderr,
-        } => {
-            let executed_lines = map_executed_lines(executed_lines, &test.expected_assertions);
-            let assertions = assertions
-                .into_iter()
-                .map(|wire| {
-                    let expected_assertion =
-                        select_expected_assertion(&test.expected_assertions, &wire);
-                    convert_assertion(wire, expected_assertion)
-                })
-                .collect();
-            TestResult {
-                test,
-                outcome: TestOutcome::Failed {
-                    message,
-                    traceback,
-                    assertions,
-                    executed_lines,
-                },
-                duration: Duration::from_millis(duration_ms),
-                stdout,
-                stderr,
-            }
-        }
+            logs,
+        } => (
+            TestOutcome::Failed {
+                message,
+                traceback,
+                assertions: convert_assertions(assertions, &test.expected_assertions),
+                executed_lines: map_executed_lines(executed_lines, &test.expected_assertions),
+            },
+            duration_ms,
+            stdout,
+            stderr,
+            logs,
+            None,
+        ),
         RunTestResultWire::Skipped {
             duration_ms,
             reason,
             stdout,
             stderr,
-        } => TestResult {
-            test,
-            outcome: TestOutcome::Skipped { reason },
-            duration: Duration::from_millis(duration_ms),
+            logs,
+        } => (
+            TestOutcome::Skipped { reason },
+            duration_ms,
             stdout,
             stderr,
-        },
+            logs,
+            None,
+        ),
         RunTestResultWire::XFailed {
             duration_ms,
             reason,
             stdout,
             stderr,
-        } => TestResult {
-            test,
-            outcome: TestOutcome::XFailed { reason },
-            duration: Duration::from_millis(duration_ms),
+            logs,
+        } => (
+            TestOutcome::XFailed { reason },
+            duration_ms,
             stdout,
             stderr,
-        },
+            logs,
+            None,
+        ),
         RunTestResultWire::XPassed {
             duration_ms,
             stdout,
             stderr,
-        } => TestResult {
-            test,
-            outcome: TestOutcome::XPassed,
-            duration: Duration::from_millis(duration_ms),
+            logs,
+        } => (
+            TestOutcome::XPassed,
+            duration_ms,
             stdout,
             stderr,
-        },
+            logs,
+            None,
+        ),
         RunTestResultWire::Todo {
             duration_ms,
             description,
             stdout,
             stderr,
-        } => TestResult {
-            test,
-            outcome: TestOutcome::Todo { description },
-            duration: Duration::from_millis(duration_ms),
+            logs,
+        } => (
+            TestOutcome::Todo { description },
+            duration_ms,
             stdout,
             stderr,
-        },
+            logs,
+            None,
+        ),
+    };
+    TestResult {
+        stdout,
+        stderr,
+        logs,
+        benchmark,
+        ..TestResult::new(test, outcome, Duration::from_millis(duration_ms))
     }
 }
 
+/// Convert a failed result's assertions, each enriched from its matching
+/// [`ExpectedAssertion`].
+fn convert_assertions(
+    assertions: Vec<AssertionWire>,
+    expected_assertions: &[ExpectedAssertion],
+) -> Vec<Assertion> {
+    assertions
+        .into_iter()
+        .map(|wire| {
+            let expected_assertion = select_expected_assertion(expected_assertions, &wire);
+            convert_assertion(wire, expected_assertion)
+        })
+        .collect()
+}
+
 /// Convert a raw [`AssertionWire`] into an [`Assertion`], enriching span /
 /// arg-span / line / path data from the optionally-supplied
-/// [`ExpectedAssertion`] (statically discovered ahead of time). When no
-/// match is provided, the assertion falls back to highlighting the whole
+/// [`ExpectedAssertion`] (statically discovered ahead of time). A match
+/// without source text gets its expression from
+/// [`ExpectedAssertion::reconstructed_expression`]. When no match is
+/// provided, the assertion falls back to highlighting the whole
 /// expression instead of attempting to re-parse Python syntax from the
 /// worker payload.
 #[must_use]
@@ -345,9 +632,11 @@ pub fn convert_assertion(
     wire: AssertionWire,
     expected_assertion: Option<&ExpectedAssertion>,
 ) -> Assertion {
-    let expression = expected_assertion
-        .and_then(|ea| (!ea.expression.is_empty()).then(|| ea.expression.clone()))
-        .unwrap_or(wire.expression);
+    let expression = match expected_assertion {
+        Some(ea) if !ea.expression.is_empty() => ea.expression.clone(),
+        Some(ea) => ea.reconstructed_expression(),
+        None => wire.expression,
+    };
     let (span_offset, span_length) = expected_assertion
         .and_then(|ea| ea.subject_span)
         .unwrap_or((0, expression.len().max(1)));
@@ -520,7 +809,27 @@ pub struct ChangedSelectionSummary {
     pub affected_tests: usize,
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+/// How many tests discovery collected versus how many survived the
+/// CLI filters (`-k`, `-m`, positional paths) and were selected to run.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SelectionSummary {
+    pub collected: usize,
+    pub selected: usize,
+    /// The filter arguments that produced the selection, e.g. `-k foo`.
+    /// `None` when no filter was given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub via: Option<String>,
+    /// How many of the `collected` tests came from files served by the
+    /// discovery cache. `None` when discovery didn't report cache usage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cached: Option<usize>,
+    /// The seed `--shuffle` ordered the selected tests with. `None` when
+    /// tests run in discovery order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct RunSummary {
     pub passed: usize,
     pub failed: usize,
@@ -531,10 +840,19 @@ pub struct RunSummary {
     pub xfailed: usize,
     #[serde(default)]
     pub todo: usize,
+    #[serde(with = "serde_millis")]
     pub duration: Duration,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        with = "serde_millis::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub discovery_duration: Option<Duration>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        with = "serde_millis::option",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub test_duration: Option<Duration>,
     #[serde(default)]
     pub file_count: usize,
@@ -542,6 +860,53 @@ pub struct RunSummary {
     pub start_time: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub changed_selection: Option<ChangedSelectionSummary>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timing_stats: Option<TimingStats>,
+    /// Tests that ran longer than `--slow-threshold`.
+    #[serde(default)]
+    pub slow: usize,
+    /// `Some(n)` when `--maxfail n` (or `-x`, which is `--maxfail 1`)
+    /// stopped the run before every test ran. The counts cover the tests
+    /// that finished.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stopped_after: Option<usize>,
+}
+
+/// Distribution of per-test durations across a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TimingStats {
+    #[serde(with = "serde_millis")]
+    pub mean: Duration,
+    #[serde(with = "serde_millis")]
+    pub p50: Duration,
+    #[serde(with = "serde_millis")]
+    pub p95: Duration,
+    #[serde(with = "serde_millis")]
+    pub p99: Duration,
+}
+
+impl TimingStats {
+    /// Mean and nearest-rank percentiles of `durations`, or `None` when
+    /// no test ran.
+    #[must_use]
+    pub fn from_durations(durations: &[Duration]) -> Option<Self> {
+        if durations.is_empty() {
+            return None;
+        }
+        let mut sorted = durations.to_vec();
+        sorted.sort_unstable();
+        let count = u32::try_from(sorted.len()).unwrap_or(u32::MAX);
+        let percentile = |p: usize| {
+            let rank = (p * sorted.len()).div_ceil(100).max(1);
+            sorted[rank.min(sorted.len()) - 1]
+        };
+        Some(Self {
+            mean: sorted.iter().sum::<Duration>() / count,
+            p50: percentile(50),
+            p95: percentile(95),
+            p99: percentile(99),
+        })
+    }
 }
 
 impl RunSummary {
@@ -564,9 +929,13 @@ impl RunSummary {
             file_count: 0,
             start_time: None,
             changed_selection: None,
+            timing_stats: None,
+            slow: 0,
+            stopped_after: None,
         };
         for r in results {
             summary.duration += r.duration;
+            summary.slow += usize::from(r.slow);
             match &r.outcome {
                 TestOutcome::Passed => summary.passed += 1,
                 TestOutcome::Failed { .. } | TestOutcome::XPassed => summary.failed += 1,
@@ -577,14 +946,43 @@ impl RunSummary {
             }
         }
         summary.test_duration = Some(summary.duration);
+        summary.timing_stats =
+            TimingStats::from_durations(&results.iter().map(|r| r.duration).collect::<Vec<_>>());
         summary
     }
+
+    /// Whether `--maxfail` or `-x` cut the run short.
+    #[must_use]
+    pub fn aborted(&self) -> bool {
+        self.stopped_after.is_some()
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct FileDiscovery {
     pub file_path: PathBuf,
     pub tests: Vec<TestItem>,
+    /// `true` when this file's tests were read from the discovery cache
+    /// instead of being parsed on this run.
+    pub from_cache: bool,
+    /// Modules the file imports at top level; see [`ParsedFile::imports`].
+    pub imports: Vec<String>,
+}
+
+/// How a test body's calls fared against assertion extraction.
+/// Surfaced by `tryke test --debug-extraction` to explain why an
+/// `expect(...)` line is missing from a test's assertion checklist.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExtractionCoverage {
+    pub name: String,
+    pub line_number: Option<u32>,
+    /// Calls extracted as `ExpectedAssertion`s.
+    pub recognized: usize,
+    /// Calls that aren't assertions, or aren't recognized as one.
+    pub unrecognized: usize,
+    /// 1-indexed lines of unrecognized calls rooted at an `expect(...)`
+    /// call — the likely assertions discovery failed to parse.
+    pub unmatched_expect_lines: Vec<u32>,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -594,13 +992,49 @@ pub struct DiscoveryResult {
     pub duration: Duration,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct DiscoveryError {
     pub file_path: PathBuf,
     pub message: String,
     pub line_number: Option<u32>,
 }
 
+/// A test that can't be told apart from an earlier one: it repeats that
+/// test's [`TestItem::id`], or its [`TestItem::display_label`] within the
+/// same file and `describe` block.
+#[derive(Debug, Clone, Copy)]
+pub struct DuplicateTest<'a> {
+    /// The repeat's position in the slice given to [`duplicate_tests`].
+    pub index: usize,
+    pub test: &'a TestItem,
+    /// The earlier test it repeats.
+    pub first: &'a TestItem,
+}
+
+/// Every test in `tests` that repeats an earlier one, in order. Discovery
+/// reports each as an error and `--collect-only` marks each
+/// `(duplicate)`, so both read from this one list.
+#[must_use]
+pub fn duplicate_tests(tests: &[TestItem]) -> Vec<DuplicateTest<'_>> {
+    let mut ids: HashMap<String, (usize, &TestItem)> = HashMap::new();
+    let mut labels: HashMap<_, (usize, &TestItem)> = HashMap::new();
+    let mut duplicates = Vec::new();
+    for (index, test) in tests.iter().enumerate() {
+        let label = (
+            test.file_path.as_deref(),
+            test.module_path.as_str(),
+            test.groups.as_slice(),
+            test.display_label(),
+        );
+        let by_id = *ids.entry(test.id()).or_insert((index, test));
+        let by_label = *labels.entry(label).or_insert((index, test));
+        if let Some((_, first)) = [by_id, by_label].into_iter().find(|(at, _)| *at != index) {
+            duplicates.push(DuplicateTest { index, test, first });
+        }
+    }
+    duplicates
+}
+
 /// The kind of issue detected during test discovery.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -618,6 +1052,17 @@ pub enum DiscoveryWarningKind {
     /// The requested distribution mode was upgraded to preserve fixture
     /// semantics, so execution may be less granular than requested.
     DistModeUpgrade,
+
+    /// A test's extracted assertion count falls outside `[tool.tryke]
+    /// min_assertions` / `max_assertions`. Fails the run under `--strict`.
+    AssertionCount,
+
+    /// Two tests in one file are defined under the same name. Python keeps
+    /// only the last definition, so the earlier test never runs as written.
+    ShadowedTest,
+
+    /// A test file failed to parse, so none of its tests were collected.
+    SyntaxError,
 }
 
 /// A non-fatal issue detected during test discovery that may degrade
@@ -698,6 +1143,16 @@ pub struct ParsedFile {
     /// a loud error instead of a silent no-op at resolution time.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<String>,
+    /// Modules named by the file's top-level `import` and `from ... import`
+    /// statements, in source order without repeats. Relative imports keep
+    /// their leading dots (`.helpers`, `..`), as written.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub imports: Vec<String>,
+    /// Why the file failed to parse, at the line the parser gave up on.
+    /// A file that doesn't parse yields no tests, so this is what tells
+    /// it apart from a file that has none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub syntax_error: Option<DiscoveryError>,
 }
 
 #[cfg(test)]
@@ -728,6 +1183,35 @@ mod tests {
         assert!(json.contains("scheduler upgraded distribution"));
     }
 
+    #[test]
+    fn timing_stats_use_nearest_rank_percentiles() {
+        let durations: Vec<Duration> = (1..=100).rev().map(Duration::from_millis).collect();
+        let stats = TimingStats::from_durations(&durations).expect("stats");
+        assert_eq!(stats.mean, Duration::from_micros(50_500));
+        assert_eq!(stats.p50, Duration::from_millis(50));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+        assert_eq!(stats.p99, Duration::from_millis(99));
+
+        let stats = TimingStats::from_durations(&[Duration::from_millis(4)]).expect("stats");
+        assert_eq!(stats.p50, Duration::from_millis(4));
+        assert_eq!(stats.p99, Duration::from_millis(4));
+        assert_eq!(TimingStats::from_durations(&[]), None);
+    }
+
+    #[test]
+    fn slow_threshold_flags_longer_tests() {
+        let threshold = Some(Duration::from_millis(100));
+        let mut result = TestResult::new(
+            TestItem::default(),
+            TestOutcome::Passed,
+            Duration::from_millis(100),
+        );
+        assert!(!result.exceeds_slow_threshold(threshold));
+        result.duration = Duration::from_millis(101);
+        assert!(result.exceeds_slow_threshold(threshold));
+        assert!(!result.exceeds_slow_threshold(None));
+    }
+
     #[test]
     fn path_to_module_basic() {
         let root = PathBuf::from("/project");
@@ -745,6 +1229,39 @@ mod tests {
         assert_eq!(path_to_module(&root, &path), Some("test_foo".to_string()));
     }
 
+    #[test]
+    fn path_to_module_names_a_package_by_its_directory() {
+        let root = PathBuf::from("/project");
+        let path = PathBuf::from("/project/foo/__init__.py");
+        assert_eq!(path_to_module(&root, &path), Some("foo".to_string()));
+        let path = PathBuf::from("/project/__init__.py");
+        assert_eq!(path_to_module(&root, &path), None);
+    }
+
+    #[test]
+    fn path_to_module_nested_packages() {
+        let root = PathBuf::from("/project");
+        let path = PathBuf::from("/project/foo/bar/__init__.py");
+        assert_eq!(path_to_module(&root, &path), Some("foo.bar".to_string()));
+        let path = PathBuf::from("/project/foo/bar/test_baz.py");
+        assert_eq!(
+            path_to_module(&root, &path),
+            Some("foo.bar.test_baz".to_string())
+        );
+    }
+
+    #[test]
+    fn path_to_module_namespace_package_is_joined_from_root() {
+        // `tests/` has no `__init__.py`; with the root on `sys.path` it
+        // still imports as a namespace package.
+        let root = PathBuf::from("/project");
+        let path = PathBuf::from("/project/tests/unit/test_math.py");
+        assert_eq!(
+            path_to_module(&root, &path),
+            Some("tests.unit.test_math".to_string())
+        );
+    }
+
     #[test]
     fn path_to_module_not_under_root() {
         let root = PathBuf::from("/project");
@@ -813,6 +1330,41 @@ mod tests {
         assert_eq!(item.id(), "tests/test_math.py::square[zero]");
     }
 
+    #[test]
+    fn nodeid_matches_pytest_format() {
+        let plain = TestItem {
+            name: "test_square".into(),
+            module_path: "tests.unit.test_math".into(),
+            file_path: Some(["tests", "unit", "test_math.py"].iter().collect()),
+            ..Default::default()
+        };
+        assert_eq!(plain.nodeid(), "tests/unit/test_math.py::test_square");
+        let case = TestItem {
+            name: "square".into(),
+            case_label: Some("zero".into()),
+            case_index: Some(0),
+            ..plain
+        };
+        assert_eq!(case.nodeid(), "tests/unit/test_math.py::square[zero]");
+    }
+
+    #[test]
+    fn qualify_root_prefixes_id_with_root_label() {
+        let mut a = TestItem {
+            name: "test_square".into(),
+            module_path: "tests.test_math".into(),
+            file_path: Some(PathBuf::from("tests/test_math.py")),
+            ..Default::default()
+        };
+        let mut b = a.clone();
+        a.qualify_root(Path::new("svc-a"), Path::new("/repo/svc-a"));
+        b.qualify_root(Path::new("svc-b"), Path::new("/repo/svc-b"));
+        assert_eq!(a.id(), "svc-a/tests/test_math.py::test_square");
+        assert_eq!(b.id(), "svc-b/tests/test_math.py::test_square");
+        assert_eq!(a.root.as_deref(), Some(Path::new("/repo/svc-a")));
+        assert_eq!(a.module_path, "tests.test_math");
+    }
+
     #[test]
     fn test_item_case_label_round_trips_through_serde() {
         let item = TestItem {
@@ -877,6 +1429,30 @@ mod tests {
         assert_eq!(item.display_label(), "squares a number[zero]");
     }
 
+    #[test]
+    fn duplicate_tests_match_on_id_or_display_name() {
+        let item = |name: &str, display_name: Option<&str>, group: Option<&str>| TestItem {
+            name: name.into(),
+            module_path: "tests.m".into(),
+            file_path: Some(PathBuf::from("tests/m.py")),
+            display_name: display_name.map(str::to_owned),
+            groups: group.into_iter().map(str::to_owned).collect(),
+            ..Default::default()
+        };
+        let tests = [
+            item("test_adds", None, None),
+            item("test_adds", None, None),
+            item("test_one", Some("adds"), None),
+            item("test_two", Some("adds"), None),
+            item("test_three", Some("adds"), Some("math")),
+        ];
+        let duplicates: Vec<_> = duplicate_tests(&tests)
+            .iter()
+            .map(|d| (d.index, d.first.name.as_str()))
+            .collect();
+        assert_eq!(duplicates, vec![(1, "test_adds"), (3, "test_one")]);
+    }
+
     #[test]
     fn test_item_case_label_omitted_when_none() {
         let item = TestItem {
@@ -914,6 +1490,12 @@ mod tests {
             }],
             testing_guard_else_lines: vec![],
             errors: vec![],
+            imports: vec!["os".into()],
+            syntax_error: Some(DiscoveryError {
+                file_path: PathBuf::from("tests/test_foo.py"),
+                message: "Expected an expression".into(),
+                line_number: Some(3),
+            }),
         };
         let json = serde_json::to_string(&pf).expect("serialize");
         let back: ParsedFile = serde_json::from_str(&json).expect("deserialize");
@@ -931,10 +1513,32 @@ mod tests {
             duration_ms: 10,
             stdout: String::new(),
             stderr: String::new(),
+            logs: Vec::new(),
+            benchmark: None,
         };
         let result = convert_wire_result(test, wire);
         assert!(matches!(result.outcome, TestOutcome::Passed));
         assert_eq!(result.duration, Duration::from_millis(10));
+        assert_eq!(result.benchmark, None);
+    }
+
+    #[test]
+    fn convert_wire_result_carries_benchmark_stats() {
+        let wire: RunTestResultWire = serde_json::from_str(
+            r#"{"outcome": "passed", "duration_ms": 3, "stdout": "", "stderr": "",
+                "benchmark": {"iterations": 20, "min_ns": 1500, "mean_ns": 2000, "stddev_ns": 250}}"#,
+        )
+        .expect("wire");
+        let result = convert_wire_result(TestItem::default(), wire);
+        assert_eq!(
+            result.benchmark,
+            Some(BenchmarkStats {
+                iterations: 20,
+                min: Duration::from_nanos(1500),
+                mean: Duration::from_micros(2),
+                stddev: Duration::from_nanos(250),
+            })
+        );
     }
 
     #[test]
@@ -983,6 +1587,88 @@ mod tests {
         assert_eq!(a.expected_arg_span, Some((19, 1)));
     }
 
+    #[test]
+    fn reconstructs_python_expression_from_parts() {
+        let mut expected = ExpectedAssertion {
+            subject: "x".into(),
+            matcher: "to_equal".into(),
+            args: vec!["2".into()],
+            ..Default::default()
+        };
+        assert_eq!(expected.reconstructed_expression(), "expect(x).to_equal(2)");
+
+        expected.negated = true;
+        expected.matcher = "to_be_between".into();
+        expected.args = vec!["1".into(), "high=3".into()];
+        assert_eq!(
+            expected.reconstructed_expression(),
+            "expect(x).not_.to_be_between(1, high=3)"
+        );
+
+        let wire = AssertionWire {
+            expression: ".to_be_between(1, high=3)".into(),
+            expected: "1..3".into(),
+            received: "2".into(),
+            line: 4,
+            column: None,
+            file: None,
+        };
+        assert_eq!(
+            convert_assertion(wire, Some(&expected)).expression,
+            "expect(x).not_.to_be_between(1, high=3)"
+        );
+    }
+
+    #[test]
+    fn parses_assertion_notifications_from_worker_stream() {
+        let test = TestItem {
+            name: "test_add".into(),
+            module_path: "tests.test_math".into(),
+            expected_assertions: vec![ExpectedAssertion {
+                subject: "total".into(),
+                matcher: "to_equal".into(),
+                args: vec!["3".into()],
+                line: 5,
+                expression: "expect(total).to_equal(3)".into(),
+                subject_span: Some((7, 5)),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let stream = concat!(
+            r#"{"jsonrpc": "2.0", "method": "assertion", "params": {"id": 4, "passed": true, "expression": "expect(1).to_equal(1)", "expected": "1", "received": "1", "line": 4}}"#,
+            "\n",
+            "noise a native library wrote to fd 1\n",
+            r#"{"jsonrpc": "2.0", "method": "assertion", "params": {"id": 4, "passed": false, "expression": "expect(total).to_equal(3)", "expected": "3", "received": "4", "line": 5, "column": 4}}"#,
+            "\n",
+            r#"{"jsonrpc": "2.0", "method": "log", "params": {"id": 4}}"#,
+            "\n",
+            r#"{"jsonrpc": "2.0", "id": 4, "result": {"outcome": "passed", "duration_ms": 1, "stdout": "", "stderr": ""}}"#,
+            "\n",
+        );
+
+        let events: Vec<AssertionEvent> = stream
+            .lines()
+            .filter_map(parse_assertion_notification)
+            .map(|wire| {
+                assert_eq!(wire.id, 4);
+                convert_assertion_event(&test, wire)
+            })
+            .collect();
+
+        assert_eq!(events.len(), 2);
+        assert!(events[0].passed);
+        assert_eq!(events[0].assertion.expression, "expect(1).to_equal(1)");
+        assert_eq!(events[0].assertion.line, 4);
+        assert!(!events[1].passed);
+        let failed = &events[1].assertion;
+        assert_eq!(failed.expression, "expect(total).to_equal(3)");
+        assert_eq!(failed.line, 5);
+        assert_eq!(failed.expected, "3");
+        assert_eq!(failed.received, "4");
+        assert_eq!((failed.span_offset, failed.span_length), (7, 5));
+    }
+
     #[test]
     fn expected_arg_value_only_splits_keyword_arguments() {
         let positional = ExpectedAssertion {
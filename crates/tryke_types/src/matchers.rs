@@ -0,0 +1,167 @@
+//! Matcher metadata reported by the Python `tryke` package.
+//!
+//! Discovery only sees matcher calls as source text, so it can't know
+//! which `to_*` names exist or how many arguments each accepts. The
+//! worker's `list_matchers` method emits the authoritative table as a
+//! JSON array, which deserializes straight into [`Matchers`].
+
+use crate::ExpectedAssertion;
+
+/// One `Expectation.to_*` method as the worker describes it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MatcherSpec {
+    pub name: String,
+    /// Arguments without a default.
+    pub min_args: usize,
+    /// Total positional and keyword arguments accepted. `None` when the
+    /// matcher takes `*args` / `**kwargs`.
+    #[serde(default)]
+    pub max_args: Option<usize>,
+    /// Whether the matcher may follow `.not_`.
+    #[serde(default = "default_negatable")]
+    pub negatable: bool,
+}
+
+fn default_negatable() -> bool {
+    true
+}
+
+/// Registry of the matchers the Python side has registered.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct Matchers {
+    specs: Vec<MatcherSpec>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatcherError {
+    Unknown(String),
+    Arity {
+        matcher: String,
+        min: usize,
+        max: Option<usize>,
+        got: usize,
+    },
+    NotNegatable(String),
+}
+
+impl std::fmt::Display for MatcherError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown(matcher) => write!(f, "unknown matcher `{matcher}`"),
+            Self::Arity {
+                matcher,
+                min,
+                max,
+                got,
+            } => {
+                let expected = match max {
+                    Some(max) if max == min => min.to_string(),
+                    Some(max) => format!("{min} to {max}"),
+                    None => format!("at least {min}"),
+                };
+                write!(
+                    f,
+                    "`{matcher}` takes {expected} argument(s) but was given {got}"
+                )
+            }
+            Self::NotNegatable(matcher) => write!(f, "`{matcher}` can't be negated with `.not_`"),
+        }
+    }
+}
+
+impl std::error::Error for MatcherError {}
+
+impl Matchers {
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&MatcherSpec> {
+        self.specs.iter().find(|spec| spec.name == name)
+    }
+
+    /// Check a discovered assertion against the registered matcher it
+    /// calls.
+    ///
+    /// # Errors
+    /// Returns a [`MatcherError`] when the matcher isn't registered, is
+    /// called with an unsupported number of arguments, or is negated but
+    /// doesn't allow it.
+    pub fn validate(&self, assertion: &ExpectedAssertion) -> Result<(), MatcherError> {
+        let spec = self
+            .get(&assertion.matcher)
+            .ok_or_else(|| MatcherError::Unknown(assertion.matcher.clone()))?;
+        let got = assertion.args.len();
+        if got < spec.min_args || spec.max_args.is_some_and(|max| got > max) {
+            return Err(MatcherError::Arity {
+                matcher: spec.name.clone(),
+                min: spec.min_args,
+                max: spec.max_args,
+                got,
+            });
+        }
+        if assertion.negated && !spec.negatable {
+            return Err(MatcherError::NotNegatable(spec.name.clone()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> Matchers {
+        serde_json::from_str(
+            r#"[
+                {"name": "to_equal", "min_args": 1, "max_args": 1, "negatable": true},
+                {"name": "to_be_truthy", "min_args": 0, "max_args": 0},
+                {"name": "to_raise", "min_args": 0, "max_args": 2, "negatable": false}
+            ]"#,
+        )
+        .expect("valid registry")
+    }
+
+    fn assertion(matcher: &str, args: &[&str], negated: bool) -> ExpectedAssertion {
+        ExpectedAssertion {
+            subject: "x".into(),
+            matcher: matcher.into(),
+            negated,
+            args: args.iter().map(|&arg| arg.into()).collect(),
+            ..ExpectedAssertion::default()
+        }
+    }
+
+    #[test]
+    fn deserializes_worker_registry() {
+        let matchers = registry();
+        let spec = matchers.get("to_be_truthy").expect("to_be_truthy");
+        assert_eq!(spec.max_args, Some(0));
+        assert!(spec.negatable, "negatable defaults to true");
+        assert!(matchers.get("to_be_cool").is_none());
+    }
+
+    #[test]
+    fn validates_assertion_arity() {
+        let matchers = registry();
+        assert_eq!(
+            matchers.validate(&assertion("to_equal", &["1"], false)),
+            Ok(())
+        );
+        assert_eq!(
+            matchers.validate(&assertion("to_equal", &["1", "2"], false)),
+            Err(MatcherError::Arity {
+                matcher: "to_equal".into(),
+                min: 1,
+                max: Some(1),
+                got: 2,
+            })
+        );
+        assert_eq!(
+            matchers.validate(&assertion("to_raise", &[], true)),
+            Err(MatcherError::NotNegatable("to_raise".into()))
+        );
+        assert_eq!(
+            matchers.validate(&assertion("to_be_cool", &[], false)),
+            Err(MatcherError::Unknown("to_be_cool".into()))
+        );
+    }
+}
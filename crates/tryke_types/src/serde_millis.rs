@@ -0,0 +1,121 @@
+//! Serde helpers that write a [`Duration`] as a float number of
+//! milliseconds (`12.5`) instead of serde's default
+//! `{"secs": 0, "nanos": 12500000}`, which is awkward to consume from
+//! JavaScript. Use with `#[serde(with = "crate::serde_millis")]`, or
+//! `crate::serde_millis::option` for an `Option<Duration>`.
+//!
+//! Values round-trip to the nanosecond for any duration under about
+//! 104 days, the range in which an `f64` holds every nanosecond count
+//! exactly.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+const NANOS_PER_MILLI: f64 = 1_000_000.0;
+
+// Exact below 2^53 ns, see the module docs.
+#[expect(clippy::cast_precision_loss)]
+fn to_millis(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / NANOS_PER_MILLI
+}
+
+// The range is checked before the cast.
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn from_millis<E: Error>(millis: f64) -> Result<Duration, E> {
+    let nanos = (millis * NANOS_PER_MILLI).round();
+    if !nanos.is_finite() || nanos < 0.0 || nanos >= u64::MAX as f64 {
+        return Err(E::custom(format!(
+            "expected a non-negative number of milliseconds, got {millis}"
+        )));
+    }
+    Ok(Duration::from_nanos(nanos as u64))
+}
+
+/// # Errors
+/// Returns the serializer's error.
+pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(to_millis(*duration))
+}
+
+/// # Errors
+/// Fails on a value that isn't a non-negative number.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+    from_millis(f64::deserialize(deserializer)?)
+}
+
+/// The same encoding for an `Option<Duration>`, with `None` as `null`.
+pub mod option {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// # Errors
+    /// Returns the serializer's error.
+    pub fn serialize<S: Serializer>(
+        duration: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match duration {
+            Some(duration) => serializer.serialize_some(&super::to_millis(*duration)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// # Errors
+    /// Fails on a value that is neither `null` nor a non-negative number.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Option::<f64>::deserialize(deserializer)?
+            .map(super::from_millis)
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Timed {
+        #[serde(with = "crate::serde_millis")]
+        duration: Duration,
+        #[serde(with = "crate::serde_millis::option")]
+        maybe: Option<Duration>,
+    }
+
+    #[test]
+    fn writes_float_milliseconds() {
+        let timed = Timed {
+            duration: Duration::from_micros(12_500),
+            maybe: None,
+        };
+        let json = serde_json::to_value(&timed).expect("serialize");
+        assert_eq!(json, serde_json::json!({"duration": 12.5, "maybe": null}));
+    }
+
+    #[test]
+    fn round_trips_to_the_nanosecond() {
+        let timed = Timed {
+            duration: Duration::new(3, 1),
+            maybe: Some(Duration::from_nanos(1500)),
+        };
+        let json = serde_json::to_string(&timed).expect("serialize");
+        assert_eq!(
+            serde_json::from_str::<Timed>(&json).expect("deserialize"),
+            timed
+        );
+    }
+
+    #[test]
+    fn rejects_negative_milliseconds() {
+        let err = serde_json::from_str::<Timed>(r#"{"duration": -1.0, "maybe": null}"#)
+            .expect_err("negative duration");
+        assert!(err.to_string().contains("non-negative"), "{err}");
+    }
+}
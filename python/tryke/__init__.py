@@ -7,6 +7,8 @@ The main exports are:
 - [`describe`][tryke.describe] — context manager for grouping tests
 - [`fixture`][tryke.hooks.fixture] — decorator for setup/teardown fixtures
 - [`Depends`][tryke.hooks.Depends] — wire fixture values into signatures
+- `skip` / `xfail` — the `test.skip` / `test.xfail` markers, for stacking
+  beside `@test`
 """
 
 from collections.abc import Generator
@@ -15,6 +17,9 @@ from contextlib import contextmanager
 from .expect import CaseArgs, CasesMarked, CaseTable, expect, test
 from .hooks import Depends, fixture
 
+skip = test.skip
+xfail = test.xfail
+
 
 @contextmanager
 def describe(name: str) -> Generator[None, None, None]:
@@ -52,5 +57,7 @@ __all__ = [
     "describe",
     "expect",
     "fixture",
+    "skip",
     "test",
+    "xfail",
 ]
@@ -2,6 +2,7 @@
 
 from __future__ import annotations
 
+import linecache
 import re
 import traceback
 from pathlib import Path
@@ -14,7 +15,7 @@ from typing import (
 )
 
 if TYPE_CHECKING:
-    from collections.abc import Callable, Coroutine, Mapping
+    from collections.abc import Callable, Coroutine, Mapping, Sequence
     from typing import Any, ClassVar, Self
 
     class _SupportsGT(Protocol):
@@ -350,6 +351,36 @@ def _build_cases_table(
     raise TypeError(msg)
 
 
+def _make_each_decorator(rows: object) -> Callable[[_Fn], _Fn]:
+    """Build the runtime decorator shared by ``test.each`` entry points.
+
+    Each row becomes one :class:`CaseEntry`: a tuple row supplies the
+    positional arguments, any other value a single argument. The label
+    is the arguments' ``repr`` joined with ``", "`` — the same label
+    discovery renders from the literal source — so ``(1, 2, 3)`` runs
+    as ``fn[1, 2, 3]``.
+    """
+    if not isinstance(rows, (list, tuple)):
+        msg = "test.each() takes a list of rows"
+        raise TypeError(msg)
+    if not rows:
+        msg = "test.each() requires at least one row"
+        raise TypeError(msg)
+    entries: list[CaseEntry] = []
+    for row in rows:
+        args = row if isinstance(row, tuple) else (row,)
+        label = ", ".join(repr(arg) for arg in args)
+        entries.append(CaseEntry(label=label, args=args, kwargs={}))
+    table = tuple(entries)
+    _validate_cases_table(table)
+
+    def decorator(f: _Fn) -> _Fn:
+        _stamp_cases(f, table)
+        return f
+
+    return decorator
+
+
 class _Marker:
     """Base for test markers that stamp a dunder attribute on the decorated function."""
 
@@ -389,6 +420,7 @@ class _SkipMarker(_Marker, attr="__tryke_skip__"):
         /,
         *,
         reason: str | None = ...,
+        category: str | None = ...,
         name: str | None = ...,
         tags: list[str] | None = ...,
     ) -> Callable[[_Fn], _SkipMarked]: ...
@@ -399,6 +431,7 @@ class _SkipMarker(_Marker, attr="__tryke_skip__"):
         /,
         *,
         reason: str | None = None,
+        category: str | None = None,  # noqa: ARG002
         name: str | None = None,  # noqa: ARG002
         tags: list[str] | None = None,  # noqa: ARG002
     ) -> object:
@@ -408,6 +441,8 @@ class _SkipMarker(_Marker, attr="__tryke_skip__"):
             fn_or_reason: The test function (when used as a bare decorator)
                 or a reason string (when called with parentheses).
             reason: Reason for skipping (alternative to positional string).
+            category: Optional skip category (e.g. ``"platform"``) used to
+                group skips in reports. Read statically by discovery.
             name: Optional test name override.
             tags: Optional list of tags for filtering.
         """
@@ -600,6 +635,14 @@ class _TestDecorator:
         """
         return _make_cases_decorator(positional, kwargs)
 
+    def each(self, rows: Sequence[object], /) -> Callable[[_Fn], _Fn]:
+        """Parametrise a labelled test over rows of positional arguments.
+
+        Equivalent to :meth:`_TestBuilder.each` but reachable through the
+        ``@test("label")`` decorator.
+        """
+        return _make_each_decorator(rows)
+
 
 class _TestBuilder:
     """Decorator for marking functions as tests.
@@ -627,6 +670,10 @@ class _TestBuilder:
         @test(tags=["slow", "network"])
         def tagged():
             ...
+
+        @test(enabled=False)
+        def switched_off():
+            ...
         ```
     """
 
@@ -646,6 +693,9 @@ class _TestBuilder:
         *,
         name: str | None = None,
         tags: list[str] | None = None,
+        enabled: bool = True,
+        benchmark: bool = False,
+        **metadata: object,
     ) -> _TestDecorator: ...
 
     def __call__(
@@ -655,6 +705,9 @@ class _TestBuilder:
         *,
         name=None,  # noqa: ARG002 - only used by static analysis/test discovery
         tags=None,  # noqa: ARG002 - only used by static analysis/test discovery
+        enabled=True,  # noqa: ARG002 - only used by static analysis/test discovery
+        benchmark=False,  # noqa: ARG002 - only used by static analysis/test discovery
+        **metadata,  # noqa: ARG002 - only used by static analysis/test discovery
     ):
         """Register a function as a test.
 
@@ -668,6 +721,14 @@ class _TestBuilder:
             fn: The test function (when used as a bare decorator).
             name: Optional display name for the test.
             tags: Optional list of tags for filtering with `-m`.
+            enabled: Pass `False` to turn the test off. It's reported as
+                skipped with reason "disabled", or left out of collection
+                when `[tool.tryke] exclude_disabled = true`.
+            benchmark: Pass `True` to mark the test as a benchmark. It
+                runs once as usual, or repeatedly with timing statistics
+                under `tryke test --benchmark`.
+            **metadata: Arbitrary extra keyword arguments. Discovery
+                records them as source text for tooling.
         """
         if callable(fn):
             return fn
@@ -761,6 +822,27 @@ class _TestBuilder:
         """
         return _make_cases_decorator(positional, kwargs)
 
+    def each(self, rows: Sequence[object], /) -> Callable[[_Fn], _Fn]:
+        """Parametrize a test over rows of positional arguments.
+
+        Each row runs as its own test, labelled by the ``repr`` of its
+        values::
+
+            @test.each([(1, 2, 3), (2, 2, 4)])
+            def add(a: int, b: int, expected: int) -> None:
+                expect(a + b).to_equal(expected)
+
+        collects ``add[1, 2, 3]`` and ``add[2, 2, 4]``. A row that is not
+        a tuple is passed as a single argument. When the rows are not
+        literals discovery can read, the function collects as one test
+        that runs every row.
+
+        Raises:
+            TypeError: If no rows are provided, two rows are equal, or
+                rows disagree on their number of values.
+        """
+        return _make_each_decorator(rows)
+
     def skip_if(
         self,
         condition: bool,  # noqa: FBT001 - this is clear enough with the method name
@@ -869,12 +951,22 @@ class SoftFailure(NamedTuple):
 
 
 class SoftContext:
-    def __init__(self) -> None:
+    def __init__(
+        self,
+        on_assertion: Callable[
+            [bool, str, str, traceback.FrameSummary | None], None
+        ]
+        | None = None,
+    ) -> None:
         self.failures: list[SoftFailure] = []
         # Line numbers of every expect() call that actually executed, in
         # order. The reporter uses this to distinguish "ran and passed"
         # from "never ran because an earlier statement raised".
         self.executed_lines: list[int] = []
+        # Told about every evaluated assertion as it happens, passed or
+        # not, with (passed, expected, received, frame). The worker uses
+        # it to stream results before the test finishes.
+        self.on_assertion = on_assertion
 
 
 class _SoftContextHolder:
@@ -898,6 +990,21 @@ def _caller_frame() -> traceback.FrameSummary | None:
     return None
 
 
+_PENDING_COMMENT = re.compile(r"#\s*pending\b")
+
+
+def _has_pending_comment(frame: traceback.FrameSummary) -> bool:
+    # Discovery reads the same marker off the source, so the checklist
+    # shows the line as pending instead of passed or failed.
+    if frame.lineno is None:
+        return False
+    end = frame.end_lineno or frame.lineno
+    return any(
+        _PENDING_COMMENT.search(linecache.getline(frame.filename, lineno))
+        for lineno in range(frame.lineno, end + 1)
+    )
+
+
 class Expectation[T]:
     """Chainable assertion wrapper created by [`expect`][tryke.expect.expect].
 
@@ -920,9 +1027,11 @@ class Expectation[T]:
         value: T,
         *,
         negated: bool = False,
+        pending: bool = False,
     ) -> None:
         self._value: T = value
         self._negated: bool = negated
+        self._pending: bool = pending
 
     @property
     def not_(self) -> Expectation[T]:
@@ -941,6 +1050,28 @@ class Expectation[T]:
         return Expectation(
             self._value,
             negated=not self._negated,
+            pending=self._pending,
+        )
+
+    def pending(self) -> Expectation[T]:
+        """Mark the next assertion as pending.
+
+        A pending assertion is neither evaluated nor counted: it shows up
+        in the checklist as a placeholder for behavior that isn't wired
+        up yet. A trailing `# pending` comment on the line does the same.
+
+        Example:
+            ```pycon
+            >>> from tryke import expect
+            >>> expect(1).pending().to_equal(2)
+            MatchResult(ok)
+
+            ```
+        """
+        return Expectation(
+            self._value,
+            negated=self._negated,
+            pending=True,
         )
 
     def _assert(
@@ -951,15 +1082,21 @@ class Expectation[T]:
         expected: str,
         received: str,
     ) -> MatchResult:
-        ok = (not passed) if self._negated else passed
+        if self._pending:
+            return MatchResult(None)
         ctx = _soft_ctx.value
         frame = _caller_frame() if ctx is not None else None
+        if frame is not None and _has_pending_comment(frame):
+            return MatchResult(None)
+        ok = (not passed) if self._negated else passed
         if ctx is not None and frame is not None and frame.lineno is not None:
             ctx.executed_lines.append(frame.lineno)
+        actual_expected = ("not " + expected) if self._negated else expected
+        if ctx is not None and ctx.on_assertion is not None:
+            ctx.on_assertion(ok, actual_expected, received, frame)
         if ok:
             return MatchResult(None)
         prefix = "expected not " if self._negated else "expected "
-        actual_expected = ("not " + expected) if self._negated else expected
         err = ExpectationError(
             prefix + message,
             expected=actual_expected,
@@ -1351,6 +1488,7 @@ class Expectation[T]:
 def expect[T](
     expr: T,
     name: str | None = None,  # noqa: ARG001 - only used by static analysis/test discovery
+    group: str | None = None,  # noqa: ARG001 - only used by static analysis/test discovery
 ) -> Expectation[T]:
     """Create an [`Expectation`][tryke.expect.Expectation] for `expr`.
 
@@ -1359,6 +1497,9 @@ def expect[T](
         name: Optional label for the assertion (used by the Rust-side
             discovery to extract assertion labels from source code;
             unused at runtime).
+        group: Optional checklist heading. Consecutive assertions that
+            share a group render together under it in the text reporter
+            (extracted by discovery; unused at runtime).
 
     Returns:
         An `Expectation` with chainable assertion methods.
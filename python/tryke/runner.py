@@ -12,6 +12,8 @@ from __future__ import annotations
 import contextlib
 import doctest
 import io
+import logging
+import statistics
 import sys
 import time
 import traceback
@@ -33,13 +35,18 @@ from tryke.expect import (
 from tryke.hooks import HookExecutor, _fixture_per
 
 if TYPE_CHECKING:
-    from collections.abc import Generator, Iterable
+    from collections.abc import Callable, Generator, Iterable
     from types import ModuleType
 
     from tryke.hooks import _FixtureFn
 
 _TRYKE_PKG = str(Path(__file__).resolve().parent)
 
+# Calls of a benchmark test: warmup calls are discarded, measured calls
+# make up the reported statistics.
+BENCHMARK_WARMUP = 3
+BENCHMARK_ITERATIONS = 20
+
 
 # -- Wire-format TypedDicts (mirror crates/tryke_runner/src/protocol.rs) ------
 
@@ -53,11 +60,25 @@ class AssertionWire(TypedDict):
     file: NotRequired[str]
 
 
+class LogRecordWire(TypedDict):
+    level: str
+    message: str
+
+
+class BenchmarkWire(TypedDict):
+    iterations: int
+    min_ns: int
+    mean_ns: int
+    stddev_ns: int
+
+
 class PassedResult(TypedDict):
     outcome: Literal["passed"]
     duration_ms: int
     stdout: str
     stderr: str
+    logs: NotRequired[list[LogRecordWire]]
+    benchmark: NotRequired[BenchmarkWire]
 
 
 class FailedResult(TypedDict):
@@ -69,6 +90,7 @@ class FailedResult(TypedDict):
     executed_lines: list[int]
     stdout: str
     stderr: str
+    logs: NotRequired[list[LogRecordWire]]
 
 
 class SkippedResult(TypedDict):
@@ -77,6 +99,7 @@ class SkippedResult(TypedDict):
     reason: str | None
     stdout: str
     stderr: str
+    logs: NotRequired[list[LogRecordWire]]
 
 
 class XFailedResult(TypedDict):
@@ -85,6 +108,7 @@ class XFailedResult(TypedDict):
     reason: str | None
     stdout: str
     stderr: str
+    logs: NotRequired[list[LogRecordWire]]
 
 
 class XPassedResult(TypedDict):
@@ -92,6 +116,7 @@ class XPassedResult(TypedDict):
     duration_ms: int
     stdout: str
     stderr: str
+    logs: NotRequired[list[LogRecordWire]]
 
 
 class TodoResult(TypedDict):
@@ -100,6 +125,7 @@ class TodoResult(TypedDict):
     description: str | None
     stdout: str
     stderr: str
+    logs: NotRequired[list[LogRecordWire]]
 
 
 type TestResult = (
@@ -369,9 +395,39 @@ def run_doctest(mod: object, object_path: str) -> TestResult:
     return passed(ms, out, err)
 
 
+type AssertionListener = Callable[[bool, AssertionWire], None]
+"""Receives ``(passed, assertion)`` for every evaluated ``expect(...)``."""
+
+
+def _wire_listener(
+    on_assertion: AssertionListener,
+) -> Callable[[bool, str, str, traceback.FrameSummary | None], None]:
+    def listener(
+        passed: bool,  # noqa: FBT001 - mirrors SoftContext.on_assertion
+        expected: str,
+        received: str,
+        frame: traceback.FrameSummary | None,
+    ) -> None:
+        on_assertion(
+            passed,
+            _make_assertion_wire(
+                expression=(frame.line or "").strip() if frame else "",
+                expected=expected,
+                received=received,
+                frame=frame,
+            ),
+        )
+
+    return listener
+
+
 @contextlib.contextmanager
-def soft_assertion_context() -> Generator[SoftContext, None, None]:
-    ctx = SoftContext()
+def soft_assertion_context(
+    on_assertion: AssertionListener | None = None,
+) -> Generator[SoftContext, None, None]:
+    ctx = SoftContext(
+        on_assertion=_wire_listener(on_assertion) if on_assertion else None,
+    )
     _set_soft_context(ctx)
     try:
         yield ctx
@@ -379,13 +435,46 @@ def soft_assertion_context() -> Generator[SoftContext, None, None]:
         _set_soft_context(None)
 
 
-def run_test(  # noqa: C901, PLR0911, PLR0912, PLR0915
+class _LogCapture(logging.Handler):
+    """Collects every record that reaches the root logger during a test.
+
+    The handler itself accepts all levels, so what gets captured is
+    decided by the loggers' own levels, the same records a default
+    ``StreamHandler`` would have printed.
+    """
+
+    def __init__(self) -> None:
+        super().__init__(level=logging.NOTSET)
+        self.records: list[LogRecordWire] = []
+
+    def emit(self, record: logging.LogRecord) -> None:
+        try:
+            message = record.getMessage()
+        except Exception:  # noqa: BLE001
+            message = str(record.msg)
+        self.records.append({"level": record.levelname, "message": message})
+
+
+@contextlib.contextmanager
+def capture_logs() -> Generator[_LogCapture, None, None]:
+    handler = _LogCapture()
+    root = logging.getLogger()
+    root.addHandler(handler)
+    try:
+        yield handler
+    finally:
+        root.removeHandler(handler)
+
+
+def run_test(
     fn: _FixtureFn,
     *,
     executor: HookExecutor,
     xfail: str | None = None,
     groups: list[str] | None = None,
     case_label: str | None = None,
+    on_assertion: AssertionListener | None = None,
+    benchmark: bool = False,
 ) -> TestResult:
     """Execute a single test function and return a typed result dict.
 
@@ -402,8 +491,77 @@ def run_test(  # noqa: C901, PLR0911, PLR0912, PLR0915
     groups:
         Scope chain for fixture scoping (e.g. ``["describe", "sub"]``).
     case_label:
-        Label of the parametrized case to run (from ``@test.cases``).
+        Label of the parametrized case to run (from ``@test.cases`` or
+        ``@test.each``). ``None`` on a parametrized function runs every
+        case in turn.
+    on_assertion:
+        Called with ``(passed, assertion)`` as each ``expect(...)`` is
+        evaluated, before the test finishes.
+    benchmark:
+        If the test passes, call it again for :data:`BENCHMARK_WARMUP`
+        discarded and :data:`BENCHMARK_ITERATIONS` timed runs, and attach
+        their statistics as ``benchmark``.
+    """
+    with capture_logs() as handler:
+        result = _run_test(
+            fn,
+            executor=executor,
+            xfail=xfail,
+            groups=groups,
+            case_label=case_label,
+            on_assertion=on_assertion,
+        )
+    if handler.records:
+        result["logs"] = handler.records
+    if benchmark and result["outcome"] == "passed":
+        stats = _benchmark(
+            lambda: _run_test(
+                fn,
+                executor=executor,
+                xfail=xfail,
+                groups=groups,
+                case_label=case_label,
+                on_assertion=None,
+            )
+        )
+        if stats is not None:
+            result["benchmark"] = stats
+    return result
+
+
+def _benchmark(call: Callable[[], TestResult]) -> BenchmarkWire | None:
+    """Time repeated calls of a passing test.
+
+    Returns ``None`` when any call stops passing, since the timings would
+    no longer describe the test's passing path.
     """
+    samples: list[int] = []
+    for i in range(BENCHMARK_WARMUP + BENCHMARK_ITERATIONS):
+        start = time.perf_counter_ns()
+        outcome = call()["outcome"]
+        elapsed = time.perf_counter_ns() - start
+        if outcome != "passed":
+            return None
+        if i >= BENCHMARK_WARMUP:
+            samples.append(elapsed)
+    mean = statistics.fmean(samples)
+    return {
+        "iterations": len(samples),
+        "min_ns": min(samples),
+        "mean_ns": round(mean),
+        "stddev_ns": round(statistics.pstdev(samples, mean)),
+    }
+
+
+def _run_test(  # noqa: C901, PLR0911, PLR0912, PLR0915
+    fn: _FixtureFn,
+    *,
+    executor: HookExecutor,
+    xfail: str | None,
+    groups: list[str] | None,
+    case_label: str | None,
+    on_assertion: AssertionListener | None,
+) -> TestResult:
     case_args: tuple[object, ...] = ()
     case_kwargs: CaseArgs | None = None
     if case_label is not None:
@@ -461,18 +619,26 @@ def run_test(  # noqa: C901, PLR0911, PLR0912, PLR0915
     stderr_buf = io.StringIO()
     start = time.monotonic()
 
-    with soft_assertion_context() as ctx:
+    with soft_assertion_context(on_assertion) as ctx:
         try:
             with (
                 contextlib.redirect_stdout(stdout_buf),
                 contextlib.redirect_stderr(stderr_buf),
             ):
-                executor.run_test(
-                    fn,
-                    groups=groups or [],
-                    case_args=case_args,
-                    case_kwargs=case_kwargs,
+                # A case table discovery couldn't read statically is
+                # dispatched without a label; run every row in turn.
+                rows = (
+                    [(e.args, e.kwargs) for e in fn.__tryke_cases__]
+                    if case_label is None and isinstance(fn, CasesMarked)
+                    else [(case_args, case_kwargs)]
                 )
+                for row_args, row_kwargs in rows:
+                    executor.run_test(
+                        fn,
+                        groups=groups or [],
+                        case_args=row_args,
+                        case_kwargs=row_kwargs,
+                    )
 
             ms = int((time.monotonic() - start) * 1000)
             out = stdout_buf.getvalue()
@@ -16,8 +16,19 @@ Supported methods:
 - `ping` → `"pong"` (used by `WorkerPool::warm` to force process spawn)
 - `register_hooks {module, hooks: [HookWire...]}` → `null`
 - `finalize_hooks {module}` → `null`
-- `run_test    {module, function, xfail?, groups?}` → tagged outcome dict
+- `run_test    {module, function, xfail?, groups?, stream_assertions?,
+  benchmark?}` →
+  tagged outcome dict. With `stream_assertions`, every `expect(...)` the
+  test evaluates is also sent right away as a notification (no `id`):
+
+      {"jsonrpc": "2.0", "method": "assertion",
+       "params": {"id": N, "passed": bool, <AssertionWire fields>}}
+
+  where `params.id` is the id of the `run_test` request. With
+  `benchmark`, a passing result also carries `benchmark` timings.
 - `run_doctest {module, object_path}` → tagged outcome dict
+- `list_matchers` → `[{name, min_args, max_args, negatable}...]`, the
+  `Expectation.to_*` table deserialized by `tryke_types::matchers`
 
 The wire format mirrors `crates/tryke_runner/src/protocol.rs`. The
 result TypedDicts live in :mod:`tryke.runner` and are shared with the
@@ -51,6 +62,7 @@ from __future__ import annotations
 
 import contextlib
 import importlib
+import inspect
 import io
 import json
 import logging
@@ -67,7 +79,9 @@ from typing import (
 )
 
 import tryke_guard
+from tryke.expect import Expectation
 from tryke.runner import (
+    AssertionWire,
     HookInfo,
     TestResult,
     build_executor_from_hooks,
@@ -95,14 +109,28 @@ if TYPE_CHECKING:
 
 _log = logging.getLogger("tryke.worker")
 
-type _DispatchResult = TestResult | str | None
+type _DispatchResult = TestResult | list[_MatcherSpec] | str | None
+
+
+class _MatcherSpec(TypedDict):
+    name: str
+    min_args: int
+    max_args: int | None
+    negatable: bool
 
 
 class _InvalidParamsError(Exception):
     """Missing or invalid JSON-RPC method parameter."""
 
 
-_Method = Literal["ping", "register_hooks", "finalize_hooks", "run_test", "run_doctest"]
+_Method = Literal[
+    "ping",
+    "register_hooks",
+    "finalize_hooks",
+    "run_test",
+    "run_doctest",
+    "list_matchers",
+]
 """Tryke RPC methods"""
 
 _JSONRPCErrorCode = Literal[-32700, -32600, -32601, -32602, -32603]
@@ -135,6 +163,44 @@ class _JSONRPCResponse(TypedDict):
     error: NotRequired[_JSONRPCError]
 
 
+class _AssertionParams(AssertionWire):
+    id: int | str | None
+    passed: bool
+
+
+class _AssertionNotification(TypedDict):
+    """https://www.jsonrpc.org/specification#notification"""
+
+    jsonrpc: Literal["2.0"]
+    method: Literal["assertion"]
+    params: _AssertionParams
+
+
+def _matcher_registry() -> list[_MatcherSpec]:
+    """Describe every `Expectation.to_*` matcher from its signature."""
+    specs: list[_MatcherSpec] = []
+    for name, fn in inspect.getmembers(Expectation, inspect.isfunction):
+        if not name.startswith("to_"):
+            continue
+        params = list(inspect.signature(fn).parameters.values())[1:]
+        variadic = any(p.kind in (p.VAR_POSITIONAL, p.VAR_KEYWORD) for p in params)
+        specs.append(
+            {
+                "name": name,
+                "min_args": sum(
+                    p.default is p.empty
+                    and p.kind not in (p.VAR_POSITIONAL, p.VAR_KEYWORD)
+                    for p in params
+                ),
+                "max_args": None if variadic else len(params),
+                # `.not_` is a property on Expectation, so every matcher
+                # can be negated.
+                "negatable": True,
+            }
+        )
+    return specs
+
+
 class Worker:
     def __init__(
         self,
@@ -148,6 +214,9 @@ class Worker:
         self._hook_metadata: dict[str, list[HookInfo]] = {}
         # Hook executors cached per module.
         self._executors: dict[str, HookExecutor] = {}
+        # Id of the request being handled, echoed on the notifications
+        # it produces.
+        self._request_id: int | str | None = None
 
     def run(self) -> None:
         for raw in self._input:
@@ -172,6 +241,7 @@ class Worker:
             id_ = req.get("id")
             method = req.get("method", "")
             params = req.get("params") or {}
+            self._request_id = id_
 
             try:
                 result = self._dispatch(method, params)
@@ -207,10 +277,23 @@ class Worker:
                     }
                 )
 
-    def _write(self, obj: _JSONRPCResponse) -> None:
+    def _write(self, obj: _JSONRPCResponse | _AssertionNotification) -> None:
         self._output.write(json.dumps(obj) + "\n")
         self._output.flush()
 
+    def _notify_assertion(
+        self,
+        passed: bool,  # noqa: FBT001 - mirrors runner.AssertionListener
+        assertion: AssertionWire,
+    ) -> None:
+        self._write(
+            {
+                "jsonrpc": "2.0",
+                "method": "assertion",
+                "params": {"id": self._request_id, "passed": passed, **assertion},
+            }
+        )
+
     def _require_str(
         self,
         params: dict[str, object],
@@ -260,12 +343,16 @@ class Worker:
                     xfail=(str(xfail_raw) if xfail_raw is not None else None),
                     groups=groups,
                     case_label=case_label,
+                    stream_assertions=params.get("stream_assertions") is True,
+                    benchmark=params.get("benchmark") is True,
                 )
             case "run_doctest":
                 return self._run_doctest(
                     self._require_str(params, "module", method),
                     str(params.get("object_path", "")),
                 )
+            case "list_matchers":
+                return _matcher_registry()
         assert_never(method)
 
     def _get_module(self, module_name: str) -> ModuleType:
@@ -360,10 +447,12 @@ class Worker:
         xfail: str | None = None,
         groups: list[str] | None = None,
         case_label: str | None = None,
+        stream_assertions: bool = False,
+        benchmark: bool = False,
     ) -> TestResult:
         try:
             mod = self._get_module(module_name)
-            fn = getattr(mod, function_name)
+            fn = _resolve_test(mod, function_name)
         except Exception as exc:  # noqa: BLE001
             return failed(
                 0,
@@ -380,6 +469,8 @@ class Worker:
             xfail=xfail,
             groups=groups,
             case_label=case_label,
+            on_assertion=self._notify_assertion if stream_assertions else None,
+            benchmark=benchmark,
         )
 
     def _run_doctest(
@@ -401,6 +492,22 @@ class Worker:
         return run_doctest(mod, object_path)
 
 
+def _resolve_test(mod: object, function_name: str) -> object:
+    """Look up a test by the name discovery gave it.
+
+    Methods of a test class arrive as `Class::method` (or
+    `Outer::Inner::method`); each runs as a bound method of a fresh
+    instance of its class, so tests never share instance state.
+    """
+    *class_path, name = function_name.split("::")
+    owner = mod
+    for class_name in class_path:
+        owner = getattr(owner, class_name)
+    if class_path:
+        owner = owner()  # type: ignore[operator]
+    return getattr(owner, name)
+
+
 def _configure_logging_from_env() -> None:
     """Opt-in worker logging via ``TRYKE_LOG``.
 
@@ -4,6 +4,7 @@ from tryke import CasesMarked, Depends, describe, expect, fixture, test
 from tryke.expect import _build_cases_table
 from tryke.hooks import HookExecutor
 from tryke.hooks import fixture as _fixture
+from tryke.runner import run_test
 
 with describe("test.cases typed form (test.case)"):
 
@@ -317,3 +318,68 @@ with describe("per-case modifiers"):
             lambda: _build_cases_table((), {"todo": {"skip": "oops"}}),
             "reserved kwarg in kwargs-form raises",
         ).to_raise(TypeError, match="reserved name")
+
+
+with describe("test.each"):
+
+    @test.each([(1, 2, 3), (2, 2, 4), (-1, 1, 0)])
+    def add_rows(a: int, b: int, total: int) -> None:
+        expect(a + b, "a + b matches total").to_equal(total)
+
+    @test
+    def each_labels_rows_by_repr() -> None:
+        @test.each([(1, "x"), (2.5, None)])
+        def pairs(*_args: object) -> None:
+            return
+
+        @test.each([7, [1, 2]])
+        def single(_value: object) -> None:
+            return
+
+        if not isinstance(pairs, CasesMarked) or not isinstance(single, CasesMarked):
+            msg = "decorator should produce a CasesMarked function"
+            raise TypeError(msg)
+        expect(
+            [e.label for e in pairs.__tryke_cases__], "tuple row labels"
+        ).to_equal(["1, 'x'", "2.5, None"])
+        expect(pairs.__tryke_cases__[0].args, "tuple rows splat").to_equal((1, "x"))
+        expect(
+            [e.label for e in single.__tryke_cases__], "bare row labels"
+        ).to_equal(["7", "[1, 2]"])
+        expect(
+            single.__tryke_cases__[1].args, "bare rows pass one argument"
+        ).to_equal(([1, 2],))
+
+    @test
+    def each_rejects_empty_rows() -> None:
+        def attempt() -> None:
+            @test.each([])
+            def _fn() -> None:
+                pass
+
+        expect(attempt, "empty @test.each raises").to_raise(
+            TypeError, match="at least one row"
+        )
+
+    @test
+    def each_without_label_runs_every_row() -> None:
+        seen: list[int] = []
+
+        @test.each([1, 2, 3])
+        def fn(n: int) -> None:
+            seen.append(n)
+
+        result = run_test(fn, executor=HookExecutor())
+        expect(result["outcome"], "all rows pass").to_equal("passed")
+        expect(seen, "every row ran in order").to_equal([1, 2, 3])
+
+    @test
+    def each_with_label_runs_one_row() -> None:
+        seen: list[int] = []
+
+        @test.each([1, 2, 3])
+        def fn(n: int) -> None:
+            seen.append(n)
+
+        run_test(fn, executor=HookExecutor(), case_label="2")
+        expect(seen, "only the labelled row ran").to_equal([2])
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import asyncio
 
+import tryke
 from tryke import describe, expect, test
 from tryke.expect import (
     Expectation,
@@ -73,6 +74,19 @@ with describe("expectations"):
         plain_base: Base = Base()
         expect(plain_base, "base is not derived").not_.to_be_instance_of(Derived)
 
+    @test(name="pending assertions are skipped")
+    def test_pending_assertions() -> None:
+        ctx = SoftContext()
+        _set_soft_context(ctx)
+        try:
+            expect(1).pending().to_equal(2)
+            expect(1).not_.pending().to_equal(1)
+            expect(1).to_equal(2)  # pending
+        finally:
+            _set_soft_context(None)
+        expect(ctx.failures, "pending assertions record no failures").to_equal([])
+        expect(ctx.executed_lines, "pending assertions never execute").to_equal([])
+
     @test(name="to_be_instance_of reports class names on failure")
     def test_to_be_instance_of_error_fields() -> None:
         ctx = SoftContext()
@@ -500,6 +514,27 @@ with describe("markers"):
             "xfail with name kwarg still stamps dunder",
         ).to_be_truthy()
 
+    @test(name="tryke.skip and tryke.xfail stack beside @test")
+    def test_sibling_markers_stack_beside_test() -> None:
+        @tryke.skip(reason="flaky")
+        @test
+        def skipped() -> None:
+            pass
+
+        @tryke.xfail
+        @test
+        def expected_fail() -> None:
+            pass
+
+        if not isinstance(skipped, _SkipMarked):
+            msg = "tryke.skip should stamp __tryke_skip__"
+            raise TypeError(msg)
+        expect(skipped.__tryke_skip__, "sibling skip stores reason").to_equal("flaky")
+        expect(
+            hasattr(expected_fail, "__tryke_xfail__"),
+            "sibling xfail stamps dunder attribute",
+        ).to_be_truthy()
+
     @test(name="skip_if(true) stamps __tryke_skip__")
     def test_skip_if_true_stamps_dunder() -> None:
         @test.skip_if(True, reason="always skip")  # noqa: FBT003
@@ -4,6 +4,7 @@ import asyncio
 import inspect
 import io
 import json
+import logging
 import sys
 import tempfile
 import traceback
@@ -15,6 +16,8 @@ from tryke import describe, expect, test
 from tryke.expect import ExpectationError, SoftFailure
 from tryke.runner import (
     _TRYKE_PKG,
+    BENCHMARK_ITERATIONS,
+    BENCHMARK_WARMUP,
     _is_user_frame,
     _make_assertion_wire,
     extract_soft_failures,
@@ -42,20 +45,15 @@ def _send(request: dict) -> dict:
     return json.loads(output_buf.getvalue().strip())
 
 
-def _run_test_fn(
-    fn: object,
-    *,
-    xfail: str | None = None,
-) -> dict:
-    """Execute *fn* via the worker run_test path and return the result."""
+def _run_test_lines(fn: object, **extra_params: object) -> list[dict]:
+    """Execute *fn* via the worker run_test path and return every line written."""
     mod = types.ModuleType("_tw")
     mod.__dict__["test_fn"] = fn
     params: dict[str, object] = {
         "module": "_tw",
         "function": "test_fn",
+        **extra_params,
     }
-    if xfail is not None:
-        params["xfail"] = xfail
     req: dict[str, object] = {
         "jsonrpc": "2.0",
         "id": 1,
@@ -67,7 +65,19 @@ def _run_test_fn(
     worker = Worker(input_buf, output_buf)
     worker._modules["_tw"] = mod  # noqa: SLF001
     worker.run()
-    resp = json.loads(output_buf.getvalue().strip())
+    return [json.loads(line) for line in output_buf.getvalue().splitlines()]
+
+
+def _run_test_fn(
+    fn: object,
+    *,
+    xfail: str | None = None,
+) -> dict:
+    """Execute *fn* via the worker run_test path and return the result."""
+    params: dict[str, object] = {}
+    if xfail is not None:
+        params["xfail"] = xfail
+    [resp] = _run_test_lines(fn, **params)
     return resp["result"]
 
 
@@ -104,6 +114,16 @@ with describe("json-rpc envelope"):
             "Expected code to be unreachable",
         )
 
+    @test(name="list_matchers reports matcher arity")
+    def test_list_matchers() -> None:
+        resp = _send(_rpc("list_matchers"))
+        specs = {spec["name"]: spec for spec in resp["result"]}
+        expect(specs["to_equal"], "to_equal takes one argument").to_equal(
+            {"name": "to_equal", "min_args": 1, "max_args": 1, "negatable": True},
+        )
+        expect(specs["to_raise"]["max_args"], "to_raise accepts match=").to_equal(2)
+        expect(specs, "only to_* methods are listed").not_.to_contain("not_")
+
     @test(name="missing required param returns -32602")
     def test_missing_param() -> None:
         resp = _send(_rpc("run_test"))
@@ -338,6 +358,63 @@ with describe("run_test outcomes"):
         result = _run_test_fn(fn, xfail="should fail")
         expect(result["outcome"], "unexpected pass reports xpassed").to_equal("xpassed")
 
+    @test(name="benchmark attaches timings to a passing result")
+    def test_benchmark_passing() -> None:
+        calls: list[int] = []
+
+        def fn() -> None:
+            calls.append(1)
+
+        [resp] = _run_test_lines(fn, benchmark=True)
+        stats = resp["result"]["benchmark"]
+        expect(stats["iterations"], "measured runs are reported").to_equal(
+            BENCHMARK_ITERATIONS
+        )
+        expect(len(calls), "initial, warmup, and measured calls").to_equal(
+            1 + BENCHMARK_WARMUP + BENCHMARK_ITERATIONS
+        )
+        expect(stats["min_ns"], "min is at most the mean").to_be_less_than_or_equal(
+            stats["mean_ns"]
+        )
+
+    @test(name="benchmark is skipped for failing tests")
+    def test_benchmark_failing() -> None:
+        def fn() -> None:
+            msg = "boom"
+            raise ValueError(msg)
+
+        [resp] = _run_test_lines(fn, benchmark=True)
+        expect(resp["result"]["outcome"], "test failed").to_equal("failed")
+        expect("benchmark" in resp["result"], "no timings on failure").to_be_falsy()
+
+    @test(name="class methods run on a fresh instance each")
+    def test_class_method() -> None:
+        class TestMath:
+            instances: list[object] = []
+
+            def test_add(self) -> None:
+                TestMath.instances.append(self)
+                expect(1 + 1).to_equal(2)
+
+        mod = types.ModuleType("_tw_class")
+        mod.__dict__["TestMath"] = TestMath
+        requests = [
+            _rpc("run_test", id_=i, module="_tw_class", function="TestMath::test_add")
+            for i in (1, 2)
+        ]
+        input_buf = io.StringIO("".join(json.dumps(r) + "\n" for r in requests))
+        output_buf = io.StringIO()
+        worker = Worker(input_buf, output_buf)
+        worker._modules["_tw_class"] = mod  # noqa: SLF001
+        worker.run()
+        outcomes = [
+            json.loads(line)["result"]["outcome"]
+            for line in output_buf.getvalue().splitlines()
+        ]
+        expect(outcomes, "both runs pass").to_equal(["passed", "passed"])
+        first, second = TestMath.instances
+        expect(first is second, "one instance per run").to_be_falsy()
+
     @test(name="xfail via marker attribute")
     def test_xfail_marker() -> None:
         def fn() -> None:
@@ -389,6 +466,56 @@ with describe("run_test outcomes"):
             "hello stderr"
         )
 
+    @test(name="logging records are captured")
+    def test_log_capture() -> None:
+        def fn() -> None:
+            log = logging.getLogger("tests.log_capture")
+            log.warning("cache miss for %s", "user 7")
+            log.debug("below the root level")
+            msg = "boom"
+            raise ValueError(msg)
+
+        result = _run_test_fn(fn)
+        expect(result["outcome"], "the test still fails").to_equal("failed")
+        expect(result.get("logs"), "warning record is captured").to_equal(
+            [{"level": "WARNING", "message": "cache miss for user 7"}]
+        )
+
+    @test(name="no logs key without log records")
+    def test_no_log_records() -> None:
+        def fn() -> None:
+            pass
+
+        result = _run_test_fn(fn)
+        expect("logs" in result, "logs omitted when nothing was logged").to_be_falsy()
+
+    @test(name="stream_assertions notifies each expect before the result")
+    def test_stream_assertions() -> None:
+        def fn() -> None:
+            expect(1).to_equal(1)
+            expect(2).not_.to_equal(2)
+
+        lines = _run_test_lines(fn, stream_assertions=True)
+        expect(lines, "two notifications then the response").to_have_length(3)
+        first, second, response = lines
+        expect(first["method"], "notification method").to_equal("assertion")
+        expect("id" in first, "notifications have no top-level id").to_be_falsy()
+        expect(first["params"]["id"], "tagged with the request id").to_equal(1)
+        expect(first["params"]["passed"], "first expect passed").to_be_truthy()
+        expect(second["params"]["passed"], "second expect failed").to_be_falsy()
+        expect(second["params"]["expected"], "negation in expected").to_equal("not 2")
+        expect(second["params"]["expression"], "source of the failing expect").to_equal(
+            "expect(2).not_.to_equal(2)"
+        )
+        expect(response["result"]["outcome"], "test still fails").to_equal("failed")
+
+    @test(name="no notifications without stream_assertions")
+    def test_no_stream_assertions() -> None:
+        def fn() -> None:
+            expect(1).to_equal(1)
+
+        expect(_run_test_lines(fn), "only the response").to_have_length(1)
+
     @test(name="import error returns failed with traceback")
     def test_import_error() -> None:
         resp = _send(